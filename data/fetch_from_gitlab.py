@@ -36,6 +36,7 @@ COMMIT = 'de4ac3cf96cb8a0893be22b03f5171d934f9d392'
 ISSUE_ID = 6 # https://gitlab.kitware.com/utils/rust-gitlab/-/issues/6
 MR_ID = 35 # https://gitlab.kitware.com/utils/rust-gitlab/-/merge_requests/35
 MR_DISCUSSION_ID = 158 # https://gitlab.kitware.com/utils/rust-gitlab/-/merge_requests/35
+MR_VERSION_ID = 13711
 NOTE_ID = 177359
 PIPELINE_ID = 145400
 PIPELINE2_ID = 168478
@@ -56,11 +57,18 @@ if __name__ == '__main__':
     write_result(token, 'commit_status', '/projects/%s/repository/commits/%s/statuses' % (REPO, COMMIT))
     write_result(token, 'issue', '/projects/%s/issues/%d' % (REPO, ISSUE_ID))
     write_result(token, 'merge_request', '/projects/%s/merge_requests/%d' % (REPO, MR_ID))
+    write_result(token, 'merge_request_commit', '/projects/%s/merge_requests/%d/commits' % (REPO, MR_ID))
+    write_result(token, 'merge_request_reviewer', '/projects/%s/merge_requests/%d/reviewers' % (REPO, MR_ID))
     write_result(token, 'issue_reference', '/projects/%s/merge_requests/%d/closes_issues' % (REPO, MR_ID))
+    write_result(token, 'merge_request_diff_version', '/projects/%s/merge_requests/%d/versions/%d' % (REPO, MR_ID, MR_VERSION_ID))
     write_result(token, 'note', '/projects/%s/merge_requests/%d/notes' % (REPO, MR_ID))
     write_result(token, 'discussion', '/projects/%s/merge_requests/%d/discussions' % (REPO, MR_ID), dumpall=True)
     write_result(token, 'award_emoji', '/projects/%s/merge_requests/%d/notes/%d/award_emoji' % (REPO, MR_ID, NOTE_ID))
     write_result(token, 'resource_label_event', '/projects/%s/issues/%d/resource_label_events' % (REPO, ISSUE_ID))
+    write_result(token, 'resource_milestone_event', '/projects/%s/issues/%d/resource_milestone_events' % (REPO, ISSUE_ID))
+    write_result(token, 'resource_state_event', '/projects/%s/merge_requests/%d/resource_state_events' % (REPO, MR_ID))
+    write_result(token, 'resource_iteration_event', '/projects/%s/issues/%d/resource_iteration_events' % (REPO, ISSUE_ID))
+    write_result(token, 'resource_weight_event', '/projects/%s/issues/%d/resource_weight_events' % (REPO, ISSUE_ID))
     write_result(token, 'pipeline_basic', '/projects/%s/pipelines' % REPO)
     write_result(token, 'pipeline', '/projects/%s/pipelines/%d' % (REPO, PIPELINE_ID))
     write_result(token, 'group', '/groups/%s' % GROUP_ID)
@@ -17,7 +17,8 @@
 //!
 //! # Example
 //!
-//! ```rust,no_run
+#![cfg_attr(feature = "client_sync", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "client_sync"), doc = "```rust,ignore")]
 //! use serde::Deserialize;
 //! use gitlab::Gitlab;
 //! use gitlab::api::{self, projects, Query};
@@ -39,6 +40,8 @@
 //! // For some endpoints (mainly `POST` endpoints), you may want to ignore the result.
 //! // `api::ignore` can be used to do this.
 //! let _: () = api::ignore(endpoint).query(&client).unwrap();
+//! // Endpoints which respond with `204 No Content` (e.g., most deletions) may also be queried
+//! // as `()` directly; errors are still reported with the details given by GitLab.
 //!
 //! // Some endpoints support pagination. They work on their own or via the `api::paged` function
 //! // to get further results.
@@ -47,6 +50,9 @@
 //! let first_page: Vec<Project> = pageable_endpoint.query(&client).unwrap();
 //! // `api::paged` can be used to get results up to some count or all results.
 //! let first_200_projects: Vec<Project> = api::paged(pageable_endpoint, api::Pagination::Limit(200)).query(&client).unwrap();
+//! // `api::single_page` gets a specific page along with metadata such as the number of pages.
+//! let pageable_endpoint = projects::Projects::builder().build().unwrap();
+//! let (third_page, page_info): (Vec<Project>, api::PageInfo) = api::single_page(pageable_endpoint, 3, 20).query(&client).unwrap();
 //!
 //! // Builders accept strings or integers for some fields. This is done wherever GitLab supports
 //! // either IDs or names being used.
@@ -55,49 +61,149 @@
 //! // usually meant for endpoints which represent file contents, pipeline artifacts, etc., but may
 //! // be used with any endpoint.
 //! let raw_data: Vec<u8> = api::raw(endpoint).query(&client).unwrap();
+//! // The `api::raw_with_metadata` function also returns the status and headers of the response.
+//! let endpoint = projects::Project::builder().project(278964).build().unwrap();
+//! let response: http::Response<bytes::Bytes> = api::raw_with_metadata(endpoint).query(&client).unwrap();
+//!
+//! // `api::exists` checks whether a resource exists using a `HEAD` request. Missing resources
+//! // are reported as `false` rather than an error.
+//! let endpoint = projects::Project::builder().project("gitlab-org/gitlab").build().unwrap();
+//! let project_exists: bool = api::exists(endpoint).query(&client).unwrap();
+//!
+//! // Expensive queries may override the timeout and retry policy of the client without changing
+//! // its configuration. The modifiers may be combined with each other and with other modifiers.
+//! let endpoint = projects::Project::builder().project(278964).build().unwrap();
+//! let slow_query = api::with_timeout(endpoint, std::time::Duration::from_secs(300));
+//! let project: Project = api::with_retry(slow_query, api::retry::RetryPolicy::default()).query(&client).unwrap();
+//! ```
+//!
+//! # Custom endpoints
+//!
+//! Endpoints which do not have a type in this crate yet may be called using
+//! `custom::CustomEndpoint`. For more control, the `Endpoint` trait may be implemented directly
+//! using the types in the `endpoint_prelude` module; these are part of the public API.
+//!
+#![cfg_attr(feature = "client_sync", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "client_sync"), doc = "```rust,ignore")]
+//! # use serde::Deserialize;
+//! # use gitlab::Gitlab;
+//! use gitlab::api::{self, common, custom, Query};
+//!
+//! # #[derive(Debug, Deserialize)]
+//! # struct Project {
+//! #     name: String,
+//! # }
+//! # let client = Gitlab::new("gitlab.com", "private-token").unwrap();
+//! let endpoint = custom::CustomEndpoint::builder()
+//!     .endpoint(format!("projects/{}", common::path_escaped("gitlab-org/gitlab")))
+//!     .query_param("statistics", true)
+//!     .build()
+//!     .unwrap();
+//! let project: Project = endpoint.query(&client).unwrap();
 //! ```
 
+#[cfg(feature = "response_cache")]
+mod cache;
 mod client;
+mod download;
 mod endpoint;
 mod error;
+mod exists;
+mod extension;
+mod head;
+mod headers;
 mod ignore;
+mod lenient;
 mod paged;
 mod params;
 pub(crate) mod query;
 mod raw;
 mod sudo;
+mod timeout;
 
 pub mod endpoint_prelude;
 
+pub mod analytics;
+pub mod application;
+pub mod audit_events;
+pub mod bulk_imports;
+pub mod clusters;
 pub mod common;
+pub mod custom;
 pub mod deploy_keys;
+pub mod events;
+pub mod features;
 pub mod groups;
+pub mod health;
 pub mod issues;
+pub mod keys;
+pub mod licenses;
+pub mod namespaces;
+pub mod oauth;
+pub mod personal_access_tokens;
 pub mod projects;
+pub mod rate_limit;
 pub mod retry;
+pub mod sidekiq;
 pub mod users;
+pub mod vulnerabilities;
 
 pub(crate) mod helpers;
 
+#[cfg(feature = "response_cache")]
+pub use self::cache::cached;
+#[cfg(feature = "response_cache")]
+pub use self::cache::Cached;
+#[cfg(feature = "response_cache")]
+pub use self::cache::CachingClient;
+#[cfg(feature = "response_cache")]
+pub use self::cache::ResponseCache;
+
 pub use self::client::AsyncClient;
+pub use self::client::BodyReader;
+pub use self::client::BodyStream;
 pub use self::client::Client;
 pub use self::client::RestClient;
 
+pub use self::download::download;
+pub use self::download::Download;
+
 pub use self::endpoint::Endpoint;
+pub use self::endpoint::UrlBase;
 
 pub use self::error::ApiError;
 pub use self::error::BodyError;
+pub use self::error::GitlabServerError;
+
+pub use self::exists::exists;
+pub use self::exists::Exists;
+
+pub use self::extension::ExtensionClient;
+
+pub use self::head::head;
+pub use self::head::Head;
+
+pub use self::headers::with_headers;
+pub use self::headers::HeaderClient;
+pub use self::headers::WithHeaders;
 
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
 
+pub use self::lenient::lenient;
+pub use self::lenient::LenientData;
+pub use self::lenient::LenientQuery;
+
 pub use self::paged::paged;
+pub use self::paged::single_page;
 pub use self::paged::LazilyPagedIter;
 pub use self::paged::LinkHeaderParseError;
+pub use self::paged::PageInfo;
 pub use self::paged::Pageable;
 pub use self::paged::Paged;
 pub use self::paged::Pagination;
 pub use self::paged::PaginationError;
+pub use self::paged::SinglePage;
 
 pub use self::params::FormParams;
 pub use self::params::ParamValue;
@@ -107,8 +213,17 @@ pub use self::query::AsyncQuery;
 pub use self::query::Query;
 
 pub use self::raw::raw;
+pub use self::raw::raw_with_metadata;
 pub use self::raw::Raw;
+pub use self::raw::RawWithMetadata;
+
+pub use self::retry::with_retry;
+pub use self::retry::WithRetry;
 
 pub use self::sudo::sudo;
 pub use self::sudo::Sudo;
 pub use self::sudo::SudoContext;
+
+pub use self::timeout::with_timeout;
+pub use self::timeout::QueryTimeout;
+pub use self::timeout::WithTimeout;
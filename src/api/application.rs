@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Application API endpoints.
+//!
+//! These endpoints are used for querying and configuring the GitLab instance itself.
+
+mod appearance;
+mod edit_appearance;
+mod edit_settings;
+mod settings;
+mod statistics;
+
+pub use self::appearance::ApplicationAppearance;
+pub use self::appearance::ApplicationAppearanceBuilder;
+pub use self::appearance::ApplicationAppearanceBuilderError;
+
+pub use self::edit_appearance::EditApplicationAppearance;
+pub use self::edit_appearance::EditApplicationAppearanceBuilder;
+pub use self::edit_appearance::EditApplicationAppearanceBuilderError;
+
+pub use self::edit_settings::EditApplicationSettings;
+pub use self::edit_settings::EditApplicationSettingsBuilder;
+pub use self::edit_settings::EditApplicationSettingsBuilderError;
+
+pub use self::settings::ApplicationSettings;
+pub use self::settings::ApplicationSettingsBuilder;
+pub use self::settings::ApplicationSettingsBuilderError;
+
+pub use self::statistics::ApplicationStatistics;
+pub use self::statistics::ApplicationStatisticsBuilder;
+pub use self::statistics::ApplicationStatisticsBuilderError;
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query the appearance of the GitLab instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ApplicationAppearance {}
+
+impl ApplicationAppearance {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ApplicationAppearanceBuilder {
+        ApplicationAppearanceBuilder::default()
+    }
+}
+
+impl Endpoint for ApplicationAppearance {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/appearance".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::application::ApplicationAppearance;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        ApplicationAppearance::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/appearance")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApplicationAppearance::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,434 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+#[derive(Debug, Clone)]
+struct AppearanceImage<'a> {
+    filename: Cow<'a, str>,
+    contents: Cow<'a, [u8]>,
+}
+
+impl<'a> AppearanceImage<'a> {
+    fn new<F, C>(filename: F, contents: C) -> Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        Self {
+            filename: filename.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// Edit the appearance of the GitLab instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditApplicationAppearance<'a> {
+    /// The title on the sign in and sign up pages.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// The description on the sign in and sign up pages (Markdown).
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The logo on the sign in and sign up pages.
+    #[builder(setter(name = "_logo"), default, private)]
+    logo: Option<AppearanceImage<'a>>,
+    /// The logo in the navigation bar.
+    #[builder(setter(name = "_header_logo"), default, private)]
+    header_logo: Option<AppearanceImage<'a>>,
+    /// The favicon.
+    #[builder(setter(name = "_favicon"), default, private)]
+    favicon: Option<AppearanceImage<'a>>,
+    /// Guidelines shown on the new project page (Markdown).
+    #[builder(setter(into), default)]
+    new_project_guidelines: Option<Cow<'a, str>>,
+    /// Guidelines shown on the profile page below the avatar (Markdown).
+    #[builder(setter(into), default)]
+    profile_image_guidelines: Option<Cow<'a, str>>,
+    /// A message shown in the header of every page.
+    #[builder(setter(into), default)]
+    header_message: Option<Cow<'a, str>>,
+    /// A message shown in the footer of every page.
+    #[builder(setter(into), default)]
+    footer_message: Option<Cow<'a, str>>,
+    /// The background color of the header and footer messages.
+    #[builder(setter(into), default)]
+    message_background_color: Option<Cow<'a, str>>,
+    /// The font color of the header and footer messages.
+    #[builder(setter(into), default)]
+    message_font_color: Option<Cow<'a, str>>,
+    /// Whether to add the header and footer messages to all outgoing emails.
+    #[builder(default)]
+    email_header_and_footer_enabled: Option<bool>,
+}
+
+impl<'a> EditApplicationAppearance<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditApplicationAppearanceBuilder<'a> {
+        EditApplicationAppearanceBuilder::default()
+    }
+}
+
+impl<'a> EditApplicationAppearanceBuilder<'a> {
+    /// Upload a new logo for the sign in and sign up pages.
+    ///
+    /// Uploading an image sends the request as a multipart form.
+    pub fn logo<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.logo = Some(Some(AppearanceImage::new(filename, contents)));
+        self
+    }
+
+    /// Upload a new logo for the navigation bar.
+    ///
+    /// Uploading an image sends the request as a multipart form.
+    pub fn header_logo<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.header_logo = Some(Some(AppearanceImage::new(filename, contents)));
+        self
+    }
+
+    /// Upload a new favicon.
+    ///
+    /// Uploading an image sends the request as a multipart form.
+    pub fn favicon<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.favicon = Some(Some(AppearanceImage::new(filename, contents)));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditApplicationAppearance<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/appearance".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("title", self.title.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt(
+                "new_project_guidelines",
+                self.new_project_guidelines.as_ref(),
+            )
+            .push_opt(
+                "profile_image_guidelines",
+                self.profile_image_guidelines.as_ref(),
+            )
+            .push_opt("header_message", self.header_message.as_ref())
+            .push_opt("footer_message", self.footer_message.as_ref())
+            .push_opt(
+                "message_background_color",
+                self.message_background_color.as_ref(),
+            )
+            .push_opt("message_font_color", self.message_font_color.as_ref())
+            .push_opt(
+                "email_header_and_footer_enabled",
+                self.email_header_and_footer_enabled,
+            );
+
+        let images = [
+            ("logo", self.logo.as_ref()),
+            ("header_logo", self.header_logo.as_ref()),
+            ("favicon", self.favicon.as_ref()),
+        ];
+        let files = images
+            .iter()
+            .filter_map(|(name, image)| {
+                image.map(|image| (*name, image.filename.as_ref(), image.contents.as_ref()))
+            })
+            .collect::<Vec<_>>();
+
+        if files.is_empty() {
+            params.into_body()
+        } else {
+            params.into_multipart_body_with_files(&files)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::application::EditApplicationAppearance;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditApplicationAppearance::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_project_guidelines() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("new_project_guidelines=new_project_guidelines")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .new_project_guidelines("new_project_guidelines")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_profile_image_guidelines() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("profile_image_guidelines=profile_image_guidelines")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .profile_image_guidelines("profile_image_guidelines")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_header_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("header_message=header_message")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .header_message("header_message")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_footer_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("footer_message=footer_message")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .footer_message("footer_message")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_message_background_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("message_background_color=message_background_color")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .message_background_color("message_background_color")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_message_font_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("message_font_color=message_font_color")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .message_font_color("message_font_color")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_email_header_and_footer_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email_header_and_footer_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .email_header_and_footer_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_logo() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"title\"\r\n",
+                "\r\n",
+                "title\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"logo\"; filename=\"logo.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "logo data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .title("title")
+            .logo("logo.png", &b"logo data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all_images() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/appearance")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"logo\"; filename=\"logo.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "logo data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"header_logo\"; filename=\"header.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "header data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"favicon\"; filename=\"favicon.ico\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "favicon data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationAppearance::builder()
+            .favicon("favicon.ico", &b"favicon data"[..])
+            .header_logo("header.png", &b"header data"[..])
+            .logo("logo.png", &b"logo data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
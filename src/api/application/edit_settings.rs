@@ -0,0 +1,732 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+
+use crate::api::common::VisibilityLevel;
+use crate::api::endpoint_prelude::*;
+use crate::api::groups::BranchProtection;
+
+/// Edit the settings of the GitLab instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditApplicationSettings<'a> {
+    /// Whether users may sign up for accounts.
+    #[builder(default)]
+    signup_enabled: Option<bool>,
+    /// Whether new accounts require administrator approval.
+    #[builder(default)]
+    require_admin_approval_after_user_signup: Option<bool>,
+    /// Whether to send confirmation emails for new accounts.
+    #[builder(default)]
+    send_user_confirmation_email: Option<bool>,
+    /// Whether password authentication is allowed for the web interface.
+    #[builder(default)]
+    password_authentication_enabled_for_web: Option<bool>,
+    /// Whether Gravatar avatars are enabled.
+    #[builder(default)]
+    gravatar_enabled: Option<bool>,
+    /// The URL to redirect to for unauthenticated visitors.
+    #[builder(setter(into), default)]
+    home_page_url: Option<Cow<'a, str>>,
+    /// The URL to redirect to after signing out.
+    #[builder(setter(into), default)]
+    after_sign_out_path: Option<Cow<'a, str>>,
+    /// The default branch name for new repositories.
+    #[builder(setter(into), default)]
+    default_branch_name: Option<Cow<'a, str>>,
+    /// The default branch protection for new projects.
+    #[builder(default)]
+    default_branch_protection: Option<BranchProtection>,
+    /// The default visibility of new projects.
+    #[builder(default)]
+    default_project_visibility: Option<VisibilityLevel>,
+    /// The default visibility of new groups.
+    #[builder(default)]
+    default_group_visibility: Option<VisibilityLevel>,
+    /// The default visibility of new snippets.
+    #[builder(default)]
+    default_snippet_visibility: Option<VisibilityLevel>,
+    /// The default number of projects a user may create.
+    #[builder(default)]
+    default_projects_limit: Option<u64>,
+    /// The maximum attachment size (in megabytes).
+    #[builder(default)]
+    max_attachment_size: Option<u64>,
+    /// The maximum import size (in megabytes).
+    #[builder(default)]
+    max_import_size: Option<u64>,
+    /// The maximum length of a web terminal session (in seconds).
+    #[builder(default)]
+    terminal_max_session_time: Option<u64>,
+    /// Whether to rate limit authenticated API requests.
+    #[builder(default)]
+    throttle_authenticated_api_enabled: Option<bool>,
+    /// The number of authenticated API requests allowed per period.
+    #[builder(default)]
+    throttle_authenticated_api_requests_per_period: Option<u64>,
+    /// The length of the authenticated API rate limit period (in seconds).
+    #[builder(default)]
+    throttle_authenticated_api_period_in_seconds: Option<u64>,
+    /// Whether to rate limit authenticated web requests.
+    #[builder(default)]
+    throttle_authenticated_web_enabled: Option<bool>,
+    /// The number of authenticated web requests allowed per period.
+    #[builder(default)]
+    throttle_authenticated_web_requests_per_period: Option<u64>,
+    /// The length of the authenticated web rate limit period (in seconds).
+    #[builder(default)]
+    throttle_authenticated_web_period_in_seconds: Option<u64>,
+    /// Whether to rate limit unauthenticated requests.
+    #[builder(default)]
+    throttle_unauthenticated_enabled: Option<bool>,
+    /// The number of unauthenticated requests allowed per period.
+    #[builder(default)]
+    throttle_unauthenticated_requests_per_period: Option<u64>,
+    /// The length of the unauthenticated rate limit period (in seconds).
+    #[builder(default)]
+    throttle_unauthenticated_period_in_seconds: Option<u64>,
+
+    /// Additional settings to set.
+    ///
+    /// These are passed through as-is for settings which do not have dedicated fields.
+    #[builder(setter(name = "_extra_settings"), default, private)]
+    extra_settings: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+}
+
+impl<'a> EditApplicationSettings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditApplicationSettingsBuilder<'a> {
+        EditApplicationSettingsBuilder::default()
+    }
+}
+
+impl<'a> EditApplicationSettingsBuilder<'a> {
+    /// Set a setting which does not have a dedicated builder method.
+    pub fn extra_setting<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.extra_settings
+            .get_or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Set multiple settings which do not have dedicated builder methods.
+    pub fn extra_settings<I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        self.extra_settings
+            .get_or_insert_with(BTreeMap::new)
+            .extend(iter.map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl<'a> Endpoint for EditApplicationSettings<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/settings".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("signup_enabled", self.signup_enabled)
+            .push_opt(
+                "require_admin_approval_after_user_signup",
+                self.require_admin_approval_after_user_signup,
+            )
+            .push_opt(
+                "send_user_confirmation_email",
+                self.send_user_confirmation_email,
+            )
+            .push_opt(
+                "password_authentication_enabled_for_web",
+                self.password_authentication_enabled_for_web,
+            )
+            .push_opt("gravatar_enabled", self.gravatar_enabled)
+            .push_opt("home_page_url", self.home_page_url.as_ref())
+            .push_opt("after_sign_out_path", self.after_sign_out_path.as_ref())
+            .push_opt("default_branch_name", self.default_branch_name.as_ref())
+            .push_opt("default_branch_protection", self.default_branch_protection)
+            .push_opt(
+                "default_project_visibility",
+                self.default_project_visibility,
+            )
+            .push_opt("default_group_visibility", self.default_group_visibility)
+            .push_opt(
+                "default_snippet_visibility",
+                self.default_snippet_visibility,
+            )
+            .push_opt("default_projects_limit", self.default_projects_limit)
+            .push_opt("max_attachment_size", self.max_attachment_size)
+            .push_opt("max_import_size", self.max_import_size)
+            .push_opt("terminal_max_session_time", self.terminal_max_session_time)
+            .push_opt(
+                "throttle_authenticated_api_enabled",
+                self.throttle_authenticated_api_enabled,
+            )
+            .push_opt(
+                "throttle_authenticated_api_requests_per_period",
+                self.throttle_authenticated_api_requests_per_period,
+            )
+            .push_opt(
+                "throttle_authenticated_api_period_in_seconds",
+                self.throttle_authenticated_api_period_in_seconds,
+            )
+            .push_opt(
+                "throttle_authenticated_web_enabled",
+                self.throttle_authenticated_web_enabled,
+            )
+            .push_opt(
+                "throttle_authenticated_web_requests_per_period",
+                self.throttle_authenticated_web_requests_per_period,
+            )
+            .push_opt(
+                "throttle_authenticated_web_period_in_seconds",
+                self.throttle_authenticated_web_period_in_seconds,
+            )
+            .push_opt(
+                "throttle_unauthenticated_enabled",
+                self.throttle_unauthenticated_enabled,
+            )
+            .push_opt(
+                "throttle_unauthenticated_requests_per_period",
+                self.throttle_unauthenticated_requests_per_period,
+            )
+            .push_opt(
+                "throttle_unauthenticated_period_in_seconds",
+                self.throttle_unauthenticated_period_in_seconds,
+            )
+            .extend(
+                self.extra_settings
+                    .iter()
+                    .map(|(key, value)| (key.as_ref(), value)),
+            );
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::application::EditApplicationSettings;
+    use crate::api::common::VisibilityLevel;
+    use crate::api::groups::BranchProtection;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        EditApplicationSettings::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_signup_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("signup_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .signup_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_require_admin_approval_after_user_signup() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("require_admin_approval_after_user_signup=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .require_admin_approval_after_user_signup(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_send_user_confirmation_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("send_user_confirmation_email=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .send_user_confirmation_email(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_password_authentication_enabled_for_web() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("password_authentication_enabled_for_web=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .password_authentication_enabled_for_web(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_gravatar_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("gravatar_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .gravatar_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_home_page_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("home_page_url=home_page_url")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .home_page_url("home_page_url")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_after_sign_out_path() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("after_sign_out_path=after_sign_out_path")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .after_sign_out_path("after_sign_out_path")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_branch_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_branch_name=default_branch_name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_branch_name("default_branch_name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_branch_protection() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_branch_protection=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_branch_protection(BranchProtection::Full)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_project_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_project_visibility=internal")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_project_visibility(VisibilityLevel::Internal)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_group_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_group_visibility=internal")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_group_visibility(VisibilityLevel::Internal)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_snippet_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_snippet_visibility=internal")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_snippet_visibility(VisibilityLevel::Internal)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_default_projects_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("default_projects_limit=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .default_projects_limit(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_attachment_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_attachment_size=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .max_attachment_size(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_import_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_import_size=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .max_import_size(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_terminal_max_session_time() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("terminal_max_session_time=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .terminal_max_session_time(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_api_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_api_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_api_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_api_requests_per_period() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_api_requests_per_period=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_api_requests_per_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_api_period_in_seconds() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_api_period_in_seconds=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_api_period_in_seconds(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_web_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_web_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_web_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_web_requests_per_period() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_web_requests_per_period=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_web_requests_per_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_authenticated_web_period_in_seconds() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_authenticated_web_period_in_seconds=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_authenticated_web_period_in_seconds(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_unauthenticated_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_unauthenticated_enabled=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_unauthenticated_enabled(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_unauthenticated_requests_per_period() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_unauthenticated_requests_per_period=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_unauthenticated_requests_per_period(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_throttle_unauthenticated_period_in_seconds() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("throttle_unauthenticated_period_in_seconds=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .throttle_unauthenticated_period_in_seconds(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_extra_settings() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("application/settings")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "signup_enabled=false",
+                "&key=value",
+                "&key2=value",
+                "&key3=value%26value",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditApplicationSettings::builder()
+            .signup_enabled(false)
+            .extra_setting("key", "value")
+            .extra_settings([("key2", "value"), ("key3", "value&value")].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query statistics about the GitLab instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ApplicationStatistics {}
+
+impl ApplicationStatistics {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ApplicationStatisticsBuilder {
+        ApplicationStatisticsBuilder::default()
+    }
+}
+
+impl Endpoint for ApplicationStatistics {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "application/statistics".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::application::ApplicationStatistics;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        ApplicationStatistics::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("application/statistics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ApplicationStatistics::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,23 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Audit event API endpoints.
+//!
+//! These endpoints are used for querying audit events of the instance.
+
+mod audit_event;
+mod audit_events;
+
+pub use self::audit_event::AuditEvent;
+pub use self::audit_event::AuditEventBuilder;
+pub use self::audit_event::AuditEventBuilderError;
+
+pub use self::audit_events::AuditEventEntityType;
+pub use self::audit_events::AuditEvents;
+pub use self::audit_events::AuditEventsBuilder;
+pub use self::audit_events::AuditEventsBuilderError;
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an audit event of the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct AuditEvent {
+    /// The ID of the audit event.
+    audit_event: u64,
+}
+
+impl AuditEvent {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AuditEventBuilder {
+        AuditEventBuilder::default()
+    }
+}
+
+impl Endpoint for AuditEvent {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("audit_events/{}", self.audit_event).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::audit_events::{AuditEvent, AuditEventBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn audit_event_is_needed() {
+        let err = AuditEvent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AuditEventBuilderError, "audit_event");
+    }
+
+    #[test]
+    fn audit_event_is_sufficient() {
+        AuditEvent::builder().audit_event(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("audit_events/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuditEvent::builder().audit_event(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Bulk import API endpoints.
+//!
+//! These endpoints are used for migrating groups and projects from another GitLab instance
+//! (also known as "direct transfer") and monitoring the progress of those migrations.
+
+mod all_entities;
+mod bulk_import;
+mod bulk_imports;
+mod create;
+mod entities;
+mod entity;
+
+pub use self::all_entities::AllBulkImportEntities;
+pub use self::all_entities::AllBulkImportEntitiesBuilder;
+pub use self::all_entities::AllBulkImportEntitiesBuilderError;
+
+pub use self::bulk_import::BulkImport;
+pub use self::bulk_import::BulkImportBuilder;
+pub use self::bulk_import::BulkImportBuilderError;
+
+pub use self::bulk_imports::BulkImportStatus;
+pub use self::bulk_imports::BulkImports;
+pub use self::bulk_imports::BulkImportsBuilder;
+pub use self::bulk_imports::BulkImportsBuilderError;
+
+pub use self::create::BulkImportEntityDefinition;
+pub use self::create::BulkImportEntityDefinitionBuilder;
+pub use self::create::BulkImportEntityDefinitionBuilderError;
+pub use self::create::BulkImportSourceType;
+pub use self::create::CreateBulkImport;
+pub use self::create::CreateBulkImportBuilder;
+pub use self::create::CreateBulkImportBuilderError;
+
+pub use self::entities::BulkImportEntities;
+pub use self::entities::BulkImportEntitiesBuilder;
+pub use self::entities::BulkImportEntitiesBuilderError;
+
+pub use self::entity::BulkImportEntity;
+pub use self::entity::BulkImportEntityBuilder;
+pub use self::entity::BulkImportEntityBuilderError;
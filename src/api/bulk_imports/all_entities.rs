@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::bulk_imports::BulkImportStatus;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the entities of all bulk imports started by the current user.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct AllBulkImportEntities {
+    /// Filter entities by status.
+    #[builder(default)]
+    status: Option<BulkImportStatus>,
+}
+
+impl AllBulkImportEntities {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AllBulkImportEntitiesBuilder {
+        AllBulkImportEntitiesBuilder::default()
+    }
+}
+
+impl Endpoint for AllBulkImportEntities {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "bulk_imports/entities".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("status", self.status);
+
+        params
+    }
+}
+
+impl Pageable for AllBulkImportEntities {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bulk_imports::{AllBulkImportEntities, BulkImportStatus};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        AllBulkImportEntities::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/entities")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllBulkImportEntities::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/entities")
+            .add_query_params(&[("status", "finished")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AllBulkImportEntities::builder()
+            .status(BulkImportStatus::Finished)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a bulk import.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct BulkImport {
+    /// The ID of the bulk import.
+    bulk_import: u64,
+}
+
+impl BulkImport {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BulkImportBuilder {
+        BulkImportBuilder::default()
+    }
+}
+
+impl Endpoint for BulkImport {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("bulk_imports/{}", self.bulk_import).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bulk_imports::{BulkImport, BulkImportBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn bulk_import_is_needed() {
+        let err = BulkImport::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BulkImportBuilderError, "bulk_import");
+    }
+
+    #[test]
+    fn bulk_import_is_sufficient() {
+        BulkImport::builder().bulk_import(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImport::builder().bulk_import(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::SortOrder;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The status of a bulk import or of an entity within it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BulkImportStatus {
+    /// The import has been created.
+    Created,
+    /// The import has started.
+    Started,
+    /// The import has finished.
+    Finished,
+    /// The import timed out.
+    Timeout,
+    /// The import failed.
+    Failed,
+}
+
+impl BulkImportStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            BulkImportStatus::Created => "created",
+            BulkImportStatus::Started => "started",
+            BulkImportStatus::Finished => "finished",
+            BulkImportStatus::Timeout => "timeout",
+            BulkImportStatus::Failed => "failed",
+        }
+    }
+}
+
+impl ParamValue<'static> for BulkImportStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for bulk imports started by the current user.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct BulkImports {
+    /// Filter bulk imports by status.
+    #[builder(default)]
+    status: Option<BulkImportStatus>,
+    /// The sort order for the returned results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl BulkImports {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BulkImportsBuilder {
+        BulkImportsBuilder::default()
+    }
+}
+
+impl Endpoint for BulkImports {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "bulk_imports".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("status", self.status)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl Pageable for BulkImports {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bulk_imports::{BulkImportStatus, BulkImports};
+    use crate::api::common::SortOrder;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        BulkImports::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImports::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports")
+            .add_query_params(&[("status", "finished")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImports::builder()
+            .status(BulkImportStatus::Finished)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports")
+            .add_query_params(&[("sort", "asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImports::builder()
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn bulk_import_status_as_str() {
+        let items = &[
+            (BulkImportStatus::Created, "created"),
+            (BulkImportStatus::Started, "started"),
+            (BulkImportStatus::Finished, "finished"),
+            (BulkImportStatus::Timeout, "timeout"),
+            (BulkImportStatus::Failed, "failed"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+}
@@ -0,0 +1,344 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// The type of an entity to import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BulkImportSourceType {
+    /// Import a group.
+    Group,
+    /// Import a project.
+    Project,
+}
+
+impl BulkImportSourceType {
+    /// The source type parameter.
+    fn as_str(self) -> &'static str {
+        match self {
+            BulkImportSourceType::Group => "group_entity",
+            BulkImportSourceType::Project => "project_entity",
+        }
+    }
+}
+
+/// An entity to import as part of a bulk import.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct BulkImportEntityDefinition<'a> {
+    /// The type of the entity.
+    pub source_type: BulkImportSourceType,
+    /// The full path of the entity on the source instance.
+    #[builder(setter(into))]
+    pub source_full_path: Cow<'a, str>,
+    /// The slug of the entity on the destination instance.
+    #[builder(setter(into))]
+    pub destination_slug: Cow<'a, str>,
+    /// The full path of the namespace to import the entity into.
+    #[builder(setter(into))]
+    pub destination_namespace: Cow<'a, str>,
+    /// Whether to also import the projects of a group.
+    ///
+    /// GitLab defaults to `true`.
+    #[builder(default)]
+    pub migrate_projects: Option<bool>,
+}
+
+impl<'a> BulkImportEntityDefinition<'a> {
+    /// Create a builder for the entity.
+    pub fn builder() -> BulkImportEntityDefinitionBuilder<'a> {
+        BulkImportEntityDefinitionBuilder::default()
+    }
+}
+
+/// Start a bulk import of groups and projects from another GitLab instance.
+#[derive(Debug, Builder)]
+pub struct CreateBulkImport<'a> {
+    /// The URL of the source GitLab instance.
+    #[builder(setter(into))]
+    url: Cow<'a, str>,
+    /// An access token for the source GitLab instance.
+    #[builder(setter(into))]
+    access_token: Cow<'a, str>,
+
+    /// The entities to import.
+    #[builder(setter(name = "_entities"), default, private)]
+    entities: Vec<BulkImportEntityDefinition<'a>>,
+}
+
+impl<'a> CreateBulkImport<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateBulkImportBuilder<'a> {
+        CreateBulkImportBuilder::default()
+    }
+}
+
+impl<'a> CreateBulkImportBuilder<'a> {
+    /// Add an entity to import.
+    pub fn entity(&mut self, entity: BulkImportEntityDefinition<'a>) -> &mut Self {
+        self.entities.get_or_insert_with(Vec::new).push(entity);
+        self
+    }
+
+    /// Add multiple entities to import.
+    pub fn entities<I, E>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = E>,
+        E: Into<BulkImportEntityDefinition<'a>>,
+    {
+        self.entities
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> Endpoint for CreateBulkImport<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "bulk_imports".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("configuration[url]", &self.url)
+            .push("configuration[access_token]", &self.access_token);
+
+        self.entities.iter().for_each(|entity| {
+            params
+                .push("entities[][source_type]", entity.source_type.as_str())
+                .push("entities[][source_full_path]", &entity.source_full_path)
+                .push("entities[][destination_slug]", &entity.destination_slug)
+                .push(
+                    "entities[][destination_namespace]",
+                    &entity.destination_namespace,
+                )
+                .push_opt("entities[][migrate_projects]", entity.migrate_projects);
+        });
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::bulk_imports::{
+        BulkImportEntityDefinition, BulkImportEntityDefinitionBuilderError, BulkImportSourceType,
+        CreateBulkImport, CreateBulkImportBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn bulk_import_source_type_as_str() {
+        let items = &[
+            (BulkImportSourceType::Group, "group_entity"),
+            (BulkImportSourceType::Project, "project_entity"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn entity_fields_are_needed() {
+        let err = BulkImportEntityDefinition::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkImportEntityDefinitionBuilderError,
+            "source_type",
+        );
+    }
+
+    #[test]
+    fn entity_source_type_is_needed() {
+        let err = BulkImportEntityDefinition::builder()
+            .source_full_path("source/group")
+            .destination_slug("group")
+            .destination_namespace("destination")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkImportEntityDefinitionBuilderError,
+            "source_type",
+        );
+    }
+
+    #[test]
+    fn entity_source_full_path_is_needed() {
+        let err = BulkImportEntityDefinition::builder()
+            .source_type(BulkImportSourceType::Group)
+            .destination_slug("group")
+            .destination_namespace("destination")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkImportEntityDefinitionBuilderError,
+            "source_full_path",
+        );
+    }
+
+    #[test]
+    fn entity_destination_slug_is_needed() {
+        let err = BulkImportEntityDefinition::builder()
+            .source_type(BulkImportSourceType::Group)
+            .source_full_path("source/group")
+            .destination_namespace("destination")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkImportEntityDefinitionBuilderError,
+            "destination_slug",
+        );
+    }
+
+    #[test]
+    fn entity_destination_namespace_is_needed() {
+        let err = BulkImportEntityDefinition::builder()
+            .source_type(BulkImportSourceType::Group)
+            .source_full_path("source/group")
+            .destination_slug("group")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BulkImportEntityDefinitionBuilderError,
+            "destination_namespace",
+        );
+    }
+
+    #[test]
+    fn entity_required_fields_are_sufficient() {
+        BulkImportEntityDefinition::builder()
+            .source_type(BulkImportSourceType::Group)
+            .source_full_path("source/group")
+            .destination_slug("group")
+            .destination_namespace("destination")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn url_and_access_token_are_needed() {
+        let err = CreateBulkImport::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBulkImportBuilderError, "url");
+    }
+
+    #[test]
+    fn url_is_needed() {
+        let err = CreateBulkImport::builder()
+            .access_token("token")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBulkImportBuilderError, "url");
+    }
+
+    #[test]
+    fn access_token_is_needed() {
+        let err = CreateBulkImport::builder()
+            .url("https://gitlab.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateBulkImportBuilderError, "access_token");
+    }
+
+    #[test]
+    fn url_and_access_token_are_sufficient() {
+        CreateBulkImport::builder()
+            .url("https://gitlab.example.com")
+            .access_token("token")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("bulk_imports")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "configuration%5Burl%5D=https%3A%2F%2Fgitlab.example.com",
+                "&configuration%5Baccess_token%5D=token",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBulkImport::builder()
+            .url("https://gitlab.example.com")
+            .access_token("token")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_entities() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("bulk_imports")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "configuration%5Burl%5D=https%3A%2F%2Fgitlab.example.com",
+                "&configuration%5Baccess_token%5D=token",
+                "&entities%5B%5D%5Bsource_type%5D=group_entity",
+                "&entities%5B%5D%5Bsource_full_path%5D=source%2Fgroup",
+                "&entities%5B%5D%5Bdestination_slug%5D=group",
+                "&entities%5B%5D%5Bdestination_namespace%5D=destination",
+                "&entities%5B%5D%5Bmigrate_projects%5D=false",
+                "&entities%5B%5D%5Bsource_type%5D=project_entity",
+                "&entities%5B%5D%5Bsource_full_path%5D=source%2Fproject",
+                "&entities%5B%5D%5Bdestination_slug%5D=project",
+                "&entities%5B%5D%5Bdestination_namespace%5D=destination",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateBulkImport::builder()
+            .url("https://gitlab.example.com")
+            .access_token("token")
+            .entity(
+                BulkImportEntityDefinition::builder()
+                    .source_type(BulkImportSourceType::Group)
+                    .source_full_path("source/group")
+                    .destination_slug("group")
+                    .destination_namespace("destination")
+                    .migrate_projects(false)
+                    .build()
+                    .unwrap(),
+            )
+            .entities(
+                [BulkImportEntityDefinition::builder()
+                    .source_type(BulkImportSourceType::Project)
+                    .source_full_path("source/project")
+                    .destination_slug("project")
+                    .destination_namespace("destination")
+                    .build()
+                    .unwrap()]
+                .iter()
+                .cloned(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,104 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::bulk_imports::BulkImportStatus;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the entities of a bulk import.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct BulkImportEntities {
+    /// The ID of the bulk import.
+    bulk_import: u64,
+
+    /// Filter entities by status.
+    #[builder(default)]
+    status: Option<BulkImportStatus>,
+}
+
+impl BulkImportEntities {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BulkImportEntitiesBuilder {
+        BulkImportEntitiesBuilder::default()
+    }
+}
+
+impl Endpoint for BulkImportEntities {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("bulk_imports/{}/entities", self.bulk_import).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("status", self.status);
+
+        params
+    }
+}
+
+impl Pageable for BulkImportEntities {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bulk_imports::{
+        BulkImportEntities, BulkImportEntitiesBuilderError, BulkImportStatus,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn bulk_import_is_needed() {
+        let err = BulkImportEntities::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BulkImportEntitiesBuilderError, "bulk_import");
+    }
+
+    #[test]
+    fn bulk_import_is_sufficient() {
+        BulkImportEntities::builder()
+            .bulk_import(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/1/entities")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImportEntities::builder()
+            .bulk_import(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/1/entities")
+            .add_query_params(&[("status", "finished")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImportEntities::builder()
+            .bulk_import(1)
+            .status(BulkImportStatus::Finished)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an entity of a bulk import.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct BulkImportEntity {
+    /// The ID of the bulk import.
+    bulk_import: u64,
+    /// The ID of the entity.
+    entity: u64,
+}
+
+impl BulkImportEntity {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BulkImportEntityBuilder {
+        BulkImportEntityBuilder::default()
+    }
+}
+
+impl Endpoint for BulkImportEntity {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("bulk_imports/{}/entities/{}", self.bulk_import, self.entity).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::bulk_imports::{BulkImportEntity, BulkImportEntityBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn bulk_import_and_entity_are_needed() {
+        let err = BulkImportEntity::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BulkImportEntityBuilderError, "bulk_import");
+    }
+
+    #[test]
+    fn bulk_import_is_needed() {
+        let err = BulkImportEntity::builder().entity(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, BulkImportEntityBuilderError, "bulk_import");
+    }
+
+    #[test]
+    fn entity_is_needed() {
+        let err = BulkImportEntity::builder()
+            .bulk_import(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, BulkImportEntityBuilderError, "entity");
+    }
+
+    #[test]
+    fn bulk_import_and_entity_are_sufficient() {
+        BulkImportEntity::builder()
+            .bulk_import(1)
+            .entity(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bulk_imports/1/entities/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BulkImportEntity::builder()
+            .bulk_import(1)
+            .entity(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
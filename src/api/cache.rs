@@ -0,0 +1,567 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{HeaderMap, Method, Response, StatusCode};
+use url::Url;
+
+use crate::api::{
+    ApiError, AsyncClient, AsyncQuery, BodyReader, BodyStream, Client, Query, RestClient,
+};
+use crate::backend::Instant;
+
+/// The key of a cached response: the client's identity and the URL and headers of the request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    identity: Option<u64>,
+    uri: String,
+    headers: Vec<(String, Vec<u8>)>,
+}
+
+impl CacheKey {
+    /// The key for a request, if it may be cached.
+    fn new(identity: Option<u64>, request: &RequestBuilder) -> Option<Self> {
+        if request.method_ref() != Some(&Method::GET) {
+            return None;
+        }
+
+        let uri = request.uri_ref()?.to_string();
+        // Headers such as `Sudo` change the response, so they are part of the key.
+        let mut headers = request
+            .headers_ref()
+            .map(|headers| {
+                headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str().into(), value.as_bytes().into()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        headers.sort();
+
+        Some(Self {
+            identity,
+            uri,
+            headers,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    expires: Instant,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl CachedResponse {
+    fn new(rsp: &Response<Bytes>, expires: Instant) -> Self {
+        Self {
+            expires,
+            status: rsp.status(),
+            headers: rsp.headers().clone(),
+            body: rsp.body().clone(),
+        }
+    }
+
+    fn to_response(&self) -> Response<Bytes> {
+        let mut rsp = Response::new(self.body.clone());
+        *rsp.status_mut() = self.status;
+        *rsp.headers_mut() = self.headers.clone();
+        rsp
+    }
+}
+
+#[derive(Debug)]
+struct CacheState {
+    entries: HashMap<CacheKey, CachedResponse>,
+}
+
+/// A cache of responses to `GET` requests.
+///
+/// Successful responses are kept for a fixed amount of time. This is meant for lookups which
+/// are repeated often and whose results rarely change (e.g., resolving users by username or
+/// projects by path). The cache may be cloned cheaply; clones share their entries.
+///
+/// Responses are keyed by the identity of the client (see `RestClient::identity`), so a cache
+/// may be shared between clients which authenticate as different users.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    ttl: Duration,
+    max_entries: usize,
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl ResponseCache {
+    /// The default maximum number of cached responses.
+    const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+    /// Create a cache which keeps responses for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            state: Arc::new(Mutex::new(CacheState {
+                entries: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Set the maximum number of cached responses.
+    ///
+    /// Once full, expired responses are dropped. If none have expired, the response which
+    /// expires first is dropped. Defaults to 1024.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// The number of cached responses (including expired ones which have not been dropped).
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached responses.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Response<Bytes>> {
+        let mut state = self.state.lock().unwrap();
+        let cached = state.entries.get(key)?;
+        if cached.expires > Instant::now() {
+            Some(cached.to_response())
+        } else {
+            state.entries.remove(key);
+            None
+        }
+    }
+
+    fn insert(&self, key: CacheKey, rsp: &Response<Bytes>) {
+        if !rsp.status().is_success() || self.max_entries == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if state.entries.len() >= self.max_entries && !state.entries.contains_key(&key) {
+            state.entries.retain(|_, cached| cached.expires > now);
+            if state.entries.len() >= self.max_entries {
+                let oldest = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, cached)| cached.expires)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    state.entries.remove(&oldest);
+                }
+            }
+        }
+        state
+            .entries
+            .insert(key, CachedResponse::new(rsp, now + self.ttl));
+    }
+}
+
+/// A client which answers `GET` requests from a response cache.
+///
+/// Streamed responses are never cached.
+#[derive(Debug)]
+pub struct CachingClient<'a, C> {
+    client: &'a C,
+    cache: &'a ResponseCache,
+}
+
+impl<'a, C> CachingClient<'a, C> {
+    /// Create a client which caches the responses of `client` in `cache`.
+    pub fn new(client: &'a C, cache: &'a ResponseCache) -> Self {
+        Self {
+            client,
+            cache,
+        }
+    }
+}
+
+impl<'a, C> RestClient for CachingClient<'a, C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.instance_endpoint(endpoint)
+    }
+
+    fn identity(&self) -> Option<u64> {
+        self.client.identity()
+    }
+}
+
+impl<'a, C> Client for CachingClient<'a, C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = CacheKey::new(self.client.identity(), &request);
+        if let Some(rsp) = key.as_ref().and_then(|key| self.cache.get(key)) {
+            return Ok(rsp);
+        }
+
+        let rsp = self.client.rest(request, body)?;
+        if let Some(key) = key {
+            self.cache.insert(key, &rsp);
+        }
+        Ok(rsp)
+    }
+
+    fn rest_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyReader>, ApiError<Self::Error>> {
+        self.client.rest_stream(request, body)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<'a, C> AsyncClient for CachingClient<'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = CacheKey::new(self.client.identity(), &request);
+        if let Some(rsp) = key.as_ref().and_then(|key| self.cache.get(key)) {
+            return Ok(rsp);
+        }
+
+        let rsp = self.client.rest_async(request, body).await?;
+        if let Some(key) = key {
+            self.cache.insert(key, &rsp);
+        }
+        Ok(rsp)
+    }
+
+    async fn rest_stream_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.client.rest_stream_async(request, body).await
+    }
+}
+
+/// A query modifier that answers the query from a response cache if possible.
+#[derive(Debug, Clone)]
+pub struct Cached<'a, Q> {
+    query: Q,
+    cache: &'a ResponseCache,
+}
+
+/// Answer a query from a response cache if possible.
+///
+/// Only successful responses to `GET` requests are cached, so endpoints which modify data are
+/// always sent to the server. Requires the `response_cache` feature.
+pub fn cached<Q>(query: Q, cache: &ResponseCache) -> Cached<'_, Q> {
+    Cached {
+        query,
+        cache,
+    }
+}
+
+impl<'a, Q, T, C> Query<T, C> for Cached<'a, Q>
+where
+    Q: for<'b> Query<T, CachingClient<'b, C>>,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = CachingClient::new(client, self.cache);
+        self.query.query(&client)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<'a, Q, T, C> AsyncQuery<T, C> for Cached<'a, Q>
+where
+    Q: for<'b> AsyncQuery<T, CachingClient<'b, C>> + Sync,
+    T: Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = CachingClient::new(client, self.cache);
+        self.query.query_async(&client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::{Response, StatusCode};
+    use serde_json::json;
+    use url::Url;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{
+        self, ApiError, AsyncClient, AsyncQuery, Client, Query, ResponseCache, RestClient,
+    };
+    use crate::test::client::TestClientError;
+
+    struct Dummy {
+        method: Method,
+        id: u64,
+    }
+
+    impl Dummy {
+        fn get(id: u64) -> Self {
+            Self {
+                method: Method::GET,
+                id,
+            }
+        }
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            self.method.clone()
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            format!("dummy/{}", self.id).into()
+        }
+    }
+
+    #[derive(Default)]
+    struct CountingClient {
+        requests: Mutex<Vec<String>>,
+        status: Option<StatusCode>,
+        identity: Option<u64>,
+    }
+
+    impl CountingClient {
+        fn failing(status: StatusCode) -> Self {
+            Self {
+                status: Some(status),
+                ..Self::default()
+            }
+        }
+
+        fn with_identity(identity: u64) -> Self {
+            Self {
+                identity: Some(identity),
+                ..Self::default()
+            }
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+
+        fn respond(&self, request: RequestBuilder) -> Response<Bytes> {
+            let uri = request.uri_ref().unwrap().to_string();
+            let mut requests = self.requests.lock().unwrap();
+            requests.push(uri.clone());
+            let body = json!({
+                "uri": uri,
+                "count": requests.len(),
+            });
+            let mut rsp = Response::new(serde_json::to_vec(&body).unwrap().into());
+            if let Some(status) = self.status {
+                *rsp.status_mut() = status;
+            }
+            rsp
+        }
+    }
+
+    impl RestClient for CountingClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+
+        fn identity(&self) -> Option<u64> {
+            self.identity
+        }
+    }
+
+    impl Client for CountingClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            Ok(self.respond(request))
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for CountingClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            Ok(self.respond(request))
+        }
+    }
+
+    fn count(value: serde_json::Value) -> u64 {
+        value["count"].as_u64().unwrap()
+    }
+
+    #[test]
+    fn responses_are_cached() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        let first: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+        let second: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+        let other: serde_json::Value = api::cached(Dummy::get(2), &cache).query(&client).unwrap();
+
+        assert_eq!(client.requests(), 2);
+        assert_eq!(count(first), 1);
+        assert_eq!(count(second), 1);
+        assert_eq!(count(other), 2);
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+        assert_eq!(client.requests(), 3);
+    }
+
+    #[tokio::test]
+    async fn responses_are_cached_async() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        for _ in 0..3 {
+            let _: serde_json::Value = api::cached(Dummy::get(1), &cache)
+                .query_async(&client)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(client.requests(), 1);
+    }
+
+    #[test]
+    fn responses_expire() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(0));
+
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+
+        assert_eq!(client.requests(), 2);
+    }
+
+    #[test]
+    fn non_get_requests_are_not_cached() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let endpoint = Dummy {
+                method: Method::POST,
+                id: 1,
+            };
+            let _: serde_json::Value = api::cached(endpoint, &cache).query(&client).unwrap();
+        }
+
+        assert_eq!(client.requests(), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let client = CountingClient::failing(StatusCode::NOT_FOUND);
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let res: Result<serde_json::Value, _> =
+                api::cached(Dummy::get(1), &cache).query(&client);
+            res.unwrap_err();
+        }
+
+        assert_eq!(client.requests(), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn sudo_is_part_of_the_key() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&client).unwrap();
+        let _: serde_json::Value = api::cached(api::sudo(Dummy::get(1), "user"), &cache)
+            .query(&client)
+            .unwrap();
+        let _: serde_json::Value = api::cached(api::sudo(Dummy::get(1), "user"), &cache)
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(client.requests(), 2);
+    }
+
+    #[test]
+    fn identity_is_part_of_the_key() {
+        let user = CountingClient::with_identity(1);
+        let other = CountingClient::with_identity(2);
+        let cache = ResponseCache::new(Duration::from_secs(60));
+
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&user).unwrap();
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&other).unwrap();
+        let _: serde_json::Value = api::cached(Dummy::get(1), &cache).query(&user).unwrap();
+
+        assert_eq!(user.requests(), 1);
+        assert_eq!(other.requests(), 1);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn max_entries_is_respected() {
+        let client = CountingClient::default();
+        let cache = ResponseCache::new(Duration::from_secs(60)).max_entries(2);
+
+        for id in 0..5 {
+            let _: serde_json::Value = api::cached(Dummy::get(id), &cache).query(&client).unwrap();
+        }
+
+        assert_eq!(cache.len(), 2);
+    }
+}
@@ -5,14 +5,18 @@
 // except according to those terms.
 
 use std::error::Error;
+use std::io::{Cursor, Read};
+use std::pin::Pin;
 
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::future;
+use futures_util::stream::{self, Stream};
 use http::request::Builder as RequestBuilder;
 use http::Response;
 use url::Url;
 
-use crate::api::ApiError;
+use crate::api::{ApiError, UrlBase};
 
 /// A trait representing a client which can communicate with a GitLab instance via REST.
 pub trait RestClient {
@@ -23,8 +27,38 @@ pub trait RestClient {
     ///
     /// This method adds the hostname for the client's target instance.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
+
+    /// Get the URL for an instance endpoint for the client.
+    ///
+    /// This is used for endpoints which are not part of the REST API (e.g., health checks). The
+    /// default implementation does not support such endpoints.
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        let _ = endpoint;
+        Err(ApiError::unsupported_url_base(UrlBase::Instance))
+    }
+
+    /// An identifier for the credentials used by the client.
+    ///
+    /// Clients which authenticate as different users must return different values. Response
+    /// caches use it to keep users from seeing each other's responses. The default
+    /// implementation returns `None`, which is shared by all such clients.
+    fn identity(&self) -> Option<u64> {
+        None
+    }
 }
 
+/// A reader for the body of a response.
+pub type BodyReader = Box<dyn Read + Send>;
+
+/// A stream of the chunks of the body of a response.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BodyStream<E> = Pin<Box<dyn Stream<Item = Result<Bytes, ApiError<E>>> + Send>>;
+/// A stream of the chunks of the body of a response.
+///
+/// Futures on `wasm32` targets run on a single thread and are not `Send`.
+#[cfg(target_arch = "wasm32")]
+pub type BodyStream<E> = Pin<Box<dyn Stream<Item = Result<Bytes, ApiError<E>>>>>;
+
 /// A trait representing a client which can communicate with a GitLab instance.
 pub trait Client: RestClient {
     /// Send a REST query.
@@ -33,10 +67,24 @@ pub trait Client: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query without reading the response body.
+    ///
+    /// The body is read on demand through the returned reader. The default implementation reads
+    /// the whole body using `rest`.
+    fn rest_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyReader>, ApiError<Self::Error>> {
+        let rsp = self.rest(request, body)?;
+        Ok(rsp.map(|body| Box::new(Cursor::new(body)) as BodyReader))
+    }
 }
 
 /// A trait representing an asynchronous client which can communicate with a GitLab instance.
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait AsyncClient: RestClient {
     /// Send a REST query asynchronously.
     async fn rest_async(
@@ -44,4 +92,18 @@ pub trait AsyncClient: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query asynchronously without reading the response body.
+    ///
+    /// The body is read on demand through the returned stream. The default implementation reads
+    /// the whole body using `rest_async`.
+    async fn rest_stream_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        let rsp = self.rest_async(request, body).await?;
+        Ok(rsp
+            .map(|body| Box::pin(stream::once(future::ready(Ok(body)))) as BodyStream<Self::Error>))
+    }
 }
@@ -0,0 +1,47 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Instance cluster API endpoints.
+//!
+//! These endpoints are used for managing Kubernetes clusters of the instance. The Kubernetes
+//! attribute builders in this module are shared with the group cluster endpoints.
+
+mod add;
+mod cluster;
+mod clusters;
+mod delete;
+mod edit;
+mod kubernetes;
+
+pub use self::add::AddInstanceCluster;
+pub use self::add::AddInstanceClusterBuilder;
+pub use self::add::AddInstanceClusterBuilderError;
+
+pub use self::cluster::InstanceCluster;
+pub use self::cluster::InstanceClusterBuilder;
+pub use self::cluster::InstanceClusterBuilderError;
+
+pub use self::clusters::InstanceClusters;
+pub use self::clusters::InstanceClustersBuilder;
+pub use self::clusters::InstanceClustersBuilderError;
+
+pub use self::delete::DeleteInstanceCluster;
+pub use self::delete::DeleteInstanceClusterBuilder;
+pub use self::delete::DeleteInstanceClusterBuilderError;
+
+pub use self::edit::EditInstanceCluster;
+pub use self::edit::EditInstanceClusterBuilder;
+pub use self::edit::EditInstanceClusterBuilderError;
+
+pub use self::kubernetes::ClusterAuthorizationType;
+pub use self::kubernetes::ClusterPlatformKubernetesAttributes;
+pub use self::kubernetes::ClusterPlatformKubernetesAttributesBuilder;
+pub use self::kubernetes::ClusterPlatformKubernetesAttributesBuilderError;
+pub use self::kubernetes::EditClusterPlatformKubernetesAttributes;
+pub use self::kubernetes::EditClusterPlatformKubernetesAttributesBuilder;
+pub use self::kubernetes::EditClusterPlatformKubernetesAttributesBuilderError;
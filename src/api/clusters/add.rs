@@ -0,0 +1,305 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::clusters::ClusterPlatformKubernetesAttributes;
+use crate::api::endpoint_prelude::*;
+
+/// Add an existing Kubernetes cluster to the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct AddInstanceCluster<'a> {
+    /// The name of the cluster.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// How to connect to the Kubernetes API of the cluster.
+    platform_kubernetes_attributes: ClusterPlatformKubernetesAttributes<'a>,
+    /// The base domain of the cluster.
+    #[builder(setter(into), default)]
+    domain: Option<Cow<'a, str>>,
+    /// The environments the cluster applies to.
+    #[builder(setter(into), default)]
+    environment_scope: Option<Cow<'a, str>>,
+    /// The ID of the management project for the cluster.
+    #[builder(default)]
+    management_project_id: Option<u64>,
+    /// Whether the cluster is active.
+    #[builder(default)]
+    enabled: Option<bool>,
+    /// Whether GitLab manages namespaces and service accounts for the cluster.
+    #[builder(default)]
+    managed: Option<bool>,
+    /// Whether to use a separate namespace for each environment.
+    #[builder(default)]
+    namespace_per_environment: Option<bool>,
+}
+
+impl<'a> AddInstanceCluster<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AddInstanceClusterBuilder<'a> {
+        AddInstanceClusterBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for AddInstanceCluster<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "admin/clusters/add".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", &self.name)
+            .push_opt("domain", self.domain.as_ref())
+            .push_opt("environment_scope", self.environment_scope.as_ref())
+            .push_opt("management_project_id", self.management_project_id)
+            .push_opt("enabled", self.enabled)
+            .push_opt("managed", self.managed)
+            .push_opt("namespace_per_environment", self.namespace_per_environment);
+
+        self.platform_kubernetes_attributes.add_params(&mut params);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::clusters::{
+        AddInstanceCluster, AddInstanceClusterBuilderError, ClusterAuthorizationType,
+        ClusterPlatformKubernetesAttributes,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn kubernetes_attributes() -> ClusterPlatformKubernetesAttributes<'static> {
+        ClusterPlatformKubernetesAttributes::builder()
+            .api_url("https://kubernetes.example.com")
+            .token("token")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn name_and_platform_kubernetes_attributes_are_needed() {
+        let err = AddInstanceCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AddInstanceClusterBuilderError, "name");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = AddInstanceCluster::builder()
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AddInstanceClusterBuilderError, "name");
+    }
+
+    #[test]
+    fn platform_kubernetes_attributes_is_needed() {
+        let err = AddInstanceCluster::builder()
+            .name("cluster")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            AddInstanceClusterBuilderError,
+            "platform_kubernetes_attributes"
+        );
+    }
+
+    #[test]
+    fn name_and_platform_kubernetes_attributes_are_sufficient() {
+        AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&domain=example.com", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .domain("example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&environment_scope=production", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .environment_scope("production")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_management_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&management_project_id=1", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .management_project_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&enabled=false", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_managed() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&managed=false", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .managed(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_per_environment() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=cluster", "&namespace_per_environment=false", "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com&platform_kubernetes_attributes%5Btoken%5D=token"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(kubernetes_attributes())
+            .namespace_per_environment(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_platform_kubernetes_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("admin/clusters/add")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=cluster",
+                "&platform_kubernetes_attributes%5Bapi_url%5D=https%3A%2F%2Fkubernetes.example.com",
+                "&platform_kubernetes_attributes%5Btoken%5D=token",
+                "&platform_kubernetes_attributes%5Bca_cert%5D=cert",
+                "&platform_kubernetes_attributes%5Bauthorization_type%5D=rbac",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddInstanceCluster::builder()
+            .name("cluster")
+            .platform_kubernetes_attributes(
+                ClusterPlatformKubernetesAttributes::builder()
+                    .api_url("https://kubernetes.example.com")
+                    .token("token")
+                    .ca_cert("cert")
+                    .authorization_type(ClusterAuthorizationType::Rbac)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
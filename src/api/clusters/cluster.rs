@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a Kubernetes cluster of the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct InstanceCluster {
+    /// The ID of the cluster.
+    cluster: u64,
+}
+
+impl InstanceCluster {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> InstanceClusterBuilder {
+        InstanceClusterBuilder::default()
+    }
+}
+
+impl Endpoint for InstanceCluster {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/clusters/{}", self.cluster).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::clusters::{InstanceCluster, InstanceClusterBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = InstanceCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, InstanceClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn cluster_is_sufficient() {
+        InstanceCluster::builder().cluster(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("admin/clusters/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = InstanceCluster::builder().cluster(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
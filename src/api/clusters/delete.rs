@@ -0,0 +1,69 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Remove a Kubernetes cluster from the instance.
+///
+/// This does not delete the cluster itself, only its integration with GitLab.
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteInstanceCluster {
+    /// The ID of the cluster.
+    cluster: u64,
+}
+
+impl DeleteInstanceCluster {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteInstanceClusterBuilder {
+        DeleteInstanceClusterBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteInstanceCluster {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/clusters/{}", self.cluster).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::clusters::{DeleteInstanceCluster, DeleteInstanceClusterBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = DeleteInstanceCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteInstanceClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn cluster_is_sufficient() {
+        DeleteInstanceCluster::builder().cluster(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/clusters/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteInstanceCluster::builder().cluster(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,253 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::clusters::EditClusterPlatformKubernetesAttributes;
+use crate::api::endpoint_prelude::*;
+
+/// Edit a Kubernetes cluster of the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditInstanceCluster<'a> {
+    /// The ID of the cluster.
+    cluster: u64,
+
+    /// The name of the cluster.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The base domain of the cluster.
+    #[builder(setter(into), default)]
+    domain: Option<Cow<'a, str>>,
+    /// The environments the cluster applies to.
+    #[builder(setter(into), default)]
+    environment_scope: Option<Cow<'a, str>>,
+    /// The ID of the management project for the cluster.
+    #[builder(default)]
+    management_project_id: Option<u64>,
+    /// Whether GitLab manages namespaces and service accounts for the cluster.
+    #[builder(default)]
+    managed: Option<bool>,
+    /// Whether to use a separate namespace for each environment.
+    #[builder(default)]
+    namespace_per_environment: Option<bool>,
+    /// Update how to connect to the Kubernetes API of the cluster.
+    #[builder(default)]
+    platform_kubernetes_attributes: Option<EditClusterPlatformKubernetesAttributes<'a>>,
+}
+
+impl<'a> EditInstanceCluster<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditInstanceClusterBuilder<'a> {
+        EditInstanceClusterBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditInstanceCluster<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/clusters/{}", self.cluster).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("domain", self.domain.as_ref())
+            .push_opt("environment_scope", self.environment_scope.as_ref())
+            .push_opt("management_project_id", self.management_project_id)
+            .push_opt("managed", self.managed)
+            .push_opt("namespace_per_environment", self.namespace_per_environment);
+
+        if let Some(attrs) = self.platform_kubernetes_attributes.as_ref() {
+            attrs.add_params(&mut params);
+        }
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::clusters::{
+        EditClusterPlatformKubernetesAttributes, EditInstanceCluster,
+        EditInstanceClusterBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = EditInstanceCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditInstanceClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn cluster_is_sufficient() {
+        EditInstanceCluster::builder().cluster(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder().cluster(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=cluster")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .name("cluster")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("domain=example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .domain("example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("environment_scope=production")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .environment_scope("production")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_management_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("management_project_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .management_project_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_managed() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("managed=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .managed(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_per_environment() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace_per_environment=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .namespace_per_environment(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_platform_kubernetes_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("admin/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("platform_kubernetes_attributes%5Btoken%5D=token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditInstanceCluster::builder()
+            .cluster(1)
+            .platform_kubernetes_attributes(
+                EditClusterPlatformKubernetesAttributes::builder()
+                    .token("token")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,189 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Authorization types for Kubernetes clusters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ClusterAuthorizationType {
+    /// Role-based access control.
+    Rbac,
+    /// Attribute-based access control.
+    Abac,
+    /// The authorization type is unknown.
+    Unknown,
+}
+
+impl ClusterAuthorizationType {
+    fn as_str(self) -> &'static str {
+        match self {
+            ClusterAuthorizationType::Rbac => "rbac",
+            ClusterAuthorizationType::Abac => "abac",
+            ClusterAuthorizationType::Unknown => "unknown_authorization",
+        }
+    }
+}
+
+impl ParamValue<'static> for ClusterAuthorizationType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Attributes for connecting to the Kubernetes API of a new cluster.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClusterPlatformKubernetesAttributes<'a> {
+    /// The URL of the Kubernetes API.
+    #[builder(setter(into))]
+    pub api_url: Cow<'a, str>,
+    /// The token to authenticate against the Kubernetes API with.
+    #[builder(setter(into))]
+    pub token: Cow<'a, str>,
+    /// The TLS certificate of the Kubernetes API.
+    ///
+    /// Required if the API uses a self-signed certificate.
+    #[builder(setter(into), default)]
+    pub ca_cert: Option<Cow<'a, str>>,
+    /// The authorization type of the cluster.
+    #[builder(default)]
+    pub authorization_type: Option<ClusterAuthorizationType>,
+}
+
+impl<'a> ClusterPlatformKubernetesAttributes<'a> {
+    /// Create a builder for the attributes.
+    pub fn builder() -> ClusterPlatformKubernetesAttributesBuilder<'a> {
+        ClusterPlatformKubernetesAttributesBuilder::default()
+    }
+
+    pub(crate) fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+        params
+            .push("platform_kubernetes_attributes[api_url]", &self.api_url)
+            .push("platform_kubernetes_attributes[token]", &self.token)
+            .push_opt(
+                "platform_kubernetes_attributes[ca_cert]",
+                self.ca_cert.as_ref(),
+            )
+            .push_opt(
+                "platform_kubernetes_attributes[authorization_type]",
+                self.authorization_type,
+            );
+    }
+}
+
+/// Attributes for updating the connection to the Kubernetes API of a cluster.
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(setter(strip_option), default)]
+pub struct EditClusterPlatformKubernetesAttributes<'a> {
+    /// The URL of the Kubernetes API.
+    #[builder(setter(into))]
+    pub api_url: Option<Cow<'a, str>>,
+    /// The token to authenticate against the Kubernetes API with.
+    #[builder(setter(into))]
+    pub token: Option<Cow<'a, str>>,
+    /// The TLS certificate of the Kubernetes API.
+    #[builder(setter(into))]
+    pub ca_cert: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditClusterPlatformKubernetesAttributes<'a> {
+    /// Create a builder for the attributes.
+    pub fn builder() -> EditClusterPlatformKubernetesAttributesBuilder<'a> {
+        EditClusterPlatformKubernetesAttributesBuilder::default()
+    }
+
+    pub(crate) fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+        params
+            .push_opt(
+                "platform_kubernetes_attributes[api_url]",
+                self.api_url.as_ref(),
+            )
+            .push_opt("platform_kubernetes_attributes[token]", self.token.as_ref())
+            .push_opt(
+                "platform_kubernetes_attributes[ca_cert]",
+                self.ca_cert.as_ref(),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::clusters::{
+        ClusterAuthorizationType, ClusterPlatformKubernetesAttributes,
+        ClusterPlatformKubernetesAttributesBuilderError, EditClusterPlatformKubernetesAttributes,
+    };
+
+    #[test]
+    fn cluster_authorization_type_as_str() {
+        let items = &[
+            (ClusterAuthorizationType::Rbac, "rbac"),
+            (ClusterAuthorizationType::Abac, "abac"),
+            (ClusterAuthorizationType::Unknown, "unknown_authorization"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn api_url_and_token_are_needed() {
+        let err = ClusterPlatformKubernetesAttributes::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ClusterPlatformKubernetesAttributesBuilderError,
+            "api_url",
+        );
+    }
+
+    #[test]
+    fn api_url_is_needed() {
+        let err = ClusterPlatformKubernetesAttributes::builder()
+            .token("token")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ClusterPlatformKubernetesAttributesBuilderError,
+            "api_url",
+        );
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = ClusterPlatformKubernetesAttributes::builder()
+            .api_url("https://kubernetes.example.com")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ClusterPlatformKubernetesAttributesBuilderError,
+            "token",
+        );
+    }
+
+    #[test]
+    fn api_url_and_token_are_sufficient() {
+        ClusterPlatformKubernetesAttributes::builder()
+            .api_url("https://kubernetes.example.com")
+            .token("token")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn edit_defaults_are_sufficient() {
+        EditClusterPlatformKubernetesAttributes::builder()
+            .build()
+            .unwrap();
+    }
+}
@@ -159,9 +159,19 @@ const PATH_SEGMENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'{')
     .add(b'}')
     .add(b'%')
-    .add(b'/');
+    .add(b'/')
+    // URL parsers treat `\` as a path separator for special schemes.
+    .add(b'\\')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'|');
 
 /// Escape a string for usage as a single URL path component.
+///
+/// The result never contains a path separator, so names with slashes (such as project paths or
+/// file paths within a repository) are kept within a single component. Note that names which
+/// are exactly `.` or `..` cannot be represented since URLs treat them as relative segments.
 pub fn path_escaped(input: &'_ str) -> impl fmt::Display + '_ {
     utf8_percent_encode(input, PATH_SEGMENT_ENCODE_SET)
 }
@@ -193,6 +203,30 @@ impl<'a> From<String> for NameOrId<'a> {
     }
 }
 
+impl<'a> NameOrId<'a> {
+    /// Check that a builder field may be used as a URL path segment.
+    pub(crate) fn validate_path_segment(field: &str, value: Option<&Self>) -> Result<(), String> {
+        if let Some(NameOrId::Name(name)) = value {
+            validate_path_segment(field, Some(name))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Check that a builder field may be used as a URL path segment.
+///
+/// URLs resolve `.` and `..` segments even when they are escaped, so such values would address a
+/// different endpoint.
+pub(crate) fn validate_path_segment(field: &str, value: Option<&str>) -> Result<(), String> {
+    match value {
+        Some(segment @ ".") | Some(segment @ "..") => {
+            Err(format!("`{}` may not be `{}`", field, segment))
+        },
+        _ => Ok(()),
+    }
+}
+
 /// Visibility levels of projects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VisibilityLevel {
@@ -375,9 +409,13 @@ mod tests {
     use std::cmp;
     use std::iter;
 
+    use percent_encoding::percent_decode_str;
+    use proptest::prelude::*;
+    use url::Url;
+
     use crate::api::common::{
-        AccessLevel, CommaSeparatedList, EnableState, NameOrId, ProtectedAccessLevel, SortOrder,
-        VisibilityLevel, YesNo,
+        self, AccessLevel, CommaSeparatedList, EnableState, NameOrId, ProtectedAccessLevel,
+        SortOrder, VisibilityLevel, YesNo,
     };
     use crate::api::params::ParamValue;
 
@@ -468,6 +506,15 @@ mod tests {
                 "special/name?string".to_string().into(),
                 "special%2Fname%3Fstring",
             ),
+            ("group/sub group#1".into(), "group%2Fsub%20group%231"),
+            ("back\\slash|[x]^".into(), "back%5Cslash%7C%5Bx%5D%5E"),
+            ("100%".into(), "100%25"),
+            (
+                "ünïcödé/プロ".into(),
+                "%C3%BCn%C3%AFc%C3%B6d%C3%A9%2F%E3%83%97%E3%83%AD",
+            ),
+            ("...".into(), "..."),
+            ("name.git".into(), "name.git"),
             (1.into(), "1"),
         ];
 
@@ -476,6 +523,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_path_segment_dot_segments() {
+        // Dot segments are always resolved by URLs, even when escaped.
+        assert_eq!(
+            common::validate_path_segment("file_path", Some(".")).unwrap_err(),
+            "`file_path` may not be `.`",
+        );
+        assert_eq!(
+            common::validate_path_segment("file_path", Some("..")).unwrap_err(),
+            "`file_path` may not be `..`",
+        );
+        common::validate_path_segment("file_path", Some("...")).unwrap();
+        common::validate_path_segment("file_path", Some("./file")).unwrap();
+        common::validate_path_segment("file_path", None).unwrap();
+    }
+
+    #[test]
+    fn name_or_id_validate_path_segment() {
+        assert_eq!(
+            NameOrId::validate_path_segment("project", Some(&"..".into())).unwrap_err(),
+            "`project` may not be `..`",
+        );
+        NameOrId::validate_path_segment("project", Some(&"group/..".into())).unwrap();
+        NameOrId::validate_path_segment("project", Some(&1.into())).unwrap();
+        NameOrId::validate_path_segment("project", None).unwrap();
+    }
+
+    // Names made of characters which are significant within URLs.
+    const PATH_SEGMENT_CHARS: &str = concat!(
+        r#"[a-z0-9 ./\\#?%&=+@:;\[\]^|{}<>`"~"#,
+        r"\x00-\x1f\u{e9}\u{30d7}\u{1f600}]{0,16}",
+    );
+
+    proptest! {
+        #[test]
+        fn path_escaped_is_a_single_segment(input in PATH_SEGMENT_CHARS) {
+            // Dot segments are rejected by builders.
+            prop_assume!(common::validate_path_segment("input", Some(&input)).is_ok());
+
+            let base = Url::parse("https://gitlab.host.invalid/api/v4/").unwrap();
+            let url = base
+                .join(&format!("projects/{}/repository", common::path_escaped(&input)))
+                .unwrap();
+
+            prop_assert_eq!(url.query(), None);
+            prop_assert_eq!(url.fragment(), None);
+
+            let segments = url.path_segments().unwrap().collect::<Vec<_>>();
+            prop_assert_eq!(segments.len(), 5);
+            prop_assert_eq!(segments[2], "projects");
+            prop_assert_eq!(segments[4], "repository");
+            let decoded = percent_decode_str(segments[3]).decode_utf8().unwrap();
+            prop_assert_eq!(decoded, input.as_str());
+        }
+    }
+
     #[test]
     fn visibility_level_as_str() {
         let items = &[
@@ -0,0 +1,19 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Custom endpoints.
+//!
+//! These endpoints may be used to call parts of the GitLab API which do not have dedicated
+//! endpoint types yet. Alternatively, the `Endpoint` trait may be implemented directly using the
+//! types in `endpoint_prelude`.
+
+mod custom;
+
+pub use self::custom::CustomEndpoint;
+pub use self::custom::CustomEndpointBuilder;
+pub use self::custom::CustomEndpointBuilderError;
@@ -0,0 +1,413 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+use serde_json::Value;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// A file in a form: the field name, the filename, and its contents.
+type FormFile<'a> = (Cow<'a, str>, Cow<'a, str>, Cow<'a, [u8]>);
+
+/// An endpoint described at runtime.
+///
+/// This may be used to call endpoints which are not yet supported by this crate without
+/// patching it. The path is used as given, so any components which may contain special
+/// characters (such as project paths) should be escaped using `common::path_escaped`.
+///
+/// The endpoint may be used with `api::paged`, but pagination is only meaningful if GitLab
+/// paginates the endpoint's results.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CustomEndpoint<'a> {
+    /// The HTTP method of the endpoint.
+    #[builder(default = "Method::GET")]
+    method: Method,
+    /// The path of the endpoint.
+    #[builder(setter(into))]
+    endpoint: Cow<'static, str>,
+    /// The base URL the path is relative to.
+    #[builder(default = "UrlBase::ApiV4")]
+    url_base: UrlBase,
+    /// Whether the endpoint supports keyset pagination.
+    #[builder(default)]
+    keyset_pagination: bool,
+
+    #[builder(setter(name = "_query"), default, private)]
+    query: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    #[builder(setter(name = "_form"), default, private)]
+    form: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    #[builder(setter(name = "_files"), default, private)]
+    files: Vec<FormFile<'a>>,
+    #[builder(setter(name = "_json"), default, private)]
+    json: Option<Value>,
+    #[builder(setter(name = "_body"), default, private)]
+    body: Option<(&'static str, Vec<u8>)>,
+}
+
+impl<'a> CustomEndpoint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CustomEndpointBuilder<'a> {
+        CustomEndpointBuilder::default()
+    }
+}
+
+impl<'a> CustomEndpointBuilder<'a> {
+    /// Add a query parameter.
+    pub fn query_param<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.as_value()));
+        self
+    }
+
+    /// Add query parameters.
+    pub fn query_params<'b, I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(|(key, value)| (key.into(), value.as_value())));
+        self
+    }
+
+    /// Add a parameter to a form body.
+    pub fn form_param<'b, K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.form
+            .get_or_insert_with(Vec::new)
+            .push((key.into(), value.as_value()));
+        self
+    }
+
+    /// Add parameters to a form body.
+    pub fn form_params<'b, I, K, V>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = (K, V)>,
+        K: Into<Cow<'a, str>>,
+        V: ParamValue<'b>,
+        'b: 'a,
+    {
+        self.form
+            .get_or_insert_with(Vec::new)
+            .extend(iter.map(|(key, value)| (key.into(), value.as_value())));
+        self
+    }
+
+    /// Add a file to the form.
+    ///
+    /// Adding a file sends the form as a multipart body.
+    pub fn file<N, F, C>(&mut self, name: N, filename: F, contents: C) -> &mut Self
+    where
+        N: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.files.get_or_insert_with(Vec::new).push((
+            name.into(),
+            filename.into(),
+            contents.into(),
+        ));
+        self
+    }
+
+    /// Send a JSON body.
+    pub fn json_body(&mut self, body: Value) -> &mut Self {
+        self.json = Some(Some(body));
+        self
+    }
+
+    /// Send a body with the given content type.
+    pub fn body(&mut self, content_type: &'static str, body: Vec<u8>) -> &mut Self {
+        self.body = Some(Some((content_type, body)));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let has_form = matches!(&self.form, Some(form) if !form.is_empty())
+            || matches!(&self.files, Some(files) if !files.is_empty());
+        let has_json = matches!(self.json, Some(Some(_)));
+        let has_body = matches!(self.body, Some(Some(_)));
+
+        if [has_form, has_json, has_body]
+            .iter()
+            .filter(|&&is_set| is_set)
+            .count()
+            > 1
+        {
+            return Err("only one of form parameters, a JSON body, or a body may be given".into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for CustomEndpoint<'a> {
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.clone()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        self.url_base
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.extend(
+            self.query
+                .iter()
+                .map(|(key, value)| (key.as_ref(), value.as_ref())),
+        );
+
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        if let Some(json) = self.json.as_ref() {
+            Ok(Some(("application/json", serde_json::to_vec(json)?)))
+        } else if let Some((content_type, body)) = self.body.as_ref() {
+            Ok(Some((content_type, body.clone())))
+        } else if !self.form.is_empty() || !self.files.is_empty() {
+            let mut params = FormParams::default();
+
+            params.extend(
+                self.form
+                    .iter()
+                    .map(|(key, value)| (key.as_ref(), value.as_ref())),
+            );
+
+            if self.files.is_empty() {
+                params.into_body()
+            } else {
+                let files = self
+                    .files
+                    .iter()
+                    .map(|(name, filename, contents)| {
+                        (name.as_ref(), filename.as_ref(), contents.as_ref())
+                    })
+                    .collect::<Vec<_>>();
+                params.into_multipart_body_with_files(&files)
+            }
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a> Pageable for CustomEndpoint<'a> {
+    fn use_keyset_pagination(&self) -> bool {
+        self.keyset_pagination
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use serde_json::json;
+
+    use crate::api::common;
+    use crate::api::custom::{CustomEndpoint, CustomEndpointBuilderError};
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+
+    #[test]
+    fn endpoint_is_needed() {
+        let err = CustomEndpoint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CustomEndpointBuilderError, "endpoint");
+    }
+
+    #[test]
+    fn endpoint_is_sufficient() {
+        CustomEndpoint::builder().endpoint("dummy").build().unwrap();
+    }
+
+    #[test]
+    fn bodies_are_exclusive() {
+        let err = CustomEndpoint::builder()
+            .endpoint("dummy")
+            .form_param("key", "value")
+            .json_body(json!({}))
+            .build()
+            .unwrap_err();
+        if let CustomEndpointBuilderError::ValidationError(msg) = err {
+            assert_eq!(
+                msg,
+                "only one of form parameters, a JSON body, or a body may be given",
+            );
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/new_feature")
+            .add_query_params(&[("enabled", "true"), ("name", "value")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .endpoint(format!(
+                "projects/{}/new_feature",
+                common::path_escaped("simple/project"),
+            ))
+            .query_param("enabled", true)
+            .query_params([("name", "value")].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_instance() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/custom")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .endpoint("-/custom")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_form() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=value&count=2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .form_param("name", "value")
+            .form_params([("count", 2u64)].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_files() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/1/wikis/attachments")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"branch\"\r\n",
+                "\r\n",
+                "main\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"image.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "image data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .method(Method::POST)
+            .endpoint("projects/1/wikis/attachments")
+            .form_param("branch", "main")
+            .file("file", "image.png", &b"image data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_json() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("dummy")
+            .content_type("application/json")
+            .body_str(r#"{"nested":{"value":1}}"#)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .method(Method::PUT)
+            .endpoint("dummy")
+            .json_body(json!({"nested": {"value": 1}}))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_body() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .content_type("text/plain")
+            .body_str("contents")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CustomEndpoint::builder()
+            .method(Method::POST)
+            .endpoint("dummy")
+            .body("text/plain", b"contents".to_vec())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..3).map(|value| json!({ "value": value })));
+
+        let endpoint = CustomEndpoint::builder().endpoint("dummy").build().unwrap();
+        let res: Vec<serde_json::Value> = api::paged(endpoint, api::Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 3);
+    }
+}
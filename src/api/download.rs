@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::Read;
+
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+
+use crate::api::raw::{request, response_error};
+use crate::api::{
+    ApiError, AsyncClient, AsyncQuery, BodyReader, BodyStream, Client, Endpoint, Query,
+};
+
+/// A query modifier that streams the data from the endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Download<E> {
+    endpoint: E,
+}
+
+/// Stream the data from the endpoint.
+///
+/// Unlike `raw`, the data is not read into memory before it is returned. This is meant for
+/// endpoints which return large files such as job artifacts, repository archives, or project
+/// exports. Clients which do not support streaming read the entire response first.
+///
+/// The blocking query returns a reader while the asynchronous query returns a stream of chunks.
+pub fn download<E>(endpoint: E) -> Download<E> {
+    Download {
+        endpoint,
+    }
+}
+
+impl<E, C> Query<BodyReader, C> for Download<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<BodyReader, ApiError<C::Error>> {
+        let (req, data) = request(&self.endpoint, client)?;
+        let rsp = client.rest_stream(req, data)?;
+        if rsp.status().is_success() {
+            return Ok(rsp.into_body());
+        }
+
+        let status = rsp.status();
+        let mut body = Vec::new();
+        // Report the error with as much of the body as could be read.
+        let _ = rsp.into_body().read_to_end(&mut body);
+        Err(response_error(status, &body))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, C> AsyncQuery<BodyStream<C::Error>, C> for Download<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<BodyStream<C::Error>, ApiError<C::Error>> {
+        let (req, data) = request(&self.endpoint, client)?;
+        let rsp = client.rest_stream_async(req, data).await?;
+        if rsp.status().is_success() {
+            return Ok(rsp.into_body());
+        }
+
+        let status = rsp.status();
+        let body = rsp
+            .into_body()
+            .try_fold(Vec::new(), |mut body, chunk| {
+                body.extend_from_slice(&chunk);
+                async move { Ok(body) }
+            })
+            .await?;
+        Err(response_error(status, &body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use futures_util::TryStreamExt;
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[test]
+    fn test_download() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "binary data");
+
+        let mut reader = api::download(Dummy).query(&client).unwrap();
+        let mut data = String::new();
+        reader.read_to_string(&mut data).unwrap();
+        assert_eq!(data, "binary data");
+    }
+
+    #[tokio::test]
+    async fn test_download_async() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "binary data");
+
+        let stream = api::download(Dummy).query_async(&client).await.unwrap();
+        let chunks: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"binary data");
+    }
+
+    #[test]
+    fn test_download_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "404 Not found",
+            }),
+        );
+
+        let err = api::download(Dummy).query(&client).err().unwrap();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "404 Not found");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_error_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "not json");
+
+        let err = api::download(Dummy)
+            .query_async(&client)
+            .await
+            .err()
+            .unwrap();
+        if let ApiError::GitlabService {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusCode::NOT_FOUND);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
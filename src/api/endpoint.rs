@@ -7,10 +7,39 @@
 use std::borrow::Cow;
 
 use async_trait::async_trait;
-use http::{self, header, Method, Request};
+use bytes::Bytes;
+use http::{self, header, Method, Request, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+
+use crate::api::{
+    lenient, query, ApiError, AsyncClient, AsyncQuery, BodyError, Client, Query, QueryParams,
+    RestClient,
+};
+
+/// The base URL an endpoint is relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UrlBase {
+    /// The endpoint is part of the REST API (i.e., under `/api/v4`).
+    ApiV4,
+    /// The endpoint is relative to the root of the instance (e.g., `-/health`).
+    Instance,
+}
 
-use crate::api::{query, ApiError, AsyncClient, AsyncQuery, BodyError, Client, Query, QueryParams};
+impl UrlBase {
+    /// Get the URL for an endpoint relative to this base.
+    pub fn endpoint_for<C>(self, client: &C, endpoint: &str) -> Result<Url, ApiError<C::Error>>
+    where
+        C: RestClient + ?Sized,
+    {
+        match self {
+            UrlBase::ApiV4 => client.rest_endpoint(endpoint),
+            UrlBase::Instance => client.instance_endpoint(endpoint),
+        }
+    }
+}
 
 /// A trait for providing the necessary information for a single REST API endpoint.
 pub trait Endpoint {
@@ -19,6 +48,13 @@ pub trait Endpoint {
     /// The path to the endpoint.
     fn endpoint(&self) -> Cow<'static, str>;
 
+    /// The base URL the endpoint path is relative to.
+    ///
+    /// Nearly all endpoints are part of the REST API.
+    fn url_base(&self) -> UrlBase {
+        UrlBase::ApiV4
+    }
+
     /// Query parameters for the endpoint.
     fn parameters(&self) -> QueryParams {
         QueryParams::default()
@@ -32,6 +68,30 @@ pub trait Endpoint {
     }
 }
 
+fn process_response<C, T>(rsp: Response<Bytes>) -> Result<T, ApiError<C::Error>>
+where
+    T: DeserializeOwned,
+    C: RestClient,
+{
+    let status = rsp.status();
+    // Some endpoints (e.g., deletions) successfully return no content at all. This is treated as
+    // `null` so that such endpoints may be queried as `()` while still detecting errors.
+    if status == StatusCode::NO_CONTENT {
+        return lenient::deserialize(Value::Null);
+    }
+
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(status, v));
+    }
+
+    lenient::deserialize(v)
+}
+
 impl<E, T, C> Query<T, C> for E
 where
     E: Endpoint,
@@ -39,7 +99,7 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint())?;
+        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
         self.parameters().add_to_url(&mut url);
 
         let req = Request::builder()
@@ -52,21 +112,12 @@ where
             (req, Vec::new())
         };
         let rsp = client.rest(req, data)?;
-        let status = rsp.status();
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
-        }
-
-        serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+        process_response::<C, _>(rsp)
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<E, T, C> AsyncQuery<T, C> for E
 where
     E: Endpoint + Sync,
@@ -74,7 +125,7 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint())?;
+        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
         self.parameters().add_to_url(&mut url);
 
         let req = Request::builder()
@@ -87,17 +138,7 @@ where
             (req, Vec::new())
         };
         let rsp = client.rest_async(req, data).await?;
-        let status = rsp.status();
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
-        }
-
-        serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
+        process_response::<C, _>(rsp)
     }
 }
 
@@ -162,6 +203,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gitlab_no_content() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NO_CONTENT)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let _: () = Dummy.query(&client).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_gitlab_no_content_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NO_CONTENT)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let _: () = Dummy.query_async(&client).await.unwrap();
+    }
+
+    #[test]
+    fn test_gitlab_no_content_with_data() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NO_CONTENT)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let res: Result<DummyResult, _> = Dummy.query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::DataType {
+            path, ..
+        } = err
+        {
+            assert_eq!(path, ".");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
     #[test]
     fn test_gitlab_error_bad_json() {
         let endpoint = ExpectedUrl::builder()
@@ -274,10 +360,14 @@ mod tests {
         if let ApiError::DataType {
             source,
             typename,
+            path,
+            snippet,
         } = err
         {
             assert_eq!(format!("{}", source), "missing field `value`");
             assert_eq!(typename, "gitlab::api::endpoint::tests::DummyResult");
+            assert_eq!(path, ".");
+            assert_eq!(snippet, r#"{"not_value":0}"#);
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -19,3 +19,4 @@ pub use crate::api::Endpoint;
 pub use crate::api::FormParams;
 pub use crate::api::Pageable;
 pub use crate::api::QueryParams;
+pub use crate::api::UrlBase;
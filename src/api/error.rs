@@ -5,11 +5,13 @@
 // except according to those terms.
 
 use std::any;
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::fmt;
 
 use thiserror::Error;
 
-use crate::api::PaginationError;
+use crate::api::{PaginationError, UrlBase};
 
 /// Errors which may occur when creating form data.
 #[derive(Debug, Error)]
@@ -22,6 +24,127 @@ pub enum BodyError {
         #[from]
         source: serde_urlencoded::ser::Error,
     },
+    /// Body data could not be serialized to JSON.
+    #[error("failed to serialize JSON body: {}", source)]
+    Json {
+        /// The source of the error.
+        #[from]
+        source: serde_json::Error,
+    },
+}
+
+/// A structured error returned by GitLab for invalid requests.
+///
+/// GitLab reports validation failures (usually with a `400 Bad Request` status) as a hash of
+/// messages for each offending field.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GitlabServerError {
+    /// The status code of the response.
+    pub status: http::StatusCode,
+    /// The error messages for each field.
+    ///
+    /// Errors for nested fields use `.` to separate the components of the field name. Errors which
+    /// are not about a specific field are usually reported under `base`.
+    pub fields: BTreeMap<String, Vec<String>>,
+}
+
+impl GitlabServerError {
+    /// The field used for messages which are not about a specific field.
+    const BASE: &'static str = "base";
+
+    fn from_value(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        let mut fields = BTreeMap::new();
+        match value {
+            serde_json::Value::Object(obj) => Self::collect_fields(&mut fields, None, obj),
+            serde_json::Value::Array(_) => Self::collect_messages(&mut fields, Self::BASE, value),
+            _ => return None,
+        }
+
+        if fields.is_empty() {
+            None
+        } else {
+            Some(Self {
+                status,
+                fields,
+            })
+        }
+    }
+
+    fn collect_fields(
+        fields: &mut BTreeMap<String, Vec<String>>,
+        prefix: Option<&str>,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) {
+        for (key, value) in obj {
+            let field = if let Some(prefix) = prefix {
+                format!("{}.{}", prefix, key)
+            } else {
+                key.clone()
+            };
+
+            if let serde_json::Value::Object(obj) = value {
+                Self::collect_fields(fields, Some(&field), obj);
+            } else {
+                Self::collect_messages(fields, &field, value);
+            }
+        }
+    }
+
+    /// Collect the messages for a field.
+    ///
+    /// Messages may be a single string or an array of strings. Anything else is skipped so that
+    /// the rest of the error is still available.
+    fn collect_messages(
+        fields: &mut BTreeMap<String, Vec<String>>,
+        field: &str,
+        value: &serde_json::Value,
+    ) {
+        let messages: Vec<String> = match value {
+            serde_json::Value::String(msg) => vec![msg.clone()],
+            serde_json::Value::Array(msgs) => {
+                msgs.iter()
+                    .filter_map(|msg| msg.as_str().map(Into::into))
+                    .collect()
+            },
+            _ => return,
+        };
+
+        if !messages.is_empty() {
+            fields.entry(field.into()).or_default().extend(messages);
+        }
+    }
+}
+
+impl fmt::Display for GitlabServerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut first = true;
+        for (field, messages) in &self.fields {
+            for message in messages {
+                if !first {
+                    write!(f, "; ")?;
+                }
+                first = false;
+                write!(f, "{} {}", field, message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The longest snippet of data to include in deserialization errors.
+const SNIPPET_LENGTH: usize = 256;
+
+fn snippet(value: &serde_json::Value) -> String {
+    let mut snippet = value.to_string();
+    if snippet.len() > SNIPPET_LENGTH {
+        let mut end = SNIPPET_LENGTH;
+        while !snippet.is_char_boundary(end) {
+            end -= 1;
+        }
+        snippet.truncate(end);
+        snippet.push_str("...");
+    }
+    snippet
 }
 
 /// Errors which may occur when using API endpoints.
@@ -72,6 +195,12 @@ where
         /// The error data from GitLab.
         data: Vec<u8>,
     },
+    /// GitLab returned errors for the fields of the request.
+    #[error("gitlab server error: {}", error)]
+    GitlabServerError {
+        /// The structured error from GitLab.
+        error: GitlabServerError,
+    },
     /// GitLab returned an error object.
     #[error("gitlab server error: {:?}", obj)]
     GitlabObject {
@@ -85,12 +214,22 @@ where
         obj: serde_json::Value,
     },
     /// Failed to parse an expected data type from JSON.
-    #[error("could not parse {} data from JSON: {}", typename, source)]
+    #[error(
+        "could not parse {} data from JSON at `{}`: {} (data: {})",
+        typename,
+        path,
+        source,
+        snippet
+    )]
     DataType {
         /// The source of the error.
         source: serde_json::Error,
         /// The name of the type that could not be deserialized.
         typename: &'static str,
+        /// The path to the field which could not be deserialized.
+        path: String,
+        /// A truncated snippet of the data which could not be deserialized.
+        snippet: String,
     },
     /// An error with pagination occurred.
     #[error("failed to handle for pagination: {}", source)]
@@ -99,6 +238,12 @@ where
         #[from]
         source: PaginationError,
     },
+    /// The client does not support the URL base of the endpoint.
+    #[error("unsupported URL base: {:?}", url_base)]
+    UnsupportedUrlBase {
+        /// The URL base that is not supported.
+        url_base: UrlBase,
+    },
 }
 
 impl<E> ApiError<E>
@@ -159,6 +304,13 @@ where
                     data,
                 }
             },
+            Self::GitlabServerError {
+                error,
+            } => {
+                ApiError::GitlabServerError {
+                    error,
+                }
+            },
             Self::GitlabObject {
                 obj,
             } => {
@@ -176,10 +328,14 @@ where
             Self::DataType {
                 source,
                 typename,
+                path,
+                snippet,
             } => {
                 ApiError::DataType {
                     source,
                     typename,
+                    path,
+                    snippet,
                 }
             },
             Self::Pagination {
@@ -189,17 +345,31 @@ where
                     source,
                 }
             },
+            Self::UnsupportedUrlBase {
+                url_base,
+            } => {
+                ApiError::UnsupportedUrlBase {
+                    url_base,
+                }
+            },
+        }
+    }
+
+    /// Create an error for an unsupported URL base.
+    pub fn unsupported_url_base(url_base: UrlBase) -> Self {
+        Self::UnsupportedUrlBase {
+            url_base,
         }
     }
 
-    pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
+    pub(crate) fn server_error(status: http::StatusCode, body: &[u8]) -> Self {
         Self::GitlabService {
             status,
-            data: body.into_iter().copied().collect(),
+            data: body.to_vec(),
         }
     }
 
-    pub(crate) fn from_gitlab(value: serde_json::Value) -> Self {
+    pub(crate) fn from_gitlab(status: http::StatusCode, value: serde_json::Value) -> Self {
         let error_value = value
             .pointer("/message")
             .or_else(|| value.pointer("/error"));
@@ -209,6 +379,10 @@ where
                 ApiError::Gitlab {
                     msg: msg.into(),
                 }
+            } else if let Some(error) = GitlabServerError::from_value(status, error_value) {
+                ApiError::GitlabServerError {
+                    error,
+                }
             } else {
                 ApiError::GitlabObject {
                     obj: error_value.clone(),
@@ -221,16 +395,22 @@ where
         }
     }
 
-    pub(crate) fn data_type<T>(source: serde_json::Error) -> Self {
+    pub(crate) fn data_type<T>(
+        err: serde_path_to_error::Error<serde_json::Error>,
+        value: &serde_json::Value,
+    ) -> Self {
         ApiError::DataType {
-            source,
+            path: err.path().to_string(),
+            source: err.into_inner(),
             typename: any::type_name::<T>(),
+            snippet: snippet(value),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use http::StatusCode;
     use serde_json::json;
     use thiserror::Error;
 
@@ -246,7 +426,7 @@ mod tests {
             "error": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::BAD_REQUEST, obj);
         if let ApiError::Gitlab {
             msg,
         } = err
@@ -263,7 +443,7 @@ mod tests {
             "message": "error contents",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::BAD_REQUEST, obj);
         if let ApiError::Gitlab {
             msg,
         } = err
@@ -277,13 +457,142 @@ mod tests {
     #[test]
     fn gitlab_error_message_object() {
         let err_obj = json!({
-            "blah": "foo",
+            "blah": 1,
+        });
+        let obj = json!({
+            "message": err_obj,
+        });
+
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::BAD_REQUEST, obj);
+        if let ApiError::GitlabObject {
+            obj,
+        } = err
+        {
+            assert_eq!(obj, err_obj);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_message_fields() {
+        let obj = json!({
+            "message": {
+                "name": ["has already been taken"],
+                "path": ["has already been taken", "is reserved"],
+                "project_feature": {
+                    "wiki_access_level": ["is not included in the list"],
+                },
+            },
+        });
+
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::BAD_REQUEST, obj);
+        if let ApiError::GitlabServerError {
+            error,
+        } = &err
+        {
+            let fields = error
+                .fields
+                .iter()
+                .map(|(field, msgs)| (field.as_str(), msgs.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(error.status, StatusCode::BAD_REQUEST);
+            assert_eq!(
+                fields,
+                [
+                    ("name", vec!["has already been taken".into()]),
+                    (
+                        "path",
+                        vec!["has already been taken".into(), "is reserved".into()],
+                    ),
+                    (
+                        "project_feature.wiki_access_level",
+                        vec!["is not included in the list".into()],
+                    ),
+                ] as [(&str, Vec<String>); 3],
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert_eq!(
+            err.to_string(),
+            "gitlab server error: name has already been taken; path has already been taken; \
+             path is reserved; project_feature.wiki_access_level is not included in the list",
+        );
+    }
+
+    fn server_error(obj: serde_json::Value) -> Vec<(String, Vec<String>)> {
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::UNPROCESSABLE_ENTITY, obj);
+        if let ApiError::GitlabServerError {
+            error,
+        } = err
+        {
+            assert_eq!(error.status, StatusCode::UNPROCESSABLE_ENTITY);
+            error.fields.into_iter().collect()
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn gitlab_error_message_fields_string() {
+        let fields = server_error(json!({
+            "message": {
+                "base": "project is archived",
+                "name": ["has already been taken"],
+            },
+        }));
+
+        assert_eq!(
+            fields,
+            [
+                ("base".into(), vec!["project is archived".into()]),
+                ("name".into(), vec!["has already been taken".into()]),
+            ],
+        );
+    }
+
+    #[test]
+    fn gitlab_error_message_fields_partial() {
+        let fields = server_error(json!({
+            "message": {
+                "limit": 10,
+                "name": ["has already been taken", 1],
+                "path": null,
+            },
+        }));
+
+        assert_eq!(
+            fields,
+            [("name".into(), vec!["has already been taken".into()])],
+        );
+    }
+
+    #[test]
+    fn gitlab_error_message_array() {
+        let fields = server_error(json!({
+            "message": ["branch is protected", "ref is invalid"],
+        }));
+
+        assert_eq!(
+            fields,
+            [(
+                "base".into(),
+                vec!["branch is protected".into(), "ref is invalid".into()],
+            )],
+        );
+    }
+
+    #[test]
+    fn gitlab_error_message_fields_unrecognized() {
+        let err_obj = json!({
+            "name": [1, 2],
         });
         let obj = json!({
             "message": err_obj,
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(obj);
+        let err: ApiError<MyError> = ApiError::from_gitlab(StatusCode::BAD_REQUEST, obj);
         if let ApiError::GitlabObject {
             obj,
         } = err
@@ -300,7 +609,8 @@ mod tests {
             "some_weird_key": "an even weirder value",
         });
 
-        let err: ApiError<MyError> = ApiError::from_gitlab(err_obj.clone());
+        let err: ApiError<MyError> =
+            ApiError::from_gitlab(StatusCode::BAD_REQUEST, err_obj.clone());
         if let ApiError::GitlabUnrecognized {
             obj,
         } = err
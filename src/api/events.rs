@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Event API endpoints.
+//!
+//! These endpoints are used for querying events.
+
+mod events;
+
+pub use self::events::EventAction;
+pub use self::events::EventScope;
+pub use self::events::EventTargetType;
+pub use self::events::Events;
+pub use self::events::EventsBuilder;
+pub use self::events::EventsBuilderError;
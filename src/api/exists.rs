@@ -0,0 +1,150 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{Response, StatusCode};
+
+use crate::api::head;
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// A query modifier that checks whether the resource of an endpoint exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exists<E> {
+    endpoint: E,
+}
+
+/// Check whether the resource of an endpoint exists.
+///
+/// A `HEAD` request is sent to the endpoint. A successful response means that the resource
+/// exists while a `404 Not Found` response means that it does not (or that it is not visible to
+/// the client). Other responses are returned as errors.
+pub fn exists<E>(endpoint: E) -> Exists<E> {
+    Exists {
+        endpoint,
+    }
+}
+
+fn check_response<C>(rsp: Response<Bytes>) -> Result<bool, ApiError<C::Error>>
+where
+    C: RestClient,
+{
+    let status = rsp.status();
+    if status.is_success() {
+        Ok(true)
+    } else if status == StatusCode::NOT_FOUND {
+        Ok(false)
+    } else {
+        Err(ApiError::server_error(status, rsp.body()))
+    }
+}
+
+impl<E, C> Query<bool, C> for Exists<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let req = head::head_request(&self.endpoint, client)?;
+        let rsp = client.rest(req, Vec::new())?;
+        check_response::<C>(rsp)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, C> AsyncQuery<bool, C> for Exists<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<bool, ApiError<C::Error>> {
+        let req = head::head_request(&self.endpoint, client)?;
+        let rsp = client.rest_async(req, Vec::new()).await?;
+        check_response::<C>(rsp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+
+    use crate::api::projects::repository::files::File;
+    use crate::api::projects::Project;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    fn client(endpoint: &'static str, status: StatusCode) -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::HEAD)
+            .endpoint(endpoint)
+            .status(status)
+            .build()
+            .unwrap();
+        SingleTestClient::new_raw(endpoint, "")
+    }
+
+    #[test]
+    fn test_exists() {
+        let client = client("projects/simple%2Fproject", StatusCode::OK);
+
+        let endpoint = Project::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        assert!(api::exists(endpoint).query(&client).unwrap());
+    }
+
+    #[test]
+    fn test_exists_not_found() {
+        let client = client("projects/simple%2Fproject", StatusCode::NOT_FOUND);
+
+        let endpoint = Project::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        assert!(!api::exists(endpoint).query(&client).unwrap());
+    }
+
+    #[test]
+    fn test_exists_error() {
+        let client = client("projects/simple%2Fproject", StatusCode::UNAUTHORIZED);
+
+        let endpoint = Project::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        let err = api::exists(endpoint).query(&client).unwrap_err();
+        if let ApiError::GitlabService {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exists_async() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::HEAD)
+            .endpoint("projects/simple%2Fproject/repository/files/path%2Fto%2Ffile")
+            .add_query_params(&[("ref", "main")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = File::builder()
+            .project("simple/project")
+            .file_path("path/to/file")
+            .ref_("main")
+            .build()
+            .unwrap();
+        assert!(!api::exists(endpoint).query_async(&client).await.unwrap());
+    }
+}
@@ -0,0 +1,216 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::Response;
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, BodyReader, BodyStream, Client, RestClient};
+
+/// A client which attaches an extension to every request sent through it.
+///
+/// This is used by query modifiers to pass settings for individual queries (e.g., a retry policy)
+/// to clients which support them. Clients which do not know about an extension ignore it.
+#[derive(Debug)]
+pub struct ExtensionClient<'a, C, X> {
+    client: &'a C,
+    extension: X,
+}
+
+impl<'a, C, X> ExtensionClient<'a, C, X> {
+    /// Create a client which attaches `extension` to all of its requests.
+    pub fn new(client: &'a C, extension: X) -> Self {
+        Self {
+            client,
+            extension,
+        }
+    }
+}
+
+impl<'a, C, X> ExtensionClient<'a, C, X>
+where
+    X: Clone + Send + Sync + 'static,
+{
+    fn extend(&self, request: RequestBuilder) -> RequestBuilder {
+        request.extension(self.extension.clone())
+    }
+}
+
+impl<'a, C, X> RestClient for ExtensionClient<'a, C, X>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.instance_endpoint(endpoint)
+    }
+
+    fn identity(&self) -> Option<u64> {
+        self.client.identity()
+    }
+}
+
+impl<'a, C, X> Client for ExtensionClient<'a, C, X>
+where
+    C: Client,
+    X: Clone + Send + Sync + 'static,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client.rest(self.extend(request), body)
+    }
+
+    fn rest_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyReader>, ApiError<Self::Error>> {
+        self.client.rest_stream(self.extend(request), body)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<'a, C, X> AsyncClient for ExtensionClient<'a, C, X>
+where
+    C: AsyncClient + Sync,
+    X: Clone + Send + Sync + 'static,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client.rest_async(self.extend(request), body).await
+    }
+
+    async fn rest_stream_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.client
+            .rest_stream_async(self.extend(request), body)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::Response;
+    use url::Url;
+
+    use crate::api::{ApiError, AsyncClient, Client, ExtensionClient, RestClient};
+    use crate::test::client::TestClientError;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Marker(&'static str);
+
+    #[derive(Default)]
+    struct RecordingClient {
+        markers: Mutex<Vec<Option<Marker>>>,
+    }
+
+    impl RecordingClient {
+        fn record(
+            &self,
+            request: RequestBuilder,
+        ) -> Result<Response<Bytes>, ApiError<TestClientError>> {
+            let marker = request
+                .extensions_ref()
+                .and_then(|extensions| extensions.get::<Marker>())
+                .cloned();
+            self.markers.lock().unwrap().push(marker);
+            Ok(Response::new(Bytes::new()))
+        }
+    }
+
+    impl RestClient for RecordingClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+    }
+
+    impl Client for RecordingClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.record(request)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for RecordingClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.record(request)
+        }
+    }
+
+    #[test]
+    fn extension_is_attached() {
+        let client = RecordingClient::default();
+
+        client.rest(RequestBuilder::new(), Vec::new()).unwrap();
+        let extended = ExtensionClient::new(&client, Marker("marker"));
+        extended.rest(RequestBuilder::new(), Vec::new()).unwrap();
+        extended.rest(RequestBuilder::new(), Vec::new()).unwrap();
+
+        assert_eq!(
+            *client.markers.lock().unwrap(),
+            [None, Some(Marker("marker")), Some(Marker("marker"))],
+        );
+    }
+
+    #[tokio::test]
+    async fn extension_is_attached_async() {
+        let client = RecordingClient::default();
+
+        let extended = ExtensionClient::new(&client, Marker("marker"));
+        extended
+            .rest_async(RequestBuilder::new(), Vec::new())
+            .await
+            .unwrap();
+
+        assert_eq!(*client.markers.lock().unwrap(), [Some(Marker("marker"))]);
+    }
+
+    #[test]
+    fn endpoints_are_forwarded() {
+        let client = RecordingClient::default();
+        let extended = ExtensionClient::new(&client, Marker("marker"));
+
+        assert_eq!(
+            extended.rest_endpoint("dummy").unwrap().as_str(),
+            "https://gitlab.host.invalid/api/v4/dummy",
+        );
+    }
+}
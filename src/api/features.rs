@@ -0,0 +1,34 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Feature flag API endpoints.
+//!
+//! These endpoints are used for querying and managing feature flags of the instance.
+
+mod definitions;
+mod delete;
+mod features;
+mod set;
+
+pub use self::definitions::FeatureDefinitions;
+pub use self::definitions::FeatureDefinitionsBuilder;
+pub use self::definitions::FeatureDefinitionsBuilderError;
+
+pub use self::delete::DeleteFeature;
+pub use self::delete::DeleteFeatureBuilder;
+pub use self::delete::DeleteFeatureBuilderError;
+
+pub use self::features::Features;
+pub use self::features::FeaturesBuilder;
+pub use self::features::FeaturesBuilderError;
+
+pub use self::set::FeaturePercentageKey;
+pub use self::set::FeatureValue;
+pub use self::set::SetFeature;
+pub use self::set::SetFeatureBuilder;
+pub use self::set::SetFeatureBuilderError;
@@ -0,0 +1,460 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{self, CommaSeparatedList};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The value to set for a feature flag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum FeatureValue {
+    /// Enable or disable the feature flag.
+    Enabled(bool),
+    /// Enable the feature flag for a percentage (`0` to `100`) of time or actors.
+    ///
+    /// Which percentage gate is used is determined by the `key` parameter.
+    Percentage(f64),
+}
+
+impl FeatureValue {
+    fn as_str(self) -> Cow<'static, str> {
+        match self {
+            FeatureValue::Enabled(enabled) => enabled.as_value(),
+            FeatureValue::Percentage(percentage) => percentage.as_value(),
+        }
+    }
+}
+
+impl From<bool> for FeatureValue {
+    fn from(enabled: bool) -> Self {
+        FeatureValue::Enabled(enabled)
+    }
+}
+
+impl ParamValue<'static> for FeatureValue {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str()
+    }
+}
+
+/// The kind of percentage gate to set for a feature flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeaturePercentageKey {
+    /// The feature is enabled for a percentage of the time.
+    Time,
+    /// The feature is enabled for a percentage of actors (users, groups, projects, etc.).
+    Actors,
+}
+
+impl FeaturePercentageKey {
+    fn as_str(self) -> &'static str {
+        match self {
+            FeaturePercentageKey::Time => "percentage_of_time",
+            FeaturePercentageKey::Actors => "percentage_of_actors",
+        }
+    }
+}
+
+impl ParamValue<'static> for FeaturePercentageKey {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Set a gate on a feature flag.
+///
+/// If the feature flag does not exist yet, it is created. When any actor gates are given, the
+/// value only applies to those actors. This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct SetFeature<'a> {
+    /// The name of the feature flag.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+    /// The value of the feature flag.
+    #[builder(setter(into))]
+    value: FeatureValue,
+
+    /// The kind of percentage gate to set.
+    ///
+    /// Only meaningful with `FeatureValue::Percentage`. GitLab defaults to a percentage of time.
+    #[builder(default)]
+    key: Option<FeaturePercentageKey>,
+    /// A feature group (e.g., `gitlab_team_members`) to apply the value to.
+    #[builder(setter(into), default)]
+    feature_group: Option<Cow<'a, str>>,
+    /// Usernames to apply the value to.
+    #[builder(setter(name = "_users"), default, private)]
+    users: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Group paths to apply the value to.
+    #[builder(setter(name = "_groups"), default, private)]
+    groups: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Namespace paths to apply the value to.
+    #[builder(setter(name = "_namespaces"), default, private)]
+    namespaces: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Project paths to apply the value to.
+    #[builder(setter(name = "_projects"), default, private)]
+    projects: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Repository paths (e.g., `user/project.wiki`) to apply the value to.
+    #[builder(setter(name = "_repositories"), default, private)]
+    repositories: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Skip the validation of the feature flag definition.
+    ///
+    /// Required to set feature flags which do not have a definition.
+    #[builder(default)]
+    force: Option<bool>,
+}
+
+impl<'a> SetFeature<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetFeatureBuilder<'a> {
+        SetFeatureBuilder::default()
+    }
+}
+
+impl<'a> SetFeatureBuilder<'a> {
+    /// Apply the value to a user (by username).
+    pub fn user<U>(&mut self, user: U) -> &mut Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        self.users
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(user.into());
+        self
+    }
+
+    /// Apply the value to a group (by path).
+    pub fn group<G>(&mut self, group: G) -> &mut Self
+    where
+        G: Into<Cow<'a, str>>,
+    {
+        self.groups
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(group.into());
+        self
+    }
+
+    /// Apply the value to a namespace (by path).
+    pub fn namespace<N>(&mut self, namespace: N) -> &mut Self
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        self.namespaces
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(namespace.into());
+        self
+    }
+
+    /// Apply the value to a project (by path).
+    pub fn project<P>(&mut self, project: P) -> &mut Self
+    where
+        P: Into<Cow<'a, str>>,
+    {
+        self.projects
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(project.into());
+        self
+    }
+
+    /// Apply the value to a repository (by path).
+    pub fn repository<R>(&mut self, repository: R) -> &mut Self
+    where
+        R: Into<Cow<'a, str>>,
+    {
+        self.repositories
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(repository.into());
+        self
+    }
+}
+
+impl<'a> Endpoint for SetFeature<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("features/{}", common::path_escaped(&self.name)).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("value", self.value)
+            .push_opt("key", self.key)
+            .push_opt("feature_group", self.feature_group.as_ref())
+            .push_opt("user", self.users.as_ref())
+            .push_opt("group", self.groups.as_ref())
+            .push_opt("namespace", self.namespaces.as_ref())
+            .push_opt("project", self.projects.as_ref())
+            .push_opt("repository", self.repositories.as_ref())
+            .push_opt("force", self.force);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::features::{
+        FeaturePercentageKey, FeatureValue, SetFeature, SetFeatureBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn feature_percentage_key_as_str() {
+        let items = &[
+            (FeaturePercentageKey::Time, "percentage_of_time"),
+            (FeaturePercentageKey::Actors, "percentage_of_actors"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn feature_value_as_str() {
+        let items = &[
+            (FeatureValue::Enabled(true), "true"),
+            (FeatureValue::Enabled(false), "false"),
+            (FeatureValue::Percentage(50.), "50"),
+            (FeatureValue::Percentage(0.5), "0.5"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn name_and_value_are_needed() {
+        let err = SetFeature::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "name");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = SetFeature::builder().value(true).build().unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "name");
+    }
+
+    #[test]
+    fn value_is_needed() {
+        let err = SetFeature::builder()
+            .name("feature_name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SetFeatureBuilderError, "value");
+    }
+
+    #[test]
+    fn name_and_value_are_sufficient() {
+        SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("value=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_key() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=25", "&key=percentage_of_actors"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(FeatureValue::Percentage(25.))
+            .key(FeaturePercentageKey::Actors)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_feature_group() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&feature_group=gitlab_team_members"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .feature_group("gitlab_team_members")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&user=alice%2Cbob"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .user("alice")
+            .user("bob")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_group() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&group=simple%2Fgroup"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&namespace=simple%2Fgroup"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .namespace("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_project() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "value=true",
+                "&project=simple%2Fproject%2Csimple%2Fproject2",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .project("simple/project")
+            .project("simple/project2")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_repository() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=true", "&repository=simple%2Fproject.wiki"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(true)
+            .repository("simple/project.wiki")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_force() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("features/feature_name")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("value=false", "&force=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetFeature::builder()
+            .name("feature_name")
+            .value(false)
+            .force(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -10,15 +10,33 @@
 //!
 //! These endpoints are used for querying and modifying groups and their resources.
 
+pub mod analytics;
+pub mod audit_events;
+mod avatar;
+pub mod billable_members;
+pub mod boards;
+pub mod clusters;
 mod create;
+pub mod dependency_proxy;
 mod edit;
+pub mod epic_boards;
+pub mod epics;
 mod group;
 mod groups;
 pub mod issues;
 pub mod members;
 pub mod milestones;
 pub mod projects;
+pub mod push_rule;
+mod share;
 pub mod subgroups;
+mod transfer;
+mod transfer_locations;
+mod unshare;
+
+pub use avatar::GroupAvatar;
+pub use avatar::GroupAvatarBuilder;
+pub use avatar::GroupAvatarBuilderError;
 
 pub use create::BranchProtection;
 pub use create::CreateGroup;
@@ -40,3 +58,19 @@ pub use groups::GroupOrderBy;
 pub use groups::Groups;
 pub use groups::GroupsBuilder;
 pub use groups::GroupsBuilderError;
+
+pub use share::ShareGroup;
+pub use share::ShareGroupBuilder;
+pub use share::ShareGroupBuilderError;
+
+pub use transfer::TransferGroup;
+pub use transfer::TransferGroupBuilder;
+pub use transfer::TransferGroupBuilderError;
+
+pub use transfer_locations::GroupTransferLocations;
+pub use transfer_locations::GroupTransferLocationsBuilder;
+pub use transfer_locations::GroupTransferLocationsBuilderError;
+
+pub use unshare::UnshareGroup;
+pub use unshare::UnshareGroupBuilder;
+pub use unshare::UnshareGroupBuilderError;
@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group analytics API endpoints.
+//!
+//! These endpoints are used for querying value stream analytics of groups.
+
+mod value_stream_stage_median;
+mod value_stream_stages;
+mod value_streams;
+
+pub use self::value_stream_stage_median::GroupValueStreamStageMedian;
+pub use self::value_stream_stage_median::GroupValueStreamStageMedianBuilder;
+pub use self::value_stream_stage_median::GroupValueStreamStageMedianBuilderError;
+
+pub use self::value_stream_stages::GroupValueStreamStages;
+pub use self::value_stream_stages::GroupValueStreamStagesBuilder;
+pub use self::value_stream_stages::GroupValueStreamStagesBuilderError;
+
+pub use self::value_streams::GroupValueStreams;
+pub use self::value_streams::GroupValueStreamsBuilder;
+pub use self::value_streams::GroupValueStreamsBuilderError;
@@ -0,0 +1,194 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the median duration of a stage of a value stream of a group.
+///
+/// The median is given in seconds.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupValueStreamStageMedian<'a> {
+    /// The group to query.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The value stream to query.
+    ///
+    /// The default value stream may be queried using `default`.
+    #[builder(setter(into))]
+    value_stream: NameOrId<'a>,
+    /// The stage to query.
+    ///
+    /// Default stages may be queried by name (e.g., `issue`).
+    #[builder(setter(into))]
+    stage: NameOrId<'a>,
+
+    /// Only consider items created after a date.
+    #[builder(default)]
+    created_after: Option<NaiveDate>,
+    /// Only consider items created before a date.
+    #[builder(default)]
+    created_before: Option<NaiveDate>,
+}
+
+impl<'a> GroupValueStreamStageMedian<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupValueStreamStageMedianBuilder<'a> {
+        GroupValueStreamStageMedianBuilder::default()
+    }
+}
+
+impl<'a> GroupValueStreamStageMedianBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())?;
+        NameOrId::validate_path_segment("value_stream", self.value_stream.as_ref())?;
+        NameOrId::validate_path_segment("stage", self.stage.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupValueStreamStageMedian<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/analytics/value_stream_analytics/value_streams/{}/stages/{}/median",
+            self.group, self.value_stream, self.stage,
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::api::groups::analytics::{
+        GroupValueStreamStageMedian, GroupValueStreamStageMedianBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_value_stream_and_stage_are_needed() {
+        let err = GroupValueStreamStageMedian::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStageMedianBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupValueStreamStageMedian::builder()
+            .value_stream("default")
+            .stage("issue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStageMedianBuilderError, "group");
+    }
+
+    #[test]
+    fn value_stream_is_needed() {
+        let err = GroupValueStreamStageMedian::builder()
+            .group(1)
+            .stage("issue")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            GroupValueStreamStageMedianBuilderError,
+            "value_stream",
+        );
+    }
+
+    #[test]
+    fn stage_is_needed() {
+        let err = GroupValueStreamStageMedian::builder()
+            .group(1)
+            .value_stream("default")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStageMedianBuilderError, "stage");
+    }
+
+    #[test]
+    fn group_value_stream_and_stage_are_sufficient() {
+        GroupValueStreamStageMedian::builder()
+            .group(1)
+            .value_stream("default")
+            .stage("issue")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/analytics/value_stream_analytics/value_streams/default/stages/issue/median")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupValueStreamStageMedian::builder()
+            .group("simple/group")
+            .value_stream("default")
+            .stage("issue")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/analytics/value_stream_analytics/value_streams/default/stages/issue/median")
+            .add_query_params(&[("created_after", "2020-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupValueStreamStageMedian::builder()
+            .group("simple/group")
+            .value_stream("default")
+            .stage("issue")
+            .created_after(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/analytics/value_stream_analytics/value_streams/default/stages/issue/median")
+            .add_query_params(&[("created_before", "2020-01-01")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupValueStreamStageMedian::builder()
+            .group("simple/group")
+            .value_stream("default")
+            .stage("issue")
+            .created_before(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the stages of a value stream of a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupValueStreamStages<'a> {
+    /// The group to query.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The value stream to query.
+    ///
+    /// The default value stream may be queried using `default`.
+    #[builder(setter(into))]
+    value_stream: NameOrId<'a>,
+}
+
+impl<'a> GroupValueStreamStages<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupValueStreamStagesBuilder<'a> {
+        GroupValueStreamStagesBuilder::default()
+    }
+}
+
+impl<'a> GroupValueStreamStagesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())?;
+        NameOrId::validate_path_segment("value_stream", self.value_stream.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupValueStreamStages<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/analytics/value_stream_analytics/value_streams/{}/stages",
+            self.group, self.value_stream,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::analytics::{
+        GroupValueStreamStages, GroupValueStreamStagesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_value_stream_are_needed() {
+        let err = GroupValueStreamStages::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStagesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupValueStreamStages::builder()
+            .value_stream("default")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStagesBuilderError, "group");
+    }
+
+    #[test]
+    fn value_stream_is_needed() {
+        let err = GroupValueStreamStages::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamStagesBuilderError, "value_stream");
+    }
+
+    #[test]
+    fn group_and_value_stream_are_sufficient() {
+        GroupValueStreamStages::builder()
+            .group(1)
+            .value_stream("default")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/analytics/value_stream_analytics/value_streams/default/stages")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupValueStreamStages::builder()
+            .group("simple/group")
+            .value_stream("default")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the value streams of a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupValueStreams<'a> {
+    /// The group to query.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupValueStreams<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupValueStreamsBuilder<'a> {
+        GroupValueStreamsBuilder::default()
+    }
+}
+
+impl<'a> GroupValueStreamsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupValueStreams<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/analytics/value_stream_analytics/value_streams",
+            self.group,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::analytics::{GroupValueStreams, GroupValueStreamsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupValueStreams::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupValueStreamsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupValueStreams::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/analytics/value_stream_analytics/value_streams")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupValueStreams::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
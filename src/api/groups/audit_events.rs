@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group audit event API endpoints.
+//!
+//! These endpoints are used for querying audit events of groups.
+
+mod audit_event;
+mod audit_events;
+
+pub use self::audit_event::GroupAuditEvent;
+pub use self::audit_event::GroupAuditEventBuilder;
+pub use self::audit_event::GroupAuditEventBuilderError;
+
+pub use self::audit_events::GroupAuditEvents;
+pub use self::audit_events::GroupAuditEventsBuilder;
+pub use self::audit_events::GroupAuditEventsBuilderError;
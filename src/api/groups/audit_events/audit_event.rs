@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for an audit event of a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupAuditEvent<'a> {
+    /// The group to query for the audit event.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the audit event.
+    audit_event: u64,
+}
+
+impl<'a> GroupAuditEvent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupAuditEventBuilder<'a> {
+        GroupAuditEventBuilder::default()
+    }
+}
+
+impl<'a> GroupAuditEventBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupAuditEvent<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/audit_events/{}", self.group, self.audit_event).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::audit_events::{GroupAuditEvent, GroupAuditEventBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_audit_event_are_needed() {
+        let err = GroupAuditEvent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAuditEventBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupAuditEvent::builder()
+            .audit_event(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAuditEventBuilderError, "group");
+    }
+
+    #[test]
+    fn audit_event_is_needed() {
+        let err = GroupAuditEvent::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAuditEventBuilderError, "audit_event");
+    }
+
+    #[test]
+    fn group_and_audit_event_are_sufficient() {
+        GroupAuditEvent::builder()
+            .group(1)
+            .audit_event(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/audit_events/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAuditEvent::builder()
+            .group("simple/group")
+            .audit_event(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
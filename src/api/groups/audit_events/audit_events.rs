@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for audit events of a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupAuditEvents<'a> {
+    /// The group to query for audit events.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Filter audit events created after a point in time.
+    #[builder(default)]
+    created_after: Option<DateTime<Utc>>,
+    /// Filter audit events created before a point in time.
+    #[builder(default)]
+    created_before: Option<DateTime<Utc>>,
+}
+
+impl<'a> GroupAuditEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupAuditEventsBuilder<'a> {
+        GroupAuditEventsBuilder::default()
+    }
+}
+
+impl<'a> GroupAuditEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupAuditEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/audit_events", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before);
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupAuditEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::groups::audit_events::{GroupAuditEvents, GroupAuditEventsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupAuditEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupAuditEventsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupAuditEvents::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/audit_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAuditEvents::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/audit_events")
+            .add_query_params(&[("created_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAuditEvents::builder()
+            .group("simple/group")
+            .created_after(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/audit_events")
+            .add_query_params(&[("created_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupAuditEvents::builder()
+            .group("simple/group")
+            .created_before(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,26 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group billable member API endpoints.
+//!
+//! These endpoints are used for querying and removing the billable members of top-level groups.
+
+mod members;
+mod memberships;
+mod remove;
+
+pub use self::members::BillableGroupMembers;
+pub use self::members::BillableGroupMembersBuilder;
+pub use self::members::BillableGroupMembersBuilderError;
+pub use self::members::BillableGroupMembersSort;
+
+pub use self::memberships::BillableGroupMemberMemberships;
+pub use self::memberships::BillableGroupMemberMembershipsBuilder;
+pub use self::memberships::BillableGroupMemberMembershipsBuilderError;
+
+pub use self::remove::RemoveBillableGroupMember;
+pub use self::remove::RemoveBillableGroupMemberBuilder;
+pub use self::remove::RemoveBillableGroupMemberBuilderError;
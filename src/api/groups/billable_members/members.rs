@@ -0,0 +1,194 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Orderings for billable members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BillableGroupMembersSort {
+    /// Order by access level, ascending.
+    AccessLevelAsc,
+    /// Order by access level, descending.
+    AccessLevelDesc,
+    /// Order by the most recently joined members.
+    LastJoined,
+    /// Order by the least recently joined members.
+    OldestJoined,
+    /// Order by name, ascending.
+    NameAsc,
+    /// Order by name, descending.
+    NameDesc,
+    /// Order by the most recent sign in.
+    RecentSignIn,
+    /// Order by the least recent sign in.
+    OldestSignIn,
+}
+
+impl BillableGroupMembersSort {
+    fn as_str(self) -> &'static str {
+        match self {
+            BillableGroupMembersSort::AccessLevelAsc => "access_level_asc",
+            BillableGroupMembersSort::AccessLevelDesc => "access_level_desc",
+            BillableGroupMembersSort::LastJoined => "last_joined",
+            BillableGroupMembersSort::OldestJoined => "oldest_joined",
+            BillableGroupMembersSort::NameAsc => "name_asc",
+            BillableGroupMembersSort::NameDesc => "name_desc",
+            BillableGroupMembersSort::RecentSignIn => "recent_sign_in",
+            BillableGroupMembersSort::OldestSignIn => "oldest_sign_in",
+        }
+    }
+}
+
+impl ParamValue<'static> for BillableGroupMembersSort {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the billable members of a top-level group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct BillableGroupMembers<'a> {
+    /// The group to query for billable members.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Search for members by name, username, or public email.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Order the results.
+    #[builder(default)]
+    sort: Option<BillableGroupMembersSort>,
+}
+
+impl<'a> BillableGroupMembers<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BillableGroupMembersBuilder<'a> {
+        BillableGroupMembersBuilder::default()
+    }
+}
+
+impl<'a> BillableGroupMembersBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for BillableGroupMembers<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/billable_members", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for BillableGroupMembers<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::billable_members::{
+        BillableGroupMembers, BillableGroupMembersBuilderError, BillableGroupMembersSort,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = BillableGroupMembers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, BillableGroupMembersBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        BillableGroupMembers::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/billable_members")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/billable_members")
+            .add_query_params(&[("search", "search")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("simple/group")
+            .search("search")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/billable_members")
+            .add_query_params(&[("sort", "name_asc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMembers::builder()
+            .group("simple/group")
+            .sort(BillableGroupMembersSort::NameAsc)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn billable_group_members_sort_as_str() {
+        let items = &[
+            (BillableGroupMembersSort::AccessLevelAsc, "access_level_asc"),
+            (
+                BillableGroupMembersSort::AccessLevelDesc,
+                "access_level_desc",
+            ),
+            (BillableGroupMembersSort::LastJoined, "last_joined"),
+            (BillableGroupMembersSort::OldestJoined, "oldest_joined"),
+            (BillableGroupMembersSort::NameAsc, "name_asc"),
+            (BillableGroupMembersSort::NameDesc, "name_desc"),
+            (BillableGroupMembersSort::RecentSignIn, "recent_sign_in"),
+            (BillableGroupMembersSort::OldestSignIn, "oldest_sign_in"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the memberships of a billable member of a top-level group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct BillableGroupMemberMemberships<'a> {
+    /// The group of the billable member.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the user.
+    user: u64,
+}
+
+impl<'a> BillableGroupMemberMemberships<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> BillableGroupMemberMembershipsBuilder<'a> {
+        BillableGroupMemberMembershipsBuilder::default()
+    }
+}
+
+impl<'a> BillableGroupMemberMembershipsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for BillableGroupMemberMemberships<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/billable_members/{}/memberships",
+            self.group, self.user,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for BillableGroupMemberMemberships<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::billable_members::{
+        BillableGroupMemberMemberships, BillableGroupMemberMembershipsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_user_are_needed() {
+        let err = BillableGroupMemberMemberships::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BillableGroupMemberMembershipsBuilderError,
+            "group"
+        );
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = BillableGroupMemberMemberships::builder()
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            BillableGroupMemberMembershipsBuilderError,
+            "group",
+        );
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = BillableGroupMemberMemberships::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, BillableGroupMemberMembershipsBuilderError, "user");
+    }
+
+    #[test]
+    fn group_and_user_are_sufficient() {
+        BillableGroupMemberMemberships::builder()
+            .group(1)
+            .user(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/billable_members/1/memberships")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BillableGroupMemberMemberships::builder()
+            .group("simple/group")
+            .user(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
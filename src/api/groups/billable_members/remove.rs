@@ -0,0 +1,105 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove a billable member from a top-level group and all of its subgroups and projects.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RemoveBillableGroupMember<'a> {
+    /// The group to remove the billable member from.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the user.
+    user: u64,
+}
+
+impl<'a> RemoveBillableGroupMember<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoveBillableGroupMemberBuilder<'a> {
+        RemoveBillableGroupMemberBuilder::default()
+    }
+}
+
+impl<'a> RemoveBillableGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for RemoveBillableGroupMember<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/billable_members/{}", self.group, self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::billable_members::{
+        RemoveBillableGroupMember, RemoveBillableGroupMemberBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_user_are_needed() {
+        let err = RemoveBillableGroupMember::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveBillableGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = RemoveBillableGroupMember::builder()
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveBillableGroupMemberBuilderError, "group");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = RemoveBillableGroupMember::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveBillableGroupMemberBuilderError, "user");
+    }
+
+    #[test]
+    fn group_and_user_are_sufficient() {
+        RemoveBillableGroupMember::builder()
+            .group(1)
+            .user(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/billable_members/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveBillableGroupMember::builder()
+            .group("simple/group")
+            .user(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group issue board API endpoints.
+//!
+//! These endpoints are used for querying and managing group issue boards.
+
+mod board;
+mod boards;
+mod create;
+mod delete;
+mod edit;
+pub mod lists;
+
+pub use self::board::GroupBoard;
+pub use self::board::GroupBoardBuilder;
+pub use self::board::GroupBoardBuilderError;
+
+pub use self::boards::GroupBoards;
+pub use self::boards::GroupBoardsBuilder;
+pub use self::boards::GroupBoardsBuilderError;
+
+pub use self::create::CreateGroupBoard;
+pub use self::create::CreateGroupBoardBuilder;
+pub use self::create::CreateGroupBoardBuilderError;
+
+pub use self::delete::DeleteGroupBoard;
+pub use self::delete::DeleteGroupBoardBuilder;
+pub use self::delete::DeleteGroupBoardBuilderError;
+
+pub use self::edit::EditGroupBoard;
+pub use self::edit::EditGroupBoardBuilder;
+pub use self::edit::EditGroupBoardBuilderError;
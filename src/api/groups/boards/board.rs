@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupBoard<'a> {
+    /// The group to query for the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+}
+
+impl<'a> GroupBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupBoardBuilder<'a> {
+        GroupBoardBuilder::default()
+    }
+}
+
+impl<'a> GroupBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupBoard<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards/{}", self.group, self.board).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::boards::{GroupBoard, GroupBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = GroupBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = GroupBoard::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        GroupBoard::builder().group(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a new issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateGroupBoard<'a> {
+    /// The group to create the board within.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The name of the board.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+}
+
+impl<'a> CreateGroupBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupBoardBuilder<'a> {
+        CreateGroupBoardBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupBoard<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("name", self.name.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{CreateGroupBoard, CreateGroupBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_name_are_needed() {
+        let err = CreateGroupBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateGroupBoard::builder()
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateGroupBoard::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardBuilderError, "name");
+    }
+
+    #[test]
+    fn group_and_name_are_sufficient() {
+        CreateGroupBoard::builder()
+            .group(1)
+            .name("name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoard::builder()
+            .group("simple/group")
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
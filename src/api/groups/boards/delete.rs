@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupBoard<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+}
+
+impl<'a> DeleteGroupBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupBoardBuilder<'a> {
+        DeleteGroupBoardBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupBoard<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards/{}", self.group, self.board).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{DeleteGroupBoard, DeleteGroupBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = DeleteGroupBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = DeleteGroupBoard::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        DeleteGroupBoard::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
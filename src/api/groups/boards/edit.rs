@@ -0,0 +1,298 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{CommaSeparatedList, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Edit an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditGroupBoard<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+
+    /// The new name of the board.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// Whether to hide the "Open" list or not.
+    #[builder(default)]
+    hide_backlog_list: Option<bool>,
+    /// Whether to hide the "Closed" list or not.
+    #[builder(default)]
+    hide_closed_list: Option<bool>,
+    /// The assignee the board should be scoped to.
+    #[builder(default)]
+    assignee_id: Option<u64>,
+    /// The milestone the board should be scoped to.
+    #[builder(default)]
+    milestone_id: Option<u64>,
+    /// The labels the board should be scoped to.
+    #[builder(setter(name = "_labels"), default, private)]
+    labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// The weight the board should be scoped to.
+    #[builder(default)]
+    weight: Option<u64>,
+}
+
+impl<'a> EditGroupBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupBoardBuilder<'a> {
+        EditGroupBoardBuilder::default()
+    }
+}
+
+impl<'a> EditGroupBoardBuilder<'a> {
+    /// Add a label to scope the board.
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Add a set of labels to scope the board.
+    pub fn labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for EditGroupBoard<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards/{}", self.group, self.board).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("hide_backlog_list", self.hide_backlog_list)
+            .push_opt("hide_closed_list", self.hide_closed_list)
+            .push_opt("assignee_id", self.assignee_id)
+            .push_opt("milestone_id", self.milestone_id)
+            .push_opt("labels", self.labels.as_ref())
+            .push_opt("weight", self.weight);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::{EditGroupBoard, EditGroupBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = EditGroupBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = EditGroupBoard::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        EditGroupBoard::builder().group(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_backlog_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_backlog_list=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .hide_backlog_list(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_hide_closed_list() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("hide_closed_list=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .hide_closed_list(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .assignee_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestone_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("milestone_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .milestone_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_weight() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("weight=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .weight(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("labels=label1%2Clabel2%2Clabel3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .label("label1")
+            .labels(["label2", "label3"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
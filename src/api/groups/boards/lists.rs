@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group issue board list API endpoints.
+//!
+//! These endpoints are used for querying and managing the lists of group issue boards.
+
+mod create;
+mod delete;
+mod edit;
+mod list;
+mod lists;
+
+pub use self::create::CreateGroupBoardList;
+pub use self::create::CreateGroupBoardListBuilder;
+pub use self::create::CreateGroupBoardListBuilderError;
+
+pub use self::delete::DeleteGroupBoardList;
+pub use self::delete::DeleteGroupBoardListBuilder;
+pub use self::delete::DeleteGroupBoardListBuilderError;
+
+pub use self::edit::EditGroupBoardList;
+pub use self::edit::EditGroupBoardListBuilder;
+pub use self::edit::EditGroupBoardListBuilderError;
+
+pub use self::list::GroupBoardList;
+pub use self::list::GroupBoardListBuilder;
+pub use self::list::GroupBoardListBuilderError;
+
+pub use self::lists::GroupBoardLists;
+pub use self::lists::GroupBoardListsBuilder;
+pub use self::lists::GroupBoardListsBuilderError;
@@ -0,0 +1,214 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a list on an issue board within a group.
+///
+/// Only one of `label_id`, `assignee_id`, `milestone_id`, or `iteration_id` should be set.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateGroupBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+
+    /// The ID of the label for the list.
+    #[builder(default)]
+    label_id: Option<u64>,
+    /// The ID of the assignee for the list.
+    #[builder(default)]
+    assignee_id: Option<u64>,
+    /// The ID of the milestone for the list.
+    #[builder(default)]
+    milestone_id: Option<u64>,
+    /// The ID of the iteration for the list.
+    #[builder(default)]
+    iteration_id: Option<u64>,
+}
+
+impl<'a> CreateGroupBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateGroupBoardListBuilder<'a> {
+        CreateGroupBoardListBuilder::default()
+    }
+}
+
+impl<'a> CreateGroupBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateGroupBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards/{}/lists", self.group, self.board).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("label_id", self.label_id)
+            .push_opt("assignee_id", self.assignee_id)
+            .push_opt("milestone_id", self.milestone_id)
+            .push_opt("iteration_id", self.iteration_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::lists::{
+        CreateGroupBoardList, CreateGroupBoardListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = CreateGroupBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateGroupBoardList::builder()
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = CreateGroupBoardList::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateGroupBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        CreateGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_label_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("label_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .label_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("assignee_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .assignee_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_milestone_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("milestone_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .milestone_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_iteration_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("iteration_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .iteration_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Delete a list from an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> DeleteGroupBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupBoardListBuilder<'a> {
+        DeleteGroupBoardListBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}/lists/{}",
+            self.group, self.board, self.list,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::lists::{
+        DeleteGroupBoardList, DeleteGroupBoardListBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_board_and_list_are_needed() {
+        let err = DeleteGroupBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupBoardList::builder()
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = DeleteGroupBoardList::builder()
+            .group(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = DeleteGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn group_board_and_list_are_sufficient() {
+        DeleteGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Move a list on an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct EditGroupBoardList<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+    /// The new position of the list.
+    position: u64,
+}
+
+impl<'a> EditGroupBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupBoardListBuilder<'a> {
+        EditGroupBoardListBuilder::default()
+    }
+}
+
+impl<'a> EditGroupBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for EditGroupBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/boards/{}/lists/{}",
+            self.group, self.board, self.list,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("position", self.position);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::boards::lists::{EditGroupBoardList, EditGroupBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_board_list_and_position_are_needed() {
+        let err = EditGroupBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupBoardList::builder()
+            .board(1)
+            .list(1)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = EditGroupBoardList::builder()
+            .group(1)
+            .list(1)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = EditGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .position(0)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn position_is_needed() {
+        let err = EditGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupBoardListBuilderError, "position");
+    }
+
+    #[test]
+    fn group_board_list_and_position_are_sufficient() {
+        EditGroupBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(1)
+            .position(0)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/boards/1/lists/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("position=0")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .list(2)
+            .position(0)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
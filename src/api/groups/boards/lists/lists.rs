@@ -0,0 +1,96 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the lists of an issue board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupBoardLists<'a> {
+    /// The group of the board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the board.
+    board: u64,
+}
+
+impl<'a> GroupBoardLists<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupBoardListsBuilder<'a> {
+        GroupBoardListsBuilder::default()
+    }
+}
+
+impl<'a> GroupBoardListsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupBoardLists<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/boards/{}/lists", self.group, self.board).into()
+    }
+}
+
+impl<'a> Pageable for GroupBoardLists<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::boards::lists::{GroupBoardLists, GroupBoardListsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = GroupBoardLists::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardListsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupBoardLists::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardListsBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = GroupBoardLists::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupBoardListsBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        GroupBoardLists::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/boards/1/lists")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupBoardLists::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
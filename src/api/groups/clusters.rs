@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group cluster API endpoints.
+//!
+//! These endpoints are used for managing Kubernetes clusters of groups.
+
+mod add;
+mod cluster;
+mod clusters;
+mod delete;
+mod edit;
+
+pub use self::add::AddGroupCluster;
+pub use self::add::AddGroupClusterBuilder;
+pub use self::add::AddGroupClusterBuilderError;
+
+pub use self::cluster::GroupCluster;
+pub use self::cluster::GroupClusterBuilder;
+pub use self::cluster::GroupClusterBuilderError;
+
+pub use self::clusters::GroupClusters;
+pub use self::clusters::GroupClustersBuilder;
+pub use self::clusters::GroupClustersBuilderError;
+
+pub use self::delete::DeleteGroupCluster;
+pub use self::delete::DeleteGroupClusterBuilder;
+pub use self::delete::DeleteGroupClusterBuilderError;
+
+pub use self::edit::EditGroupCluster;
+pub use self::edit::EditGroupClusterBuilder;
+pub use self::edit::EditGroupClusterBuilderError;
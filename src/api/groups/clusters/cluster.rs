@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a Kubernetes cluster of a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupCluster<'a> {
+    /// The group of the cluster.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the cluster.
+    cluster: u64,
+}
+
+impl<'a> GroupCluster<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupClusterBuilder<'a> {
+        GroupClusterBuilder::default()
+    }
+}
+
+impl<'a> GroupClusterBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupCluster<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/clusters/{}", self.group, self.cluster).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::clusters::{GroupCluster, GroupClusterBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_cluster_are_needed() {
+        let err = GroupCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupCluster::builder().cluster(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = GroupCluster::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn group_and_cluster_are_sufficient() {
+        GroupCluster::builder().group(1).cluster(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove a Kubernetes cluster from a group.
+///
+/// This does not delete the cluster itself, only its integration with GitLab.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DeleteGroupCluster<'a> {
+    /// The group of the cluster.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the cluster.
+    cluster: u64,
+}
+
+impl<'a> DeleteGroupCluster<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteGroupClusterBuilder<'a> {
+        DeleteGroupClusterBuilder::default()
+    }
+}
+
+impl<'a> DeleteGroupClusterBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for DeleteGroupCluster<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/clusters/{}", self.group, self.cluster).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::clusters::{DeleteGroupCluster, DeleteGroupClusterBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_cluster_are_needed() {
+        let err = DeleteGroupCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = DeleteGroupCluster::builder()
+            .cluster(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = DeleteGroupCluster::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteGroupClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn group_and_cluster_are_sufficient() {
+        DeleteGroupCluster::builder()
+            .group(1)
+            .cluster(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
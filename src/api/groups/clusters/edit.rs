@@ -0,0 +1,286 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::clusters::EditClusterPlatformKubernetesAttributes;
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit a Kubernetes cluster of a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditGroupCluster<'a> {
+    /// The group of the cluster.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the cluster.
+    cluster: u64,
+
+    /// The name of the cluster.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// The base domain of the cluster.
+    #[builder(setter(into), default)]
+    domain: Option<Cow<'a, str>>,
+    /// The environments the cluster applies to.
+    #[builder(setter(into), default)]
+    environment_scope: Option<Cow<'a, str>>,
+    /// The ID of the management project for the cluster.
+    #[builder(default)]
+    management_project_id: Option<u64>,
+    /// Whether GitLab manages namespaces and service accounts for the cluster.
+    #[builder(default)]
+    managed: Option<bool>,
+    /// Whether to use a separate namespace for each environment.
+    #[builder(default)]
+    namespace_per_environment: Option<bool>,
+    /// Update how to connect to the Kubernetes API of the cluster.
+    #[builder(default)]
+    platform_kubernetes_attributes: Option<EditClusterPlatformKubernetesAttributes<'a>>,
+}
+
+impl<'a> EditGroupCluster<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupClusterBuilder<'a> {
+        EditGroupClusterBuilder::default()
+    }
+}
+
+impl<'a> EditGroupClusterBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for EditGroupCluster<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/clusters/{}", self.group, self.cluster).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("name", self.name.as_ref())
+            .push_opt("domain", self.domain.as_ref())
+            .push_opt("environment_scope", self.environment_scope.as_ref())
+            .push_opt("management_project_id", self.management_project_id)
+            .push_opt("managed", self.managed)
+            .push_opt("namespace_per_environment", self.namespace_per_environment);
+
+        if let Some(attrs) = self.platform_kubernetes_attributes.as_ref() {
+            attrs.add_params(&mut params);
+        }
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::clusters::EditClusterPlatformKubernetesAttributes;
+    use crate::api::groups::clusters::{EditGroupCluster, EditGroupClusterBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_cluster_are_needed() {
+        let err = EditGroupCluster::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupCluster::builder().cluster(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupClusterBuilderError, "group");
+    }
+
+    #[test]
+    fn cluster_is_needed() {
+        let err = EditGroupCluster::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupClusterBuilderError, "cluster");
+    }
+
+    #[test]
+    fn group_and_cluster_are_sufficient() {
+        EditGroupCluster::builder()
+            .group(1)
+            .cluster(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=cluster")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .name("cluster")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_domain() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("domain=example.com")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .domain("example.com")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_environment_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("environment_scope=production")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .environment_scope("production")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_management_project_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("management_project_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .management_project_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_managed() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("managed=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .managed(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_namespace_per_environment() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("namespace_per_environment=false")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .namespace_per_environment(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_platform_kubernetes_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/clusters/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("platform_kubernetes_attributes%5Btoken%5D=token")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupCluster::builder()
+            .group("simple/group")
+            .cluster(1)
+            .platform_kubernetes_attributes(
+                EditClusterPlatformKubernetesAttributes::builder()
+                    .token("token")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
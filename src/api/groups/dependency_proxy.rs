@@ -0,0 +1,15 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group dependency proxy API endpoints.
+//!
+//! These endpoints are used for managing the dependency proxy of groups.
+
+mod purge_cache;
+
+pub use self::purge_cache::PurgeGroupDependencyProxyCache;
+pub use self::purge_cache::PurgeGroupDependencyProxyCacheBuilder;
+pub use self::purge_cache::PurgeGroupDependencyProxyCacheBuilderError;
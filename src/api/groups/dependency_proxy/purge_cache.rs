@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Purge the dependency proxy cache of a group.
+///
+/// This schedules the removal of all cached blobs and manifests of the group. Requires
+/// owner access to the group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct PurgeGroupDependencyProxyCache<'a> {
+    /// The group to purge the cache of.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> PurgeGroupDependencyProxyCache<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PurgeGroupDependencyProxyCacheBuilder<'a> {
+        PurgeGroupDependencyProxyCacheBuilder::default()
+    }
+}
+
+impl<'a> PurgeGroupDependencyProxyCacheBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for PurgeGroupDependencyProxyCache<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/dependency_proxy/cache", self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::dependency_proxy::{
+        PurgeGroupDependencyProxyCache, PurgeGroupDependencyProxyCacheBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = PurgeGroupDependencyProxyCache::builder()
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            PurgeGroupDependencyProxyCacheBuilderError,
+            "group",
+        );
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        PurgeGroupDependencyProxyCache::builder()
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/dependency_proxy/cache")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PurgeGroupDependencyProxyCache::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
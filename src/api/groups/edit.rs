@@ -13,9 +13,18 @@ use crate::api::groups::{
     SubgroupCreationAccessLevel,
 };
 
+#[derive(Debug, Clone)]
+enum AvatarUpdate<'a> {
+    Upload {
+        filename: Cow<'a, str>,
+        contents: Cow<'a, [u8]>,
+    },
+    Remove,
+}
+
 /// Edit an existing group.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditGroup<'a> {
     /// The group to edit.
     #[builder(setter(into))]
@@ -54,9 +63,9 @@ pub struct EditGroup<'a> {
     /// Disable email notifications from the group.
     #[builder(default)]
     emails_disabled: Option<bool>,
-    // TODO: Figure out how to actually use this.
-    // avatar   mixed   no  Image file for avatar of the group
-    // avatar: ???,
+    /// The avatar image for the group.
+    #[builder(setter(name = "_avatar"), default, private)]
+    avatar: Option<AvatarUpdate<'a>>,
     /// Disable group-wide mentions.
     #[builder(default)]
     mentions_disabled: Option<bool>,
@@ -87,6 +96,33 @@ impl<'a> EditGroup<'a> {
     }
 }
 
+impl<'a> EditGroupBuilder<'a> {
+    /// Upload a new avatar image for the group.
+    ///
+    /// Setting an avatar sends the request as a multipart form.
+    pub fn avatar<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.avatar = Some(Some(AvatarUpdate::Upload {
+            filename: filename.into(),
+            contents: contents.into(),
+        }));
+        self
+    }
+
+    /// Remove the avatar image of the group.
+    pub fn remove_avatar(&mut self) -> &mut Self {
+        self.avatar = Some(Some(AvatarUpdate::Remove));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EditGroup<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -128,7 +164,17 @@ impl<'a> Endpoint for EditGroup<'a> {
                 self.extra_shared_runners_minutes_limit,
             );
 
-        params.into_body()
+        match self.avatar.as_ref() {
+            Some(AvatarUpdate::Upload {
+                filename,
+                contents,
+            }) => params.into_multipart_body("avatar", filename, contents),
+            Some(AvatarUpdate::Remove) => {
+                params.push("avatar", "");
+                params.into_body()
+            },
+            None => params.into_body(),
+        }
     }
 }
 
@@ -510,4 +556,56 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "name\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "image data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroup::builder()
+            .group("simple/group")
+            .name("name")
+            .avatar("avatar.png", &b"image data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_remove_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("avatar=")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroup::builder()
+            .group("simple/group")
+            .remove_avatar()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
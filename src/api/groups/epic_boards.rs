@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group epic board API endpoints.
+//!
+//! These endpoints are used for querying group epic boards.
+
+mod board;
+mod boards;
+mod list;
+mod lists;
+
+pub use self::board::GroupEpicBoard;
+pub use self::board::GroupEpicBoardBuilder;
+pub use self::board::GroupEpicBoardBuilderError;
+
+pub use self::boards::GroupEpicBoards;
+pub use self::boards::GroupEpicBoardsBuilder;
+pub use self::boards::GroupEpicBoardsBuilderError;
+
+pub use self::list::GroupEpicBoardList;
+pub use self::list::GroupEpicBoardListBuilder;
+pub use self::list::GroupEpicBoardListBuilderError;
+
+pub use self::lists::GroupEpicBoardLists;
+pub use self::lists::GroupEpicBoardListsBuilder;
+pub use self::lists::GroupEpicBoardListsBuilderError;
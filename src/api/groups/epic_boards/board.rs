@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for an epic board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupEpicBoard<'a> {
+    /// The group to query for the epic board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic board.
+    board: u64,
+}
+
+impl<'a> GroupEpicBoard<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupEpicBoardBuilder<'a> {
+        GroupEpicBoardBuilder::default()
+    }
+}
+
+impl<'a> GroupEpicBoardBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupEpicBoard<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epic_boards/{}", self.group, self.board).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epic_boards::{GroupEpicBoard, GroupEpicBoardBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_board_are_needed() {
+        let err = GroupEpicBoard::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupEpicBoard::builder().board(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = GroupEpicBoard::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardBuilderError, "board");
+    }
+
+    #[test]
+    fn group_and_board_are_sufficient() {
+        GroupEpicBoard::builder().group(1).board(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epic_boards/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupEpicBoard::builder()
+            .group("simple/group")
+            .board(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,77 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for epic boards within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupEpicBoards<'a> {
+    /// The group to query for epic boards.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+}
+
+impl<'a> GroupEpicBoards<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupEpicBoardsBuilder<'a> {
+        GroupEpicBoardsBuilder::default()
+    }
+}
+
+impl<'a> GroupEpicBoardsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupEpicBoards<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epic_boards", self.group).into()
+    }
+}
+
+impl<'a> Pageable for GroupEpicBoards<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epic_boards::{GroupEpicBoards, GroupEpicBoardsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupEpicBoards::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupEpicBoards::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epic_boards")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupEpicBoards::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a list of an epic board within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupEpicBoardList<'a> {
+    /// The group of the epic board.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the epic board.
+    board: u64,
+    /// The ID of the list.
+    list: u64,
+}
+
+impl<'a> GroupEpicBoardList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupEpicBoardListBuilder<'a> {
+        GroupEpicBoardListBuilder::default()
+    }
+}
+
+impl<'a> GroupEpicBoardListBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupEpicBoardList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epic_boards/{}/lists/{}",
+            self.group, self.board, self.list,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epic_boards::{GroupEpicBoardList, GroupEpicBoardListBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_board_and_list_are_needed() {
+        let err = GroupEpicBoardList::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupEpicBoardList::builder()
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardListBuilderError, "group");
+    }
+
+    #[test]
+    fn board_is_needed() {
+        let err = GroupEpicBoardList::builder()
+            .group(1)
+            .list(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardListBuilderError, "board");
+    }
+
+    #[test]
+    fn list_is_needed() {
+        let err = GroupEpicBoardList::builder()
+            .group(1)
+            .board(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupEpicBoardListBuilderError, "list");
+    }
+
+    #[test]
+    fn group_board_and_list_are_sufficient() {
+        GroupEpicBoardList::builder()
+            .group(1)
+            .board(1)
+            .list(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epic_boards/1/lists/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupEpicBoardList::builder()
+            .group("simple/group")
+            .board(1)
+            .list(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group epic issue API endpoints.
+//!
+//! These endpoints are used for querying and managing the issues assigned to an epic.
+
+mod assign;
+mod issues;
+mod remove;
+mod update;
+
+pub use self::assign::AssignEpicIssue;
+pub use self::assign::AssignEpicIssueBuilder;
+pub use self::assign::AssignEpicIssueBuilderError;
+
+pub use self::issues::EpicIssues;
+pub use self::issues::EpicIssuesBuilder;
+pub use self::issues::EpicIssuesBuilderError;
+
+pub use self::remove::RemoveEpicIssue;
+pub use self::remove::RemoveEpicIssueBuilder;
+pub use self::remove::RemoveEpicIssueBuilderError;
+
+pub use self::update::UpdateEpicIssue;
+pub use self::update::UpdateEpicIssueBuilder;
+pub use self::update::UpdateEpicIssueBuilderError;
@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Assign an issue to an epic.
+///
+/// If the issue is already assigned to another epic, it is moved to this epic.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct AssignEpicIssue<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+    /// The global ID of the issue to assign.
+    issue: u64,
+}
+
+impl<'a> AssignEpicIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AssignEpicIssueBuilder<'a> {
+        AssignEpicIssueBuilder::default()
+    }
+}
+
+impl<'a> AssignEpicIssueBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for AssignEpicIssue<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/issues/{}",
+            self.group, self.epic, self.issue,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::issues::{AssignEpicIssue, AssignEpicIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_issue_are_needed() {
+        let err = AssignEpicIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AssignEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = AssignEpicIssue::builder()
+            .epic(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = AssignEpicIssue::builder()
+            .group(1)
+            .issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignEpicIssueBuilderError, "epic");
+    }
+
+    #[test]
+    fn issue_is_needed() {
+        let err = AssignEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignEpicIssueBuilderError, "issue");
+    }
+
+    #[test]
+    fn group_epic_and_issue_are_sufficient() {
+        AssignEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/issues/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AssignEpicIssue::builder()
+            .group("simple/group")
+            .epic(1)
+            .issue(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Remove an issue from an epic.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RemoveEpicIssue<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+    /// The ID of the epic-issue association.
+    epic_issue: u64,
+}
+
+impl<'a> RemoveEpicIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RemoveEpicIssueBuilder<'a> {
+        RemoveEpicIssueBuilder::default()
+    }
+}
+
+impl<'a> RemoveEpicIssueBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for RemoveEpicIssue<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/issues/{}",
+            self.group, self.epic, self.epic_issue,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::issues::{RemoveEpicIssue, RemoveEpicIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_epic_issue_are_needed() {
+        let err = RemoveEpicIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = RemoveEpicIssue::builder()
+            .epic(1)
+            .epic_issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = RemoveEpicIssue::builder()
+            .group(1)
+            .epic_issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveEpicIssueBuilderError, "epic");
+    }
+
+    #[test]
+    fn epic_issue_is_needed() {
+        let err = RemoveEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RemoveEpicIssueBuilderError, "epic_issue");
+    }
+
+    #[test]
+    fn group_epic_and_epic_issue_are_sufficient() {
+        RemoveEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .epic_issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/epics/1/issues/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveEpicIssue::builder()
+            .group("simple/group")
+            .epic(1)
+            .epic_issue(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
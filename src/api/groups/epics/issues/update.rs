@@ -0,0 +1,184 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reorder an issue within an epic.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UpdateEpicIssue<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+    /// The ID of the epic-issue association.
+    epic_issue: u64,
+
+    /// The ID of the epic-issue association which should be placed before this one.
+    #[builder(default)]
+    move_before_id: Option<u64>,
+    /// The ID of the epic-issue association which should be placed after this one.
+    #[builder(default)]
+    move_after_id: Option<u64>,
+}
+
+impl<'a> UpdateEpicIssue<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateEpicIssueBuilder<'a> {
+        UpdateEpicIssueBuilder::default()
+    }
+}
+
+impl<'a> UpdateEpicIssueBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UpdateEpicIssue<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/issues/{}",
+            self.group, self.epic, self.epic_issue,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("move_before_id", self.move_before_id)
+            .push_opt("move_after_id", self.move_after_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::issues::{UpdateEpicIssue, UpdateEpicIssueBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_epic_issue_are_needed() {
+        let err = UpdateEpicIssue::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = UpdateEpicIssue::builder()
+            .epic(1)
+            .epic_issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateEpicIssueBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = UpdateEpicIssue::builder()
+            .group(1)
+            .epic_issue(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateEpicIssueBuilderError, "epic");
+    }
+
+    #[test]
+    fn epic_issue_is_needed() {
+        let err = UpdateEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateEpicIssueBuilderError, "epic_issue");
+    }
+
+    #[test]
+    fn group_epic_and_epic_issue_are_sufficient() {
+        UpdateEpicIssue::builder()
+            .group(1)
+            .epic(1)
+            .epic_issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/issues/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateEpicIssue::builder()
+            .group("simple/group")
+            .epic(1)
+            .epic_issue(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_before_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/issues/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_before_id=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateEpicIssue::builder()
+            .group("simple/group")
+            .epic(1)
+            .epic_issue(2)
+            .move_before_id(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_after_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/issues/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_after_id=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateEpicIssue::builder()
+            .group("simple/group")
+            .epic(1)
+            .epic_issue(2)
+            .move_after_id(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,36 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group epic link API endpoints.
+//!
+//! These endpoints are used for querying and managing the parent/child relationships between
+//! epics.
+
+mod assign;
+mod children;
+mod create;
+mod remove;
+mod update;
+
+pub use self::assign::AssignChildEpic;
+pub use self::assign::AssignChildEpicBuilder;
+pub use self::assign::AssignChildEpicBuilderError;
+
+pub use self::children::ChildEpics;
+pub use self::children::ChildEpicsBuilder;
+pub use self::children::ChildEpicsBuilderError;
+
+pub use self::create::CreateChildEpic;
+pub use self::create::CreateChildEpicBuilder;
+pub use self::create::CreateChildEpicBuilderError;
+
+pub use self::remove::RemoveChildEpic;
+pub use self::remove::RemoveChildEpicBuilder;
+pub use self::remove::RemoveChildEpicBuilderError;
+
+pub use self::update::UpdateChildEpic;
+pub use self::update::UpdateChildEpicBuilder;
+pub use self::update::UpdateChildEpicBuilderError;
@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Assign an existing epic as a child of an epic.
+///
+/// If the child epic already has a parent, it is moved to this epic.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct AssignChildEpic<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+    /// The global ID of the epic to assign as a child.
+    child_epic: u64,
+}
+
+impl<'a> AssignChildEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AssignChildEpicBuilder<'a> {
+        AssignChildEpicBuilder::default()
+    }
+}
+
+impl<'a> AssignChildEpicBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for AssignChildEpic<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/epics/{}",
+            self.group, self.epic, self.child_epic,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::links::{AssignChildEpic, AssignChildEpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_child_epic_are_needed() {
+        let err = AssignChildEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AssignChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = AssignChildEpic::builder()
+            .epic(1)
+            .child_epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = AssignChildEpic::builder()
+            .group(1)
+            .child_epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignChildEpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn child_epic_is_needed() {
+        let err = AssignChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AssignChildEpicBuilderError, "child_epic");
+    }
+
+    #[test]
+    fn group_epic_and_child_epic_are_sufficient() {
+        AssignChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .child_epic(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AssignChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .child_epic(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
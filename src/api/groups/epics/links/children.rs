@@ -0,0 +1,92 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the child epics of an epic.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ChildEpics<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+}
+
+impl<'a> ChildEpics<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ChildEpicsBuilder<'a> {
+        ChildEpicsBuilder::default()
+    }
+}
+
+impl<'a> ChildEpicsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ChildEpics<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}/epics", self.group, self.epic).into()
+    }
+}
+
+impl<'a> Pageable for ChildEpics<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::epics::links::{ChildEpics, ChildEpicsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_epic_are_needed() {
+        let err = ChildEpics::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ChildEpicsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = ChildEpics::builder().epic(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ChildEpicsBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = ChildEpics::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ChildEpicsBuilderError, "epic");
+    }
+
+    #[test]
+    fn group_and_epic_are_sufficient() {
+        ChildEpics::builder().group(1).epic(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/epics/1/epics")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ChildEpics::builder()
+            .group("simple/group")
+            .epic(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a new epic as a child of an epic.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateChildEpic<'a> {
+    /// The group of the parent epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the parent epic.
+    epic: u64,
+    /// The title of the new epic.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+
+    /// Whether the new epic should be confidential or not.
+    #[builder(default)]
+    confidential: Option<bool>,
+}
+
+impl<'a> CreateChildEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateChildEpicBuilder<'a> {
+        CreateChildEpicBuilder::default()
+    }
+}
+
+impl<'a> CreateChildEpicBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateChildEpic<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/epics/{}/epics", self.group, self.epic).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", self.title.as_ref())
+            .push_opt("confidential", self.confidential);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::links::{CreateChildEpic, CreateChildEpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_title_are_needed() {
+        let err = CreateChildEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = CreateChildEpic::builder()
+            .epic(1)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = CreateChildEpic::builder()
+            .group(1)
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateChildEpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn title_is_needed() {
+        let err = CreateChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateChildEpicBuilderError, "title");
+    }
+
+    #[test]
+    fn group_epic_and_title_are_sufficient() {
+        CreateChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .title("title")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("title=title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_confidential() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&confidential=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .title("title")
+            .confidential(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
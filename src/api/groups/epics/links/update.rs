@@ -0,0 +1,184 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Reorder a child epic within its parent epic.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UpdateChildEpic<'a> {
+    /// The group of the epic.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The internal ID of the epic.
+    epic: u64,
+    /// The global ID of the child epic.
+    child_epic: u64,
+
+    /// The global ID of the child epic.which should be placed before this one.
+    #[builder(default)]
+    move_before_id: Option<u64>,
+    /// The global ID of the child epic.which should be placed after this one.
+    #[builder(default)]
+    move_after_id: Option<u64>,
+}
+
+impl<'a> UpdateChildEpic<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UpdateChildEpicBuilder<'a> {
+        UpdateChildEpicBuilder::default()
+    }
+}
+
+impl<'a> UpdateChildEpicBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UpdateChildEpic<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/epics/{}/epics/{}",
+            self.group, self.epic, self.child_epic,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("move_before_id", self.move_before_id)
+            .push_opt("move_after_id", self.move_after_id);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::epics::links::{UpdateChildEpic, UpdateChildEpicBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_epic_and_child_epic_are_needed() {
+        let err = UpdateChildEpic::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = UpdateChildEpic::builder()
+            .epic(1)
+            .child_epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateChildEpicBuilderError, "group");
+    }
+
+    #[test]
+    fn epic_is_needed() {
+        let err = UpdateChildEpic::builder()
+            .group(1)
+            .child_epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateChildEpicBuilderError, "epic");
+    }
+
+    #[test]
+    fn child_epic_is_needed() {
+        let err = UpdateChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UpdateChildEpicBuilderError, "child_epic");
+    }
+
+    #[test]
+    fn group_epic_and_child_epic_are_sufficient() {
+        UpdateChildEpic::builder()
+            .group(1)
+            .epic(1)
+            .child_epic(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .child_epic(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_before_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_before_id=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .child_epic(2)
+            .move_before_id(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_move_after_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/epics/1/epics/2")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("move_after_id=3")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UpdateChildEpic::builder()
+            .group("simple/group")
+            .epic(1)
+            .child_epic(2)
+            .move_after_id(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
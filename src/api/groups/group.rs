@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific group on an instance.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Group<'a> {
     /// The group to get.
     #[builder(setter(into))]
@@ -33,6 +33,12 @@ impl<'a> Group<'a> {
     }
 }
 
+impl<'a> GroupBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Group<'a> {
     fn method(&self) -> Method {
         Method::GET
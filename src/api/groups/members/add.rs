@@ -12,7 +12,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Add a user as a member of a group.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct AddGroupMember<'a> {
     /// The group to add the user to.
     #[builder(setter(into))]
@@ -25,6 +25,9 @@ pub struct AddGroupMember<'a> {
     /// When the user's access expires.
     #[builder(default)]
     expires_at: Option<NaiveDate>,
+    /// The ID of a custom member role to assign to the user.
+    #[builder(default)]
+    member_role_id: Option<u64>,
 }
 
 impl<'a> AddGroupMember<'a> {
@@ -34,6 +37,12 @@ impl<'a> AddGroupMember<'a> {
     }
 }
 
+impl<'a> AddGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for AddGroupMember<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -49,7 +58,8 @@ impl<'a> Endpoint for AddGroupMember<'a> {
         params
             .push("user_id", self.user)
             .push("access_level", self.access_level.as_u64())
-            .push_opt("expires_at", self.expires_at);
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("member_role_id", self.member_role_id);
 
         params.into_body()
     }
@@ -155,4 +165,29 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/group%2Fsubgroup/members")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "user_id=1",
+                "&access_level=30",
+                "&member_role_id=5",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddGroupMember::builder()
+            .group("group/subgroup")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .member_role_id(5)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -11,18 +11,39 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a single member of a group.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct GroupMember<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
     group: NameOrId<'a>,
     /// The ID of the user.
     user: u64,
+    // Whether to include ancestor users from enclosing Groups in the queried list of members.
+    #[builder(private)]
+    _include_ancestors: bool,
 }
 
 impl<'a> GroupMember<'a> {
     /// Create a builder for the endpoint.
     pub fn builder() -> GroupMemberBuilder<'a> {
-        GroupMemberBuilder::default()
+        GroupMemberBuilder {
+            _include_ancestors: Some(false),
+            ..Default::default()
+        }
+    }
+
+    /// Create an ancestor-including builder for the endpoint.
+    pub fn all_builder() -> GroupMemberBuilder<'a> {
+        GroupMemberBuilder {
+            _include_ancestors: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> GroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
     }
 }
 
@@ -32,7 +53,11 @@ impl<'a> Endpoint for GroupMember<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members/{}", self.group, self.user).into()
+        if self._include_ancestors {
+            format!("groups/{}/members/all/{}", self.group, self.user).into()
+        } else {
+            format!("groups/{}/members/{}", self.group, self.user).into()
+        }
     }
 }
 
@@ -80,4 +105,20 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/members/all/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMember::all_builder()
+            .group("group/subgroup")
+            .user(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
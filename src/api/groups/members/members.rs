@@ -13,7 +13,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a members of a group.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupMembers<'a> {
     /// The group to query for membership.
     #[builder(setter(into))]
@@ -25,12 +25,24 @@ pub struct GroupMembers<'a> {
     /// A search string to filter members by.
     #[builder(setter(name = "_user_ids"), default, private)]
     user_ids: HashSet<u64>,
+    // Whether to include ancestor users from enclosing Groups in the queried list of members.
+    #[builder(private)]
+    _include_ancestors: bool,
 }
 
 impl<'a> GroupMembers<'a> {
     /// Create a builder for the endpoint.
     pub fn builder() -> GroupMembersBuilder<'a> {
-        GroupMembersBuilder::default()
+        let mut builder = GroupMembersBuilder::default();
+        builder._include_ancestors(false);
+        builder
+    }
+
+    /// Create a builder for the endpoint that includes ancestor groups.
+    pub fn all_builder() -> GroupMembersBuilder<'a> {
+        let mut builder = GroupMembersBuilder::default();
+        builder._include_ancestors(true);
+        builder
     }
 }
 
@@ -51,6 +63,10 @@ impl<'a> GroupMembersBuilder<'a> {
         self.user_ids.get_or_insert_with(HashSet::new).extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
 }
 
 impl<'a> Endpoint for GroupMembers<'a> {
@@ -59,7 +75,11 @@ impl<'a> Endpoint for GroupMembers<'a> {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("groups/{}/members", self.group).into()
+        if self._include_ancestors {
+            format!("groups/{}/members/all", self.group).into()
+        } else {
+            format!("groups/{}/members", self.group).into()
+        }
     }
 
     fn parameters(&self) -> QueryParams {
@@ -85,11 +105,16 @@ mod tests {
     fn group_is_needed() {
         let err = GroupMembers::builder().build().unwrap_err();
         crate::test::assert_missing_field!(err, GroupMembersBuilderError, "group");
+
+        let err = GroupMembers::all_builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMembersBuilderError, "group");
     }
 
     #[test]
     fn group_is_sufficient() {
         GroupMembers::builder().group(1).build().unwrap();
+
+        GroupMembers::all_builder().group(1).build().unwrap();
     }
 
     #[test]
@@ -107,6 +132,21 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/group%2Fsubgroup/members/all")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMembers::all_builder()
+            .group("group/subgroup")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
     #[test]
     fn endpoint_query() {
         let endpoint = ExpectedUrl::builder()
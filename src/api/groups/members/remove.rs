@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Remove a user from a group.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RemoveGroupMember<'a> {
     /// The group to remove the user from.
     #[builder(setter(into))]
@@ -21,6 +21,9 @@ pub struct RemoveGroupMember<'a> {
     /// unassign from any issues or merge requests inside a given group.
     #[builder(default)]
     unassign_issuables: Option<bool>,
+    /// Skip removing the user's direct memberships in subgroups and projects.
+    #[builder(default)]
+    skip_subresources: Option<bool>,
 }
 
 impl<'a> RemoveGroupMember<'a> {
@@ -30,6 +33,12 @@ impl<'a> RemoveGroupMember<'a> {
     }
 }
 
+impl<'a> RemoveGroupMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for RemoveGroupMember<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -42,7 +51,9 @@ impl<'a> Endpoint for RemoveGroupMember<'a> {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         let mut params = FormParams::default();
 
-        params.push_opt("unassign_issuables", self.unassign_issuables);
+        params
+            .push_opt("unassign_issuables", self.unassign_issuables)
+            .push_opt("skip_subresources", self.skip_subresources);
 
         params.into_body()
     }
@@ -120,4 +131,24 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_skip_subresources() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/group%2Fsubgroup/members/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("skip_subresources=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveGroupMember::builder()
+            .group("group/subgroup")
+            .user(1)
+            .skip_subresources(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
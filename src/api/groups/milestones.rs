@@ -6,10 +6,51 @@
 
 //! Group milestone API endpoints.
 //!
-//! These endpoints are used for querying group milestones.
+//! These endpoints are used for querying and managing group milestones.
 
+mod burndown_events;
 mod create;
+mod delete;
+mod edit;
+mod issues;
+mod merge_requests;
+mod milestone;
+mod milestones;
+
+pub use self::burndown_events::GroupMilestoneBurndownEvents;
+pub use self::burndown_events::GroupMilestoneBurndownEventsBuilder;
+pub use self::burndown_events::GroupMilestoneBurndownEventsBuilderError;
 
 pub use self::create::CreateGroupMilestone;
 pub use self::create::CreateGroupMilestoneBuilder;
 pub use self::create::CreateGroupMilestoneBuilderError;
+
+pub use self::delete::DeleteGroupMilestone;
+pub use self::delete::DeleteGroupMilestoneBuilder;
+pub use self::delete::DeleteGroupMilestoneBuilderError;
+
+pub use self::edit::EditGroupMilestone;
+pub use self::edit::EditGroupMilestoneBuilder;
+pub use self::edit::EditGroupMilestoneBuilderError;
+
+pub use self::issues::GroupMilestoneIssues;
+pub use self::issues::GroupMilestoneIssuesBuilder;
+pub use self::issues::GroupMilestoneIssuesBuilderError;
+
+pub use self::merge_requests::GroupMilestoneMergeRequests;
+pub use self::merge_requests::GroupMilestoneMergeRequestsBuilder;
+pub use self::merge_requests::GroupMilestoneMergeRequestsBuilderError;
+
+pub use self::milestone::GroupMilestone;
+pub use self::milestone::GroupMilestoneBuilder;
+pub use self::milestone::GroupMilestoneBuilderError;
+
+pub use self::milestones::GroupMilestones;
+pub use self::milestones::GroupMilestonesBuilder;
+pub use self::milestones::GroupMilestonesBuilderError;
+
+/// Filters for milestone states.
+pub type MilestoneState = crate::api::projects::milestones::MilestoneState;
+
+/// States a milestone may be set to.
+pub type MilestoneStateEvent = crate::api::projects::milestones::MilestoneStateEvent;
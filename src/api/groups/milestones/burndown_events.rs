@@ -0,0 +1,112 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the burndown chart events of a milestone within a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct GroupMilestoneBurndownEvents<'a> {
+    /// The group of the milestone.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the milestone.
+    milestone: u64,
+}
+
+impl<'a> GroupMilestoneBurndownEvents<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestoneBurndownEventsBuilder<'a> {
+        GroupMilestoneBurndownEventsBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestoneBurndownEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestoneBurndownEvents<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "groups/{}/milestones/{}/burndown_events",
+            self.group, self.milestone,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for GroupMilestoneBurndownEvents<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{
+        GroupMilestoneBurndownEvents, GroupMilestoneBurndownEventsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_milestone_are_needed() {
+        let err = GroupMilestoneBurndownEvents::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneBurndownEventsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestoneBurndownEvents::builder()
+            .milestone(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestoneBurndownEventsBuilderError, "group");
+    }
+
+    #[test]
+    fn milestone_is_needed() {
+        let err = GroupMilestoneBurndownEvents::builder()
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            GroupMilestoneBurndownEventsBuilderError,
+            "milestone",
+        );
+    }
+
+    #[test]
+    fn group_and_milestone_are_sufficient() {
+        GroupMilestoneBurndownEvents::builder()
+            .group(1)
+            .milestone(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones/1/burndown_events")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestoneBurndownEvents::builder()
+            .group("simple/group")
+            .milestone(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -13,7 +13,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new milestone on a group.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateGroupMilestone<'a> {
     /// The group to create a new milestone within.
     #[builder(setter(into))]
@@ -40,6 +40,12 @@ impl<'a> CreateGroupMilestone<'a> {
     }
 }
 
+impl<'a> CreateGroupMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateGroupMilestone<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -0,0 +1,233 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::milestones::MilestoneState;
+
+/// Query for milestones within a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupMilestones<'a> {
+    /// The group to query for milestones.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Return only the milestones having the given internal IDs.
+    #[builder(setter(name = "_iids"), default, private)]
+    iids: BTreeSet<u64>,
+    /// Filter milestones by state.
+    #[builder(default)]
+    state: Option<MilestoneState>,
+    /// Return only milestones with the given title.
+    #[builder(setter(into), default)]
+    title: Option<Cow<'a, str>>,
+    /// Return only milestones with a title or description matching the query.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Include milestones from subgroups and their projects.
+    #[builder(default)]
+    include_descendants: Option<bool>,
+    /// Include milestones from ancestor groups.
+    #[builder(default)]
+    include_ancestor_groups: Option<bool>,
+}
+
+impl<'a> GroupMilestones<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupMilestonesBuilder<'a> {
+        GroupMilestonesBuilder::default()
+    }
+}
+
+impl<'a> GroupMilestonesBuilder<'a> {
+    /// Return a milestone with the given internal ID.
+    pub fn iid(&mut self, iid: u64) -> &mut Self {
+        self.iids.get_or_insert_with(BTreeSet::new).insert(iid);
+        self
+    }
+
+    /// Return milestones with one of the given internal IDs.
+    pub fn iids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.iids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupMilestones<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/milestones", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .extend(self.iids.iter().map(|&value| ("iids[]", value)))
+            .push_opt("state", self.state)
+            .push_opt("title", self.title.as_ref())
+            .push_opt("search", self.search.as_ref())
+            .push_opt("include_descendants", self.include_descendants)
+            .push_opt("include_ancestor_groups", self.include_ancestor_groups);
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupMilestones<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::milestones::{
+        GroupMilestones, GroupMilestonesBuilderError, MilestoneState,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupMilestones::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupMilestonesBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupMilestones::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("state", "active")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .state(MilestoneState::Active)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_title() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("title", "title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .title("title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("search", "search")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .search("search")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_descendants() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("include_descendants", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .include_descendants(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_include_ancestor_groups() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("include_ancestor_groups", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .include_ancestor_groups(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_iids() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/milestones")
+            .add_query_params(&[("iids[]", "1"), ("iids[]", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupMilestones::builder()
+            .group("simple/group")
+            .iid(1)
+            .iids([1, 2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -58,7 +58,7 @@ impl ParamValue<'static> for GroupProjectsOrderBy {
 
 /// Query projects of a group.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupProjects<'a> {
     /// The group to query for projects.
     #[builder(setter(into))]
@@ -122,6 +122,12 @@ impl<'a> GroupProjects<'a> {
     }
 }
 
+impl<'a> GroupProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
 impl<'a> Endpoint for GroupProjects<'a> {
     fn method(&self) -> Method {
         Method::GET
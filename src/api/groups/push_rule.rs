@@ -0,0 +1,30 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Group push rule API endpoints.
+//!
+//! These endpoints are used for managing the push rule of a group.
+
+mod create;
+mod delete;
+mod edit;
+mod push_rule;
+
+pub use self::create::CreateGroupPushRule;
+pub use self::create::CreateGroupPushRuleBuilder;
+pub use self::create::CreateGroupPushRuleBuilderError;
+
+pub use self::delete::DeleteGroupPushRule;
+pub use self::delete::DeleteGroupPushRuleBuilder;
+pub use self::delete::DeleteGroupPushRuleBuilderError;
+
+pub use self::edit::EditGroupPushRule;
+pub use self::edit::EditGroupPushRuleBuilder;
+pub use self::edit::EditGroupPushRuleBuilderError;
+
+pub use self::push_rule::GroupPushRule;
+pub use self::push_rule::GroupPushRuleBuilder;
+pub use self::push_rule::GroupPushRuleBuilderError;
@@ -0,0 +1,347 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit the push rule of a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditGroupPushRule<'a> {
+    /// The group to edit the push rule of.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Deny deleting tags.
+    #[builder(default)]
+    deny_delete_tag: Option<bool>,
+    /// Restrict commits to those authored by existing GitLab users.
+    #[builder(default)]
+    member_check: Option<bool>,
+    /// Reject files which are likely to contain secrets.
+    #[builder(default)]
+    prevent_secrets: Option<bool>,
+    /// A regular expression all commit messages must match.
+    #[builder(setter(into), default)]
+    commit_message_regex: Option<Cow<'a, str>>,
+    /// A regular expression no commit message may match.
+    #[builder(setter(into), default)]
+    commit_message_negative_regex: Option<Cow<'a, str>>,
+    /// A regular expression all branch names must match.
+    #[builder(setter(into), default)]
+    branch_name_regex: Option<Cow<'a, str>>,
+    /// A regular expression all commit author emails must match.
+    #[builder(setter(into), default)]
+    author_email_regex: Option<Cow<'a, str>>,
+    /// A regular expression no committed file name may match.
+    #[builder(setter(into), default)]
+    file_name_regex: Option<Cow<'a, str>>,
+    /// The maximum file size (in MiB) allowed in commits.
+    ///
+    /// Use `0` for no limit.
+    #[builder(default)]
+    max_file_size: Option<u64>,
+    /// Only allow commits committed by the pushing user using one of their verified emails.
+    #[builder(default)]
+    commit_committer_check: Option<bool>,
+    /// Reject commits which are not signed.
+    #[builder(default)]
+    reject_unsigned_commits: Option<bool>,
+}
+
+impl<'a> EditGroupPushRule<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditGroupPushRuleBuilder<'a> {
+        EditGroupPushRuleBuilder::default()
+    }
+}
+
+impl<'a> EditGroupPushRuleBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for EditGroupPushRule<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/push_rule", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("deny_delete_tag", self.deny_delete_tag)
+            .push_opt("member_check", self.member_check)
+            .push_opt("prevent_secrets", self.prevent_secrets)
+            .push_opt("commit_message_regex", self.commit_message_regex.as_ref())
+            .push_opt(
+                "commit_message_negative_regex",
+                self.commit_message_negative_regex.as_ref(),
+            )
+            .push_opt("branch_name_regex", self.branch_name_regex.as_ref())
+            .push_opt("author_email_regex", self.author_email_regex.as_ref())
+            .push_opt("file_name_regex", self.file_name_regex.as_ref())
+            .push_opt("max_file_size", self.max_file_size)
+            .push_opt("commit_committer_check", self.commit_committer_check)
+            .push_opt("reject_unsigned_commits", self.reject_unsigned_commits);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::push_rule::{EditGroupPushRule, EditGroupPushRuleBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = EditGroupPushRule::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditGroupPushRuleBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        EditGroupPushRule::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_deny_delete_tag() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("deny_delete_tag=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .deny_delete_tag(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_member_check() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("member_check=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .member_check(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_prevent_secrets() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("prevent_secrets=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .prevent_secrets(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_commit_message_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("commit_message_regex=%5EJIRA-")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .commit_message_regex("^JIRA-")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_commit_message_negative_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("commit_message_negative_regex=WIP")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .commit_message_negative_regex("WIP")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_branch_name_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("branch_name_regex=%5Etopic%2F")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .branch_name_regex("^topic/")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_author_email_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("author_email_regex=%40example.com%24")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .author_email_regex("@example.com$")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_file_name_regex() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("file_name_regex=%5C.exe%24")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .file_name_regex("\\.exe$")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_max_file_size() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("max_file_size=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .max_file_size(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_commit_committer_check() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("commit_committer_check=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .commit_committer_check(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_reject_unsigned_commits() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("groups/simple%2Fgroup/push_rule")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("reject_unsigned_commits=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditGroupPushRule::builder()
+            .group("simple/group")
+            .reject_unsigned_commits(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,164 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Share a group with another group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ShareGroup<'a> {
+    /// The group to share.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the group to share with.
+    share_with: u64,
+    /// The access level to grant to the group.
+    group_access: AccessLevel,
+
+    /// When the share expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> ShareGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ShareGroupBuilder<'a> {
+        ShareGroupBuilder::default()
+    }
+}
+
+impl<'a> ShareGroupBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ShareGroup<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/share", self.group).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("group_id", self.share_with)
+            .push("group_access", self.group_access.as_u64())
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::groups::{ShareGroup, ShareGroupBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_share_with_and_group_access_are_needed() {
+        let err = ShareGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ShareGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = ShareGroup::builder()
+            .share_with(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn share_with_is_needed() {
+        let err = ShareGroup::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareGroupBuilderError, "share_with");
+    }
+
+    #[test]
+    fn group_access_is_needed() {
+        let err = ShareGroup::builder()
+            .group(1)
+            .share_with(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareGroupBuilderError, "group_access");
+    }
+
+    #[test]
+    fn group_share_with_and_group_access_are_sufficient() {
+        ShareGroup::builder()
+            .group(1)
+            .share_with(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/share")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("group_id=2", "&group_access=30"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ShareGroup::builder()
+            .group("simple/group")
+            .share_with(2)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("groups/simple%2Fgroup/share")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "group_id=2",
+                "&group_access=30",
+                "&expires_at=2020-01-01",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ShareGroup::builder()
+            .group("simple/group")
+            .share_with(2)
+            .group_access(AccessLevel::Developer)
+            .expires_at(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -6,10 +6,15 @@
 
 //! Group subgroups API endpoints.
 //!
-//! These endpoints are used for querying group subgroups.
+//! These endpoints are used for querying group subgroups and descendant groups.
 
+mod descendant_groups;
 mod subgroups;
 
+pub use self::descendant_groups::GroupDescendantGroups;
+pub use self::descendant_groups::GroupDescendantGroupsBuilder;
+pub use self::descendant_groups::GroupDescendantGroupsBuilderError;
+
 pub use self::subgroups::GroupSubgroups;
 pub use self::subgroups::GroupSubgroupsBuilder;
 pub use self::subgroups::GroupSubgroupsBuilderError;
@@ -48,7 +48,7 @@ impl ParamValue<'static> for GroupSubgroupsOrderBy {
 
 /// Query subgroups of a group.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct GroupSubgroups<'a> {
     /// The group to query for subgroups.
     #[builder(setter(into))]
@@ -116,6 +116,10 @@ impl<'a> GroupSubgroupsBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
 }
 
 impl<'a> Endpoint for GroupSubgroups<'a> {
@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the groups a group may be transferred to.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct GroupTransferLocations<'a> {
+    /// The group to query for transfer locations.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+
+    /// Search for groups by name.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+}
+
+impl<'a> GroupTransferLocations<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> GroupTransferLocationsBuilder<'a> {
+        GroupTransferLocationsBuilder::default()
+    }
+}
+
+impl<'a> GroupTransferLocationsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for GroupTransferLocations<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/transfer_locations", self.group).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("search", self.search.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for GroupTransferLocations<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::groups::{GroupTransferLocations, GroupTransferLocationsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_is_needed() {
+        let err = GroupTransferLocations::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, GroupTransferLocationsBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_sufficient() {
+        GroupTransferLocations::builder().group(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/transfer_locations")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupTransferLocations::builder()
+            .group("simple/group")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("groups/simple%2Fgroup/transfer_locations")
+            .add_query_params(&[("search", "search")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = GroupTransferLocations::builder()
+            .group("simple/group")
+            .search("search")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
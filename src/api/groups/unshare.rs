@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Stop sharing a group with another group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnshareGroup<'a> {
+    /// The group which is shared.
+    #[builder(setter(into))]
+    group: NameOrId<'a>,
+    /// The ID of the group the group is shared with.
+    shared_with: u64,
+}
+
+impl<'a> UnshareGroup<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnshareGroupBuilder<'a> {
+        UnshareGroupBuilder::default()
+    }
+}
+
+impl<'a> UnshareGroupBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("group", self.group.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UnshareGroup<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("groups/{}/share/{}", self.group, self.shared_with).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::groups::{UnshareGroup, UnshareGroupBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn group_and_shared_with_are_needed() {
+        let err = UnshareGroup::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = UnshareGroup::builder().shared_with(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareGroupBuilderError, "group");
+    }
+
+    #[test]
+    fn shared_with_is_needed() {
+        let err = UnshareGroup::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareGroupBuilderError, "shared_with");
+    }
+
+    #[test]
+    fn group_and_shared_with_are_sufficient() {
+        UnshareGroup::builder()
+            .group(1)
+            .shared_with(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("groups/simple%2Fgroup/share/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnshareGroup::builder()
+            .group("simple/group")
+            .shared_with(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
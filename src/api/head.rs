@@ -0,0 +1,165 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use http::request::Builder as RequestBuilder;
+use http::{HeaderMap, Method, Request};
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+
+/// A query modifier that sends a `HEAD` request to an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Head<E> {
+    endpoint: E,
+}
+
+/// Send a `HEAD` request to an endpoint and return the headers of the response.
+///
+/// The method of the endpoint is replaced and its body is not sent. GitLab answers `HEAD`
+/// requests for any `GET` endpoint; some endpoints (e.g., repository files) report metadata in
+/// the headers. Every value of a repeated header is kept. Unsuccessful responses are returned as
+/// errors.
+pub fn head<E>(endpoint: E) -> Head<E> {
+    Head {
+        endpoint,
+    }
+}
+
+/// Build a `HEAD` request for an endpoint.
+pub(crate) fn head_request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<RequestBuilder, ApiError<C::Error>>
+where
+    E: Endpoint,
+    C: RestClient,
+{
+    let mut url = endpoint
+        .url_base()
+        .endpoint_for(client, &endpoint.endpoint())?;
+    endpoint.parameters().add_to_url(&mut url);
+
+    Ok(Request::builder()
+        .method(Method::HEAD)
+        .uri(query::url_to_http_uri(url)))
+}
+
+impl<E, C> Query<HeaderMap, C> for Head<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<HeaderMap, ApiError<C::Error>> {
+        let req = head_request(&self.endpoint, client)?;
+        let rsp = client.rest(req, Vec::new())?;
+        if !rsp.status().is_success() {
+            // Responses to `HEAD` requests have no body to describe the error.
+            return Err(ApiError::server_error(rsp.status(), rsp.body()));
+        }
+
+        Ok(rsp.into_parts().0.headers)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, C> AsyncQuery<HeaderMap, C> for Head<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<HeaderMap, ApiError<C::Error>> {
+        let req = head_request(&self.endpoint, client)?;
+        let rsp = client.rest_async(req, Vec::new()).await?;
+        if !rsp.status().is_success() {
+            // Responses to `HEAD` requests have no body to describe the error.
+            return Err(ApiError::server_error(rsp.status(), rsp.body()));
+        }
+
+        Ok(rsp.into_parts().0.headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Method, StatusCode};
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::POST
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams {
+            let mut params = QueryParams::default();
+            params.push("ref", "main");
+            params
+        }
+
+        fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+            let mut params = FormParams::default();
+            params.push("ignored", true);
+            params.into_body()
+        }
+    }
+
+    #[test]
+    fn test_head() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::HEAD)
+            .endpoint("dummy")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::head(Dummy).query(&client).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_head_async() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::HEAD)
+            .endpoint("dummy")
+            .add_query_params(&[("ref", "main")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        api::head(Dummy).query_async(&client).await.unwrap();
+    }
+
+    #[test]
+    fn test_head_error() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::HEAD)
+            .endpoint("dummy")
+            .add_query_params(&[("ref", "main")])
+            .status(StatusCode::FORBIDDEN)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let err = api::head(Dummy).query(&client).unwrap_err();
+        if let ApiError::GitlabService {
+            status, ..
+        } = err
+        {
+            assert_eq!(status, StatusCode::FORBIDDEN);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
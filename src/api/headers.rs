@@ -0,0 +1,316 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{HeaderMap, Response};
+use url::Url;
+
+use crate::api::{
+    ApiError, AsyncClient, AsyncQuery, BodyReader, BodyStream, Client, Query, RestClient,
+};
+
+/// A client which adds headers to every request sent through it.
+///
+/// Headers replace any values the request already has for the same name. This is used by
+/// `with_headers`.
+#[derive(Debug)]
+pub struct HeaderClient<'a, C> {
+    client: &'a C,
+    headers: &'a HeaderMap,
+}
+
+impl<'a, C> HeaderClient<'a, C> {
+    fn add_headers(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(headers) = request.headers_mut() {
+            for key in self.headers.keys() {
+                headers.remove(key);
+            }
+            for (key, value) in self.headers {
+                headers.append(key, value.clone());
+            }
+        }
+        request
+    }
+}
+
+impl<'a, C> RestClient for HeaderClient<'a, C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.instance_endpoint(endpoint)
+    }
+
+    fn identity(&self) -> Option<u64> {
+        self.client.identity()
+    }
+}
+
+impl<'a, C> Client for HeaderClient<'a, C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client.rest(self.add_headers(request), body)
+    }
+
+    fn rest_stream(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyReader>, ApiError<Self::Error>> {
+        self.client.rest_stream(self.add_headers(request), body)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<'a, C> AsyncClient for HeaderClient<'a, C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.client
+            .rest_async(self.add_headers(request), body)
+            .await
+    }
+
+    async fn rest_stream_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<BodyStream<Self::Error>>, ApiError<Self::Error>> {
+        self.client
+            .rest_stream_async(self.add_headers(request), body)
+            .await
+    }
+}
+
+/// A query modifier that adds headers to its requests.
+#[derive(Debug, Clone)]
+pub struct WithHeaders<Q> {
+    query: Q,
+    headers: HeaderMap,
+}
+
+/// Add headers to the requests of a query.
+///
+/// This may be used to pass information such as correlation IDs for individual queries. The
+/// headers are added to every request made by the query (e.g., for each page of a paginated
+/// query). The headers replace any the endpoint sets with the same name (such as `Content-Type`
+/// or `Accept`), but headers set by the client itself (such as authentication) take precedence.
+pub fn with_headers<Q>(query: Q, headers: HeaderMap) -> WithHeaders<Q> {
+    WithHeaders {
+        query,
+        headers,
+    }
+}
+
+impl<Q, T, C> Query<T, C> for WithHeaders<Q>
+where
+    Q: for<'a> Query<T, HeaderClient<'a, C>>,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = HeaderClient {
+            client,
+            headers: &self.headers,
+        };
+        self.query.query(&client)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<Q, T, C> AsyncQuery<T, C> for WithHeaders<Q>
+where
+    Q: for<'a> AsyncQuery<T, HeaderClient<'a, C>> + Sync,
+    T: Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = HeaderClient {
+            client,
+            headers: &self.headers,
+        };
+        self.query.query_async(&client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::{HeaderMap, HeaderValue, Response};
+    use url::Url;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Query, RestClient};
+    use crate::test::client::TestClientError;
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Default)]
+    struct HeaderRecordingClient {
+        headers: Mutex<Vec<HeaderMap>>,
+    }
+
+    impl RestClient for HeaderRecordingClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+    }
+
+    impl Client for HeaderRecordingClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let headers = request.headers_ref().cloned().unwrap_or_default();
+            self.headers.lock().unwrap().push(headers);
+            Ok(Response::new("{}".into()))
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for HeaderRecordingClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.rest(request, body)
+        }
+    }
+
+    fn headers_of(client: &HeaderRecordingClient, request: usize, name: &str) -> Vec<String> {
+        client.headers.lock().unwrap()[request]
+            .get_all(name)
+            .iter()
+            .map(|value| value.to_str().unwrap().into())
+            .collect()
+    }
+
+    fn correlation_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-request-id", HeaderValue::from_static("abc123"));
+        headers.insert("x-profile-token", HeaderValue::from_static("token"));
+        headers
+    }
+
+    #[test]
+    fn with_headers_adds_headers() {
+        let client = HeaderRecordingClient::default();
+
+        api::with_headers(api::ignore(Dummy), correlation_headers())
+            .query(&client)
+            .unwrap();
+
+        let headers = client.headers.lock().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0]["x-request-id"], "abc123");
+        assert_eq!(headers[0]["x-profile-token"], "token");
+        assert_eq!(headers[0].len(), 2);
+    }
+
+    #[test]
+    fn with_headers_keeps_endpoint_headers() {
+        struct PostDummy;
+
+        impl Endpoint for PostDummy {
+            fn method(&self) -> Method {
+                Method::POST
+            }
+
+            fn endpoint(&self) -> Cow<'static, str> {
+                "dummy".into()
+            }
+
+            fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+                FormParams::default().into_body()
+            }
+        }
+
+        let client = HeaderRecordingClient::default();
+
+        api::with_headers(api::ignore(PostDummy), correlation_headers())
+            .query(&client)
+            .unwrap();
+
+        {
+            let headers = client.headers.lock().unwrap();
+            assert_eq!(
+                headers[0][http::header::CONTENT_TYPE],
+                "application/x-www-form-urlencoded",
+            );
+            assert_eq!(headers[0]["x-request-id"], "abc123");
+        }
+
+        let mut replacements = correlation_headers();
+        replacements.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        replacements.append("x-request-id", HeaderValue::from_static("def456"));
+        api::with_headers(api::ignore(PostDummy), replacements)
+            .query(&client)
+            .unwrap();
+
+        let content_types = headers_of(&client, 1, http::header::CONTENT_TYPE.as_str());
+        assert_eq!(content_types, ["application/json"]);
+        let request_ids = headers_of(&client, 1, "x-request-id");
+        assert_eq!(request_ids, ["abc123", "def456"]);
+    }
+
+    #[tokio::test]
+    async fn with_headers_adds_headers_async() {
+        let client = HeaderRecordingClient::default();
+
+        api::with_headers(api::ignore(Dummy), correlation_headers())
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        let headers = client.headers.lock().unwrap();
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0]["x-request-id"], "abc123");
+    }
+}
@@ -0,0 +1,28 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Health check endpoints.
+//!
+//! These endpoints are used for probing the health of the instance and live outside of the
+//! REST API.
+
+mod health;
+mod liveness;
+mod readiness;
+
+pub use self::health::HealthCheck;
+pub use self::health::HealthCheckBuilder;
+pub use self::health::HealthCheckBuilderError;
+
+pub use self::liveness::LivenessCheck;
+pub use self::liveness::LivenessCheckBuilder;
+pub use self::liveness::LivenessCheckBuilderError;
+
+pub use self::readiness::ReadinessCheck;
+pub use self::readiness::ReadinessCheckBuilder;
+pub use self::readiness::ReadinessCheckBuilderError;
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Check whether the GitLab instance is able to serve requests.
+///
+/// This endpoint is outside of the REST API and returns plain text rather than JSON, so it
+/// should be queried using `api::raw` or `api::ignore`. Access requires the client to be in
+/// the monitoring IP allowlist of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct HealthCheck {}
+
+impl HealthCheck {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> HealthCheckBuilder {
+        HealthCheckBuilder::default()
+    }
+}
+
+impl Endpoint for HealthCheck {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/health".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::health::HealthCheck;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        HealthCheck::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/health")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = HealthCheck::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Check whether the GitLab instance is running.
+///
+/// This endpoint is outside of the REST API. Access requires the client to be in the monitoring
+/// IP allowlist of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct LivenessCheck {}
+
+impl LivenessCheck {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LivenessCheckBuilder {
+        LivenessCheckBuilder::default()
+    }
+}
+
+impl Endpoint for LivenessCheck {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/liveness".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::health::LivenessCheck;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        LivenessCheck::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/liveness")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = LivenessCheck::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
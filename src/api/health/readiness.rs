@@ -0,0 +1,89 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Check whether the GitLab instance and its services are ready to accept traffic.
+///
+/// This endpoint is outside of the REST API. Access requires the client to be in the monitoring
+/// IP allowlist of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct ReadinessCheck {
+    /// Include the results of all checks rather than just the instance itself.
+    #[builder(default)]
+    all: Option<bool>,
+}
+
+impl ReadinessCheck {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ReadinessCheckBuilder {
+        ReadinessCheckBuilder::default()
+    }
+}
+
+impl Endpoint for ReadinessCheck {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "-/readiness".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("all", self.all);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::health::ReadinessCheck;
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        ReadinessCheck::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/readiness")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReadinessCheck::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_all() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("-/readiness")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[("all", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ReadinessCheck::builder().all(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -28,7 +28,10 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
         self.endpoint.parameters().add_to_url(&mut url);
 
         let req = Request::builder()
@@ -47,21 +50,25 @@ where
             } else {
                 return Err(ApiError::server_error(rsp.status(), rsp.body()));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), v));
         }
 
         Ok(())
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<E, C> AsyncQuery<(), C> for Ignore<E>
 where
     E: Endpoint + Sync,
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
         self.endpoint.parameters().add_to_url(&mut url);
 
         let req = Request::builder()
@@ -80,7 +87,7 @@ where
             } else {
                 return Err(ApiError::server_error(rsp.status(), rsp.body()));
             };
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(rsp.status(), v));
         }
 
         Ok(())
@@ -85,18 +85,31 @@ pub enum IssueIteration<'a> {
 
 impl<'a> IssueIteration<'a> {
     fn add_params<'b>(&'b self, params: &mut QueryParams<'b>) {
+        self.add_params_with_keys(params, "iteration_id", "iteration_title");
+    }
+
+    fn add_not_params<'b>(&'b self, params: &mut QueryParams<'b>) {
+        self.add_params_with_keys(params, "not[iteration_id]", "not[iteration_title]");
+    }
+
+    fn add_params_with_keys<'b>(
+        &'b self,
+        params: &mut QueryParams<'b>,
+        id_key: &'static str,
+        title_key: &'static str,
+    ) {
         match self {
             IssueIteration::None => {
-                params.push("iteration_id", "None");
+                params.push(id_key, "None");
             },
             IssueIteration::Any => {
-                params.push("iteration_id", "Any");
+                params.push(id_key, "Any");
             },
             IssueIteration::Id(id) => {
-                params.push("iteration_id", *id);
+                params.push(id_key, *id);
             },
             IssueIteration::Title(title) => {
-                params.push("iteration_title", title);
+                params.push(title_key, title);
             },
         }
     }
@@ -129,6 +142,29 @@ impl<'a> Assignee<'a> {
     }
 }
 
+#[derive(Debug, Clone)]
+enum NotAssignee<'a> {
+    Id(u64),
+    Usernames(BTreeSet<Cow<'a, str>>),
+}
+
+impl<'a> NotAssignee<'a> {
+    fn add_params<'b>(&'b self, params: &mut QueryParams<'b>) {
+        match self {
+            NotAssignee::Id(id) => {
+                params.push("not[assignee_id]", *id);
+            },
+            NotAssignee::Usernames(usernames) => {
+                params.extend(
+                    usernames
+                        .iter()
+                        .map(|value| ("not[assignee_username][]", value)),
+                );
+            },
+        }
+    }
+}
+
 /// Filter issues by weight.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IssueWeight {
@@ -215,6 +251,96 @@ impl ParamValue<'static> for IssueDueDateFilter {
     }
 }
 
+/// Filter issues by epic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueEpic {
+    /// Issues without an epic.
+    None,
+    /// Issues with any epic.
+    Any,
+    /// Issues with a given epic (by ID).
+    Id(u64),
+}
+
+impl IssueEpic {
+    fn as_str(self) -> Cow<'static, str> {
+        match self {
+            IssueEpic::None => "None".into(),
+            IssueEpic::Any => "Any".into(),
+            IssueEpic::Id(id) => format!("{}", id).into(),
+        }
+    }
+}
+
+impl ParamValue<'static> for IssueEpic {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str()
+    }
+}
+
+/// Types of issues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueType {
+    /// A regular issue.
+    Issue,
+    /// An incident.
+    Incident,
+    /// A test case.
+    TestCase,
+    /// A task.
+    Task,
+}
+
+impl IssueType {
+    fn as_str(self) -> &'static str {
+        match self {
+            IssueType::Issue => "issue",
+            IssueType::Incident => "incident",
+            IssueType::TestCase => "test_case",
+            IssueType::Task => "task",
+        }
+    }
+}
+
+impl ParamValue<'static> for IssueType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Filter issues by health status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueHealthStatus {
+    /// Issues without a health status.
+    None,
+    /// Issues with any health status.
+    Any,
+    /// Issues which are on track.
+    OnTrack,
+    /// Issues which need attention.
+    NeedsAttention,
+    /// Issues which are at risk.
+    AtRisk,
+}
+
+impl IssueHealthStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            IssueHealthStatus::None => "None",
+            IssueHealthStatus::Any => "Any",
+            IssueHealthStatus::OnTrack => "on_track",
+            IssueHealthStatus::NeedsAttention => "needs_attention",
+            IssueHealthStatus::AtRisk => "at_risk",
+        }
+    }
+}
+
+impl ParamValue<'static> for IssueHealthStatus {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
 /// Keys issue results may be ordered by.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IssueOrderBy {
@@ -271,7 +397,8 @@ impl ParamValue<'static> for IssueOrderBy {
 #[cfg(test)]
 mod tests {
     use crate::api::issues::{
-        IssueDueDateFilter, IssueOrderBy, IssueScope, IssueSearchScope, IssueState, IssueWeight,
+        IssueDueDateFilter, IssueEpic, IssueHealthStatus, IssueOrderBy, IssueScope,
+        IssueSearchScope, IssueState, IssueType, IssueWeight,
     };
 
     #[test]
@@ -342,6 +469,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn issue_epic_as_str() {
+        let items = &[
+            (IssueEpic::None, "None"),
+            (IssueEpic::Any, "Any"),
+            (IssueEpic::Id(1), "1"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn issue_type_as_str() {
+        let items = &[
+            (IssueType::Issue, "issue"),
+            (IssueType::Incident, "incident"),
+            (IssueType::TestCase, "test_case"),
+            (IssueType::Task, "task"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn issue_health_status_as_str() {
+        let items = &[
+            (IssueHealthStatus::None, "None"),
+            (IssueHealthStatus::Any, "Any"),
+            (IssueHealthStatus::OnTrack, "on_track"),
+            (IssueHealthStatus::NeedsAttention, "needs_attention"),
+            (IssueHealthStatus::AtRisk, "at_risk"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
     #[test]
     fn issue_order_by_default() {
         assert_eq!(IssueOrderBy::default(), IssueOrderBy::CreatedAt);
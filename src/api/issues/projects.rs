@@ -17,15 +17,13 @@ use crate::api::{
 };
 
 use super::{
-    Assignee, IssueDueDateFilter, IssueIteration, IssueOrderBy, IssueScope, IssueSearchScope,
-    IssueState, IssueWeight,
+    Assignee, IssueDueDateFilter, IssueEpic, IssueHealthStatus, IssueIteration, IssueOrderBy,
+    IssueScope, IssueSearchScope, IssueState, IssueType, IssueWeight, NotAssignee,
 };
 
 /// Query for issues within a project.
-///
-/// TODO: Negation (not) filters are not yet supported.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectIssues<'a> {
     /// The project to query for issues.
     #[builder(setter(into))]
@@ -64,6 +62,15 @@ pub struct ProjectIssues<'a> {
     /// Filter issues by weight.
     #[builder(default)]
     weight: Option<IssueWeight>,
+    /// Filter issues by epic.
+    #[builder(default)]
+    epic: Option<IssueEpic>,
+    /// Filter issues by type.
+    #[builder(default)]
+    issue_type: Option<IssueType>,
+    /// Filter issues by health status.
+    #[builder(default)]
+    health_status: Option<IssueHealthStatus>,
 
     /// Filter issues with a search query.
     #[builder(setter(into), default)]
@@ -89,8 +96,31 @@ pub struct ProjectIssues<'a> {
     #[builder(default)]
     due_date: Option<IssueDueDateFilter>,
 
-    // TODO: How best to support this parameter?
-    // not
+    /// Filter issues without specific internal IDs.
+    #[builder(setter(name = "_not_iids"), default, private)]
+    not_iids: BTreeSet<u64>,
+    /// Filter issues without any of the given labels.
+    #[builder(setter(name = "_not_labels"), default, private)]
+    not_labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Filter issues not in a given milestone.
+    #[builder(setter(into), default)]
+    not_milestone: Option<Cow<'a, str>>,
+    /// Filter issues not in a given iteration.
+    #[builder(default)]
+    not_iteration: Option<IssueIteration<'a>>,
+    /// Filter issues not created by an author.
+    #[builder(setter(into), default)]
+    not_author: Option<NameOrId<'a>>,
+    /// Filter issues not assigned to users.
+    #[builder(setter(name = "_not_assignee"), default, private)]
+    not_assignee: Option<NotAssignee<'a>>,
+    /// Filter issues without a given reaction by the API caller.
+    #[builder(setter(into), default)]
+    not_my_reaction_emoji: Option<Cow<'a, str>>,
+    /// Filter issues without a given weight.
+    #[builder(default)]
+    not_weight: Option<u64>,
+
     /// Order results by a given key.
     #[builder(default)]
     order_by: Option<IssueOrderBy>,
@@ -270,6 +300,82 @@ impl<'a> ProjectIssuesBuilder<'a> {
             .push(scope);
         self
     }
+
+    /// Exclude an issue with an internal ID.
+    pub fn not_iid(&mut self, iid: u64) -> &mut Self {
+        self.not_iids.get_or_insert_with(BTreeSet::new).insert(iid);
+        self
+    }
+
+    /// Exclude issues with any of a set of internal IDs.
+    pub fn not_iids<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = u64>,
+    {
+        self.not_iids.get_or_insert_with(BTreeSet::new).extend(iter);
+        self
+    }
+
+    /// Exclude issues with a given label.
+    pub fn not_label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.not_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Exclude issues with any of the given labels.
+    pub fn not_labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.not_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Exclude issues assigned to a user (by ID).
+    pub fn not_assignee_id(&mut self, assignee: u64) -> &mut Self {
+        self.not_assignee = Some(Some(NotAssignee::Id(assignee)));
+        self
+    }
+
+    /// Exclude issues assigned to a user (by username).
+    pub fn not_assignee<A>(&mut self, assignee: A) -> &mut Self
+    where
+        A: Into<Cow<'a, str>>,
+    {
+        self.not_assignees(iter::once(assignee))
+    }
+
+    /// Exclude issues assigned to any of a set of users (by username).
+    pub fn not_assignees<I, A>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<Cow<'a, str>>,
+    {
+        let iter = iter.into_iter().map(Into::into);
+        let assignees =
+            if let Some(Some(NotAssignee::Usernames(mut set))) = self.not_assignee.take() {
+                set.extend(iter);
+                set
+            } else {
+                iter.collect()
+            };
+        self.not_assignee = Some(Some(NotAssignee::Usernames(assignees)));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for ProjectIssues<'a> {
@@ -293,6 +399,9 @@ impl<'a> Endpoint for ProjectIssues<'a> {
             .push_opt("scope", self.scope)
             .push_opt("my_reaction_emoji", self.my_reaction_emoji.as_ref())
             .push_opt("weight", self.weight)
+            .push_opt("epic_id", self.epic)
+            .push_opt("issue_type", self.issue_type)
+            .push_opt("health_status", self.health_status)
             .push_opt("search", self.search.as_ref())
             .push_opt("in", self.search_in.as_ref())
             .push_opt("created_after", self.created_after)
@@ -301,6 +410,14 @@ impl<'a> Endpoint for ProjectIssues<'a> {
             .push_opt("updated_before", self.updated_before)
             .push_opt("confidential", self.confidential)
             .push_opt("due_date", self.due_date)
+            .extend(self.not_iids.iter().map(|&value| ("not[iids][]", value)))
+            .push_opt("not[labels]", self.not_labels.as_ref())
+            .push_opt("not[milestone]", self.not_milestone.as_ref())
+            .push_opt(
+                "not[my_reaction_emoji]",
+                self.not_my_reaction_emoji.as_ref(),
+            )
+            .push_opt("not[weight]", self.not_weight)
             .push_opt("order_by", self.order_by)
             .push_opt("sort", self.sort);
 
@@ -320,6 +437,22 @@ impl<'a> Endpoint for ProjectIssues<'a> {
         if let Some(assignee) = self.assignee.as_ref() {
             assignee.add_params(&mut params);
         }
+        if let Some(author) = self.not_author.as_ref() {
+            match author {
+                NameOrId::Name(name) => {
+                    params.push("not[author_username]", name);
+                },
+                NameOrId::Id(id) => {
+                    params.push("not[author_id]", *id);
+                },
+            }
+        }
+        if let Some(iteration) = self.not_iteration.as_ref() {
+            iteration.add_not_params(&mut params);
+        }
+        if let Some(assignee) = self.not_assignee.as_ref() {
+            assignee.add_params(&mut params);
+        }
 
         params
     }
@@ -334,7 +467,8 @@ mod tests {
     use crate::api::common::SortOrder;
     use crate::api::issues::{
         projects::ProjectIssues, projects::ProjectIssuesBuilderError, IssueDueDateFilter,
-        IssueIteration, IssueOrderBy, IssueScope, IssueSearchScope, IssueState, IssueWeight,
+        IssueEpic, IssueHealthStatus, IssueIteration, IssueOrderBy, IssueScope, IssueSearchScope,
+        IssueState, IssueType, IssueWeight,
     };
     use crate::api::{self, Query};
     use crate::test::client::{ExpectedUrl, SingleTestClient};
@@ -915,4 +1049,265 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_epic() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("epic_id", "None")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .epic(IssueEpic::None)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_epic_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("epic_id", "4")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .epic(IssueEpic::Id(4))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_issue_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("issue_type", "incident")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .issue_type(IssueType::Incident)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_health_status() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("health_status", "at_risk")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .health_status(IssueHealthStatus::AtRisk)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_iids() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[iids][]", "1"), ("not[iids][]", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_iid(1)
+            .not_iids([1, 2].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[labels]", "label,label1,label2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_label("label")
+            .not_labels(["label1", "label2"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_milestone() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[milestone]", "1.0")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_milestone("1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_iteration_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[iteration_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_iteration(IssueIteration::Id(1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_iteration_title() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[iteration_title]", "title")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_iteration(IssueIteration::Title("title".into()))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_author_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[author_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_author(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_author_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[author_username]", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_author("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[assignee_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_assignee_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_assignee_user() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[
+                ("not[assignee_username][]", "name1"),
+                ("not[assignee_username][]", "name2"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_assignee("name1")
+            .not_assignees(["name1", "name2"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_my_reaction_emoji() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[my_reaction_emoji]", "tada")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_my_reaction_emoji("tada")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_weight() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues")
+            .add_query_params(&[("not[weight]", "3")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectIssues::builder()
+            .project("simple/project")
+            .not_weight(3)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! SSH key API endpoints.
+//!
+//! These endpoints are used for looking up SSH keys and the users which own them.
+
+mod key;
+mod key_by_fingerprint;
+
+pub use self::key::Key;
+pub use self::key::KeyBuilder;
+pub use self::key::KeyBuilderError;
+
+pub use self::key_by_fingerprint::KeyByFingerprint;
+pub use self::key_by_fingerprint::KeyByFingerprintBuilder;
+pub use self::key_by_fingerprint::KeyByFingerprintBuilderError;
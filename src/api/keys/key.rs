@@ -0,0 +1,62 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an SSH key and its owner by the ID of the key.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Key {
+    /// The ID of the SSH key.
+    key: u64,
+}
+
+impl Key {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> KeyBuilder {
+        KeyBuilder::default()
+    }
+}
+
+impl Endpoint for Key {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("keys/{}", self.key).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::keys::{Key, KeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_needed() {
+        let err = Key::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, KeyBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        Key::builder().key(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("keys/1").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Key::builder().key(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an SSH key and its owner by the fingerprint of the key.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+pub struct KeyByFingerprint<'a> {
+    /// The fingerprint of the SSH key.
+    ///
+    /// Both MD5 (`ba:81:59:...`) and SHA256 (`SHA256:...`) fingerprints are supported.
+    #[builder(setter(into))]
+    fingerprint: Cow<'a, str>,
+}
+
+impl<'a> KeyByFingerprint<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> KeyByFingerprintBuilder<'a> {
+        KeyByFingerprintBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for KeyByFingerprint<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "keys".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push("fingerprint", self.fingerprint.as_ref());
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::keys::{KeyByFingerprint, KeyByFingerprintBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn fingerprint_is_needed() {
+        let err = KeyByFingerprint::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, KeyByFingerprintBuilderError, "fingerprint");
+    }
+
+    #[test]
+    fn fingerprint_is_sufficient() {
+        KeyByFingerprint::builder()
+            .fingerprint("SHA256:abc")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("keys")
+            .add_query_params(&[("fingerprint", "SHA256:abc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = KeyByFingerprint::builder()
+            .fingerprint("SHA256:abc")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,399 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::error::Error;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
+
+/// Deserialize response data, reporting the path to the field which failed on errors.
+pub(crate) fn deserialize<T, E>(value: Value) -> Result<T, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    serde_path_to_error::deserialize(&value).map_err(|err| ApiError::data_type::<T>(err, &value))
+}
+
+/// Data deserialized by a lenient query.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct LenientData<T> {
+    /// The deserialized data.
+    pub data: T,
+    /// Fields which were ignored by the data type, keyed by their path.
+    pub unknown: Map<String, Value>,
+    /// Fields which could not be deserialized and were dropped, keyed by their path.
+    ///
+    /// Values are as they were received. When an object is dropped because one of its fields
+    /// failed, both the object and the field are reported.
+    pub invalid: Map<String, Value>,
+}
+
+/// A query modifier that tolerates fields which fail to deserialize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenientQuery<E> {
+    endpoint: E,
+}
+
+/// Deserialize the data from an endpoint leniently.
+///
+/// Fields which fail to deserialize are dropped and retried as if they were missing from the
+/// response. This allows optional fields which have changed in newer GitLab versions to be
+/// skipped rather than failing the entire query. The dropped fields are returned along with any
+/// fields which the data type does not know about so that such drift may be diagnosed.
+pub fn lenient<E>(endpoint: E) -> LenientQuery<E> {
+    LenientQuery {
+        endpoint,
+    }
+}
+
+/// A component of the path to a field.
+enum Segment<'a> {
+    Index(usize),
+    Key(&'a str),
+}
+
+impl<'a> Segment<'a> {
+    fn from_error_path(path: &'a serde_path_to_error::Path) -> Option<Vec<Self>> {
+        path.iter()
+            .map(|segment| {
+                match segment {
+                    serde_path_to_error::Segment::Seq {
+                        index,
+                    } => Some(Segment::Index(*index)),
+                    serde_path_to_error::Segment::Map {
+                        key,
+                    } => Some(Segment::Key(key)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    fn from_ignored_path(path: &'a serde_ignored::Path<'a>, segments: &mut Vec<Self>) {
+        match path {
+            serde_ignored::Path::Root => (),
+            serde_ignored::Path::Seq {
+                parent,
+                index,
+            } => {
+                Self::from_ignored_path(parent, segments);
+                segments.push(Segment::Index(*index));
+            },
+            serde_ignored::Path::Map {
+                parent,
+                key,
+            } => {
+                Self::from_ignored_path(parent, segments);
+                segments.push(Segment::Key(key));
+            },
+            serde_ignored::Path::Some {
+                parent,
+            }
+            | serde_ignored::Path::NewtypeStruct {
+                parent,
+            }
+            | serde_ignored::Path::NewtypeVariant {
+                parent,
+            } => Self::from_ignored_path(parent, segments),
+        }
+    }
+
+    /// The JSON pointer for the path.
+    fn pointer(segments: &[Self]) -> String {
+        segments
+            .iter()
+            .map(|segment| {
+                match segment {
+                    Segment::Index(index) => format!("/{}", index),
+                    Segment::Key(key) => format!("/{}", key.replace('~', "~0").replace('/', "~1")),
+                }
+            })
+            .collect()
+    }
+
+    /// The path as formatted by `serde_path_to_error`.
+    fn display(segments: &[Self]) -> String {
+        if segments.is_empty() {
+            return ".".into();
+        }
+
+        let mut path = String::new();
+        for segment in segments {
+            match segment {
+                Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+                Segment::Key(key) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                },
+            }
+        }
+        path
+    }
+}
+
+/// Remove the field at the given path from its parent object.
+///
+/// Returns the value of the field in `original`, before any of its own fields were removed.
+fn remove_field(
+    value: &mut Value,
+    original: &Value,
+    path: &serde_path_to_error::Path,
+) -> Option<Value> {
+    let segments = Segment::from_error_path(path)?;
+    let (last, parents) = segments.split_last()?;
+    let key = if let Segment::Key(key) = last {
+        key
+    } else {
+        // Array elements cannot be dropped without shifting the remaining elements.
+        return None;
+    };
+
+    let parent = value.pointer_mut(&Segment::pointer(parents))?;
+    let field = parent.as_object_mut()?.remove(*key)?;
+    // Only object fields are removed, so the path is the same in the original value.
+    Some(
+        original
+            .pointer(&Segment::pointer(&segments))
+            .cloned()
+            .unwrap_or(field),
+    )
+}
+
+fn deserialize_lenient<T, E>(mut value: Value) -> Result<LenientData<T>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: Error + Send + Sync + 'static,
+{
+    let mut invalid = Map::new();
+    let mut first_error = None;
+    let mut original = None;
+
+    loop {
+        let mut unknown = Map::new();
+        let res = {
+            let value = &value;
+            let mut record_unknown = |path: serde_ignored::Path| {
+                let mut segments = Vec::new();
+                Segment::from_ignored_path(&path, &mut segments);
+                if let Some(field) = value.pointer(&Segment::pointer(&segments)) {
+                    unknown.insert(Segment::display(&segments), field.clone());
+                }
+            };
+            let deserializer = serde_ignored::Deserializer::new(value, &mut record_unknown);
+            serde_path_to_error::deserialize(deserializer)
+        };
+
+        let err = match res {
+            Ok(data) => {
+                return Ok(LenientData {
+                    data,
+                    unknown,
+                    invalid,
+                });
+            },
+            Err(err) => err,
+        };
+
+        let path = err.path().clone();
+        if first_error.is_none() {
+            first_error = Some(ApiError::data_type::<T>(err, &value));
+        }
+
+        // Every retry removes a field, so this loop is bounded by the size of the data.
+        let original = original.get_or_insert_with(|| value.clone());
+        if let Some(field) = remove_field(&mut value, original, &path) {
+            invalid.insert(path.to_string(), field);
+        } else {
+            return Err(first_error.expect("an error was recorded"));
+        }
+    }
+}
+
+impl<E, T, C> Query<LenientData<T>, C> for LenientQuery<E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<LenientData<T>, ApiError<C::Error>> {
+        let value: Value = self.endpoint.query(client)?;
+        deserialize_lenient(value)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, T, C> AsyncQuery<LenientData<T>, C> for LenientQuery<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<LenientData<T>, ApiError<C::Error>> {
+        let value: Value = self.endpoint.query_async(client).await?;
+        deserialize_lenient(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, LenientData, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct DummyAuthor {
+        id: u64,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: u8,
+        label: Option<String>,
+        author: Option<DummyAuthor>,
+    }
+
+    fn client(data: serde_json::Value) -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        SingleTestClient::new_json(endpoint, &data)
+    }
+
+    #[test]
+    fn test_lenient_exact() {
+        let client = client(json!({
+            "value": 1,
+            "label": "label",
+            "author": {
+                "id": 2,
+            },
+        }));
+
+        let res: LenientData<DummyResult> = api::lenient(Dummy).query(&client).unwrap();
+        assert_eq!(
+            res.data,
+            DummyResult {
+                value: 1,
+                label: Some("label".into()),
+                author: Some(DummyAuthor {
+                    id: 2,
+                }),
+            },
+        );
+        assert!(res.unknown.is_empty());
+        assert!(res.invalid.is_empty());
+    }
+
+    #[test]
+    fn test_lenient_unknown_fields() {
+        let client = client(json!({
+            "value": 1,
+            "new_field": [1, 2],
+            "author": {
+                "id": 2,
+                "new/field": "new",
+            },
+        }));
+
+        let res: LenientData<DummyResult> = api::lenient(Dummy).query(&client).unwrap();
+        assert_eq!(res.data.value, 1);
+        assert_eq!(res.unknown.len(), 2);
+        assert_eq!(res.unknown["new_field"], json!([1, 2]));
+        assert_eq!(res.unknown["author.new/field"], json!("new"));
+        assert!(res.invalid.is_empty());
+    }
+
+    #[test]
+    fn test_lenient_invalid_fields() {
+        let client = client(json!({
+            "value": 1,
+            "label": 5,
+            "author": {
+                "id": "not a number",
+            },
+        }));
+
+        let res: LenientData<DummyResult> = api::lenient(Dummy).query(&client).unwrap();
+        assert_eq!(
+            res.data,
+            DummyResult {
+                value: 1,
+                label: None,
+                author: None,
+            },
+        );
+        assert!(res.unknown.is_empty());
+        assert_eq!(res.invalid.len(), 3);
+        assert_eq!(res.invalid["label"], json!(5));
+        assert_eq!(res.invalid["author.id"], json!("not a number"));
+        assert_eq!(
+            res.invalid["author"],
+            json!({
+                "id": "not a number",
+            }),
+        );
+    }
+
+    #[test]
+    fn test_lenient_required_field() {
+        let client = client(json!({
+            "value": "not a number",
+        }));
+
+        let res: Result<LenientData<DummyResult>, _> = api::lenient(Dummy).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::DataType {
+            source,
+            path,
+            snippet,
+            ..
+        } = err
+        {
+            // The original error is reported.
+            assert_eq!(
+                source.to_string(),
+                "invalid type: string \"not a number\", expected u8",
+            );
+            assert_eq!(path, "value");
+            assert_eq!(snippet, r#"{"value":"not a number"}"#);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lenient_async() {
+        let client = client(json!({
+            "value": 1,
+            "label": 5,
+        }));
+
+        let res: LenientData<DummyResult> = api::lenient(Dummy).query_async(&client).await.unwrap();
+        assert_eq!(res.data.value, 1);
+        assert_eq!(res.invalid["label"], json!(5));
+    }
+}
@@ -0,0 +1,32 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! License API endpoints.
+//!
+//! These endpoints are used for querying and managing licenses of the instance.
+
+mod create;
+mod delete;
+mod license;
+mod licenses;
+
+pub use self::create::CreateLicense;
+pub use self::create::CreateLicenseBuilder;
+pub use self::create::CreateLicenseBuilderError;
+
+pub use self::delete::DeleteLicense;
+pub use self::delete::DeleteLicenseBuilder;
+pub use self::delete::DeleteLicenseBuilderError;
+
+pub use self::license::License;
+pub use self::license::LicenseBuilder;
+pub use self::license::LicenseBuilderError;
+
+pub use self::licenses::Licenses;
+pub use self::licenses::LicensesBuilder;
+pub use self::licenses::LicensesBuilderError;
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Add a license to the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+pub struct CreateLicense<'a> {
+    /// The license string.
+    #[builder(setter(into))]
+    license: Cow<'a, str>,
+}
+
+impl<'a> CreateLicense<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateLicenseBuilder<'a> {
+        CreateLicenseBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateLicense<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "license".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("license", self.license.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::licenses::{CreateLicense, CreateLicenseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn license_is_needed() {
+        let err = CreateLicense::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateLicenseBuilderError, "license");
+    }
+
+    #[test]
+    fn license_is_sufficient() {
+        CreateLicense::builder().license("license").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("license")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("license=license")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateLicense::builder().license("license").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
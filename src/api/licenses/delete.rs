@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete a license from the instance.
+///
+/// This endpoint requires administrator privileges.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteLicense {
+    /// The ID of the license.
+    license: u64,
+}
+
+impl DeleteLicense {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteLicenseBuilder {
+        DeleteLicenseBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteLicense {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("license/{}", self.license).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::licenses::{DeleteLicense, DeleteLicenseBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn license_is_needed() {
+        let err = DeleteLicense::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteLicenseBuilderError, "license");
+    }
+
+    #[test]
+    fn license_is_sufficient() {
+        DeleteLicense::builder().license(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("license/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteLicense::builder().license(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the current license of the instance.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct License {}
+
+impl License {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> LicenseBuilder {
+        LicenseBuilder::default()
+    }
+}
+
+impl Endpoint for License {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "license".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::licenses::License;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        License::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder().endpoint("license").build().unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = License::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,117 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for namespaces visible to the current user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct Namespaces<'a> {
+    /// Search for namespaces by name or path.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Only return namespaces owned by the current user.
+    #[builder(default)]
+    owned_only: Option<bool>,
+    /// Only return top-level namespaces.
+    #[builder(default)]
+    top_level_only: Option<bool>,
+}
+
+impl<'a> Namespaces<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> NamespacesBuilder<'a> {
+        NamespacesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Namespaces<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "namespaces".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("owned_only", self.owned_only)
+            .push_opt("top_level_only", self.top_level_only);
+
+        params
+    }
+}
+
+impl<'a> Pageable for Namespaces<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::namespaces::Namespaces;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        Namespaces::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Namespaces::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces")
+            .add_query_params(&[("search", "search")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Namespaces::builder().search("search").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_owned_only() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces")
+            .add_query_params(&[("owned_only", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Namespaces::builder().owned_only(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_top_level_only() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("namespaces")
+            .add_query_params(&[("top_level_only", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Namespaces::builder().top_level_only(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! OAuth2 endpoints.
+//!
+//! These endpoints are used by OAuth2 applications to obtain access tokens and live outside of
+//! the REST API. They are usually queried using an unauthenticated client.
+//!
+//! The authorization code flow starts by sending the user to the URL of an [`Authorize`]
+//! endpoint. The code GitLab passes to the redirect URI is then exchanged for an access token
+//! using the [`Token`] endpoint.
+//!
+//! The device authorization flow starts with an [`AuthorizeDevice`] request. While the user
+//! enters the returned code, the [`Token`] endpoint is polled with the device code. Until the
+//! user has authorized the device, GitLab responds with an `authorization_pending` error.
+
+mod authorize;
+mod authorize_device;
+mod token;
+
+pub use self::authorize::Authorize;
+pub use self::authorize::AuthorizeBuilder;
+pub use self::authorize::AuthorizeBuilderError;
+pub use self::authorize::CodeChallengeMethod;
+pub use self::authorize::OAuthScope;
+
+pub use self::authorize_device::AuthorizeDevice;
+pub use self::authorize_device::AuthorizeDeviceBuilder;
+pub use self::authorize_device::AuthorizeDeviceBuilderError;
+
+pub use self::token::Token;
+pub use self::token::TokenBuilder;
+pub use self::token::TokenBuilderError;
+pub use self::token::TokenGrant;
@@ -0,0 +1,389 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+use itertools::Itertools;
+use url::Url;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::{ApiError, ParamValue, RestClient};
+
+/// Scopes which may be requested by OAuth2 applications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum OAuthScope {
+    /// Access the API and perform git reads and writes.
+    Api,
+    /// Read access to the API.
+    ReadApi,
+    /// Read the authenticated user's profile.
+    ReadUser,
+    /// Read access to repositories.
+    ReadRepository,
+    /// Write access to repositories.
+    WriteRepository,
+    /// Read access to container registries.
+    ReadRegistry,
+    /// Write access to container registries.
+    WriteRegistry,
+    /// Perform API actions as any user in the system.
+    Sudo,
+    /// Perform API actions as an administrator when admin mode is enabled.
+    AdminMode,
+    /// Authenticate using OpenID Connect.
+    OpenId,
+    /// Read the user's profile using OpenID Connect.
+    Profile,
+    /// Read the user's primary email address using OpenID Connect.
+    Email,
+}
+
+impl OAuthScope {
+    /// The scope as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OAuthScope::Api => "api",
+            OAuthScope::ReadApi => "read_api",
+            OAuthScope::ReadUser => "read_user",
+            OAuthScope::ReadRepository => "read_repository",
+            OAuthScope::WriteRepository => "write_repository",
+            OAuthScope::ReadRegistry => "read_registry",
+            OAuthScope::WriteRegistry => "write_registry",
+            OAuthScope::Sudo => "sudo",
+            OAuthScope::AdminMode => "admin_mode",
+            OAuthScope::OpenId => "openid",
+            OAuthScope::Profile => "profile",
+            OAuthScope::Email => "email",
+        }
+    }
+
+    /// Join scopes into the space-separated form used by OAuth2.
+    pub(crate) fn join(scopes: &BTreeSet<Self>) -> Option<String> {
+        if scopes.is_empty() {
+            None
+        } else {
+            Some(scopes.iter().map(|scope| scope.as_str()).join(" "))
+        }
+    }
+}
+
+impl ParamValue<'static> for OAuthScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Methods for deriving a PKCE code challenge from its verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CodeChallengeMethod {
+    /// The challenge is the verifier itself.
+    Plain,
+    /// The challenge is the URL-safe base64 encoding of the SHA-256 hash of the verifier.
+    S256,
+}
+
+impl CodeChallengeMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            CodeChallengeMethod::Plain => "plain",
+            CodeChallengeMethod::S256 => "S256",
+        }
+    }
+}
+
+impl ParamValue<'static> for CodeChallengeMethod {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Request authorization for an OAuth2 application.
+///
+/// This endpoint is visited by the user in a browser rather than queried by the application; use
+/// `url` to get the URL to send the user to.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct Authorize<'a> {
+    /// The ID of the application.
+    #[builder(setter(into))]
+    client_id: Cow<'a, str>,
+    /// Where to redirect the user after authorization.
+    ///
+    /// Must match the redirect URI registered for the application.
+    #[builder(setter(into))]
+    redirect_uri: Cow<'a, str>,
+
+    /// An opaque value passed back to the redirect URI.
+    ///
+    /// Should be used to protect against cross-site request forgery.
+    #[builder(setter(into), default)]
+    state: Option<Cow<'a, str>>,
+    /// The scopes to request.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: BTreeSet<OAuthScope>,
+    /// The PKCE code challenge.
+    #[builder(setter(into), default)]
+    code_challenge: Option<Cow<'a, str>>,
+    /// How the code challenge was derived from its verifier.
+    #[builder(default)]
+    code_challenge_method: Option<CodeChallengeMethod>,
+}
+
+impl<'a> Authorize<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AuthorizeBuilder<'a> {
+        AuthorizeBuilder::default()
+    }
+
+    /// The URL to send the user to.
+    pub fn url<C>(&self, client: &C) -> Result<Url, ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self.url_base().endpoint_for(client, &self.endpoint())?;
+        self.parameters().add_to_url(&mut url);
+        Ok(url)
+    }
+}
+
+impl<'a> AuthorizeBuilder<'a> {
+    /// Request a scope.
+    pub fn scope(&mut self, scope: OAuthScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Request scopes.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = OAuthScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+}
+
+impl<'a> Endpoint for Authorize<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "oauth/authorize".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push("client_id", &self.client_id)
+            .push("redirect_uri", &self.redirect_uri)
+            .push("response_type", "code")
+            .push_opt("state", self.state.as_ref())
+            .push_opt("scope", OAuthScope::join(&self.scopes))
+            .push_opt("code_challenge", self.code_challenge.as_ref())
+            .push_opt("code_challenge_method", self.code_challenge_method);
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::oauth::{Authorize, AuthorizeBuilderError, CodeChallengeMethod, OAuthScope};
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn oauth_scope_as_str() {
+        let items = &[
+            (OAuthScope::Api, "api"),
+            (OAuthScope::ReadApi, "read_api"),
+            (OAuthScope::ReadUser, "read_user"),
+            (OAuthScope::ReadRepository, "read_repository"),
+            (OAuthScope::WriteRepository, "write_repository"),
+            (OAuthScope::ReadRegistry, "read_registry"),
+            (OAuthScope::WriteRegistry, "write_registry"),
+            (OAuthScope::Sudo, "sudo"),
+            (OAuthScope::AdminMode, "admin_mode"),
+            (OAuthScope::OpenId, "openid"),
+            (OAuthScope::Profile, "profile"),
+            (OAuthScope::Email, "email"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn code_challenge_method_as_str() {
+        let items = &[
+            (CodeChallengeMethod::Plain, "plain"),
+            (CodeChallengeMethod::S256, "S256"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn client_id_and_redirect_uri_are_needed() {
+        let err = Authorize::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AuthorizeBuilderError, "client_id");
+    }
+
+    #[test]
+    fn client_id_is_needed() {
+        let err = Authorize::builder()
+            .redirect_uri("https://app.invalid/callback")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, AuthorizeBuilderError, "client_id");
+    }
+
+    #[test]
+    fn redirect_uri_is_needed() {
+        let err = Authorize::builder().client_id("app").build().unwrap_err();
+        crate::test::assert_missing_field!(err, AuthorizeBuilderError, "redirect_uri");
+    }
+
+    #[test]
+    fn client_id_and_redirect_uri_are_sufficient() {
+        Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("oauth/authorize")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[
+                ("client_id", "app"),
+                ("redirect_uri", "https://app.invalid/callback"),
+                ("response_type", "code"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("oauth/authorize")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[
+                ("client_id", "app"),
+                ("redirect_uri", "https://app.invalid/callback"),
+                ("response_type", "code"),
+                ("state", "nonce"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .state("nonce")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("oauth/authorize")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[
+                ("client_id", "app"),
+                ("redirect_uri", "https://app.invalid/callback"),
+                ("response_type", "code"),
+                ("scope", "api read_user"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .scope(OAuthScope::ReadUser)
+            .scopes([OAuthScope::Api, OAuthScope::ReadUser].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_code_challenge() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("oauth/authorize")
+            .url_base(UrlBase::Instance)
+            .add_query_params(&[
+                ("client_id", "app"),
+                ("redirect_uri", "https://app.invalid/callback"),
+                ("response_type", "code"),
+                ("code_challenge", "challenge"),
+                ("code_challenge_method", "S256"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .code_challenge("challenge")
+            .code_challenge_method(CodeChallengeMethod::S256)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn url() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("oauth/authorize")
+            .url_base(UrlBase::Instance)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Authorize::builder()
+            .client_id("app")
+            .redirect_uri("https://app.invalid/callback")
+            .scope(OAuthScope::Api)
+            .build()
+            .unwrap();
+        assert_eq!(
+            endpoint.url(&client).unwrap().as_str(),
+            "https://gitlab.host.invalid/oauth/authorize?client_id=app\
+             &redirect_uri=https%3A%2F%2Fapp.invalid%2Fcallback&response_type=code&scope=api",
+        );
+    }
+}
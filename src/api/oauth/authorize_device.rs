@@ -0,0 +1,130 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::oauth::OAuthScope;
+
+/// Start the device authorization flow for an OAuth2 application.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct AuthorizeDevice<'a> {
+    /// The ID of the application.
+    #[builder(setter(into))]
+    client_id: Cow<'a, str>,
+
+    /// The scopes to request.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: BTreeSet<OAuthScope>,
+}
+
+impl<'a> AuthorizeDevice<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> AuthorizeDeviceBuilder<'a> {
+        AuthorizeDeviceBuilder::default()
+    }
+}
+
+impl<'a> AuthorizeDeviceBuilder<'a> {
+    /// Request a scope.
+    pub fn scope(&mut self, scope: OAuthScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Request scopes.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = OAuthScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+}
+
+impl<'a> Endpoint for AuthorizeDevice<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "oauth/authorize_device".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("client_id", &self.client_id)
+            .push_opt("scope", OAuthScope::join(&self.scopes));
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::oauth::{AuthorizeDevice, AuthorizeDeviceBuilderError, OAuthScope};
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn client_id_is_needed() {
+        let err = AuthorizeDevice::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, AuthorizeDeviceBuilderError, "client_id");
+    }
+
+    #[test]
+    fn client_id_is_sufficient() {
+        AuthorizeDevice::builder().client_id("app").build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("oauth/authorize_device")
+            .url_base(UrlBase::Instance)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("client_id=app")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuthorizeDevice::builder().client_id("app").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("oauth/authorize_device")
+            .url_base(UrlBase::Instance)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("client_id=app", "&scope=api+read_user"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AuthorizeDevice::builder()
+            .client_id("app")
+            .scope(OAuthScope::ReadUser)
+            .scopes([OAuthScope::Api].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// The grant to exchange for an access token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenGrant<'a> {
+    /// An authorization code passed to the redirect URI.
+    AuthorizationCode(Cow<'a, str>),
+    /// A refresh token from a previously issued access token.
+    RefreshToken(Cow<'a, str>),
+    /// A device code from a device authorization.
+    DeviceCode(Cow<'a, str>),
+}
+
+impl<'a> TokenGrant<'a> {
+    fn grant_type(&self) -> &'static str {
+        match self {
+            TokenGrant::AuthorizationCode(_) => "authorization_code",
+            TokenGrant::RefreshToken(_) => "refresh_token",
+            TokenGrant::DeviceCode(_) => "urn:ietf:params:oauth:grant-type:device_code",
+        }
+    }
+
+    fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+        params.push("grant_type", self.grant_type());
+
+        match self {
+            TokenGrant::AuthorizationCode(code) => params.push("code", code),
+            TokenGrant::RefreshToken(token) => params.push("refresh_token", token),
+            TokenGrant::DeviceCode(code) => params.push("device_code", code),
+        };
+    }
+}
+
+/// Request an access token for an OAuth2 application.
+///
+/// Returns an `OAuthToken`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct Token<'a> {
+    /// The grant to exchange for the token.
+    grant: TokenGrant<'a>,
+    /// The ID of the application.
+    #[builder(setter(into))]
+    client_id: Cow<'a, str>,
+
+    /// The secret of the application.
+    ///
+    /// Not required for public applications.
+    #[builder(setter(into), default)]
+    client_secret: Option<Cow<'a, str>>,
+    /// The redirect URI used when requesting authorization.
+    #[builder(setter(into), default)]
+    redirect_uri: Option<Cow<'a, str>>,
+    /// The PKCE code verifier.
+    #[builder(setter(into), default)]
+    code_verifier: Option<Cow<'a, str>>,
+}
+
+impl<'a> Token<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TokenBuilder<'a> {
+        TokenBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Token<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "oauth/token".into()
+    }
+
+    fn url_base(&self) -> UrlBase {
+        UrlBase::Instance
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        self.grant.add_params(&mut params);
+        params
+            .push("client_id", &self.client_id)
+            .push_opt("client_secret", self.client_secret.as_ref())
+            .push_opt("redirect_uri", self.redirect_uri.as_ref())
+            .push_opt("code_verifier", self.code_verifier.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::oauth::{Token, TokenBuilderError, TokenGrant};
+    use crate::api::{self, Query, UrlBase};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn grant_and_client_id_are_needed() {
+        let err = Token::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, TokenBuilderError, "grant");
+    }
+
+    #[test]
+    fn grant_is_needed() {
+        let err = Token::builder().client_id("app").build().unwrap_err();
+        crate::test::assert_missing_field!(err, TokenBuilderError, "grant");
+    }
+
+    #[test]
+    fn client_id_is_needed() {
+        let err = Token::builder()
+            .grant(TokenGrant::RefreshToken("refresh".into()))
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, TokenBuilderError, "client_id");
+    }
+
+    #[test]
+    fn grant_and_client_id_are_sufficient() {
+        Token::builder()
+            .grant(TokenGrant::RefreshToken("refresh".into()))
+            .client_id("app")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint_authorization_code() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("oauth/token")
+            .url_base(UrlBase::Instance)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "grant_type=authorization_code",
+                "&code=code",
+                "&client_id=app",
+                "&client_secret=secret",
+                "&redirect_uri=https%3A%2F%2Fapp.invalid%2Fcallback",
+                "&code_verifier=verifier",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Token::builder()
+            .grant(TokenGrant::AuthorizationCode("code".into()))
+            .client_id("app")
+            .client_secret("secret")
+            .redirect_uri("https://app.invalid/callback")
+            .code_verifier("verifier")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_refresh_token() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("oauth/token")
+            .url_base(UrlBase::Instance)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "grant_type=refresh_token",
+                "&refresh_token=refresh",
+                "&client_id=app",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Token::builder()
+            .grant(TokenGrant::RefreshToken("refresh".into()))
+            .client_id("app")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_device_code() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("oauth/token")
+            .url_base(UrlBase::Instance)
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code",
+                "&device_code=device",
+                "&client_id=app",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Token::builder()
+            .grant(TokenGrant::DeviceCode("device".into()))
+            .client_id("app")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -9,6 +9,7 @@ mod pagination;
 
 mod all_at_once;
 mod lazy;
+mod single_page;
 
 /// A trait to indicate that an endpoint is pageable.
 pub trait Pageable {
@@ -27,3 +28,7 @@ pub use self::all_at_once::paged;
 pub use self::all_at_once::Paged;
 
 pub use self::lazy::LazilyPagedIter;
+
+pub use self::single_page::single_page;
+pub use self::single_page::PageInfo;
+pub use self::single_page::SinglePage;
@@ -12,7 +12,8 @@ use serde::de::DeserializeOwned;
 
 use crate::api::paged::link_header;
 use crate::api::{
-    query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination, Query,
+    lenient, query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination,
+    Query,
 };
 
 /// A query modifier that paginates an endpoint.
@@ -42,7 +43,8 @@ where
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<E, T, C> AsyncQuery<Vec<T>, C> for Paged<E>
 where
     E: Endpoint + Sync,
@@ -52,7 +54,10 @@ where
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
         let url = {
-            let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
+            let mut url = self
+                .endpoint
+                .url_base()
+                .endpoint_for(client, &self.endpoint.endpoint())?;
             self.endpoint.parameters().add_to_url(&mut url);
             url
         };
@@ -90,7 +95,7 @@ where
 
             let req = Request::builder()
                 .method(self.endpoint.method())
-                .uri(query::url_to_http_uri(page_url));
+                .uri(query::url_to_http_uri(page_url.clone()));
             let (req, data) = if let Some((mime, data)) = body.as_ref() {
                 let req = req.header(header::CONTENT_TYPE, *mime);
                 (req, data.clone())
@@ -101,7 +106,7 @@ where
             let status = rsp.status();
 
             if use_keyset_pagination {
-                next_url = link_header::next_page_from_headers(rsp.headers())?;
+                next_url = link_header::next_page_from_headers(rsp.headers(), &page_url)?;
             }
 
             let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
@@ -110,11 +115,10 @@ where
                 return Err(ApiError::server_error(status, rsp.body()));
             };
             if !status.is_success() {
-                return Err(ApiError::from_gitlab(v));
+                return Err(ApiError::from_gitlab(status, v));
             }
 
-            let page =
-                serde_json::from_value::<Vec<T>>(v).map_err(ApiError::data_type::<Vec<T>>)?;
+            let page = lenient::deserialize::<Vec<T>, _>(v)?;
             let page_len = page.len();
 
             // Gitlab used to have issues returning paginated results; these have been fixed since,
@@ -4,6 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::ops::Deref;
 use std::sync::RwLock;
 
 use async_trait::async_trait;
@@ -17,7 +18,7 @@ use url::Url;
 
 use crate::api::paged::link_header;
 use crate::api::{
-    query, ApiError, AsyncClient, Client, Endpoint, Pageable, Paged, Query, RestClient,
+    lenient, query, ApiError, AsyncClient, Client, Endpoint, Pageable, Paged, Query, RestClient,
 };
 
 impl<E> Paged<E>
@@ -27,7 +28,18 @@ where
 {
     /// Create an iterator over the results of paginated results for with a client.
     pub fn iter<'a, C, T>(&'a self, client: &'a C) -> LazilyPagedIter<'a, E, C, T> {
-        LazilyPagedIter::new(self, client)
+        LazilyPagedIter::new(PagedRef::Borrowed(self), client)
+    }
+
+    /// Create an iterator over the results of paginated results for with a client.
+    ///
+    /// Unlike `iter`, the iterator takes ownership of the endpoint, so it may outlive the scope
+    /// which created it (e.g., to be returned from a function).
+    pub fn into_iter<'a, C, T>(self, client: &'a C) -> LazilyPagedIter<'a, E, C, T>
+    where
+        E: 'a,
+    {
+        LazilyPagedIter::new(PagedRef::Owned(self), client)
     }
 }
 
@@ -44,10 +56,40 @@ where
         T: DeserializeOwned + 'static,
         C: AsyncClient + Sync,
     {
-        let iter = LazilyPagedIter::new(self, client);
-        futures_util::stream::unfold(iter, |mut iter| {
-            async move { iter.next_async().await.map(|item| (item, iter)) }
-        })
+        LazilyPagedIter::new(PagedRef::Borrowed(self), client).into_stream()
+    }
+
+    /// Create a stream over the results of paginated results for with a client.
+    ///
+    /// Unlike `iter_async`, the stream takes ownership of the endpoint, so it may outlive the
+    /// scope which created it (e.g., to be returned from a function).
+    pub fn into_iter_async<'a, C, T>(
+        self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a
+    where
+        T: DeserializeOwned + 'static,
+        C: AsyncClient + Sync,
+        E: 'a,
+    {
+        LazilyPagedIter::new(PagedRef::Owned(self), client).into_stream()
+    }
+}
+
+/// A paginated endpoint which is either borrowed or owned by an iterator.
+enum PagedRef<'a, E> {
+    Borrowed(&'a Paged<E>),
+    Owned(Paged<E>),
+}
+
+impl<'a, E> Deref for PagedRef<'a, E> {
+    type Target = Paged<E>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(paged) => paged,
+            Self::Owned(paged) => paged,
+        }
     }
 }
 
@@ -111,7 +153,7 @@ struct PageState {
 }
 
 struct LazilyPagedState<'a, E> {
-    paged: &'a Paged<E>,
+    paged: PagedRef<'a, E>,
     page_state: RwLock<PageState>,
 }
 
@@ -119,7 +161,7 @@ impl<'a, E> LazilyPagedState<'a, E>
 where
     E: Pageable,
 {
-    fn new(paged: &'a Paged<E>) -> Self {
+    fn new(paged: PagedRef<'a, E>) -> Self {
         let next_page = if paged.endpoint.use_keyset_pagination() {
             Page::Keyset(KeysetPage::First)
         } else {
@@ -177,7 +219,10 @@ where
         let url = if let Some(next_url) = next_page.next_url() {
             next_url.clone()
         } else {
-            let mut url = client.rest_endpoint(&self.paged.endpoint.endpoint())?;
+            let endpoint = &self.paged.endpoint;
+            let mut url = endpoint
+                .url_base()
+                .endpoint_for(client, &endpoint.endpoint())?;
             self.paged.endpoint.parameters().add_to_url(&mut url);
 
             let per_page = self.paged.pagination.page_limit();
@@ -213,7 +258,11 @@ where
         })
     }
 
-    fn process_response<C, T>(&self, rsp: Response<Bytes>) -> Result<Vec<T>, ApiError<C::Error>>
+    fn process_response<C, T>(
+        &self,
+        rsp: Response<Bytes>,
+        url: &Url,
+    ) -> Result<Vec<T>, ApiError<C::Error>>
     where
         E: Pageable,
         T: DeserializeOwned,
@@ -222,7 +271,7 @@ where
         let status = rsp.status();
 
         let next_url = if self.paged.endpoint.use_keyset_pagination() {
-            link_header::next_page_from_headers(rsp.headers())?
+            link_header::next_page_from_headers(rsp.headers(), url)?
         } else {
             None
         };
@@ -233,10 +282,10 @@ where
             return Err(ApiError::server_error(status, rsp.body()));
         };
         if !status.is_success() {
-            return Err(ApiError::from_gitlab(v));
+            return Err(ApiError::from_gitlab(status, v));
         }
 
-        let page = serde_json::from_value::<Vec<T>>(v).map_err(ApiError::data_type::<Vec<T>>)?;
+        let page = lenient::deserialize::<Vec<T>, _>(v)?;
         self.next_page(page.len(), next_url);
 
         Ok(page)
@@ -259,13 +308,14 @@ where
             // XXX: Return a new kind of PaginationError here?
             return Ok(Vec::new());
         };
-        let (req, data) = self.build_request::<C>(url)?;
+        let (req, data) = self.build_request::<C>(url.clone())?;
         let rsp = client.rest(req, data)?;
-        self.process_response::<C, _>(rsp)
+        self.process_response::<C, _>(rsp, &url)
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<'a, E, T, C> AsyncQuery<Vec<T>, C> for LazilyPagedState<'a, E>
 where
     E: Endpoint + Pageable + Sync,
@@ -280,9 +330,9 @@ where
             // XXX: Return a new kind of PaginationError here?
             return Ok(Vec::new());
         };
-        let (req, data) = self.build_request::<C>(url)?;
+        let (req, data) = self.build_request::<C>(url.clone())?;
         let rsp = client.rest_async(req, data).await?;
-        self.process_response::<C, _>(rsp)
+        self.process_response::<C, _>(rsp, &url)
     }
 }
 
@@ -301,7 +351,7 @@ where
     E: Endpoint,
     E: Pageable,
 {
-    fn new(paged: &'a Paged<E>, client: &'a C) -> Self {
+    fn new(paged: PagedRef<'a, E>, client: &'a C) -> Self {
         let state = LazilyPagedState::new(paged);
 
         Self {
@@ -357,18 +407,24 @@ where
 
         self.current_page.pop().map(Ok)
     }
+
+    fn into_stream(self) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a {
+        futures_util::stream::unfold(self, |mut iter| {
+            async move { iter.next_async().await.map(|item| (item, iter)) }
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use futures_util::TryStreamExt;
+    use futures_util::{Stream, TryStreamExt};
     use http::StatusCode;
     use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use crate::api::endpoint_prelude::*;
-    use crate::api::{self, ApiError, Pagination};
-    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+    use crate::api::{self, ApiError, LazilyPagedIter, Pagination};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient, TestClientError};
 
     #[derive(Debug, Default)]
     struct Dummy {
@@ -761,6 +817,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pagination_early_exit() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+        let query = Dummy::default();
+
+        let res = api::paged(query, Pagination::All)
+            .iter(&client)
+            .find(|res: &Result<DummyResult, _>| res.as_ref().unwrap().value == 150)
+            .unwrap()
+            .unwrap();
+        assert_eq!(res.value, 150);
+        // Only the first two pages should have been fetched.
+        assert_eq!(client.requests(), 2);
+    }
+
+    fn dummy_iter(
+        client: &PagedTestClient<DummyResult>,
+        with_keyset: bool,
+    ) -> LazilyPagedIter<'_, Dummy, PagedTestClient<DummyResult>, DummyResult> {
+        let query = Dummy {
+            with_keyset,
+        };
+
+        api::paged(query, Pagination::All).into_iter(client)
+    }
+
+    #[test]
+    fn test_pagination_all_into_iter() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = dummy_iter(&client, false)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(res.len(), 256);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
+    #[test]
+    fn test_keyset_pagination_early_exit_into_iter() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res = dummy_iter(&client, true).next().unwrap().unwrap();
+        assert_eq!(res.value, 0);
+        // Only the first page should have been fetched.
+        assert_eq!(client.requests(), 1);
+    }
+
     #[tokio::test]
     async fn test_pagination_all_async() {
         let endpoint = ExpectedUrl::builder()
@@ -789,6 +930,63 @@ mod tests {
         }
     }
 
+    fn dummy_stream(
+        client: &PagedTestClient<DummyResult>,
+        with_keyset: bool,
+    ) -> impl Stream<Item = Result<DummyResult, ApiError<TestClientError>>> + '_ {
+        let query = Dummy {
+            with_keyset,
+        };
+
+        api::paged(query, Pagination::All).into_iter_async(client)
+    }
+
+    #[tokio::test]
+    async fn test_pagination_all_into_iter_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = dummy_stream(&client, false).try_collect().await.unwrap();
+        assert_eq!(res.len(), 256);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keyset_pagination_all_into_iter_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = dummy_stream(&client, true).try_collect().await.unwrap();
+        assert_eq!(res.len(), 256);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
     #[test]
     fn test_keyset_pagination_limit() {
         let endpoint = ExpectedUrl::builder()
@@ -82,29 +82,73 @@ impl LinkHeaderParseError {
     }
 }
 
-pub(crate) fn next_page_from_headers(headers: &HeaderMap) -> Result<Option<Url>, PaginationError> {
+/// Split a header value into the links it contains.
+///
+/// Multiple links may be joined into a single header value with commas.
+fn split_links(value: &str) -> impl Iterator<Item = &str> {
+    let mut links = Vec::new();
+    let mut in_url = false;
+    let mut in_quotes = false;
+    let mut start = 0;
+
+    for (idx, c) in value.char_indices() {
+        match c {
+            '<' if !in_quotes => in_url = true,
+            '>' if !in_quotes => in_url = false,
+            '"' if !in_url => in_quotes = !in_quotes,
+            ',' if !in_url && !in_quotes => {
+                links.push(value[start..idx].trim());
+                start = idx + 1;
+            },
+            _ => (),
+        }
+    }
+    links.push(value[start..].trim());
+
+    links.into_iter().filter(|link| !link.is_empty())
+}
+
+pub(crate) fn next_page_from_headers(
+    headers: &HeaderMap,
+    request_url: &Url,
+) -> Result<Option<Url>, PaginationError> {
+    link_from_headers(headers, "next", request_url)
+}
+
+/// Find the URL of the link with the given relation in the headers of a response.
+///
+/// Links are resolved relative to the URL of the request.
+pub(crate) fn link_from_headers(
+    headers: &HeaderMap,
+    rel: &str,
+    request_url: &Url,
+) -> Result<Option<Url>, PaginationError> {
     let link_headers = headers.get_all(reqwest::header::LINK).iter();
     // GitLab 14.0 will deprecate this header in preference for the W3C spec's `Link` header. Make
     // it less preferred to it in anticipation for this change.
     let links_headers = headers.get_all("Links").iter();
     link_headers
         .chain(links_headers)
-        .map(|link| {
-            let value = link
-                .to_str()
-                .map_err(LinkHeaderParseError::invalid_header)?;
-            Ok(LinkHeader::parse(value)?)
-        })
-        .collect::<Result<Vec<_>, PaginationError>>()?
+        .map(|link| link.to_str().map_err(LinkHeaderParseError::invalid_header))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flat_map(split_links)
+        .map(LinkHeader::parse)
+        .collect::<Result<Vec<_>, _>>()?
         .into_iter()
         .find_map(|header| {
-            let is_next_link = header
+            let is_rel_link = header
                 .params
                 .into_iter()
-                .any(|(key, value)| key == "rel" && value == "next");
+                .any(|(key, value)| key == "rel" && value == rel);
 
-            if is_next_link {
-                Some(header.url.parse().map_err(PaginationError::from))
+            if is_rel_link {
+                Some(
+                    request_url
+                        .join(header.url)
+                        .map(|url| rebase_link(url, request_url))
+                        .map_err(PaginationError::from),
+                )
             } else {
                 None
             }
@@ -112,9 +156,40 @@ pub(crate) fn next_page_from_headers(headers: &HeaderMap) -> Result<Option<Url>,
         .transpose()
 }
 
+/// The path prefix of the instance serving the REST API at a URL.
+fn api_prefix(url: &Url) -> Option<&str> {
+    const API_PATH: &str = "/api/v4/";
+
+    let path = url.path();
+    path.find(API_PATH).map(|idx| &path[..idx])
+}
+
+/// Move a link to the API of an instance under the path prefix used by the request.
+///
+/// Instances served under a relative URL behind a reverse proxy which strips the prefix (and
+/// which have not been configured with it) generate links without it.
+fn rebase_link(link: Url, request_url: &Url) -> Url {
+    if link.origin() != request_url.origin() {
+        return link;
+    }
+
+    match (api_prefix(request_url), api_prefix(&link)) {
+        (Some(prefix), Some(link_prefix)) if prefix != link_prefix => {
+            let path = format!("{}{}", prefix, &link.path()[link_prefix.len()..]);
+            let mut link = link;
+            link.set_path(&path);
+            link
+        },
+        _ => link,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::api::paged::link_header::LinkHeader;
+    use http::{HeaderMap, HeaderValue};
+    use url::Url;
+
+    use crate::api::paged::link_header::{self, LinkHeader};
     use crate::api::LinkHeaderParseError;
 
     #[test]
@@ -165,4 +240,103 @@ mod tests {
         assert_eq!(link.params[1].0, "param2");
         assert_eq!(link.params[1].1, "value");
     }
+
+    #[test]
+    fn test_split_links() {
+        let links = link_header::split_links(
+            "<https://host/path?labels=a,b>; rel=\"next\", <https://host/path>; rel=\"first\",",
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(
+            links,
+            [
+                "<https://host/path?labels=a,b>; rel=\"next\"",
+                "<https://host/path>; rel=\"first\"",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_link_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::LINK,
+            HeaderValue::from_static(concat!(
+                "<https://host/path?page=1>; rel=\"prev\", ",
+                "<https://host/path?page=3>; rel=\"next\"",
+            )),
+        );
+
+        let url = Url::parse("https://host/path?page=2").unwrap();
+        let next = link_header::link_from_headers(&headers, "next", &url).unwrap();
+        assert_eq!(next.unwrap().as_str(), "https://host/path?page=3");
+        let prev = link_header::link_from_headers(&headers, "prev", &url).unwrap();
+        assert_eq!(prev.unwrap().as_str(), "https://host/path?page=1");
+        let last = link_header::link_from_headers(&headers, "last", &url).unwrap();
+        assert_eq!(last, None);
+    }
+
+    fn next_link(link: &'static str, request_url: &str) -> String {
+        let mut headers = HeaderMap::new();
+        headers.insert(http::header::LINK, HeaderValue::from_static(link));
+        let url = Url::parse(request_url).unwrap();
+
+        link_header::next_page_from_headers(&headers, &url)
+            .unwrap()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_link_relative() {
+        assert_eq!(
+            next_link(
+                "</gitlab/api/v4/projects?page=2>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?page=1",
+            ),
+            "https://host/gitlab/api/v4/projects?page=2",
+        );
+        assert_eq!(
+            next_link(
+                "<projects?page=2>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?page=1",
+            ),
+            "https://host/gitlab/api/v4/projects?page=2",
+        );
+    }
+
+    #[test]
+    fn test_link_relative_url_prefix() {
+        // Links generated without the prefix the instance is served under.
+        assert_eq!(
+            next_link(
+                "<https://host/api/v4/projects?id_after=5>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?pagination=keyset",
+            ),
+            "https://host/gitlab/api/v4/projects?id_after=5",
+        );
+        assert_eq!(
+            next_link(
+                "</api/v4/projects?id_after=5>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?pagination=keyset",
+            ),
+            "https://host/gitlab/api/v4/projects?id_after=5",
+        );
+        // Links with the prefix are kept.
+        assert_eq!(
+            next_link(
+                "<https://host/gitlab/api/v4/projects?id_after=5>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?pagination=keyset",
+            ),
+            "https://host/gitlab/api/v4/projects?id_after=5",
+        );
+        // Links to other hosts are not modified.
+        assert_eq!(
+            next_link(
+                "<https://other/api/v4/projects?id_after=5>; rel=\"next\"",
+                "https://host/gitlab/api/v4/projects?pagination=keyset",
+            ),
+            "https://other/api/v4/projects?id_after=5",
+        );
+    }
 }
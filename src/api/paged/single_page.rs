@@ -0,0 +1,405 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, HeaderMap, Request, Response};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::api::paged::link_header;
+use crate::api::{
+    lenient, query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Pageable, Pagination,
+    PaginationError, Query, RestClient,
+};
+
+const TOTAL: &str = "x-total";
+const TOTAL_PAGES: &str = "x-total-pages";
+const PER_PAGE: &str = "x-per-page";
+const PAGE: &str = "x-page";
+const NEXT_PAGE: &str = "x-next-page";
+const PREV_PAGE: &str = "x-prev-page";
+
+/// Pagination metadata reported by a response.
+///
+/// GitLab omits the totals for large collections, so they may be missing even if the endpoint
+/// supports pagination.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PageInfo {
+    /// The total number of results (`X-Total`).
+    pub total: Option<u64>,
+    /// The total number of pages (`X-Total-Pages`).
+    pub total_pages: Option<u64>,
+    /// The number of results per page (`X-Per-Page`).
+    pub per_page: Option<u64>,
+    /// The index of the current page, starting at 1 (`X-Page`).
+    pub page: Option<u64>,
+    /// The index of the next page (`X-Next-Page`).
+    pub next_page: Option<u64>,
+    /// The index of the previous page (`X-Prev-Page`).
+    pub prev_page: Option<u64>,
+    /// The URL of the next page.
+    pub next: Option<Url>,
+    /// The URL of the previous page.
+    pub prev: Option<Url>,
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+impl PageInfo {
+    /// Extract the pagination metadata from a set of response headers.
+    ///
+    /// Missing or malformed numeric headers are ignored; malformed `Link` headers are an error.
+    /// Links are resolved relative to `request_url`, the URL of the request.
+    pub fn from_headers(headers: &HeaderMap, request_url: &Url) -> Result<Self, PaginationError> {
+        Ok(Self {
+            total: header_u64(headers, TOTAL),
+            total_pages: header_u64(headers, TOTAL_PAGES),
+            per_page: header_u64(headers, PER_PAGE),
+            page: header_u64(headers, PAGE),
+            next_page: header_u64(headers, NEXT_PAGE),
+            prev_page: header_u64(headers, PREV_PAGE),
+            next: link_header::link_from_headers(headers, "next", request_url)?,
+            prev: link_header::link_from_headers(headers, "prev", request_url)?,
+        })
+    }
+}
+
+/// A query modifier that fetches a single page of results from an endpoint.
+///
+/// The results are returned along with the pagination metadata of the response. Pages are
+/// always requested by number, even for endpoints supporting keyset pagination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinglePage<E> {
+    endpoint: E,
+    page: u64,
+    per_page: usize,
+}
+
+/// Fetch a single page of results from an endpoint.
+///
+/// Pages are numbered starting at 1. The page size is limited to 100 results.
+pub fn single_page<E>(endpoint: E, page: u64, per_page: usize) -> SinglePage<E> {
+    SinglePage {
+        endpoint,
+        page,
+        per_page,
+    }
+}
+
+impl<E> SinglePage<E>
+where
+    E: Endpoint,
+{
+    fn page_url<C>(&self, client: &C) -> Result<Url, ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let mut url = self
+            .endpoint
+            .url_base()
+            .endpoint_for(client, &self.endpoint.endpoint())?;
+        self.endpoint.parameters().add_to_url(&mut url);
+
+        {
+            let per_page = Pagination::Limit(self.per_page).page_limit();
+            let mut pairs = url.query_pairs_mut();
+            pairs.append_pair("page", &format!("{}", self.page));
+            pairs.append_pair("per_page", &format!("{}", per_page));
+        }
+
+        Ok(url)
+    }
+
+    fn build_request<C>(&self, url: Url) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        let req = Request::builder()
+            .method(self.endpoint.method())
+            .uri(query::url_to_http_uri(url));
+        Ok(if let Some((mime, data)) = self.endpoint.body()? {
+            let req = req.header(header::CONTENT_TYPE, mime);
+            (req, data)
+        } else {
+            (req, Vec::new())
+        })
+    }
+}
+
+fn process_response<C, T>(
+    rsp: Response<Bytes>,
+    url: &Url,
+) -> Result<(Vec<T>, PageInfo), ApiError<C::Error>>
+where
+    T: DeserializeOwned,
+    C: RestClient,
+{
+    let status = rsp.status();
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(status, rsp.body()));
+    };
+    if !status.is_success() {
+        return Err(ApiError::from_gitlab(status, v));
+    }
+
+    let page = lenient::deserialize::<Vec<T>, _>(v)?;
+    let info = PageInfo::from_headers(rsp.headers(), url)?;
+
+    Ok((page, info))
+}
+
+impl<E, T, C> Query<(Vec<T>, PageInfo), C> for SinglePage<E>
+where
+    E: Endpoint,
+    E: Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<(Vec<T>, PageInfo), ApiError<C::Error>> {
+        let url = self.page_url(client)?;
+        let (req, data) = self.build_request::<C>(url.clone())?;
+        let rsp = client.rest(req, data)?;
+        process_response::<C, _>(rsp, &url)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, T, C> AsyncQuery<(Vec<T>, PageInfo), C> for SinglePage<E>
+where
+    E: Endpoint + Sync,
+    E: Pageable,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<(Vec<T>, PageInfo), ApiError<C::Error>> {
+        let url = self.page_url(client)?;
+        let (req, data) = self.build_request::<C>(url.clone())?;
+        let rsp = client.rest_async(req, data).await?;
+        process_response::<C, _>(rsp, &url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{HeaderMap, HeaderValue, StatusCode};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+    use url::Url;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, PageInfo, Query};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
+
+    #[derive(Debug, Default)]
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "paged_dummy".into()
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    fn request_url() -> Url {
+        Url::parse("https://gitlab.host.invalid/api/v4/paged_dummy?page=3").unwrap()
+    }
+
+    #[test]
+    fn page_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Total", HeaderValue::from_static("1137"));
+        headers.insert("X-Total-Pages", HeaderValue::from_static("57"));
+        headers.insert("X-Per-Page", HeaderValue::from_static("20"));
+        headers.insert("X-Page", HeaderValue::from_static("3"));
+        headers.insert("X-Next-Page", HeaderValue::from_static("4"));
+        headers.insert("X-Prev-Page", HeaderValue::from_static("2"));
+        headers.insert(
+            http::header::LINK,
+            HeaderValue::from_static(concat!(
+                "<https://gitlab.host.invalid/api/v4/paged_dummy?page=2>; rel=\"prev\", ",
+                "<https://gitlab.host.invalid/api/v4/paged_dummy?page=4>; rel=\"next\"",
+            )),
+        );
+
+        let info = PageInfo::from_headers(&headers, &request_url()).unwrap();
+        assert_eq!(info.total, Some(1137));
+        assert_eq!(info.total_pages, Some(57));
+        assert_eq!(info.per_page, Some(20));
+        assert_eq!(info.page, Some(3));
+        assert_eq!(info.next_page, Some(4));
+        assert_eq!(info.prev_page, Some(2));
+        assert_eq!(
+            info.next.unwrap().as_str(),
+            "https://gitlab.host.invalid/api/v4/paged_dummy?page=4",
+        );
+        assert_eq!(
+            info.prev.unwrap().as_str(),
+            "https://gitlab.host.invalid/api/v4/paged_dummy?page=2",
+        );
+    }
+
+    #[test]
+    fn page_info_from_headers_relative() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::LINK,
+            HeaderValue::from_static("<paged_dummy?page=4>; rel=\"next\""),
+        );
+
+        let info = PageInfo::from_headers(&headers, &request_url()).unwrap();
+        assert_eq!(
+            info.next.unwrap().as_str(),
+            "https://gitlab.host.invalid/api/v4/paged_dummy?page=4",
+        );
+    }
+
+    #[test]
+    fn page_info_from_headers_empty() {
+        let mut headers = HeaderMap::new();
+        // GitLab sends empty values for missing pages.
+        headers.insert("X-Next-Page", HeaderValue::from_static(""));
+
+        let info = PageInfo::from_headers(&headers, &request_url()).unwrap();
+        assert_eq!(info, PageInfo::default());
+    }
+
+    #[test]
+    fn page_info_from_headers_bad_link() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::LINK,
+            HeaderValue::from_static("https://gitlab.host.invalid; rel=\"next\""),
+        );
+
+        PageInfo::from_headers(&headers, &request_url()).unwrap_err();
+    }
+
+    #[test]
+    fn test_single_page() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "3"), ("per_page", "20")])
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let (res, info): (Vec<DummyResult>, _) =
+            api::single_page(Dummy, 3, 20).query(&client).unwrap();
+        assert_eq!(client.requests(), 1);
+        assert_eq!(res.len(), 20);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, 40 + i as u8);
+        }
+        assert_eq!(info.total, Some(256));
+        assert_eq!(info.total_pages, Some(13));
+        assert_eq!(info.per_page, Some(20));
+        assert_eq!(info.page, Some(3));
+        assert_eq!(info.next_page, Some(4));
+        assert_eq!(info.prev_page, Some(2));
+        assert!(info.next.is_some());
+        assert!(info.prev.is_some());
+    }
+
+    #[test]
+    fn test_single_page_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "100")])
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let (res, info): (Vec<DummyResult>, _) =
+            api::single_page(Dummy, 1, 500).query(&client).unwrap();
+        assert_eq!(res.len(), 100);
+        assert_eq!(info.total_pages, Some(3));
+        assert_eq!(info.prev_page, None);
+        assert_eq!(info.prev, None);
+    }
+
+    #[tokio::test]
+    async fn test_single_page_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "13"), ("per_page", "20")])
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let (res, info): (Vec<DummyResult>, _) = api::single_page(Dummy, 13, 20)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 16);
+        assert_eq!(info.page, Some(13));
+        assert_eq!(info.next_page, None);
+        assert_eq!(info.next, None);
+    }
+
+    #[test]
+    fn test_single_page_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("page", "1"), ("per_page", "20")])
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client =
+            SingleTestClient::new_json(endpoint, &json!({"message": "dummy error message"}));
+
+        let res: Result<(Vec<DummyResult>, _), _> = api::single_page(Dummy, 1, 20).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
@@ -140,6 +140,73 @@ impl<'a> FormParams<'a> {
             body.into_bytes(),
         )))
     }
+
+    /// Encode the parameters along with a file into a multipart request body.
+    pub fn into_multipart_body(
+        self,
+        name: &str,
+        filename: &str,
+        contents: &[u8],
+    ) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.into_multipart_body_with_files(&[(name, filename, contents)])
+    }
+
+    /// Encode the parameters along with files into a multipart request body.
+    ///
+    /// Each file is given as a tuple of the form field name, the filename, and its contents.
+    pub fn into_multipart_body_with_files(
+        self,
+        files: &[(&str, &str, &[u8])],
+    ) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut body = Vec::new();
+
+        for (key, value) in self.params {
+            body.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                    MULTIPART_BOUNDARY,
+                    escape_multipart_name(&key),
+                    value,
+                )
+                .as_bytes(),
+            );
+        }
+
+        for (name, filename, contents) in files {
+            body.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                     Content-Type: application/octet-stream\r\n\r\n",
+                    MULTIPART_BOUNDARY,
+                    escape_multipart_name(name),
+                    escape_multipart_name(filename),
+                )
+                .as_bytes(),
+            );
+            body.extend_from_slice(contents);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", MULTIPART_BOUNDARY).as_bytes());
+
+        Ok(Some((MULTIPART_CONTENT_TYPE, body)))
+    }
+}
+
+/// The boundary used to separate parts of multipart request bodies.
+const MULTIPART_BOUNDARY: &str = "------------------------gitlab-rs-multipart-boundary";
+/// The content type of multipart request bodies.
+const MULTIPART_CONTENT_TYPE: &str =
+    "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary";
+
+fn escape_multipart_name(name: &str) -> Cow<'_, str> {
+    if name.contains(&['"', '\r', '\n'][..]) {
+        name.replace('"', "%22")
+            .replace('\r', "%0D")
+            .replace('\n', "%0A")
+            .into()
+    } else {
+        name.into()
+    }
 }
 
 /// A structure for query parameters.
@@ -195,7 +262,7 @@ impl<'a> QueryParams<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::api::ParamValue;
+    use crate::api::{FormParams, ParamValue};
 
     #[test]
     fn bool_str() {
@@ -205,4 +272,74 @@ mod tests {
             assert_eq!((*i).as_value(), *s);
         }
     }
+
+    #[test]
+    fn multipart_body() {
+        let mut params = FormParams::default();
+        params.push("key", "value");
+
+        let (content_type, body) = params
+            .into_multipart_body("file", "file\".txt", b"contents")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            content_type,
+            "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"key\"\r\n",
+                "\r\n",
+                "value\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"file%22.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "contents\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn multipart_body_with_files() {
+        let mut params = FormParams::default();
+        params.push("key", "value");
+
+        let (content_type, body) = params
+            .into_multipart_body_with_files(&[
+                ("file", "file.txt", b"contents"),
+                ("other", "other.txt", b"other contents"),
+            ])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            content_type,
+            "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"key\"\r\n",
+                "\r\n",
+                "value\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"file.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "contents\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"other\"; filename=\"other.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "other contents\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ),
+        );
+    }
 }
@@ -0,0 +1,49 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! Personal access token API endpoints.
+//!
+//! These endpoints are used for querying and managing personal access tokens.
+
+mod create;
+mod current;
+mod personal_access_token;
+mod personal_access_tokens;
+mod revoke;
+mod rotate;
+mod rotate_current;
+
+pub use self::create::CreatePersonalAccessToken;
+pub use self::create::CreatePersonalAccessTokenBuilder;
+pub use self::create::CreatePersonalAccessTokenBuilderError;
+pub use self::create::PersonalAccessTokenScope;
+
+pub use self::current::CurrentPersonalAccessToken;
+pub use self::current::CurrentPersonalAccessTokenBuilder;
+pub use self::current::CurrentPersonalAccessTokenBuilderError;
+
+pub use self::personal_access_token::PersonalAccessToken;
+pub use self::personal_access_token::PersonalAccessTokenBuilder;
+pub use self::personal_access_token::PersonalAccessTokenBuilderError;
+
+pub use self::personal_access_tokens::PersonalAccessTokenState;
+pub use self::personal_access_tokens::PersonalAccessTokens;
+pub use self::personal_access_tokens::PersonalAccessTokensBuilder;
+pub use self::personal_access_tokens::PersonalAccessTokensBuilderError;
+
+pub use self::revoke::RevokePersonalAccessToken;
+pub use self::revoke::RevokePersonalAccessTokenBuilder;
+pub use self::revoke::RevokePersonalAccessTokenBuilderError;
+
+pub use self::rotate::RotatePersonalAccessToken;
+pub use self::rotate::RotatePersonalAccessTokenBuilder;
+pub use self::rotate::RotatePersonalAccessTokenBuilderError;
+
+pub use self::rotate_current::RotateCurrentPersonalAccessToken;
+pub use self::rotate_current::RotateCurrentPersonalAccessTokenBuilder;
+pub use self::rotate_current::RotateCurrentPersonalAccessTokenBuilderError;
@@ -0,0 +1,300 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Scopes for personal access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum PersonalAccessTokenScope {
+    /// Access the API and perform git reads and writes.
+    Api,
+    /// Read the authenticated user's profile.
+    ReadUser,
+    /// Read access to the API.
+    ReadApi,
+    /// Read access to repositories.
+    ReadRepository,
+    /// Write access to repositories.
+    WriteRepository,
+    /// Read access to container registries.
+    ReadRegistry,
+    /// Write access to container registries.
+    WriteRegistry,
+    /// Perform API actions as any user in the system.
+    Sudo,
+    /// Perform API actions as an administrator when admin mode is enabled.
+    AdminMode,
+    /// Create runners.
+    CreateRunner,
+    /// Access AI features.
+    AiFeatures,
+    /// Perform Kubernetes API calls using the agent for Kubernetes.
+    K8sProxy,
+}
+
+impl PersonalAccessTokenScope {
+    /// The scope as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PersonalAccessTokenScope::Api => "api",
+            PersonalAccessTokenScope::ReadUser => "read_user",
+            PersonalAccessTokenScope::ReadApi => "read_api",
+            PersonalAccessTokenScope::ReadRepository => "read_repository",
+            PersonalAccessTokenScope::WriteRepository => "write_repository",
+            PersonalAccessTokenScope::ReadRegistry => "read_registry",
+            PersonalAccessTokenScope::WriteRegistry => "write_registry",
+            PersonalAccessTokenScope::Sudo => "sudo",
+            PersonalAccessTokenScope::AdminMode => "admin_mode",
+            PersonalAccessTokenScope::CreateRunner => "create_runner",
+            PersonalAccessTokenScope::AiFeatures => "ai_features",
+            PersonalAccessTokenScope::K8sProxy => "k8s_proxy",
+        }
+    }
+}
+
+impl ParamValue<'static> for PersonalAccessTokenScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create a personal access token for a user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreatePersonalAccessToken<'a> {
+    /// The user to create the token for.
+    user: u64,
+    /// The name of the token.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The scopes granted to the token.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: BTreeSet<PersonalAccessTokenScope>,
+    /// The description of the token.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// When the token expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreatePersonalAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreatePersonalAccessTokenBuilder<'a> {
+        CreatePersonalAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> CreatePersonalAccessTokenBuilder<'a> {
+    /// Add a scope to the token.
+    pub fn scope(&mut self, scope: PersonalAccessTokenScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Add scopes to the token.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = PersonalAccessTokenScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+}
+
+impl<'a> Endpoint for CreatePersonalAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/personal_access_tokens", self.user).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", self.name.as_ref())
+            .extend(self.scopes.iter().map(|&value| ("scopes[]", value)))
+            .push_opt("description", self.description.as_ref())
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::personal_access_tokens::{
+        CreatePersonalAccessToken, CreatePersonalAccessTokenBuilderError, PersonalAccessTokenScope,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_name_are_needed() {
+        let err = CreatePersonalAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePersonalAccessTokenBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = CreatePersonalAccessToken::builder()
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePersonalAccessTokenBuilderError, "user");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreatePersonalAccessToken::builder()
+            .user(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreatePersonalAccessTokenBuilderError, "name");
+    }
+
+    #[test]
+    fn user_and_name_are_sufficient() {
+        CreatePersonalAccessToken::builder()
+            .user(1)
+            .name("name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/personal_access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePersonalAccessToken::builder()
+            .user(1)
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/personal_access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&description=description"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePersonalAccessToken::builder()
+            .user(1)
+            .name("name")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/personal_access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&expires_at=2020-01-01"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePersonalAccessToken::builder()
+            .user(1)
+            .name("name")
+            .expires_at(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn personal_access_token_scope_as_str() {
+        let items = &[
+            (PersonalAccessTokenScope::Api, "api"),
+            (PersonalAccessTokenScope::ReadUser, "read_user"),
+            (PersonalAccessTokenScope::ReadApi, "read_api"),
+            (PersonalAccessTokenScope::ReadRepository, "read_repository"),
+            (
+                PersonalAccessTokenScope::WriteRepository,
+                "write_repository",
+            ),
+            (PersonalAccessTokenScope::ReadRegistry, "read_registry"),
+            (PersonalAccessTokenScope::WriteRegistry, "write_registry"),
+            (PersonalAccessTokenScope::Sudo, "sudo"),
+            (PersonalAccessTokenScope::AdminMode, "admin_mode"),
+            (PersonalAccessTokenScope::CreateRunner, "create_runner"),
+            (PersonalAccessTokenScope::AiFeatures, "ai_features"),
+            (PersonalAccessTokenScope::K8sProxy, "k8s_proxy"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/personal_access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&scopes%5B%5D=api",
+                "&scopes%5B%5D=read_user",
+                "&scopes%5B%5D=read_repository",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreatePersonalAccessToken::builder()
+            .user(1)
+            .name("name")
+            .scope(PersonalAccessTokenScope::ReadRepository)
+            .scopes(
+                [
+                    PersonalAccessTokenScope::Api,
+                    PersonalAccessTokenScope::ReadUser,
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the personal access token used to make the request.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CurrentPersonalAccessToken {}
+
+impl CurrentPersonalAccessToken {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentPersonalAccessTokenBuilder {
+        CurrentPersonalAccessTokenBuilder::default()
+    }
+}
+
+impl Endpoint for CurrentPersonalAccessToken {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "personal_access_tokens/self".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::personal_access_tokens::CurrentPersonalAccessToken;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        CurrentPersonalAccessToken::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens/self")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentPersonalAccessToken::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
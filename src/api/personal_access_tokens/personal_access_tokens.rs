@@ -0,0 +1,265 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Filters for personal access token states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalAccessTokenState {
+    /// Filter tokens which are active.
+    Active,
+    /// Filter tokens which are inactive.
+    Inactive,
+}
+
+impl PersonalAccessTokenState {
+    fn as_str(self) -> &'static str {
+        match self {
+            PersonalAccessTokenState::Active => "active",
+            PersonalAccessTokenState::Inactive => "inactive",
+        }
+    }
+}
+
+impl ParamValue<'static> for PersonalAccessTokenState {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for personal access tokens.
+///
+/// Administrators may query tokens of all users; other users only see their own tokens.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct PersonalAccessTokens<'a> {
+    /// Filter tokens by the ID of their owner.
+    #[builder(default)]
+    user: Option<u64>,
+    /// Filter tokens by whether they have been revoked.
+    #[builder(default)]
+    revoked: Option<bool>,
+    /// Filter tokens by state.
+    #[builder(default)]
+    state: Option<PersonalAccessTokenState>,
+    /// Filter tokens created after a point in time.
+    #[builder(default)]
+    created_after: Option<DateTime<Utc>>,
+    /// Filter tokens created before a point in time.
+    #[builder(default)]
+    created_before: Option<DateTime<Utc>>,
+    /// Filter tokens last used after a point in time.
+    #[builder(default)]
+    last_used_after: Option<DateTime<Utc>>,
+    /// Filter tokens last used before a point in time.
+    #[builder(default)]
+    last_used_before: Option<DateTime<Utc>>,
+    /// Search for tokens by name.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+}
+
+impl<'a> PersonalAccessTokens<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> PersonalAccessTokensBuilder<'a> {
+        PersonalAccessTokensBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PersonalAccessTokens<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "personal_access_tokens".into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("user_id", self.user)
+            .push_opt("revoked", self.revoked)
+            .push_opt("state", self.state)
+            .push_opt("created_after", self.created_after)
+            .push_opt("created_before", self.created_before)
+            .push_opt("last_used_after", self.last_used_after)
+            .push_opt("last_used_before", self.last_used_before)
+            .push_opt("search", self.search.as_ref());
+
+        params
+    }
+}
+
+impl<'a> Pageable for PersonalAccessTokens<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::api::personal_access_tokens::{PersonalAccessTokenState, PersonalAccessTokens};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        PersonalAccessTokens::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("user_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_revoked() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("revoked", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .revoked(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_state() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("state", "inactive")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .state(PersonalAccessTokenState::Inactive)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("created_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .created_after(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("created_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .created_before(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_last_used_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("last_used_after", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .last_used_after(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_last_used_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("last_used_before", "2020-01-01T00:00:00Z")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .last_used_before(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("personal_access_tokens")
+            .add_query_params(&[("search", "search")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = PersonalAccessTokens::builder()
+            .search("search")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn personal_access_token_state_as_str() {
+        let items = &[
+            (PersonalAccessTokenState::Active, "active"),
+            (PersonalAccessTokenState::Inactive, "inactive"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+}
@@ -10,10 +10,14 @@
 //!
 //! These endpoints are used for querying and modifying projects and their resources.
 
+pub mod access_tokens;
+pub mod analytics;
+pub mod audit_events;
 mod create;
 pub mod deploy_keys;
 mod edit;
 pub mod environments;
+mod events;
 pub mod hooks;
 pub mod issues;
 pub mod jobs;
@@ -29,7 +33,12 @@ pub mod protected_branches;
 pub mod protected_tags;
 pub mod releases;
 pub mod repository;
+mod share;
+pub mod snippets;
+mod unshare;
+mod upload;
 pub mod variables;
+pub mod vulnerabilities;
 
 pub use self::create::AutoDevOpsDeployStrategy;
 pub use self::create::BuildGitStrategy;
@@ -51,6 +60,10 @@ pub use self::edit::EditProject;
 pub use self::edit::EditProjectBuilder;
 pub use self::edit::EditProjectBuilderError;
 
+pub use self::events::ProjectEvents;
+pub use self::events::ProjectEventsBuilder;
+pub use self::events::ProjectEventsBuilderError;
+
 pub use self::project::Project;
 pub use self::project::ProjectBuilder;
 pub use self::project::ProjectBuilderError;
@@ -59,3 +72,15 @@ pub use self::projects::ProjectOrderBy;
 pub use self::projects::Projects;
 pub use self::projects::ProjectsBuilder;
 pub use self::projects::ProjectsBuilderError;
+
+pub use self::share::ShareProject;
+pub use self::share::ShareProjectBuilder;
+pub use self::share::ShareProjectBuilderError;
+
+pub use self::unshare::UnshareProject;
+pub use self::unshare::UnshareProjectBuilder;
+pub use self::unshare::UnshareProjectBuilderError;
+
+pub use self::upload::UploadFile;
+pub use self::upload::UploadFileBuilder;
+pub use self::upload::UploadFileBuilderError;
@@ -0,0 +1,79 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for access tokens of a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectAccessTokens<'a> {
+    /// The project to query for access tokens.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectAccessTokens<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectAccessTokensBuilder<'a> {
+        ProjectAccessTokensBuilder::default()
+    }
+}
+
+impl<'a> ProjectAccessTokensBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ProjectAccessTokens<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/access_tokens", self.project).into()
+    }
+}
+
+impl<'a> Pageable for ProjectAccessTokens<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::access_tokens::{
+        ProjectAccessTokens, ProjectAccessTokensBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectAccessTokens::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAccessTokensBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectAccessTokens::builder().project(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAccessTokens::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,316 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Scopes for project access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ProjectAccessTokenScope {
+    /// Access the API and perform git reads and writes.
+    Api,
+    /// Read access to the API.
+    ReadApi,
+    /// Read access to the repository.
+    ReadRepository,
+    /// Write access to the repository.
+    WriteRepository,
+    /// Read access to the container registry.
+    ReadRegistry,
+    /// Write access to the container registry.
+    WriteRegistry,
+    /// Create runners.
+    CreateRunner,
+    /// Access AI features.
+    AiFeatures,
+    /// Perform Kubernetes API calls using the agent for Kubernetes.
+    K8sProxy,
+}
+
+impl ProjectAccessTokenScope {
+    /// The scope as a query parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProjectAccessTokenScope::Api => "api",
+            ProjectAccessTokenScope::ReadApi => "read_api",
+            ProjectAccessTokenScope::ReadRepository => "read_repository",
+            ProjectAccessTokenScope::WriteRepository => "write_repository",
+            ProjectAccessTokenScope::ReadRegistry => "read_registry",
+            ProjectAccessTokenScope::WriteRegistry => "write_registry",
+            ProjectAccessTokenScope::CreateRunner => "create_runner",
+            ProjectAccessTokenScope::AiFeatures => "ai_features",
+            ProjectAccessTokenScope::K8sProxy => "k8s_proxy",
+        }
+    }
+}
+
+impl ParamValue<'static> for ProjectAccessTokenScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Create an access token for a project.
+///
+/// A bot user is created for the project and the token is associated with it.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateProjectAccessToken<'a> {
+    /// The project to create the access token in.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the token.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// The scopes granted to the token.
+    #[builder(setter(name = "_scopes"), default, private)]
+    scopes: BTreeSet<ProjectAccessTokenScope>,
+    /// The access level of the token within the project.
+    #[builder(default)]
+    access_level: Option<AccessLevel>,
+    /// The description of the token.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// When the token expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> CreateProjectAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateProjectAccessTokenBuilder<'a> {
+        CreateProjectAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> CreateProjectAccessTokenBuilder<'a> {
+    /// Add a scope to the token.
+    pub fn scope(&mut self, scope: ProjectAccessTokenScope) -> &mut Self {
+        self.scopes.get_or_insert_with(BTreeSet::new).insert(scope);
+        self
+    }
+
+    /// Add scopes to the token.
+    pub fn scopes<I>(&mut self, scopes: I) -> &mut Self
+    where
+        I: Iterator<Item = ProjectAccessTokenScope>,
+    {
+        self.scopes.get_or_insert_with(BTreeSet::new).extend(scopes);
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateProjectAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/access_tokens", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("name", self.name.as_ref())
+            .extend(self.scopes.iter().map(|&value| ("scopes[]", value)))
+            .push_opt("access_level", self.access_level.map(|a| a.as_u64()))
+            .push_opt("description", self.description.as_ref())
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::projects::access_tokens::{
+        CreateProjectAccessToken, CreateProjectAccessTokenBuilderError, ProjectAccessTokenScope,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_name_are_needed() {
+        let err = CreateProjectAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateProjectAccessToken::builder()
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateProjectAccessToken::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateProjectAccessTokenBuilderError, "name");
+    }
+
+    #[test]
+    fn project_and_name_are_sufficient() {
+        CreateProjectAccessToken::builder()
+            .project(1)
+            .name("name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&access_level=30"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .access_level(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&description=description"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("name=name", "&expires_at=2020-01-01"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .expires_at(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn project_access_token_scope_as_str() {
+        let items = &[
+            (ProjectAccessTokenScope::Api, "api"),
+            (ProjectAccessTokenScope::ReadApi, "read_api"),
+            (ProjectAccessTokenScope::ReadRepository, "read_repository"),
+            (ProjectAccessTokenScope::WriteRepository, "write_repository"),
+            (ProjectAccessTokenScope::ReadRegistry, "read_registry"),
+            (ProjectAccessTokenScope::WriteRegistry, "write_registry"),
+            (ProjectAccessTokenScope::CreateRunner, "create_runner"),
+            (ProjectAccessTokenScope::AiFeatures, "ai_features"),
+            (ProjectAccessTokenScope::K8sProxy, "k8s_proxy"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn endpoint_scopes() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "name=name",
+                "&scopes%5B%5D=api",
+                "&scopes%5B%5D=read_api",
+                "&scopes%5B%5D=write_repository",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProjectAccessToken::builder()
+            .project("simple/project")
+            .name("name")
+            .scope(ProjectAccessTokenScope::WriteRepository)
+            .scopes(
+                [
+                    ProjectAccessTokenScope::Api,
+                    ProjectAccessTokenScope::ReadApi,
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
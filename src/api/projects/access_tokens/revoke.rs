@@ -0,0 +1,105 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Revoke an access token of a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RevokeProjectAccessToken<'a> {
+    /// The project with the access token.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the access token.
+    token: u64,
+}
+
+impl<'a> RevokeProjectAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RevokeProjectAccessTokenBuilder<'a> {
+        RevokeProjectAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> RevokeProjectAccessTokenBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for RevokeProjectAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/access_tokens/{}", self.project, self.token).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::access_tokens::{
+        RevokeProjectAccessToken, RevokeProjectAccessTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_token_are_needed() {
+        let err = RevokeProjectAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RevokeProjectAccessToken::builder()
+            .token(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = RevokeProjectAccessToken::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RevokeProjectAccessTokenBuilderError, "token");
+    }
+
+    #[test]
+    fn project_and_token_are_sufficient() {
+        RevokeProjectAccessToken::builder()
+            .project(1)
+            .token(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/access_tokens/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RevokeProjectAccessToken::builder()
+            .project("simple/project")
+            .token(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
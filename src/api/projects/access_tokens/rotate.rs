@@ -0,0 +1,147 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Rotate an access token of a project.
+///
+/// The token is revoked and a new token with the same scopes is returned.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct RotateProjectAccessToken<'a> {
+    /// The project with the access token.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the access token.
+    token: u64,
+
+    /// When the new token expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> RotateProjectAccessToken<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RotateProjectAccessTokenBuilder<'a> {
+        RotateProjectAccessTokenBuilder::default()
+    }
+}
+
+impl<'a> RotateProjectAccessTokenBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for RotateProjectAccessToken<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/access_tokens/{}/rotate",
+            self.project, self.token,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::projects::access_tokens::{
+        RotateProjectAccessToken, RotateProjectAccessTokenBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_token_are_needed() {
+        let err = RotateProjectAccessToken::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RotateProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = RotateProjectAccessToken::builder()
+            .token(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RotateProjectAccessTokenBuilderError, "project");
+    }
+
+    #[test]
+    fn token_is_needed() {
+        let err = RotateProjectAccessToken::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, RotateProjectAccessTokenBuilderError, "token");
+    }
+
+    #[test]
+    fn project_and_token_are_sufficient() {
+        RotateProjectAccessToken::builder()
+            .project(1)
+            .token(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens/1/rotate")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RotateProjectAccessToken::builder()
+            .project("simple/project")
+            .token(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/access_tokens/1/rotate")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("expires_at=2020-01-01")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RotateProjectAccessToken::builder()
+            .project("simple/project")
+            .token(1)
+            .expires_at(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
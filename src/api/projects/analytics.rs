@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project analytics API endpoints.
+//!
+//! These endpoints are used for querying value stream analytics of projects.
+
+mod value_stream_stage_median;
+mod value_stream_stages;
+mod value_streams;
+
+pub use self::value_stream_stage_median::ProjectValueStreamStageMedian;
+pub use self::value_stream_stage_median::ProjectValueStreamStageMedianBuilder;
+pub use self::value_stream_stage_median::ProjectValueStreamStageMedianBuilderError;
+
+pub use self::value_stream_stages::ProjectValueStreamStages;
+pub use self::value_stream_stages::ProjectValueStreamStagesBuilder;
+pub use self::value_stream_stages::ProjectValueStreamStagesBuilderError;
+
+pub use self::value_streams::ProjectValueStreams;
+pub use self::value_streams::ProjectValueStreamsBuilder;
+pub use self::value_streams::ProjectValueStreamsBuilderError;
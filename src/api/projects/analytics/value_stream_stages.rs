@@ -0,0 +1,114 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the stages of a value stream of a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectValueStreamStages<'a> {
+    /// The project to query.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The value stream to query.
+    ///
+    /// The default value stream may be queried using `default`.
+    #[builder(setter(into))]
+    value_stream: NameOrId<'a>,
+}
+
+impl<'a> ProjectValueStreamStages<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectValueStreamStagesBuilder<'a> {
+        ProjectValueStreamStagesBuilder::default()
+    }
+}
+
+impl<'a> ProjectValueStreamStagesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        NameOrId::validate_path_segment("value_stream", self.value_stream.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ProjectValueStreamStages<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/analytics/value_stream_analytics/value_streams/{}/stages",
+            self.project, self.value_stream,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::analytics::{
+        ProjectValueStreamStages, ProjectValueStreamStagesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_value_stream_are_needed() {
+        let err = ProjectValueStreamStages::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectValueStreamStagesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectValueStreamStages::builder()
+            .value_stream("default")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectValueStreamStagesBuilderError, "project");
+    }
+
+    #[test]
+    fn value_stream_is_needed() {
+        let err = ProjectValueStreamStages::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectValueStreamStagesBuilderError,
+            "value_stream",
+        );
+    }
+
+    #[test]
+    fn project_and_value_stream_are_sufficient() {
+        ProjectValueStreamStages::builder()
+            .project(1)
+            .value_stream("default")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/analytics/value_stream_analytics/value_streams/default/stages")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectValueStreamStages::builder()
+            .project("simple/project")
+            .value_stream("default")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,20 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project audit event API endpoints.
+//!
+//! These endpoints are used for querying audit events of projects.
+
+mod audit_event;
+mod audit_events;
+
+pub use self::audit_event::ProjectAuditEvent;
+pub use self::audit_event::ProjectAuditEventBuilder;
+pub use self::audit_event::ProjectAuditEventBuilderError;
+
+pub use self::audit_events::ProjectAuditEvents;
+pub use self::audit_events::ProjectAuditEventsBuilder;
+pub use self::audit_events::ProjectAuditEventsBuilderError;
@@ -0,0 +1,101 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for an audit event of a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectAuditEvent<'a> {
+    /// The project to query for the audit event.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the audit event.
+    audit_event: u64,
+}
+
+impl<'a> ProjectAuditEvent<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectAuditEventBuilder<'a> {
+        ProjectAuditEventBuilder::default()
+    }
+}
+
+impl<'a> ProjectAuditEventBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ProjectAuditEvent<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/audit_events/{}",
+            self.project, self.audit_event,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::audit_events::{ProjectAuditEvent, ProjectAuditEventBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_audit_event_are_needed() {
+        let err = ProjectAuditEvent::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAuditEventBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectAuditEvent::builder()
+            .audit_event(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAuditEventBuilderError, "project");
+    }
+
+    #[test]
+    fn audit_event_is_needed() {
+        let err = ProjectAuditEvent::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectAuditEventBuilderError, "audit_event");
+    }
+
+    #[test]
+    fn project_and_audit_event_are_sufficient() {
+        ProjectAuditEvent::builder()
+            .project(1)
+            .audit_event(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/audit_events/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectAuditEvent::builder()
+            .project("simple/project")
+            .audit_event(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
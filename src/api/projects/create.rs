@@ -618,9 +618,9 @@ pub struct CreateProject<'a> {
     /// A list of topics to apply to the repository.
     #[builder(setter(name = "_topics"), default, private)]
     topics: BTreeSet<Cow<'a, str>>,
-    // TODO: Figure out how to actually use this.
-    // avatar   mixed   no  Image file for avatar of the project
-    // avatar: ???,
+    /// The avatar image for the project.
+    #[builder(setter(name = "_avatar"), default, private)]
+    avatar: Option<(Cow<'a, str>, Cow<'a, [u8]>)>,
     /// Whether to show a link to create or view a merge request when pushing a branch from the
     /// command line or not.
     #[builder(default)]
@@ -713,6 +713,18 @@ impl<'a> CreateProject<'a> {
 }
 
 impl<'a> CreateProjectBuilder<'a> {
+    /// Upload an avatar image for the project.
+    ///
+    /// Setting an avatar sends the request as a multipart form.
+    pub fn avatar<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.avatar = Some(Some((filename.into(), contents.into())));
+        self
+    }
+
     /// Set the name of the project.
     ///
     /// If not set, it will default to the value of `path`.
@@ -954,7 +966,11 @@ impl<'a> Endpoint for CreateProject<'a> {
                 .push_opt("snippets_enabled", self.snippets_enabled);
         }
 
-        params.into_body()
+        if let Some((filename, contents)) = self.avatar.as_ref() {
+            params.into_multipart_body("avatar", filename, contents)
+        } else {
+            params.into_body()
+        }
     }
 }
 
@@ -2670,4 +2686,36 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "name\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "image data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateProject::builder()
+            .name("name")
+            .avatar("avatar.png", &b"image data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
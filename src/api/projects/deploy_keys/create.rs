@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new deploy key on project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateDeployKey<'a> {
     /// The project to add the deploy key to
     #[builder(setter(into))]
@@ -34,6 +34,12 @@ impl<'a> CreateDeployKey<'a> {
     }
 }
 
+impl<'a> CreateDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateDeployKey<'a> {
     fn method(&self) -> Method {
         Method::POST
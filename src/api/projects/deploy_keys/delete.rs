@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Delete a deploy key from a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteDeployKey<'a> {
     /// The project to delete the deploy key from.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> DeleteDeployKey<'a> {
     }
 }
 
+impl<'a> DeleteDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeleteDeployKey<'a> {
     fn method(&self) -> Method {
         Method::DELETE
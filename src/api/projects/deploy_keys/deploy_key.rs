@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a deploy key on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeployKey<'a> {
     /// The project with the merge requset.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> DeployKey<'a> {
     }
 }
 
+impl<'a> DeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeployKey<'a> {
     fn method(&self) -> Method {
         Method::GET
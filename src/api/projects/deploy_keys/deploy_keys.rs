@@ -12,6 +12,7 @@ use crate::api::endpoint_prelude::*;
 /// Query for deploy keys within a project.
 ///
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeployKeys<'a> {
     /// The project to query for deploy keys.
     #[builder(setter(into))]
@@ -25,6 +26,12 @@ impl<'a> DeployKeys<'a> {
     }
 }
 
+impl<'a> DeployKeysBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeployKeys<'a> {
     fn method(&self) -> Method {
         Method::GET
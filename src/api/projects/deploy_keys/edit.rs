@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Edit a new deploy key on project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditDeployKey<'a> {
     /// The project to open the deploy key on.
     #[builder(setter(into))]
@@ -33,6 +33,12 @@ impl<'a> EditDeployKey<'a> {
     }
 }
 
+impl<'a> EditDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EditDeployKey<'a> {
     fn method(&self) -> Method {
         Method::PUT
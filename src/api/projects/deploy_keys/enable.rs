@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Enable a new deploy key on project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EnableDeployKey<'a> {
     /// The project to enable the deploy key on.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> EnableDeployKey<'a> {
     }
 }
 
+impl<'a> EnableDeployKeyBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EnableDeployKey<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -15,9 +15,18 @@ use crate::api::projects::{
     FeatureAccessLevelPublic, MergeMethod, SquashOption,
 };
 
+#[derive(Debug, Clone)]
+enum AvatarUpdate<'a> {
+    Upload {
+        filename: Cow<'a, str>,
+        contents: Cow<'a, [u8]>,
+    },
+    Remove,
+}
+
 /// Edit an existing project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditProject<'a> {
     /// The project to edit.
     #[builder(setter(into))]
@@ -164,9 +173,9 @@ pub struct EditProject<'a> {
     /// A list of topics to apply to the repository.
     #[builder(setter(name = "_topics"), default, private)]
     topics: BTreeSet<Cow<'a, str>>,
-    // TODO: Figure out how to actually use this.
-    // avatar   mixed   no  Image file for avatar of the project
-    // avatar: ???,
+    /// The avatar image for the project.
+    #[builder(setter(name = "_avatar"), default, private)]
+    avatar: Option<AvatarUpdate<'a>>,
     /// The default Git strategy for CI jobs of the project.
     #[builder(default)]
     build_git_strategy: Option<BuildGitStrategy>,
@@ -261,6 +270,27 @@ impl<'a> EditProject<'a> {
 }
 
 impl<'a> EditProjectBuilder<'a> {
+    /// Upload a new avatar image for the project.
+    ///
+    /// Setting an avatar sends the request as a multipart form.
+    pub fn avatar<F, C>(&mut self, filename: F, contents: C) -> &mut Self
+    where
+        F: Into<Cow<'a, str>>,
+        C: Into<Cow<'a, [u8]>>,
+    {
+        self.avatar = Some(Some(AvatarUpdate::Upload {
+            filename: filename.into(),
+            contents: contents.into(),
+        }));
+        self
+    }
+
+    /// Remove the avatar image of the project.
+    pub fn remove_avatar(&mut self) -> &mut Self {
+        self.avatar = Some(Some(AvatarUpdate::Remove));
+        self
+    }
+
     /// Add a tag.
     pub fn tag<T>(&mut self, tag: T) -> &mut Self
     where
@@ -306,6 +336,10 @@ impl<'a> EditProjectBuilder<'a> {
             .extend(iter.map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for EditProject<'a> {
@@ -460,7 +494,17 @@ impl<'a> Endpoint for EditProject<'a> {
                 .push_opt("snippets_enabled", self.snippets_enabled);
         }
 
-        params.into_body()
+        match self.avatar.as_ref() {
+            Some(AvatarUpdate::Upload {
+                filename,
+                contents,
+            }) => params.into_multipart_body("avatar", filename, contents),
+            Some(AvatarUpdate::Remove) => {
+                params.push("avatar", "");
+                params.into_body()
+            },
+            None => params.into_body(),
+        }
     }
 }
 
@@ -1940,4 +1984,56 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"name\"\r\n",
+                "\r\n",
+                "name\r\n",
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "image data\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProject::builder()
+            .project("simple/project")
+            .name("name")
+            .avatar("avatar.png", &b"image data"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_remove_avatar() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("avatar=")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditProject::builder()
+            .project("simple/project")
+            .remove_avatar()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
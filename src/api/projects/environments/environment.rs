@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for an environment within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Environment<'a> {
     /// The project to query for the environment.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> Environment<'a> {
     }
 }
 
+impl<'a> EnvironmentBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Environment<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -42,7 +42,7 @@ impl ParamValue<'static> for EnvironmentState {
 
 /// Query for environments within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Environments<'a> {
     /// The project to query for environments.
     #[builder(setter(into))]
@@ -86,6 +86,10 @@ impl<'a> EnvironmentsBuilder<'a> {
         self.name_or_search = Some(Some(NameOrSearch::Search(search.into())));
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for Environments<'a> {
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new webhook for a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateHook<'a> {
     /// The project to create a webhook within.
     #[builder(setter(into))]
@@ -78,6 +78,12 @@ impl<'a> CreateHook<'a> {
     }
 }
 
+impl<'a> CreateHookBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateHook<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Edit an existing webhook for a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteHook<'a> {
     /// The project to edit a webhook within.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> DeleteHook<'a> {
     }
 }
 
+impl<'a> DeleteHookBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeleteHook<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Edit an existing webhook for a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditHook<'a> {
     /// The project to edit a webhook within.
     #[builder(setter(into))]
@@ -80,6 +80,12 @@ impl<'a> EditHook<'a> {
     }
 }
 
+impl<'a> EditHookBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EditHook<'a> {
     fn method(&self) -> Method {
         Method::PUT
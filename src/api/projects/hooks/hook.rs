@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a webhook within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Hook<'a> {
     /// The project to query for webhooks.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> Hook<'a> {
     }
 }
 
+impl<'a> HookBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Hook<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for webhooks within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Hooks<'a> {
     /// The project to query for webhooks.
     #[builder(setter(into))]
@@ -24,6 +25,12 @@ impl<'a> Hooks<'a> {
     }
 }
 
+impl<'a> HooksBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Hooks<'a> {
     fn method(&self) -> Method {
         Method::GET
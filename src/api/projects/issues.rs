@@ -9,12 +9,18 @@
 //! These endpoints are used for querying projects issues.
 
 mod create;
+pub mod discussions;
 mod edit;
 mod issue;
 mod issues;
 mod merge_requests_closing;
 pub mod notes;
+mod resource_iteration_events;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod resource_weight_events;
+mod user_agent_detail;
 
 pub use self::create::CreateIssue;
 pub use self::create::CreateIssueBuilder;
@@ -30,11 +36,14 @@ pub use self::issue::IssueBuilder;
 pub use self::issue::IssueBuilderError;
 
 pub use self::issues::IssueDueDateFilter;
+pub use self::issues::IssueEpic;
+pub use self::issues::IssueHealthStatus;
 pub use self::issues::IssueIteration;
 pub use self::issues::IssueOrderBy;
 pub use self::issues::IssueScope;
 pub use self::issues::IssueSearchScope;
 pub use self::issues::IssueState;
+pub use self::issues::IssueType;
 pub use self::issues::IssueWeight;
 pub use self::issues::Issues;
 pub use self::issues::IssuesBuilder;
@@ -44,6 +53,26 @@ pub use self::merge_requests_closing::MergeRequestsClosing;
 pub use self::merge_requests_closing::MergeRequestsClosingBuilder;
 pub use self::merge_requests_closing::MergeRequestsClosingBuilderError;
 
+pub use self::resource_iteration_events::IssueResourceIterationEvents;
+pub use self::resource_iteration_events::IssueResourceIterationEventsBuilder;
+pub use self::resource_iteration_events::IssueResourceIterationEventsBuilderError;
+
 pub use self::resource_label_events::IssueResourceLabelEvents;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilder;
 pub use self::resource_label_events::IssueResourceLabelEventsBuilderError;
+
+pub use self::resource_milestone_events::IssueResourceMilestoneEvents;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::IssueResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::IssueResourceStateEvents;
+pub use self::resource_state_events::IssueResourceStateEventsBuilder;
+pub use self::resource_state_events::IssueResourceStateEventsBuilderError;
+
+pub use self::resource_weight_events::IssueResourceWeightEvents;
+pub use self::resource_weight_events::IssueResourceWeightEventsBuilder;
+pub use self::resource_weight_events::IssueResourceWeightEventsBuilderError;
+
+pub use self::user_agent_detail::IssueUserAgentDetail;
+pub use self::user_agent_detail::IssueUserAgentDetailBuilder;
+pub use self::user_agent_detail::IssueUserAgentDetailBuilderError;
@@ -14,7 +14,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new issue on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssue<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -129,6 +129,10 @@ impl<'a> CreateIssueBuilder<'a> {
             .extend(iter.into_iter().map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for CreateIssue<'a> {
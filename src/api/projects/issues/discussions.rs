@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project issue discussion API endpoints.
+//!
+//! These endpoints are used for querying project issue discussions.
+
+mod create;
+mod create_note;
+mod discussions;
+
+pub use self::create::CreateIssueDiscussion;
+pub use self::create::CreateIssueDiscussionBuilder;
+pub use self::create::CreateIssueDiscussionBuilderError;
+
+pub use self::create_note::CreateIssueDiscussionNote;
+pub use self::create_note::CreateIssueDiscussionNoteBuilder;
+pub use self::create_note::CreateIssueDiscussionNoteBuilderError;
+
+pub use self::discussions::IssueDiscussions;
+pub use self::discussions::IssueDiscussionsBuilder;
+pub use self::discussions::IssueDiscussionsBuilderError;
@@ -0,0 +1,191 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Add a note to an existing discussion on an issue on a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateIssueDiscussionNote<'a> {
+    /// The project of the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The issue of the discussion.
+    issue: u64,
+    /// The ID of the discussion to reply to.
+    #[builder(setter(into))]
+    discussion: Cow<'a, str>,
+    /// The content of the note.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+
+    /// When the note was created.
+    ///
+    /// Requires administrator or owner permissions.
+    #[builder(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateIssueDiscussionNote<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateIssueDiscussionNoteBuilder<'a> {
+        CreateIssueDiscussionNoteBuilder::default()
+    }
+}
+
+impl<'a> CreateIssueDiscussionNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateIssueDiscussionNote<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/discussions/{}/notes",
+            self.project, self.issue, self.discussion,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("body", self.body.as_ref())
+            .push_opt("created_at", self.created_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::issues::discussions::{
+        CreateIssueDiscussionNote, CreateIssueDiscussionNoteBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_issue_discussion_and_body_are_necessary() {
+        let err = CreateIssueDiscussionNote::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueDiscussionNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateIssueDiscussionNote::builder()
+            .issue(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueDiscussionNoteBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_necessary() {
+        let err = CreateIssueDiscussionNote::builder()
+            .project(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueDiscussionNoteBuilderError, "issue");
+    }
+
+    #[test]
+    fn discussion_is_necessary() {
+        let err = CreateIssueDiscussionNote::builder()
+            .project(1)
+            .issue(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            CreateIssueDiscussionNoteBuilderError,
+            "discussion",
+        );
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = CreateIssueDiscussionNote::builder()
+            .project(1)
+            .issue(1)
+            .discussion("deadbeef")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateIssueDiscussionNoteBuilderError, "body");
+    }
+
+    #[test]
+    fn project_issue_discussion_and_body_are_sufficient() {
+        CreateIssueDiscussionNote::builder()
+            .project(1)
+            .issue(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/discussions/deadbeef/notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("body=body")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueDiscussionNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .discussion("deadbeef")
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/issues/1/discussions/deadbeef/notes")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("body=body", "&created_at=2020-01-01T00%3A00%3A00Z"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateIssueDiscussionNote::builder()
+            .project("simple/project")
+            .issue(1)
+            .discussion("deadbeef")
+            .body("body")
+            .created_at(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
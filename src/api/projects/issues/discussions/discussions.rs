@@ -0,0 +1,102 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for discussions on an issue within a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct IssueDiscussions<'a> {
+    /// The project to query for the issue.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the issue.
+    issue: u64,
+}
+
+impl<'a> IssueDiscussions<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IssueDiscussionsBuilder<'a> {
+        IssueDiscussionsBuilder::default()
+    }
+}
+
+impl<'a> IssueDiscussionsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for IssueDiscussions<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/issues/{}/discussions",
+            self.project, self.issue,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for IssueDiscussions<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::issues::discussions::{
+        IssueDiscussions, IssueDiscussionsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_issue_are_necessary() {
+        let err = IssueDiscussions::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = IssueDiscussions::builder().issue(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn issue_is_necessary() {
+        let err = IssueDiscussions::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, IssueDiscussionsBuilderError, "issue");
+    }
+
+    #[test]
+    fn project_and_issue_are_sufficient() {
+        IssueDiscussions::builder()
+            .project(1)
+            .issue(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/issues/1/discussions")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IssueDiscussions::builder()
+            .project("simple/project")
+            .issue(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
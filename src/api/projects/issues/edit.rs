@@ -61,7 +61,7 @@ impl<'a, 'b: 'a> ParamValue<'a> for &'b IssueLabels<'a> {
 
 /// Create a new issue on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditIssue<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -252,6 +252,10 @@ impl<'a> EditIssueBuilder<'a> {
             .push(label.into());
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for EditIssue<'a> {
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a issue within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Issue<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> Issue<'a> {
     }
 }
 
+impl<'a> IssueBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Issue<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -22,12 +22,19 @@ pub type IssueSearchScope = crate::api::issues::IssueSearchScope;
 /// Filter values for due dates.
 pub type IssueDueDateFilter = crate::api::issues::IssueDueDateFilter;
 
+/// Filter issues by epic.
+pub type IssueEpic = crate::api::issues::IssueEpic;
+
+/// Types of issues.
+pub type IssueType = crate::api::issues::IssueType;
+
+/// Filter issues by health status.
+pub type IssueHealthStatus = crate::api::issues::IssueHealthStatus;
+
 /// Keys issue results may be ordered by.
 pub type IssueOrderBy = crate::api::issues::IssueOrderBy;
 
 /// Query for issues within a project.
-///
-/// TODO: Negation (not) filters are not yet supported.
 pub type Issues<'a> = crate::api::issues::ProjectIssues<'a>;
 /// Builder for [`Issues`].
 pub type IssuesBuilder<'a> = crate::api::issues::ProjectIssuesBuilder<'a>;
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for merge requests closing an issue
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestsClosing<'a> {
     /// The project to of the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestsClosing<'a> {
     }
 }
 
+impl<'a> MergeRequestsClosingBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestsClosing<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -12,7 +12,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new note on an issue on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateIssueNote<'a> {
     /// The project the issue belongs to.
     #[builder(setter(into))]
@@ -40,6 +40,12 @@ impl<'a> CreateIssueNote<'a> {
     }
 }
 
+impl<'a> CreateIssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateIssueNote<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Edit an issue note on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditIssueNote<'a> {
     /// The project to add the issue to.
     #[builder(setter(into))]
@@ -36,6 +36,12 @@ impl<'a> EditIssueNote<'a> {
     }
 }
 
+impl<'a> EditIssueNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EditIssueNote<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -12,7 +12,7 @@ use crate::api::helpers::NoteOrderBy;
 
 /// Query for notes on an issue within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct IssueNotes<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -35,6 +35,12 @@ impl<'a> IssueNotes<'a> {
     }
 }
 
+impl<'a> IssueNotesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for IssueNotes<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for resource label events for an issue.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct IssueResourceLabelEvents<'a> {
     /// The project to query for the issue.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> IssueResourceLabelEvents<'a> {
     }
 }
 
+impl<'a> IssueResourceLabelEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for IssueResourceLabelEvents<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Cancel a job.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CancelJob<'a> {
     /// The project which owns the job.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> CancelJob<'a> {
     }
 }
 
+impl<'a> CancelJobBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CancelJob<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a job within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Job<'a> {
     /// The project to query for the job.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> Job<'a> {
     }
 }
 
+impl<'a> JobBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Job<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -57,6 +57,7 @@ impl ParamValue<'static> for JobScope {
 
 /// Query for jobs within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Jobs<'a> {
     /// The project to query for jobs.
     #[builder(setter(into))]
@@ -89,6 +90,10 @@ impl<'a> JobsBuilder<'a> {
         self.scopes.get_or_insert_with(HashSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for Jobs<'a> {
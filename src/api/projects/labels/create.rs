@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a label within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateLabel<'a> {
     /// The project to create a label within.
     #[builder(setter(into))]
@@ -42,6 +42,12 @@ impl<'a> CreateLabel<'a> {
     }
 }
 
+impl<'a> CreateLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateLabel<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Delete a label within a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeleteLabel<'a> {
     /// The project to delete a label within.
     #[builder(setter(into))]
@@ -27,6 +28,13 @@ impl<'a> DeleteLabel<'a> {
     }
 }
 
+impl<'a> DeleteLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        NameOrId::validate_path_segment("label", self.label.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeleteLabel<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -0,0 +1,206 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Edit a label within a project.
+///
+/// At least one of `new_name` or `color` is required by GitLab.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EditLabel<'a> {
+    /// The project to edit a label within.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID or title of the label.
+    #[builder(setter(into))]
+    label: NameOrId<'a>,
+
+    /// The new name of the label.
+    #[builder(setter(into), default)]
+    new_name: Option<Cow<'a, str>>,
+    /// The new color of the label.
+    ///
+    /// CSS and RGB colors in `#RRGGBB` format are supported.
+    #[builder(setter(into), default)]
+    color: Option<Cow<'a, str>>,
+    /// The new description of the label.
+    #[builder(setter(into), default)]
+    description: Option<Cow<'a, str>>,
+    /// The new priority of the label.
+    #[builder(default)]
+    priority: Option<u64>,
+}
+
+impl<'a> EditLabel<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditLabelBuilder<'a> {
+        EditLabelBuilder::default()
+    }
+}
+
+impl<'a> EditLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        NameOrId::validate_path_segment("label", self.label.as_ref())
+    }
+}
+
+impl<'a> Endpoint for EditLabel<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/labels/{}", self.project, self.label).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("new_name", self.new_name.as_ref())
+            .push_opt("color", self.color.as_ref())
+            .push_opt("description", self.description.as_ref())
+            .push_opt("priority", self.priority);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::labels::{EditLabel, EditLabelBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_label_are_needed() {
+        let err = EditLabel::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = EditLabel::builder().label(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditLabelBuilderError, "project");
+    }
+
+    #[test]
+    fn label_is_needed() {
+        let err = EditLabel::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditLabelBuilderError, "label");
+    }
+
+    #[test]
+    fn project_and_label_are_sufficient() {
+        EditLabel::builder().project(1).label(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/labels/label")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditLabel::builder()
+            .project("simple/project")
+            .label("label")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_new_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/labels/label")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("new_name=new_name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditLabel::builder()
+            .project("simple/project")
+            .label("label")
+            .new_name("new_name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/labels/label")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("color=%23ffffff")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditLabel::builder()
+            .project("simple/project")
+            .label("label")
+            .color("#ffffff")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_description() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/labels/label")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("description=description")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditLabel::builder()
+            .project("simple/project")
+            .label("label")
+            .description("description")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_priority() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("projects/simple%2Fproject/labels/label")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("priority=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditLabel::builder()
+            .project("simple/project")
+            .label("label")
+            .priority(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
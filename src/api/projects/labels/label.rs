@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a label within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Label<'a> {
     /// The project to query for the label.
     #[builder(setter(into))]
@@ -34,6 +34,13 @@ impl<'a> Label<'a> {
     }
 }
 
+impl<'a> LabelBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        NameOrId::validate_path_segment("label", self.label.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Label<'a> {
     fn method(&self) -> Method {
         Method::GET
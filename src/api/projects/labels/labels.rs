@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for labels within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Labels<'a> {
     /// The project to query for labels.
     #[builder(setter(into))]
@@ -37,6 +37,12 @@ impl<'a> Labels<'a> {
     }
 }
 
+impl<'a> LabelsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Labels<'a> {
     fn method(&self) -> Method {
         Method::GET
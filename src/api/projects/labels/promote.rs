@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Promote a label within a project to a group label.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PromoteLabel<'a> {
     /// The project to promote a label within.
     #[builder(setter(into))]
@@ -27,6 +28,13 @@ impl<'a> PromoteLabel<'a> {
     }
 }
 
+impl<'a> PromoteLabelBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        NameOrId::validate_path_segment("label", self.label.as_ref())
+    }
+}
+
 impl<'a> Endpoint for PromoteLabel<'a> {
     fn method(&self) -> Method {
         Method::PUT
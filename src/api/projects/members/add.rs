@@ -12,7 +12,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Add a user as a member of a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct AddProjectMember<'a> {
     /// The project to add the user to.
     #[builder(setter(into))]
@@ -26,6 +26,9 @@ pub struct AddProjectMember<'a> {
     /// When the user's access expires.
     #[builder(default)]
     expires_at: Option<NaiveDate>,
+    /// The ID of a custom member role to assign to the user.
+    #[builder(default)]
+    member_role_id: Option<u64>,
 }
 
 impl<'a> AddProjectMember<'a> {
@@ -54,6 +57,10 @@ impl<'a> AddProjectMemberBuilder<'a> {
             .extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for AddProjectMember<'a> {
@@ -71,7 +78,8 @@ impl<'a> Endpoint for AddProjectMember<'a> {
         params
             .push("user_id", &self.user_ids)
             .push("access_level", self.access_level.as_u64())
-            .push_opt("expires_at", self.expires_at);
+            .push_opt("expires_at", self.expires_at)
+            .push_opt("member_role_id", self.member_role_id);
 
         params.into_body()
     }
@@ -203,4 +211,29 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_member_role_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/members")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "user_id=1",
+                "&access_level=30",
+                "&member_role_id=5",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = AddProjectMember::builder()
+            .project("simple/project")
+            .user(1)
+            .access_level(AccessLevel::Developer)
+            .member_role_id(5)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
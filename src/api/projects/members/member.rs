@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a single member of a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectMember<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -40,6 +41,12 @@ impl<'a> ProjectMember<'a> {
     }
 }
 
+impl<'a> ProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProjectMember<'a> {
     fn method(&self) -> Method {
         Method::GET
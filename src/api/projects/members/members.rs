@@ -13,7 +13,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a members of a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectMembers<'a> {
     /// The project to query for membership.
     #[builder(setter(into))]
@@ -63,6 +63,10 @@ impl<'a> ProjectMembersBuilder<'a> {
         self.user_ids.get_or_insert_with(BTreeSet::new).extend(iter);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for ProjectMembers<'a> {
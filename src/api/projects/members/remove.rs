@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Remove a user from a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RemoveProjectMember<'a> {
     /// The project to remove the user from.
     #[builder(setter(into))]
@@ -21,6 +21,9 @@ pub struct RemoveProjectMember<'a> {
     /// unassign from any issues or merge requests inside a given project.
     #[builder(default)]
     unassign_issuables: Option<bool>,
+    /// Skip removing the user's direct memberships in subgroups and projects.
+    #[builder(default)]
+    skip_subresources: Option<bool>,
 }
 
 impl<'a> RemoveProjectMember<'a> {
@@ -30,6 +33,12 @@ impl<'a> RemoveProjectMember<'a> {
     }
 }
 
+impl<'a> RemoveProjectMemberBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for RemoveProjectMember<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -42,7 +51,9 @@ impl<'a> Endpoint for RemoveProjectMember<'a> {
     fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
         let mut params = FormParams::default();
 
-        params.push_opt("unassign_issuables", self.unassign_issuables);
+        params
+            .push_opt("unassign_issuables", self.unassign_issuables)
+            .push_opt("skip_subresources", self.skip_subresources);
 
         params.into_body()
     }
@@ -123,4 +134,24 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_skip_subresources() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/project%2Fsubproject/members/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("skip_subresources=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RemoveProjectMember::builder()
+            .project("project/subproject")
+            .user(1)
+            .skip_subresources(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
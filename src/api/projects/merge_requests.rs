@@ -15,17 +15,26 @@ pub mod awards;
 mod changes;
 mod commits;
 mod create;
+mod create_pipeline;
+mod diffs;
 pub mod discussions;
+pub mod draft_notes;
 mod edit;
 mod issues_closed_by;
 mod merge;
 mod merge_request;
 mod merge_requests;
 pub mod notes;
+mod participants;
 mod pipelines;
 mod rebase;
 mod resource_label_events;
+mod resource_milestone_events;
+mod resource_state_events;
+mod reviewers;
 mod unapprove;
+mod version;
+mod versions;
 
 pub use self::approve::ApproveMergeRequest;
 pub use self::approve::ApproveMergeRequestBuilder;
@@ -35,6 +44,10 @@ pub use self::create::CreateMergeRequest;
 pub use self::create::CreateMergeRequestBuilder;
 pub use self::create::CreateMergeRequestBuilderError;
 
+pub use self::create_pipeline::CreateMergeRequestPipeline;
+pub use self::create_pipeline::CreateMergeRequestPipelineBuilder;
+pub use self::create_pipeline::CreateMergeRequestPipelineBuilderError;
+
 pub use self::edit::EditMergeRequest;
 pub use self::edit::EditMergeRequestBuilder;
 pub use self::edit::EditMergeRequestBuilderError;
@@ -64,6 +77,18 @@ pub use self::changes::MergeRequestChanges;
 pub use self::changes::MergeRequestChangesBuilder;
 pub use self::changes::MergeRequestChangesBuilderError;
 
+pub use self::diffs::MergeRequestDiffs;
+pub use self::diffs::MergeRequestDiffsBuilder;
+pub use self::diffs::MergeRequestDiffsBuilderError;
+
+pub use self::version::MergeRequestDiffVersion;
+pub use self::version::MergeRequestDiffVersionBuilder;
+pub use self::version::MergeRequestDiffVersionBuilderError;
+
+pub use self::versions::MergeRequestDiffVersions;
+pub use self::versions::MergeRequestDiffVersionsBuilder;
+pub use self::versions::MergeRequestDiffVersionsBuilderError;
+
 pub use self::merge_requests::MergeRequestOrderBy;
 pub use self::merge_requests::MergeRequestScope;
 pub use self::merge_requests::MergeRequestSearchScope;
@@ -73,14 +98,30 @@ pub use self::merge_requests::MergeRequests;
 pub use self::merge_requests::MergeRequestsBuilder;
 pub use self::merge_requests::MergeRequestsBuilderError;
 
+pub use self::participants::MergeRequestParticipants;
+pub use self::participants::MergeRequestParticipantsBuilder;
+pub use self::participants::MergeRequestParticipantsBuilderError;
 pub use self::rebase::RebaseMergeRequest;
 pub use self::rebase::RebaseMergeRequestBuilder;
+
 pub use self::rebase::RebaseMergeRequestBuilderError;
 
 pub use self::resource_label_events::MergeRequestResourceLabelEvents;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilder;
 pub use self::resource_label_events::MergeRequestResourceLabelEventsBuilderError;
 
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEvents;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilder;
+pub use self::resource_milestone_events::MergeRequestResourceMilestoneEventsBuilderError;
+
+pub use self::resource_state_events::MergeRequestResourceStateEvents;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilder;
+pub use self::resource_state_events::MergeRequestResourceStateEventsBuilderError;
+
+pub use self::reviewers::MergeRequestReviewers;
+pub use self::reviewers::MergeRequestReviewersBuilder;
+pub use self::reviewers::MergeRequestReviewersBuilderError;
+
 pub use self::unapprove::UnapproveMergeRequest;
 pub use self::unapprove::UnapproveMergeRequestBuilder;
 pub use self::unapprove::UnapproveMergeRequestBuilderError;
@@ -10,7 +10,8 @@
 //!
 //! # Example
 //!
-//! ```rust,no_run
+#![cfg_attr(feature = "client_sync", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "client_sync"), doc = "```rust,ignore")]
 //! use serde::Deserialize;
 //! use gitlab::Gitlab;
 //! use gitlab::api::{self, Query};
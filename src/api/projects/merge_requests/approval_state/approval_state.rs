@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for approval state of a merge request.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestApprovalState<'a> {
     /// The project to query for approval state.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestApprovalState<'a> {
     }
 }
 
+impl<'a> MergeRequestApprovalStateBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestApprovalState<'a> {
     fn method(&self) -> Method {
         Method::GET
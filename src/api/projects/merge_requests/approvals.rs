@@ -10,7 +10,8 @@
 //! See <https://docs.gitlab.com/ee/api/merge_request_approvals.html#merge-request-level-mr-approvals>
 //!
 //! # Example
-//! ```rust,no_run
+#![cfg_attr(feature = "client_sync", doc = "```rust,no_run")]
+#![cfg_attr(not(feature = "client_sync"), doc = "```rust,ignore")]
 //! use serde::{Serialize, Deserialize};
 //! use gitlab::*;
 //! use gitlab::api::Query;
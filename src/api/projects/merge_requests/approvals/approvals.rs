@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for approvals on a merge request within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestApprovals<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestApprovals<'a> {
     }
 }
 
+impl<'a> MergeRequestApprovalsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestApprovals<'a> {
     fn method(&self) -> Method {
         Method::GET
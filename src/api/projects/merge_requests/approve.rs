@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Approve a merge request.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ApproveMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -37,6 +37,12 @@ impl<'a> ApproveMergeRequest<'a> {
     }
 }
 
+impl<'a> ApproveMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ApproveMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
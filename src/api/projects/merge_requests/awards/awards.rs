@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for awards on a merge request within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestAwards<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestAwards<'a> {
     }
 }
 
+impl<'a> MergeRequestAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
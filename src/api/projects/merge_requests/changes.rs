@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Shows information of a merge request including its files and changes.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeRequestChanges<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -22,6 +22,9 @@ pub struct MergeRequestChanges<'a> {
     /// Retrieve changes diffs via Gitaly
     #[builder(default)]
     access_raw_diffs: Option<bool>,
+    /// Return diffs in the unified diff format.
+    #[builder(default)]
+    unidiff: Option<bool>,
 }
 
 impl<'a> MergeRequestChanges<'a> {
@@ -31,6 +34,12 @@ impl<'a> MergeRequestChanges<'a> {
     }
 }
 
+impl<'a> MergeRequestChangesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestChanges<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -44,19 +53,19 @@ impl<'a> Endpoint for MergeRequestChanges<'a> {
         .into()
     }
 
-    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
-        let mut params = FormParams::default();
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
 
-        params.push_opt("access_raw_diffs", self.access_raw_diffs);
+        params
+            .push_opt("access_raw_diffs", self.access_raw_diffs)
+            .push_opt("unidiff", self.unidiff);
 
-        params.into_body()
+        params
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use http::Method;
-
     use crate::api::projects::merge_requests::{
         MergeRequestChanges, MergeRequestChangesBuilderError,
     };
@@ -99,10 +108,7 @@ mod tests {
     #[test]
     fn endpoint() {
         let endpoint = ExpectedUrl::builder()
-            .method(Method::GET)
             .endpoint("projects/simple%2Fproject/merge_requests/1/changes")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("")
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -118,10 +124,8 @@ mod tests {
     #[test]
     fn endpoint_access_raw_diffs() {
         let endpoint = ExpectedUrl::builder()
-            .method(Method::GET)
             .endpoint("projects/simple%2Fproject/merge_requests/1/changes")
-            .content_type("application/x-www-form-urlencoded")
-            .body_str("access_raw_diffs=true")
+            .add_query_params(&[("access_raw_diffs", "true")])
             .build()
             .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "");
@@ -134,4 +138,22 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_unidiff() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/changes")
+            .add_query_params(&[("unidiff", "true")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestChanges::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .unidiff(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
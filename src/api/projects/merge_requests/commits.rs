@@ -9,8 +9,9 @@ use derive_builder::Builder;
 use crate::api::common::NameOrId;
 use crate::api::endpoint_prelude::*;
 
-/// Query for a merge request on a project.
+/// Query for the commits of a merge request on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestCommits<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestCommits<'a> {
     }
 }
 
+impl<'a> MergeRequestCommitsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestCommits<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -59,7 +59,7 @@ impl Reviewer {
 
 /// Create a new merge request on project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequest<'a> {
     /// The project to open the merge requset *from*.
     #[builder(setter(into))]
@@ -77,6 +77,7 @@ pub struct CreateMergeRequest<'a> {
     /// The assignee of the merge request.
     #[builder(setter(name = "_assignee"), default, private)]
     assignee: Option<Assignee>,
+    /// The reviewers of the merge request.
     #[builder(setter(name = "_reviewer"), default, private)]
     reviewer: Option<Reviewer>,
     /// The description of the merge request.
@@ -163,7 +164,7 @@ impl<'a> CreateMergeRequestBuilder<'a> {
         self
     }
 
-    /// Filter merge requests without a reviewer.
+    /// Unset reviewer(s) of the merge request.
     pub fn without_reviewer(&mut self) -> &mut Self {
         self.reviewer = Some(Some(Reviewer::Unassigned));
         self
@@ -222,6 +223,10 @@ impl<'a> CreateMergeRequestBuilder<'a> {
             .extend(iter.map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for CreateMergeRequest<'a> {
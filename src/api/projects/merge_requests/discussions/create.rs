@@ -220,7 +220,7 @@ impl<'a> Position<'a> {
         PositionBuilder::default()
     }
 
-    fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
+    pub(crate) fn add_params<'b>(&'b self, params: &mut FormParams<'b>) {
         params
             .push("position[base_sha]", self.base_sha.as_ref())
             .push("position[start_sha]", self.start_sha.as_ref())
@@ -233,7 +233,7 @@ impl<'a> Position<'a> {
 
 /// Create a new discussion on a merge request on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestDiscussion<'a> {
     /// The project of the merge request.
     #[builder(setter(into))]
@@ -264,6 +264,12 @@ impl<'a> CreateMergeRequestDiscussion<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestDiscussionBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestDiscussion<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for discussions on an merge request within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestDiscussions<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestDiscussions<'a> {
     }
 }
 
+impl<'a> MergeRequestDiscussionsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestDiscussions<'a> {
     fn method(&self) -> Method {
         Method::GET
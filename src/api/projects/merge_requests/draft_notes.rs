@@ -0,0 +1,46 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project merge request draft note API endpoints.
+//!
+//! These endpoints are used for managing pending review comments on project merge requests.
+//! Draft notes are only visible to their author until they are published.
+
+mod bulk_publish;
+mod create;
+mod delete;
+mod draft_note;
+mod draft_notes;
+mod edit;
+mod publish;
+
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotes;
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotesBuilder;
+pub use self::bulk_publish::BulkPublishMergeRequestDraftNotesBuilderError;
+
+pub use self::create::CreateMergeRequestDraftNote;
+pub use self::create::CreateMergeRequestDraftNoteBuilder;
+pub use self::create::CreateMergeRequestDraftNoteBuilderError;
+
+pub use self::delete::DeleteMergeRequestDraftNote;
+pub use self::delete::DeleteMergeRequestDraftNoteBuilder;
+pub use self::delete::DeleteMergeRequestDraftNoteBuilderError;
+
+pub use self::draft_note::MergeRequestDraftNote;
+pub use self::draft_note::MergeRequestDraftNoteBuilder;
+pub use self::draft_note::MergeRequestDraftNoteBuilderError;
+
+pub use self::draft_notes::MergeRequestDraftNotes;
+pub use self::draft_notes::MergeRequestDraftNotesBuilder;
+pub use self::draft_notes::MergeRequestDraftNotesBuilderError;
+
+pub use self::edit::EditMergeRequestDraftNote;
+pub use self::edit::EditMergeRequestDraftNoteBuilder;
+pub use self::edit::EditMergeRequestDraftNoteBuilderError;
+
+pub use self::publish::PublishMergeRequestDraftNote;
+pub use self::publish::PublishMergeRequestDraftNoteBuilder;
+pub use self::publish::PublishMergeRequestDraftNoteBuilderError;
@@ -54,7 +54,7 @@ impl ParamValue<'static> for MergeRequestStateEvent {
 
 /// Edit a new merge request on project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct EditMergeRequest<'a> {
     /// The project to open the merge requset *from*.
     #[builder(setter(into))]
@@ -71,6 +71,7 @@ pub struct EditMergeRequest<'a> {
     /// The assignee of the merge request.
     #[builder(setter(name = "_assignee"), default, private)]
     assignee: Option<Assignee>,
+    /// The reviewers of the merge request.
     #[builder(setter(name = "_reviewer"), default, private)]
     reviewer: Option<Reviewer>,
     /// The ID of the milestone to add the merge request to.
@@ -159,7 +160,7 @@ impl<'a> EditMergeRequestBuilder<'a> {
         self
     }
 
-    /// Filter merge requests without a reviewer.
+    /// Unset reviewer(s) of the merge request.
     pub fn without_reviewer(&mut self) -> &mut Self {
         self.reviewer = Some(Some(Reviewer::Unassigned));
         self
@@ -266,6 +267,10 @@ impl<'a> EditMergeRequestBuilder<'a> {
             .push(label.into());
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for EditMergeRequest<'a> {
@@ -11,9 +11,9 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for issues closed by a merge request.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct IssuesClosedBy<'a> {
-    /// The project to of the merge request.
+    /// The project of the merge request.
     #[builder(setter(into))]
     project: NameOrId<'a>,
     /// The ID of the merge request.
@@ -27,6 +27,12 @@ impl<'a> IssuesClosedBy<'a> {
     }
 }
 
+impl<'a> IssuesClosedByBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for IssuesClosedBy<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Merge a merge request.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -47,6 +47,12 @@ impl<'a> MergeMergeRequest<'a> {
     }
 }
 
+impl<'a> MergeMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a merge request on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -37,6 +37,12 @@ impl<'a> MergeRequest<'a> {
     }
 }
 
+impl<'a> MergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequest<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -216,11 +216,35 @@ impl ApprovedByIds {
     }
 }
 
+#[derive(Debug, Clone)]
+enum Reviewer<'a> {
+    Any,
+    None,
+    User(NameOrId<'a>),
+}
+
+impl<'a> Reviewer<'a> {
+    fn add_params<'b>(&'b self, params: &mut QueryParams<'b>) {
+        match self {
+            Reviewer::Any => {
+                params.push("reviewer_id", "Any");
+            },
+            Reviewer::None => {
+                params.push("reviewer_id", "None");
+            },
+            Reviewer::User(NameOrId::Name(name)) => {
+                params.push("reviewer_username", name);
+            },
+            Reviewer::User(NameOrId::Id(id)) => {
+                params.push("reviewer_id", *id);
+            },
+        }
+    }
+}
+
 /// Query for merge requests within a project.
-///
-/// TODO: Negation (not) filters are not yet supported.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeRequests<'a> {
     /// The project to query for merge requests.
     #[builder(setter(into))]
@@ -277,8 +301,8 @@ pub struct MergeRequests<'a> {
     #[builder(setter(name = "_approved_by_ids"), default, private)]
     approved_by_ids: Option<ApprovedByIds>,
     /// Filter merge requests by reviewers.
-    #[builder(setter(into), default)]
-    reviewer: Option<NameOrId<'a>>,
+    #[builder(setter(name = "_reviewer"), default, private)]
+    reviewer: Option<Reviewer<'a>>,
     /// Filter merge requests by the API caller's reactions.
     #[builder(setter(name = "_my_reaction_emoji"), default, private)]
     my_reaction_emoji: Option<ReactionEmoji<'a>>,
@@ -307,6 +331,25 @@ pub struct MergeRequests<'a> {
     #[builder(setter(name = "_search_in"), default, private)]
     search_in: Option<CommaSeparatedList<MergeRequestSearchScope>>,
 
+    /// Filter merge requests without any of the given labels.
+    #[builder(setter(name = "_not_labels"), default, private)]
+    not_labels: Option<CommaSeparatedList<Cow<'a, str>>>,
+    /// Filter merge requests not in a given milestone.
+    #[builder(setter(into), default)]
+    not_milestone: Option<Cow<'a, str>>,
+    /// Filter merge requests not created by an author.
+    #[builder(setter(into), default)]
+    not_author: Option<NameOrId<'a>>,
+    /// Filter merge requests not assigned to a user (by ID).
+    #[builder(default)]
+    not_assignee_id: Option<u64>,
+    /// Filter merge requests not being reviewed by a user.
+    #[builder(setter(into), default)]
+    not_reviewer: Option<NameOrId<'a>>,
+    /// Filter merge requests without a given reaction by the API caller.
+    #[builder(setter(into), default)]
+    not_my_reaction_emoji: Option<Cow<'a, str>>,
+
     /// Order results by a given key.
     #[builder(default)]
     order_by: Option<MergeRequestOrderBy>,
@@ -504,6 +547,27 @@ impl<'a> MergeRequestsBuilder<'a> {
         self
     }
 
+    /// Filter merge requests without a reviewer.
+    pub fn no_reviewer(&mut self) -> &mut Self {
+        self.reviewer = Some(Some(Reviewer::None));
+        self
+    }
+
+    /// Filter merge requests with any reviewer.
+    pub fn any_reviewer(&mut self) -> &mut Self {
+        self.reviewer = Some(Some(Reviewer::Any));
+        self
+    }
+
+    /// Filter merge requests being reviewed by a user (by ID or username).
+    pub fn reviewer<R>(&mut self, reviewer: R) -> &mut Self
+    where
+        R: Into<NameOrId<'a>>,
+    {
+        self.reviewer = Some(Some(Reviewer::User(reviewer.into())));
+        self
+    }
+
     /// Filter merge requests without a reaction by the API caller.
     pub fn no_reaction(&mut self) -> &mut Self {
         self.my_reaction_emoji = Some(Some(ReactionEmoji::None));
@@ -533,6 +597,35 @@ impl<'a> MergeRequestsBuilder<'a> {
             .push(scope);
         self
     }
+
+    /// Exclude merge requests with a given label.
+    pub fn not_label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<Cow<'a, str>>,
+    {
+        self.not_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .push(label.into());
+        self
+    }
+
+    /// Exclude merge requests with any of the given labels.
+    pub fn not_labels<I, L>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<Cow<'a, str>>,
+    {
+        self.not_labels
+            .get_or_insert(None)
+            .get_or_insert_with(CommaSeparatedList::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for MergeRequests<'a> {
@@ -569,6 +662,13 @@ impl<'a> Endpoint for MergeRequests<'a> {
             .push_opt("environment", self.environment.as_ref())
             .push_opt("deployed_after", self.deployed_after)
             .push_opt("deployed_before", self.deployed_before)
+            .push_opt("not[labels]", self.not_labels.as_ref())
+            .push_opt("not[milestone]", self.not_milestone.as_ref())
+            .push_opt("not[assignee_id]", self.not_assignee_id)
+            .push_opt(
+                "not[my_reaction_emoji]",
+                self.not_my_reaction_emoji.as_ref(),
+            )
             .push_opt("order_by", self.order_by)
             .push_opt("sort", self.sort);
 
@@ -592,12 +692,25 @@ impl<'a> Endpoint for MergeRequests<'a> {
             approved_by_ids.add_params(&mut params);
         }
         if let Some(reviewer) = self.reviewer.as_ref() {
+            reviewer.add_params(&mut params);
+        }
+        if let Some(author) = self.not_author.as_ref() {
+            match author {
+                NameOrId::Name(name) => {
+                    params.push("not[author_username]", name);
+                },
+                NameOrId::Id(id) => {
+                    params.push("not[author_id]", *id);
+                },
+            }
+        }
+        if let Some(reviewer) = self.not_reviewer.as_ref() {
             match reviewer {
                 NameOrId::Name(name) => {
-                    params.push("reviewer_username", name);
+                    params.push("not[reviewer_username]", name);
                 },
                 NameOrId::Id(id) => {
-                    params.push("reviewer_id", *id);
+                    params.push("not[reviewer_id]", *id);
                 },
             }
         }
@@ -1403,4 +1516,175 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_reviewer_none() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("reviewer_id", "None")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .no_reviewer()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_reviewer_any() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("reviewer_id", "Any")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .any_reviewer()
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_labels() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[labels]", "label,label1,label2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_label("label")
+            .not_labels(["label1", "label2"].iter().cloned())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_milestone() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[milestone]", "1.0")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_milestone("1.0")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_author_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[author_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_author(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_author_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[author_username]", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_author("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_assignee_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[assignee_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_assignee_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_reviewer_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[reviewer_id]", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_reviewer(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_reviewer_name() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[reviewer_username]", "name")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_reviewer("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_not_my_reaction_emoji() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests")
+            .add_query_params(&[("not[my_reaction_emoji]", "tada")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequests::builder()
+            .project("simple/project")
+            .not_my_reaction_emoji("tada")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for awards on a note on a merge request within a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestNoteAwards<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -28,6 +29,12 @@ impl<'a> MergeRequestNoteAwards<'a> {
     }
 }
 
+impl<'a> MergeRequestNoteAwardsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestNoteAwards<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new award on a note on a merge request on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestNoteAward<'a> {
     /// The project the merge request belongs to.
     #[builder(setter(into))]
@@ -31,6 +32,12 @@ impl<'a> CreateMergeRequestNoteAward<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestNoteAwardBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestNoteAward<'a> {
     fn method(&self) -> Method {
         Method::POST
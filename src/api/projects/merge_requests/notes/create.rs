@@ -12,7 +12,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new note on a merge request on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateMergeRequestNote<'a> {
     /// The project the merge request belongs to.
     #[builder(setter(into))]
@@ -37,6 +37,12 @@ impl<'a> CreateMergeRequestNote<'a> {
     }
 }
 
+impl<'a> CreateMergeRequestNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateMergeRequestNote<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Edit a merge request note on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct EditMergeRequestNote<'a> {
     /// The project to add the merge request to.
     #[builder(setter(into))]
@@ -32,6 +33,12 @@ impl<'a> EditMergeRequestNote<'a> {
     }
 }
 
+impl<'a> EditMergeRequestNoteBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for EditMergeRequestNote<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// List all pipelines attached to a merge request.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestPipelines<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestPipelines<'a> {
     }
 }
 
+impl<'a> MergeRequestPipelinesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestPipelines<'a> {
     fn method(&self) -> Method {
         Method::GET
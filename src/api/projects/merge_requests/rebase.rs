@@ -14,7 +14,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Rebase a merge request.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct RebaseMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -34,6 +34,12 @@ impl<'a> RebaseMergeRequest<'a> {
     }
 }
 
+impl<'a> RebaseMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for RebaseMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::PUT
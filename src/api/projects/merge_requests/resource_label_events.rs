@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for resource label events for a merge request.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequestResourceLabelEvents<'a> {
     /// The project to query for the merge request.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> MergeRequestResourceLabelEvents<'a> {
     }
 }
 
+impl<'a> MergeRequestResourceLabelEventsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequestResourceLabelEvents<'a> {
     fn method(&self) -> Method {
         Method::GET
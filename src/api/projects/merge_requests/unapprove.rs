@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Unapprove a merge request.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UnapproveMergeRequest<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -27,6 +27,12 @@ impl<'a> UnapproveMergeRequest<'a> {
     }
 }
 
+impl<'a> UnapproveMergeRequestBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for UnapproveMergeRequest<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -0,0 +1,126 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for a single diff version of a merge request.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct MergeRequestDiffVersion<'a> {
+    /// The project with the merge request.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the merge request.
+    merge_request: u64,
+    /// The ID of the diff version.
+    version: u64,
+}
+
+impl<'a> MergeRequestDiffVersion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> MergeRequestDiffVersionBuilder<'a> {
+        MergeRequestDiffVersionBuilder::default()
+    }
+}
+
+impl<'a> MergeRequestDiffVersionBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for MergeRequestDiffVersion<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/merge_requests/{}/versions/{}",
+            self.project, self.merge_request, self.version,
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::merge_requests::version::{
+        MergeRequestDiffVersion, MergeRequestDiffVersionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_merge_request_and_version_are_needed() {
+        let err = MergeRequestDiffVersion::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiffVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = MergeRequestDiffVersion::builder()
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiffVersionBuilderError, "project");
+    }
+
+    #[test]
+    fn merge_request_is_needed() {
+        let err = MergeRequestDiffVersion::builder()
+            .project(1)
+            .version(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            MergeRequestDiffVersionBuilderError,
+            "merge_request",
+        );
+    }
+
+    #[test]
+    fn version_is_needed() {
+        let err = MergeRequestDiffVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, MergeRequestDiffVersionBuilderError, "version");
+    }
+
+    #[test]
+    fn project_merge_request_and_version_are_sufficient() {
+        MergeRequestDiffVersion::builder()
+            .project(1)
+            .merge_request(1)
+            .version(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/merge_requests/1/versions/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = MergeRequestDiffVersion::builder()
+            .project("simple/project")
+            .merge_request(1)
+            .version(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -39,7 +39,7 @@ impl ParamValue<'static> for MergeTrainsScope {
 
 /// Get the list of merge trains for project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct MergeTrains<'a> {
     /// The project with the merge request.
     #[builder(setter(into))]
@@ -61,6 +61,12 @@ impl<'a> MergeTrains<'a> {
     }
 }
 
+impl<'a> MergeTrainsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeTrains<'a> {
     fn method(&self) -> Method {
         Method::GET
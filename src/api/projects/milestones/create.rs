@@ -13,7 +13,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new milestone on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProjectMilestone<'a> {
     /// The project to create a new milestone within.
     #[builder(setter(into))]
@@ -40,6 +40,12 @@ impl<'a> CreateProjectMilestone<'a> {
     }
 }
 
+impl<'a> CreateProjectMilestoneBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateProjectMilestone<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Cancel a pipeline.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CancelPipeline<'a> {
     /// The project to query for the pipeline.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> CancelPipeline<'a> {
     }
 }
 
+impl<'a> CancelPipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CancelPipeline<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -62,6 +62,7 @@ impl<'a> PipelineVariable<'a> {
 
 /// Create a new pipeline on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreatePipeline<'a> {
     /// The project to create the pipeline within.
     #[builder(setter(into))]
@@ -101,6 +102,10 @@ impl<'a> CreatePipelineBuilder<'a> {
             .extend(iter.map(Into::into));
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for CreatePipeline<'a> {
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Delete a pipeline.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct DeletePipeline<'a> {
     /// The project to delete the pipeline from.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> DeletePipeline<'a> {
     }
 }
 
+impl<'a> DeletePipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for DeletePipeline<'a> {
     fn method(&self) -> Method {
         Method::DELETE
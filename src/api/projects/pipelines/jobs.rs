@@ -14,6 +14,7 @@ use crate::api::projects::jobs::JobScope;
 
 /// Query for jobs within a pipeline.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PipelineJobs<'a> {
     /// The project to query for the pipeline.
     #[builder(setter(into))]
@@ -48,6 +49,10 @@ impl<'a> PipelineJobsBuilder<'a> {
         self.scopes.get_or_insert_with(HashSet::new).extend(scopes);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for PipelineJobs<'a> {
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a single pipeline on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Pipeline<'a> {
     /// The project to query for pipeline.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> Pipeline<'a> {
     }
 }
 
+impl<'a> PipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Pipeline<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -133,7 +133,7 @@ impl ParamValue<'static> for PipelineOrderBy {
 
 /// Query for pipelines within a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Pipelines<'a> {
     /// The project to query for pipelines.
     #[builder(setter(into))]
@@ -183,6 +183,12 @@ impl<'a> Pipelines<'a> {
     }
 }
 
+impl<'a> PipelinesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Pipelines<'a> {
     fn method(&self) -> Method {
         Method::GET
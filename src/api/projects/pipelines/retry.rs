@@ -13,6 +13,7 @@ use crate::api::endpoint_prelude::*;
 ///
 /// Note that this only restarts failed jobs.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct RetryPipeline<'a> {
     /// The project of the pipelines.
     #[builder(setter(into))]
@@ -28,6 +29,12 @@ impl<'a> RetryPipeline<'a> {
     }
 }
 
+impl<'a> RetryPipelineBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for RetryPipeline<'a> {
     fn method(&self) -> Method {
         Method::POST
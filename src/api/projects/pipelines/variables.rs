@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for the variables of a pipeline.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct PipelineVariables<'a> {
     /// The project of the pipelines.
     #[builder(setter(into))]
@@ -26,6 +27,12 @@ impl<'a> PipelineVariables<'a> {
     }
 }
 
+impl<'a> PipelineVariablesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for PipelineVariables<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific project on an instance.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Project<'a> {
     /// The project to get.
     #[builder(setter(into))]
@@ -35,6 +35,12 @@ impl<'a> Project<'a> {
     }
 }
 
+impl<'a> ProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Project<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -68,6 +74,16 @@ mod tests {
         crate::test::assert_missing_field!(err, ProjectBuilderError, "project");
     }
 
+    #[test]
+    fn project_dot_segments_are_rejected() {
+        let err = Project::builder().project("..").build().unwrap_err();
+        if let ProjectBuilderError::ValidationError(msg) = err {
+            assert_eq!(msg, "`project` may not be `..`");
+        } else {
+            panic!("unexpected error: {:?}", err);
+        }
+    }
+
     #[test]
     fn project_is_sufficient() {
         Project::builder().project(1).build().unwrap();
@@ -70,7 +70,7 @@ impl From<ProtectedAccessLevel> for ProtectedAccess {
 
 /// Protect a branch or set of branches on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectBranch<'a> {
     /// The project to protect a branch within.
     #[builder(setter(into))]
@@ -132,6 +132,10 @@ impl<'a> ProtectBranchBuilder<'a> {
             .insert(access);
         self
     }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
 }
 
 impl<'a> Endpoint for ProtectBranch<'a> {
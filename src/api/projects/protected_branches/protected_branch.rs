@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a protected branch of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedBranch<'a> {
     /// The project to query for the protected branch.
     #[builder(setter(into))]
@@ -28,6 +29,12 @@ impl<'a> ProtectedBranch<'a> {
     }
 }
 
+impl<'a> ProtectedBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProtectedBranch<'a> {
     fn method(&self) -> Method {
         Method::GET
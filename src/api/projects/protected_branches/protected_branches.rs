@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query protected branches of a project.
 #[derive(Debug, Clone, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectedBranches<'a> {
     /// The project to query for protected branches.
     #[builder(setter(into))]
@@ -31,6 +31,12 @@ impl<'a> ProtectedBranches<'a> {
     }
 }
 
+impl<'a> ProtectedBranchesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProtectedBranches<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Unprotect a branch in a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnprotectBranch<'a> {
     /// The project to unprotect a branch within.
     #[builder(setter(into))]
@@ -27,6 +28,12 @@ impl<'a> UnprotectBranch<'a> {
     }
 }
 
+impl<'a> UnprotectBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for UnprotectBranch<'a> {
     fn method(&self) -> Method {
         Method::DELETE
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Protect a tag or set of tags on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProtectTag<'a> {
     /// The project to protect a tag within.
     #[builder(setter(into))]
@@ -31,6 +31,12 @@ impl<'a> ProtectTag<'a> {
     }
 }
 
+impl<'a> ProtectTagBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProtectTag<'a> {
     fn method(&self) -> Method {
         Method::POST
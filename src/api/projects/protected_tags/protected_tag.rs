@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query a protected tag of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedTag<'a> {
     /// The project to query for the protected tag.
     #[builder(setter(into))]
@@ -28,6 +29,12 @@ impl<'a> ProtectedTag<'a> {
     }
 }
 
+impl<'a> ProtectedTagBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProtectedTag<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query protected tags of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProtectedTags<'a> {
     /// The project to query for protected tags.
     #[builder(setter(into))]
@@ -24,6 +25,12 @@ impl<'a> ProtectedTags<'a> {
     }
 }
 
+impl<'a> ProtectedTagsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProtectedTags<'a> {
     fn method(&self) -> Method {
         Method::GET
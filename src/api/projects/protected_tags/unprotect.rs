@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Unprotect a tag in a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct UnprotectTag<'a> {
     /// The project to unprotect a tag within.
     #[builder(setter(into))]
@@ -27,6 +28,12 @@ impl<'a> UnprotectTag<'a> {
     }
 }
 
+impl<'a> UnprotectTagBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for UnprotectTag<'a> {
     fn method(&self) -> Method {
         Method::DELETE
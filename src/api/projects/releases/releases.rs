@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query releases of a project.
 #[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct ProjectReleases<'a> {
     /// The project to query for releases.
     #[builder(setter(into))]
@@ -24,6 +25,12 @@ impl<'a> ProjectReleases<'a> {
     }
 }
 
+impl<'a> ProjectReleasesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProjectReleases<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Branch<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -27,6 +28,12 @@ impl<'a> Branch<'a> {
     }
 }
 
+impl<'a> BranchBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Branch<'a> {
     fn method(&self) -> Method {
         Method::GET
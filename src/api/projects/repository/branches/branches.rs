@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Branches<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -28,6 +28,12 @@ impl<'a> Branches<'a> {
     }
 }
 
+impl<'a> BranchesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Branches<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a branch on a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CreateBranch<'a> {
     /// The project to create a branch on.
     #[builder(setter(into))]
@@ -30,6 +31,12 @@ impl<'a> CreateBranch<'a> {
     }
 }
 
+impl<'a> CreateBranchBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateBranch<'a> {
     fn method(&self) -> Method {
         Method::POST
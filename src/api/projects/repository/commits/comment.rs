@@ -36,7 +36,7 @@ impl ParamValue<'static> for LineType {
 
 /// Post a comment on a specific commit in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CommentOnCommit<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -68,6 +68,12 @@ impl<'a> CommentOnCommit<'a> {
     }
 }
 
+impl<'a> CommentOnCommitBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CommentOnCommit<'a> {
     fn method(&self) -> Method {
         Method::POST
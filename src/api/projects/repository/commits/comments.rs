@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for comments on a specific commit in a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct CommitComments<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -27,6 +28,12 @@ impl<'a> CommitComments<'a> {
     }
 }
 
+impl<'a> CommitCommentsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CommitComments<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific commit in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Commit<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -32,6 +32,12 @@ impl<'a> Commit<'a> {
     }
 }
 
+impl<'a> CommitBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Commit<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -43,7 +43,7 @@ impl ParamValue<'static> for CommitsOrder {
 
 /// Query for commits in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Commits<'a> {
     /// The project to get commits from.
     #[builder(setter(into))]
@@ -84,6 +84,12 @@ impl<'a> Commits<'a> {
     }
 }
 
+impl<'a> CommitsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Commits<'a> {
     fn method(&self) -> Method {
         Method::GET
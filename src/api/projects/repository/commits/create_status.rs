@@ -45,7 +45,7 @@ impl ParamValue<'static> for CommitStatusState {
 
 /// Post a comment on a specific commit in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateCommitStatus<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -84,6 +84,12 @@ impl<'a> CreateCommitStatus<'a> {
     }
 }
 
+impl<'a> CreateCommitStatusBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateCommitStatus<'a> {
     fn method(&self) -> Method {
         Method::POST
@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Get a list of merge requests related to the specified commit.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct MergeRequests<'a> {
     /// The project to get commits from.
     #[builder(setter(into))]
@@ -30,6 +31,12 @@ impl<'a> MergeRequests<'a> {
     }
 }
 
+impl<'a> MergeRequestsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for MergeRequests<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for statuses on a specific commit in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CommitStatuses<'a> {
     /// The project to get a commit from.
     #[builder(setter(into))]
@@ -41,6 +41,12 @@ impl<'a> CommitStatuses<'a> {
     }
 }
 
+impl<'a> CommitStatusesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CommitStatuses<'a> {
     fn method(&self) -> Method {
         Method::GET
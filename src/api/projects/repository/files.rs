@@ -10,6 +10,7 @@
 
 mod create;
 mod delete;
+mod file;
 mod file_raw;
 mod update;
 
@@ -22,6 +23,10 @@ pub use self::delete::DeleteFile;
 pub use self::delete::DeleteFileBuilder;
 pub use self::delete::DeleteFileBuilderError;
 
+pub use self::file::File;
+pub use self::file::FileBuilder;
+pub use self::file::FileBuilderError;
+
 pub use self::file_raw::FileRaw;
 pub use self::file_raw::FileRawBuilder;
 pub use self::file_raw::FileRawBuilderError;
@@ -69,7 +69,7 @@ impl ParamValue<'static> for Encoding {
 
 /// Create a new file in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateFile<'a> {
     /// The project to create a file within.
     #[builder(setter(into))]
@@ -115,6 +115,13 @@ impl<'a> CreateFile<'a> {
     }
 }
 
+impl<'a> CreateFileBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        common::validate_path_segment("file_path", self.file_path.as_deref())
+    }
+}
+
 const SAFE_ENCODING: Encoding = Encoding::Base64;
 
 impl<'a> Endpoint for CreateFile<'a> {
@@ -7,7 +7,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a new file in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct DeleteFile<'a> {
     /// The project to create a file within.
     #[builder(setter(into))]
@@ -42,6 +42,13 @@ impl<'a> DeleteFile<'a> {
     }
 }
 
+impl<'a> DeleteFileBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        common::validate_path_segment("file_path", self.file_path.as_deref())
+    }
+}
+
 impl<'a> Endpoint for DeleteFile<'a> {
     fn method(&self) -> Method {
         Method::DELETE
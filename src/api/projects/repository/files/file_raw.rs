@@ -14,6 +14,7 @@ use crate::api::endpoint_prelude::*;
 /// Note: This endpoint returns raw data, so [`crate::api::raw`] is recommended to avoid the normal
 /// JSON parsing present in the typical endpoint handling.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct FileRaw<'a> {
     /// The project to get a file within.
     #[builder(setter(into))]
@@ -35,6 +36,13 @@ impl<'a> FileRaw<'a> {
     }
 }
 
+impl<'a> FileRawBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        common::validate_path_segment("file_path", self.file_path.as_deref())
+    }
+}
+
 impl<'a> Endpoint for FileRaw<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -130,4 +138,26 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn endpoint_special_characters() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::GET)
+            .endpoint(
+                "projects/gr%C3%BCppe%2Fproject/repository/files/\
+                 dir%2F.%2Fsub%20dir%2Ffile%20%231%3F.txt/raw",
+            )
+            .add_query_params(&[("ref", "branch")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FileRaw::builder()
+            .project("grüppe/project")
+            .file_path("dir/./sub dir/file #1?.txt")
+            .ref_("branch")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
 }
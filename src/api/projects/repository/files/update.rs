@@ -15,7 +15,7 @@ use crate::api::projects::repository::files::Encoding;
 
 /// Update a file in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateFile<'a> {
     /// The project to update a file within.
     #[builder(setter(into))]
@@ -61,6 +61,13 @@ impl<'a> UpdateFile<'a> {
     }
 }
 
+impl<'a> UpdateFileBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())?;
+        common::validate_path_segment("file_path", self.file_path.as_deref())
+    }
+}
+
 const SAFE_ENCODING: Encoding = Encoding::Base64;
 
 impl<'a> Endpoint for UpdateFile<'a> {
@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Create a tag on a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateTag<'a> {
     /// The project to create a tag on.
     #[builder(setter(into))]
@@ -34,6 +34,12 @@ impl<'a> CreateTag<'a> {
     }
 }
 
+impl<'a> CreateTagBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateTag<'a> {
     fn method(&self) -> Method {
         Method::POST
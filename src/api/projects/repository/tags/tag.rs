@@ -11,6 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct Tag<'a> {
     /// The project to get a atg from.
     #[builder(setter(into))]
@@ -27,6 +28,12 @@ impl<'a> Tag<'a> {
     }
 }
 
+impl<'a> TagBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Tag<'a> {
     fn method(&self) -> Method {
         Method::GET
@@ -42,7 +42,7 @@ impl ParamValue<'static> for TagsOrderBy {
 
 /// Query for a specific branch in a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Tags<'a> {
     /// The project to get a branch from.
     #[builder(setter(into))]
@@ -67,6 +67,12 @@ impl<'a> Tags<'a> {
     }
 }
 
+impl<'a> TagsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Tags<'a> {
     fn method(&self) -> Method {
         Method::GET
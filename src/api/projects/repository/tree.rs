@@ -11,7 +11,7 @@ use crate::api::endpoint_prelude::*;
 
 /// Get the tree of a given path.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct Tree<'a> {
     /// The ID or URL-encoded path of the project.
     #[builder(setter(into))]
@@ -34,6 +34,12 @@ impl<'a> Tree<'a> {
     }
 }
 
+impl<'a> TreeBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for Tree<'a> {
     fn method(&self) -> Method {
         Method::GET
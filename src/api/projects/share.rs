@@ -0,0 +1,164 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId};
+use crate::api::endpoint_prelude::*;
+
+/// Share a project with a group.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ShareProject<'a> {
+    /// The project to share.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the group to share with.
+    group: u64,
+    /// The access level to grant to the group.
+    group_access: AccessLevel,
+
+    /// When the share expires.
+    #[builder(default)]
+    expires_at: Option<NaiveDate>,
+}
+
+impl<'a> ShareProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ShareProjectBuilder<'a> {
+        ShareProjectBuilder::default()
+    }
+}
+
+impl<'a> ShareProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ShareProject<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/share", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("group_id", self.group)
+            .push("group_access", self.group_access.as_u64())
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use http::Method;
+
+    use crate::api::common::AccessLevel;
+    use crate::api::projects::{ShareProject, ShareProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_group_and_group_access_are_needed() {
+        let err = ShareProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ShareProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ShareProject::builder()
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = ShareProject::builder()
+            .project(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareProjectBuilderError, "group");
+    }
+
+    #[test]
+    fn group_access_is_needed() {
+        let err = ShareProject::builder()
+            .project(1)
+            .group(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, ShareProjectBuilderError, "group_access");
+    }
+
+    #[test]
+    fn project_group_and_group_access_are_sufficient() {
+        ShareProject::builder()
+            .project(1)
+            .group(1)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/share")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("group_id=2", "&group_access=30"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ShareProject::builder()
+            .project("simple/project")
+            .group(2)
+            .group_access(AccessLevel::Developer)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/share")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "group_id=2",
+                "&group_access=30",
+                "&expires_at=2020-01-01",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ShareProject::builder()
+            .project("simple/project")
+            .group(2)
+            .group_access(AccessLevel::Developer)
+            .expires_at(NaiveDate::from_ymd(2020, 1, 1))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
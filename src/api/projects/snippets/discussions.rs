@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project snippet discussion API endpoints.
+//!
+//! These endpoints are used for querying project snippet discussions.
+
+mod create;
+mod create_note;
+mod discussions;
+
+pub use self::create::CreateSnippetDiscussion;
+pub use self::create::CreateSnippetDiscussionBuilder;
+pub use self::create::CreateSnippetDiscussionBuilderError;
+
+pub use self::create_note::CreateSnippetDiscussionNote;
+pub use self::create_note::CreateSnippetDiscussionNoteBuilder;
+pub use self::create_note::CreateSnippetDiscussionNoteBuilderError;
+
+pub use self::discussions::SnippetDiscussions;
+pub use self::discussions::SnippetDiscussionsBuilder;
+pub use self::discussions::SnippetDiscussionsBuilderError;
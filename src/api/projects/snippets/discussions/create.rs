@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create a new discussion on a snippet on a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CreateSnippetDiscussion<'a> {
+    /// The project of the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The snippet to start a new discussion on.
+    snippet: u64,
+    /// The content of the discussion.
+    #[builder(setter(into))]
+    body: Cow<'a, str>,
+
+    /// When the discussion was created.
+    ///
+    /// Requires administrator or owner permissions.
+    #[builder(default)]
+    created_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateSnippetDiscussion<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateSnippetDiscussionBuilder<'a> {
+        CreateSnippetDiscussionBuilder::default()
+    }
+}
+
+impl<'a> CreateSnippetDiscussionBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateSnippetDiscussion<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/discussions",
+            self.project, self.snippet,
+        )
+        .into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("body", self.body.as_ref())
+            .push_opt("created_at", self.created_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::projects::snippets::discussions::{
+        CreateSnippetDiscussion, CreateSnippetDiscussionBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_snippet_and_body_are_necessary() {
+        let err = CreateSnippetDiscussion::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSnippetDiscussionBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = CreateSnippetDiscussion::builder()
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSnippetDiscussionBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = CreateSnippetDiscussion::builder()
+            .project(1)
+            .body("body")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSnippetDiscussionBuilderError, "snippet");
+    }
+
+    #[test]
+    fn body_is_necessary() {
+        let err = CreateSnippetDiscussion::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateSnippetDiscussionBuilderError, "body");
+    }
+
+    #[test]
+    fn project_snippet_and_body_are_sufficient() {
+        CreateSnippetDiscussion::builder()
+            .project(1)
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/snippets/1/discussions")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("body=body")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateSnippetDiscussion::builder()
+            .project("simple/project")
+            .snippet(1)
+            .body("body")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_created_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/snippets/1/discussions")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("body=body", "&created_at=2020-01-01T00%3A00%3A00Z"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateSnippetDiscussion::builder()
+            .project("simple/project")
+            .snippet(1)
+            .body("body")
+            .created_at(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for discussions on a snippet within a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct SnippetDiscussions<'a> {
+    /// The project to query for the snippet.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the snippet.
+    snippet: u64,
+}
+
+impl<'a> SnippetDiscussions<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SnippetDiscussionsBuilder<'a> {
+        SnippetDiscussionsBuilder::default()
+    }
+}
+
+impl<'a> SnippetDiscussionsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for SnippetDiscussions<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/snippets/{}/discussions",
+            self.project, self.snippet,
+        )
+        .into()
+    }
+}
+
+impl<'a> Pageable for SnippetDiscussions<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::snippets::discussions::{
+        SnippetDiscussions, SnippetDiscussionsBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_snippet_are_necessary() {
+        let err = SnippetDiscussions::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_necessary() {
+        let err = SnippetDiscussions::builder()
+            .snippet(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetDiscussionsBuilderError, "project");
+    }
+
+    #[test]
+    fn snippet_is_necessary() {
+        let err = SnippetDiscussions::builder()
+            .project(1)
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, SnippetDiscussionsBuilderError, "snippet");
+    }
+
+    #[test]
+    fn project_and_snippet_are_sufficient() {
+        SnippetDiscussions::builder()
+            .project(1)
+            .snippet(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/snippets/1/discussions")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SnippetDiscussions::builder()
+            .project("simple/project")
+            .snippet(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
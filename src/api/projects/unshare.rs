@@ -0,0 +1,97 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Stop sharing a project with a group.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UnshareProject<'a> {
+    /// The project which is shared.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The ID of the group the project is shared with.
+    group: u64,
+}
+
+impl<'a> UnshareProject<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UnshareProjectBuilder<'a> {
+        UnshareProjectBuilder::default()
+    }
+}
+
+impl<'a> UnshareProjectBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UnshareProject<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/share/{}", self.project, self.group).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{UnshareProject, UnshareProjectBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_and_group_are_needed() {
+        let err = UnshareProject::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = UnshareProject::builder().group(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareProjectBuilderError, "project");
+    }
+
+    #[test]
+    fn group_is_needed() {
+        let err = UnshareProject::builder().project(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, UnshareProjectBuilderError, "group");
+    }
+
+    #[test]
+    fn project_and_group_are_sufficient() {
+        UnshareProject::builder()
+            .project(1)
+            .group(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("projects/simple%2Fproject/share/2")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UnshareProject::builder()
+            .project("simple/project")
+            .group(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Upload a file to a project.
+///
+/// The response contains the URL and Markdown snippet to use in order to reference the file
+/// from issues, merge requests, and comments.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UploadFile<'a> {
+    /// The project to upload the file to.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+    /// The name of the file.
+    #[builder(setter(into))]
+    filename: Cow<'a, str>,
+    /// The contents of the file.
+    #[builder(setter(into))]
+    contents: Cow<'a, [u8]>,
+}
+
+impl<'a> UploadFile<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UploadFileBuilder<'a> {
+        UploadFileBuilder::default()
+    }
+}
+
+impl<'a> UploadFileBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UploadFile<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/uploads", self.project).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        FormParams::default().into_multipart_body("file", &self.filename, &self.contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::projects::{UploadFile, UploadFileBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_filename_and_contents_are_needed() {
+        let err = UploadFile::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UploadFileBuilderError, "project");
+    }
+
+    #[test]
+    fn filename_is_needed() {
+        let err = UploadFile::builder()
+            .project(1)
+            .contents(&b"contents"[..])
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadFileBuilderError, "filename");
+    }
+
+    #[test]
+    fn contents_are_needed() {
+        let err = UploadFile::builder()
+            .project(1)
+            .filename("file.txt")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, UploadFileBuilderError, "contents");
+    }
+
+    #[test]
+    fn project_filename_and_contents_are_sufficient() {
+        UploadFile::builder()
+            .project(1)
+            .filename("file.txt")
+            .contents(&b"contents"[..])
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("projects/simple%2Fproject/uploads")
+            .content_type(
+                "multipart/form-data; boundary=------------------------gitlab-rs-multipart-boundary",
+            )
+            .body_str(concat!(
+                "--------------------------gitlab-rs-multipart-boundary\r\n",
+                "Content-Disposition: form-data; name=\"file\"; filename=\"file.txt\"\r\n",
+                "Content-Type: application/octet-stream\r\n",
+                "\r\n",
+                "contents\r\n",
+                "--------------------------gitlab-rs-multipart-boundary--\r\n",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UploadFile::builder()
+            .project("simple/project")
+            .filename("file.txt")
+            .contents(&b"contents"[..])
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
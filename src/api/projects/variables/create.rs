@@ -42,7 +42,7 @@ impl ParamValue<'static> for ProjectVariableType {
 
 /// Add a variable to a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct CreateProjectVariable<'a> {
     /// The project to add the variable to.
     #[builder(setter(into))]
@@ -74,6 +74,12 @@ impl<'a> CreateProjectVariable<'a> {
     }
 }
 
+impl<'a> CreateProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for CreateProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::POST
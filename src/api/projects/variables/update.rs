@@ -12,7 +12,7 @@ use crate::api::projects::variables::ProjectVariableType;
 
 /// Edit a variable of a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct UpdateProjectVariable<'a> {
     /// The project to edit the variable on.
     #[builder(setter(into))]
@@ -44,6 +44,12 @@ impl<'a> UpdateProjectVariable<'a> {
     }
 }
 
+impl<'a> UpdateProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for UpdateProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::PUT
@@ -32,7 +32,7 @@ impl<'a> ProjectVariableFilter<'a> {
 
 /// Get the variable from a project.
 #[derive(Debug, Builder)]
-#[builder(setter(strip_option))]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
 pub struct ProjectVariable<'a> {
     /// The project to get the variable from.
     #[builder(setter(into))]
@@ -52,6 +52,12 @@ impl<'a> ProjectVariable<'a> {
     }
 }
 
+impl<'a> ProjectVariableBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
 impl<'a> Endpoint for ProjectVariable<'a> {
     fn method(&self) -> Method {
         Method::GET
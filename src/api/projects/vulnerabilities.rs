@@ -0,0 +1,24 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Project vulnerability API endpoints.
+//!
+//! These endpoints are used for querying the vulnerabilities and vulnerability findings of projects.
+
+mod findings;
+mod vulnerabilities;
+
+pub use self::findings::ProjectVulnerabilityFindings;
+pub use self::findings::ProjectVulnerabilityFindingsBuilder;
+pub use self::findings::ProjectVulnerabilityFindingsBuilderError;
+pub use self::findings::VulnerabilityConfidence;
+pub use self::findings::VulnerabilityFindingScope;
+pub use self::findings::VulnerabilityReportType;
+pub use self::findings::VulnerabilitySeverity;
+
+pub use self::vulnerabilities::ProjectVulnerabilities;
+pub use self::vulnerabilities::ProjectVulnerabilitiesBuilder;
+pub use self::vulnerabilities::ProjectVulnerabilitiesBuilderError;
@@ -0,0 +1,555 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Report types which may be used to filter vulnerability findings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum VulnerabilityReportType {
+    /// Static application security testing.
+    Sast,
+    /// Dynamic application security testing.
+    Dast,
+    /// Dependency scanning.
+    DependencyScanning,
+    /// Container scanning.
+    ContainerScanning,
+    /// Secret detection.
+    SecretDetection,
+    /// Coverage-guided fuzz testing.
+    CoverageFuzzing,
+    /// API fuzz testing.
+    ApiFuzzing,
+    /// Cluster image scanning.
+    ClusterImageScanning,
+}
+
+impl VulnerabilityReportType {
+    fn as_str(self) -> &'static str {
+        match self {
+            VulnerabilityReportType::Sast => "sast",
+            VulnerabilityReportType::Dast => "dast",
+            VulnerabilityReportType::DependencyScanning => "dependency_scanning",
+            VulnerabilityReportType::ContainerScanning => "container_scanning",
+            VulnerabilityReportType::SecretDetection => "secret_detection",
+            VulnerabilityReportType::CoverageFuzzing => "coverage_fuzzing",
+            VulnerabilityReportType::ApiFuzzing => "api_fuzzing",
+            VulnerabilityReportType::ClusterImageScanning => "cluster_image_scanning",
+        }
+    }
+}
+
+impl ParamValue<'static> for VulnerabilityReportType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Severities which may be used to filter vulnerability findings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum VulnerabilitySeverity {
+    /// Informational findings.
+    Info,
+    /// Findings of unknown severity.
+    Unknown,
+    /// Low severity findings.
+    Low,
+    /// Medium severity findings.
+    Medium,
+    /// High severity findings.
+    High,
+    /// Critical severity findings.
+    Critical,
+}
+
+impl VulnerabilitySeverity {
+    fn as_str(self) -> &'static str {
+        match self {
+            VulnerabilitySeverity::Info => "info",
+            VulnerabilitySeverity::Unknown => "unknown",
+            VulnerabilitySeverity::Low => "low",
+            VulnerabilitySeverity::Medium => "medium",
+            VulnerabilitySeverity::High => "high",
+            VulnerabilitySeverity::Critical => "critical",
+        }
+    }
+}
+
+impl ParamValue<'static> for VulnerabilitySeverity {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Confidence levels which may be used to filter vulnerability findings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum VulnerabilityConfidence {
+    /// The confidence is not defined.
+    Undefined,
+    /// The finding should be ignored.
+    Ignore,
+    /// The confidence is unknown.
+    Unknown,
+    /// Experimental findings.
+    Experimental,
+    /// Low confidence findings.
+    Low,
+    /// Medium confidence findings.
+    Medium,
+    /// High confidence findings.
+    High,
+    /// Confirmed findings.
+    Confirmed,
+}
+
+impl VulnerabilityConfidence {
+    fn as_str(self) -> &'static str {
+        match self {
+            VulnerabilityConfidence::Undefined => "undefined",
+            VulnerabilityConfidence::Ignore => "ignore",
+            VulnerabilityConfidence::Unknown => "unknown",
+            VulnerabilityConfidence::Experimental => "experimental",
+            VulnerabilityConfidence::Low => "low",
+            VulnerabilityConfidence::Medium => "medium",
+            VulnerabilityConfidence::High => "high",
+            VulnerabilityConfidence::Confirmed => "confirmed",
+        }
+    }
+}
+
+impl ParamValue<'static> for VulnerabilityConfidence {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// The scope of vulnerability findings to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VulnerabilityFindingScope {
+    /// Return all findings.
+    All,
+    /// Return only findings which have been dismissed.
+    Dismissed,
+}
+
+impl VulnerabilityFindingScope {
+    fn as_str(self) -> &'static str {
+        match self {
+            VulnerabilityFindingScope::All => "all",
+            VulnerabilityFindingScope::Dismissed => "dismissed",
+        }
+    }
+}
+
+impl ParamValue<'static> for VulnerabilityFindingScope {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for vulnerability findings of a project.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct ProjectVulnerabilityFindings<'a> {
+    /// The project to query for vulnerability findings.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+
+    /// Filter findings by report type.
+    #[builder(setter(name = "_report_types"), default, private)]
+    report_types: BTreeSet<VulnerabilityReportType>,
+    /// Filter findings by whether they have been dismissed.
+    #[builder(default)]
+    scope: Option<VulnerabilityFindingScope>,
+    /// Filter findings by severity.
+    #[builder(setter(name = "_severities"), default, private)]
+    severities: BTreeSet<VulnerabilitySeverity>,
+    /// Filter findings by confidence.
+    #[builder(setter(name = "_confidences"), default, private)]
+    confidences: BTreeSet<VulnerabilityConfidence>,
+    /// Filter findings by the scanner which reported them.
+    #[builder(setter(name = "_scanners"), default, private)]
+    scanners: BTreeSet<Cow<'a, str>>,
+    /// Return findings reported by a specific pipeline.
+    ///
+    /// By default, findings from the latest pipeline on the default branch are returned.
+    #[builder(default)]
+    pipeline_id: Option<u64>,
+}
+
+impl<'a> ProjectVulnerabilityFindings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectVulnerabilityFindingsBuilder<'a> {
+        ProjectVulnerabilityFindingsBuilder::default()
+    }
+}
+
+impl<'a> ProjectVulnerabilityFindingsBuilder<'a> {
+    /// Filter findings by a report type.
+    pub fn report_type(&mut self, report_type: VulnerabilityReportType) -> &mut Self {
+        self.report_types
+            .get_or_insert_with(BTreeSet::new)
+            .insert(report_type);
+        self
+    }
+
+    /// Filter findings by a set of report types.
+    pub fn report_types<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = VulnerabilityReportType>,
+    {
+        self.report_types
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+
+    /// Filter findings by a severity.
+    pub fn severity(&mut self, severity: VulnerabilitySeverity) -> &mut Self {
+        self.severities
+            .get_or_insert_with(BTreeSet::new)
+            .insert(severity);
+        self
+    }
+
+    /// Filter findings by a set of severities.
+    pub fn severities<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = VulnerabilitySeverity>,
+    {
+        self.severities
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+
+    /// Filter findings by a confidence level.
+    pub fn confidence(&mut self, confidence: VulnerabilityConfidence) -> &mut Self {
+        self.confidences
+            .get_or_insert_with(BTreeSet::new)
+            .insert(confidence);
+        self
+    }
+
+    /// Filter findings by a set of confidence levels.
+    pub fn confidences<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = VulnerabilityConfidence>,
+    {
+        self.confidences
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter);
+        self
+    }
+
+    /// Filter findings by a scanner.
+    pub fn scanner<S>(&mut self, scanner: S) -> &mut Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.scanners
+            .get_or_insert_with(BTreeSet::new)
+            .insert(scanner.into());
+        self
+    }
+
+    /// Filter findings by a set of scanners.
+    pub fn scanners<I, S>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        self.scanners
+            .get_or_insert_with(BTreeSet::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ProjectVulnerabilityFindings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/vulnerability_findings", self.project).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .extend(
+                self.report_types
+                    .iter()
+                    .map(|&value| ("report_type[]", value)),
+            )
+            .push_opt("scope", self.scope)
+            .extend(self.severities.iter().map(|&value| ("severity[]", value)))
+            .extend(
+                self.confidences
+                    .iter()
+                    .map(|&value| ("confidence[]", value)),
+            )
+            .extend(self.scanners.iter().map(|value| ("scanner[]", value)))
+            .push_opt("pipeline_id", self.pipeline_id);
+
+        params
+    }
+}
+
+impl<'a> Pageable for ProjectVulnerabilityFindings<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::vulnerabilities::{
+        ProjectVulnerabilityFindings, ProjectVulnerabilityFindingsBuilderError,
+        VulnerabilityConfidence, VulnerabilityFindingScope, VulnerabilityReportType,
+        VulnerabilitySeverity,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn vulnerability_report_type_as_str() {
+        let items = &[
+            (VulnerabilityReportType::Sast, "sast"),
+            (VulnerabilityReportType::Dast, "dast"),
+            (
+                VulnerabilityReportType::DependencyScanning,
+                "dependency_scanning",
+            ),
+            (
+                VulnerabilityReportType::ContainerScanning,
+                "container_scanning",
+            ),
+            (VulnerabilityReportType::SecretDetection, "secret_detection"),
+            (VulnerabilityReportType::CoverageFuzzing, "coverage_fuzzing"),
+            (VulnerabilityReportType::ApiFuzzing, "api_fuzzing"),
+            (
+                VulnerabilityReportType::ClusterImageScanning,
+                "cluster_image_scanning",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn vulnerability_severity_as_str() {
+        let items = &[
+            (VulnerabilitySeverity::Info, "info"),
+            (VulnerabilitySeverity::Unknown, "unknown"),
+            (VulnerabilitySeverity::Low, "low"),
+            (VulnerabilitySeverity::Medium, "medium"),
+            (VulnerabilitySeverity::High, "high"),
+            (VulnerabilitySeverity::Critical, "critical"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn vulnerability_confidence_as_str() {
+        let items = &[
+            (VulnerabilityConfidence::Undefined, "undefined"),
+            (VulnerabilityConfidence::Ignore, "ignore"),
+            (VulnerabilityConfidence::Unknown, "unknown"),
+            (VulnerabilityConfidence::Experimental, "experimental"),
+            (VulnerabilityConfidence::Low, "low"),
+            (VulnerabilityConfidence::Medium, "medium"),
+            (VulnerabilityConfidence::High, "high"),
+            (VulnerabilityConfidence::Confirmed, "confirmed"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn vulnerability_finding_scope_as_str() {
+        let items = &[
+            (VulnerabilityFindingScope::All, "all"),
+            (VulnerabilityFindingScope::Dismissed, "dismissed"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectVulnerabilityFindings::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(
+            err,
+            ProjectVulnerabilityFindingsBuilderError,
+            "project",
+        );
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectVulnerabilityFindings::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_report_types() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[
+                ("report_type[]", "sast"),
+                ("report_type[]", "secret_detection"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .report_type(VulnerabilityReportType::Sast)
+            .report_types(
+                [
+                    VulnerabilityReportType::Sast,
+                    VulnerabilityReportType::SecretDetection,
+                ]
+                .iter()
+                .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scope() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[("scope", "dismissed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .scope(VulnerabilityFindingScope::Dismissed)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_severities() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[("severity[]", "high"), ("severity[]", "critical")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .severity(VulnerabilitySeverity::Critical)
+            .severities(
+                [VulnerabilitySeverity::High, VulnerabilitySeverity::Critical]
+                    .iter()
+                    .copied(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_confidences() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[("confidence[]", "high"), ("confidence[]", "confirmed")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .confidence(VulnerabilityConfidence::High)
+            .confidences([VulnerabilityConfidence::Confirmed].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_scanners() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[("scanner[]", "bandit"), ("scanner[]", "semgrep")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .scanner("semgrep")
+            .scanners(["bandit", "semgrep"].iter().copied())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_pipeline_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerability_findings")
+            .add_query_params(&[("pipeline_id", "1")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilityFindings::builder()
+            .project("simple/project")
+            .pipeline_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for vulnerabilities of a project.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct ProjectVulnerabilities<'a> {
+    /// The project to query for vulnerabilities.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> ProjectVulnerabilities<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ProjectVulnerabilitiesBuilder<'a> {
+        ProjectVulnerabilitiesBuilder::default()
+    }
+}
+
+impl<'a> ProjectVulnerabilitiesBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for ProjectVulnerabilities<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/vulnerabilities", self.project).into()
+    }
+}
+
+impl<'a> Pageable for ProjectVulnerabilities<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::projects::vulnerabilities::{
+        ProjectVulnerabilities, ProjectVulnerabilitiesBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = ProjectVulnerabilities::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ProjectVulnerabilitiesBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        ProjectVulnerabilities::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("projects/simple%2Fproject/vulnerabilities")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ProjectVulnerabilities::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
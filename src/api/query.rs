@@ -26,7 +26,8 @@ where
 }
 
 /// A trait which represents an asynchronous query which may be made to a GitLab client.
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait AsyncQuery<T, C>
 where
     C: AsyncClient,
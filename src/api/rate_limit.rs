@@ -0,0 +1,316 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Rate limit handling
+//!
+//! GitLab reports the state of the rate limit of a client through the `RateLimit-*` headers and
+//! rejects requests exceeding it with a `429 Too Many Requests` status. This module provides a
+//! parser for these headers and a policy which clients may use to wait and retry requests which
+//! have been rate limited.
+
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+use derive_builder::Builder;
+use http::{HeaderMap, Response, StatusCode};
+
+const RATE_LIMIT_LIMIT: &str = "ratelimit-limit";
+const RATE_LIMIT_REMAINING: &str = "ratelimit-remaining";
+const RATE_LIMIT_RESET: &str = "ratelimit-reset";
+
+/// The state of the rate limit as reported by a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimit {
+    /// The number of requests allowed within the current period.
+    pub limit: Option<u64>,
+    /// The number of requests remaining within the current period.
+    pub remaining: Option<u64>,
+    /// When the current period ends.
+    pub reset: Option<DateTime<Utc>>,
+    /// How long the server asked to wait before making another request.
+    pub retry_after: Option<Duration>,
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    header_str(headers, name).and_then(|value| value.trim().parse().ok())
+}
+
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse() {
+        Some(Duration::from_secs(secs))
+    } else {
+        // The header may also be an HTTP date.
+        DateTime::parse_from_rfc2822(value)
+            .ok()
+            .map(|date| duration_until(date.with_timezone(&Utc), now))
+    }
+}
+
+fn duration_until(when: DateTime<Utc>, now: DateTime<Utc>) -> Duration {
+    (when - now).to_std().unwrap_or_default()
+}
+
+impl RateLimit {
+    /// Extract the rate limit state from a set of response headers.
+    ///
+    /// Missing or malformed headers are ignored.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self::from_headers_at(headers, Utc::now())
+    }
+
+    fn from_headers_at(headers: &HeaderMap, now: DateTime<Utc>) -> Self {
+        let reset = header_u64(headers, RATE_LIMIT_RESET)
+            .and_then(|secs| Utc.timestamp_opt(secs as i64, 0).single());
+        let retry_after = header_str(headers, http::header::RETRY_AFTER.as_str())
+            .and_then(|value| parse_retry_after(value, now));
+
+        Self {
+            limit: header_u64(headers, RATE_LIMIT_LIMIT),
+            remaining: header_u64(headers, RATE_LIMIT_REMAINING),
+            reset,
+            retry_after,
+        }
+    }
+
+    fn wait_time(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.retry_after
+            .or_else(|| self.reset.map(|reset| duration_until(reset, now)))
+    }
+}
+
+/// How to handle requests which have been rejected due to the rate limit.
+///
+/// Requests rejected with a `429 Too Many Requests` status are retried after waiting for the time
+/// indicated by the `Retry-After` or `RateLimit-Reset` headers.
+#[derive(Debug, Clone, Builder)]
+pub struct RateLimitPolicy {
+    /// The maximum number of times to retry a request.
+    ///
+    /// Defaults to `3`.
+    #[builder(default = "3")]
+    retries: usize,
+    /// The longest time to wait before retrying a request.
+    ///
+    /// If the server asks to wait any longer, the rate limited response is returned instead.
+    ///
+    /// Defaults to 60 seconds.
+    #[builder(default = "Duration::from_secs(60)")]
+    max_wait: Duration,
+    /// How long to wait if the server does not indicate when to retry.
+    ///
+    /// Defaults to 1 second.
+    #[builder(default = "Duration::from_secs(1)")]
+    default_wait: Duration,
+}
+
+impl RateLimitPolicy {
+    /// Create a builder for a rate limit policy.
+    pub fn builder() -> RateLimitPolicyBuilder {
+        RateLimitPolicyBuilder::default()
+    }
+
+    /// How long to wait before retrying a request which received the given response.
+    ///
+    /// Returns `None` if the request should not be retried.
+    pub(crate) fn wait_for<B>(&self, retries: usize, rsp: &Response<B>) -> Option<Duration> {
+        self.wait_for_at(retries, rsp, Utc::now())
+    }
+
+    fn wait_for_at<B>(
+        &self,
+        retries: usize,
+        rsp: &Response<B>,
+        now: DateTime<Utc>,
+    ) -> Option<Duration> {
+        if rsp.status() != StatusCode::TOO_MANY_REQUESTS || retries >= self.retries {
+            return None;
+        }
+
+        let wait = RateLimit::from_headers_at(rsp.headers(), now)
+            .wait_time(now)
+            .unwrap_or(self.default_wait);
+
+        if wait > self.max_wait {
+            None
+        } else {
+            Some(wait)
+        }
+    }
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use chrono::{TimeZone, Utc};
+    use http::{HeaderMap, Response, StatusCode};
+
+    use crate::api::rate_limit::{RateLimit, RateLimitPolicy};
+
+    fn response(status: StatusCode, headers: &[(&str, &str)]) -> Response<Bytes> {
+        let mut builder = Response::builder().status(status);
+        for (key, value) in headers {
+            builder = builder.header(*key, *value);
+        }
+        builder.body(Bytes::new()).unwrap()
+    }
+
+    #[test]
+    fn rate_limit_from_headers() {
+        let now = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let rsp = response(
+            StatusCode::OK,
+            &[
+                ("RateLimit-Limit", "600"),
+                ("RateLimit-Remaining", "599"),
+                ("RateLimit-Reset", "1600000030"),
+            ],
+        );
+
+        let rate_limit = RateLimit::from_headers_at(rsp.headers(), now);
+        assert_eq!(rate_limit.limit, Some(600));
+        assert_eq!(rate_limit.remaining, Some(599));
+        assert_eq!(
+            rate_limit.reset,
+            Some(Utc.timestamp_opt(1_600_000_030, 0).unwrap()),
+        );
+        assert_eq!(rate_limit.retry_after, None);
+        assert_eq!(rate_limit.wait_time(now), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn rate_limit_from_headers_empty() {
+        let rate_limit = RateLimit::from_headers(&HeaderMap::new());
+        assert_eq!(rate_limit, RateLimit::default());
+    }
+
+    #[test]
+    fn rate_limit_from_headers_malformed() {
+        let rsp = response(
+            StatusCode::OK,
+            &[
+                ("RateLimit-Limit", "lots"),
+                ("RateLimit-Remaining", "-1"),
+                ("Retry-After", "soon"),
+            ],
+        );
+
+        let rate_limit = RateLimit::from_headers(rsp.headers());
+        assert_eq!(rate_limit, RateLimit::default());
+    }
+
+    #[test]
+    fn rate_limit_retry_after_seconds() {
+        let now = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let rsp = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &[("Retry-After", "5"), ("RateLimit-Reset", "1600000030")],
+        );
+
+        let rate_limit = RateLimit::from_headers_at(rsp.headers(), now);
+        assert_eq!(rate_limit.retry_after, Some(Duration::from_secs(5)));
+        // `Retry-After` takes precedence over `RateLimit-Reset`.
+        assert_eq!(rate_limit.wait_time(now), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn rate_limit_retry_after_date() {
+        let now = Utc.timestamp_opt(1_445_412_470, 0).unwrap();
+        let rsp = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &[("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT")],
+        );
+
+        let rate_limit = RateLimit::from_headers_at(rsp.headers(), now);
+        assert_eq!(rate_limit.retry_after, Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn rate_limit_reset_in_the_past() {
+        let now = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let rsp = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &[("RateLimit-Reset", "1599999990")],
+        );
+
+        let rate_limit = RateLimit::from_headers_at(rsp.headers(), now);
+        assert_eq!(rate_limit.wait_time(now), Some(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn policy_defaults() {
+        let policy = RateLimitPolicy::default();
+        assert_eq!(policy.retries, 3);
+        assert_eq!(policy.max_wait, Duration::from_secs(60));
+        assert_eq!(policy.default_wait, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn policy_ignores_other_statuses() {
+        let policy = RateLimitPolicy::default();
+        let rsp = response(StatusCode::SERVICE_UNAVAILABLE, &[("Retry-After", "5")]);
+
+        assert_eq!(policy.wait_for(0, &rsp), None);
+    }
+
+    #[test]
+    fn policy_uses_headers() {
+        let now = Utc.timestamp_opt(1_600_000_000, 0).unwrap();
+        let policy = RateLimitPolicy::default();
+        let rsp = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &[("RateLimit-Reset", "1600000030")],
+        );
+
+        assert_eq!(
+            policy.wait_for_at(0, &rsp, now),
+            Some(Duration::from_secs(30)),
+        );
+    }
+
+    #[test]
+    fn policy_default_wait() {
+        let policy = RateLimitPolicy::builder()
+            .default_wait(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let rsp = response(StatusCode::TOO_MANY_REQUESTS, &[]);
+
+        assert_eq!(policy.wait_for(0, &rsp), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn policy_retry_limit() {
+        let policy = RateLimitPolicy::builder().retries(2).build().unwrap();
+        let rsp = response(StatusCode::TOO_MANY_REQUESTS, &[]);
+
+        assert!(policy.wait_for(1, &rsp).is_some());
+        assert_eq!(policy.wait_for(2, &rsp), None);
+    }
+
+    #[test]
+    fn policy_max_wait() {
+        let policy = RateLimitPolicy::builder()
+            .max_wait(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        let rsp = response(StatusCode::TOO_MANY_REQUESTS, &[("Retry-After", "11")]);
+
+        assert_eq!(policy.wait_for(0, &rsp), None);
+    }
+}
@@ -5,9 +5,11 @@
 // except according to those terms.
 
 use async_trait::async_trait;
-use http::{header, Request};
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, Request, Response, StatusCode};
 
-use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
 
 /// A query modifier that returns the raw data from the endpoint.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,79 +24,131 @@ pub fn raw<E>(endpoint: E) -> Raw<E> {
     }
 }
 
+/// A query modifier that returns the raw response from the endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawWithMetadata<E> {
+    endpoint: E,
+}
+
+/// Return the raw response from the endpoint.
+///
+/// In addition to the data, this gives access to the status and headers of the response (e.g.,
+/// `X-Total`, `ETag`, or `Link`). As with `raw`, unsuccessful responses are returned as errors.
+pub fn raw_with_metadata<E>(endpoint: E) -> RawWithMetadata<E> {
+    RawWithMetadata {
+        endpoint,
+    }
+}
+
+pub(crate) fn request<E, C>(
+    endpoint: &E,
+    client: &C,
+) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+where
+    E: Endpoint,
+    C: RestClient,
+{
+    let mut url = endpoint
+        .url_base()
+        .endpoint_for(client, &endpoint.endpoint())?;
+    endpoint.parameters().add_to_url(&mut url);
+
+    let req = Request::builder()
+        .method(endpoint.method())
+        .uri(query::url_to_http_uri(url));
+    Ok(if let Some((mime, data)) = endpoint.body()? {
+        let req = req.header(header::CONTENT_TYPE, mime);
+        (req, data)
+    } else {
+        (req, Vec::new())
+    })
+}
+
+/// The error for an unsuccessful response.
+pub(crate) fn response_error<E>(status: StatusCode, body: &[u8]) -> ApiError<E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    if let Ok(v) = serde_json::from_slice(body) {
+        ApiError::from_gitlab(status, v)
+    } else {
+        ApiError::server_error(status, body)
+    }
+}
+
+fn check_response<E>(rsp: Response<Bytes>) -> Result<Response<Bytes>, ApiError<E>>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    if !rsp.status().is_success() {
+        return Err(response_error(rsp.status(), rsp.body()));
+    }
+
+    Ok(rsp)
+}
+
 impl<E, C> Query<Vec<u8>, C> for Raw<E>
 where
     E: Endpoint,
     C: Client,
 {
     fn query(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
-        self.endpoint.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest(req, data)?;
-        if !rsp.status().is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_gitlab(v));
-        }
-
+        let (req, data) = request(&self.endpoint, client)?;
+        let rsp = check_response(client.rest(req, data)?)?;
         Ok(rsp.into_body().as_ref().into())
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<E, C> AsyncQuery<Vec<u8>, C> for Raw<E>
 where
     E: Endpoint + Sync,
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Vec<u8>, ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
-        self.endpoint.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest_async(req, data).await?;
-        if !rsp.status().is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_gitlab(v));
-        }
-
+        let (req, data) = request(&self.endpoint, client)?;
+        let rsp = check_response(client.rest_async(req, data).await?)?;
         Ok(rsp.into_body().as_ref().into())
     }
 }
 
+impl<E, C> Query<Response<Bytes>, C> for RawWithMetadata<E>
+where
+    E: Endpoint,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let (req, data) = request(&self.endpoint, client)?;
+        check_response(client.rest(req, data)?)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<E, C> AsyncQuery<Response<Bytes>, C> for RawWithMetadata<E>
+where
+    E: Endpoint + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let (req, data) = request(&self.endpoint, client)?;
+        check_response(client.rest_async(req, data).await?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use http::StatusCode;
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::{Response, StatusCode};
     use serde_json::json;
+    use url::Url;
 
     use crate::api::endpoint_prelude::*;
-    use crate::api::{self, ApiError, AsyncQuery, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Query, RestClient};
+    use crate::test::client::{ExpectedUrl, SingleTestClient, TestClientError};
 
     struct Dummy;
 
@@ -224,4 +278,108 @@ mod tests {
             panic!("unexpected error: {}", err);
         }
     }
+
+    struct MetadataClient {
+        status: StatusCode,
+    }
+
+    impl RestClient for MetadataClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+    }
+
+    impl Client for MetadataClient {
+        fn rest(
+            &self,
+            _: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            Ok(Response::builder()
+                .status(self.status)
+                .header("x-total", "42")
+                .header("etag", "W/\"abc\"")
+                .body(Bytes::from_static(b"[]"))
+                .unwrap())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for MetadataClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.rest(request, body)
+        }
+    }
+
+    #[test]
+    fn test_raw_with_metadata() {
+        let client = MetadataClient {
+            status: StatusCode::OK,
+        };
+
+        let rsp = api::raw_with_metadata(Dummy).query(&client).unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(rsp.headers()["x-total"], "42");
+        assert_eq!(rsp.headers()["etag"], "W/\"abc\"");
+        assert_eq!(rsp.body().as_ref(), b"[]");
+    }
+
+    #[tokio::test]
+    async fn test_raw_with_metadata_async() {
+        let client = MetadataClient {
+            status: StatusCode::OK,
+        };
+
+        let rsp = api::raw_with_metadata(Dummy)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(rsp.headers()["x-total"], "42");
+        assert_eq!(rsp.body().as_ref(), b"[]");
+    }
+
+    #[test]
+    fn test_raw_with_metadata_created() {
+        let client = MetadataClient {
+            status: StatusCode::CREATED,
+        };
+
+        let rsp = api::raw_with_metadata(Dummy).query(&client).unwrap();
+        assert_eq!(rsp.status(), StatusCode::CREATED);
+    }
+
+    #[test]
+    fn test_raw_with_metadata_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let err = api::raw_with_metadata(Dummy).query(&client).unwrap_err();
+        if let ApiError::Gitlab {
+            msg,
+        } = err
+        {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
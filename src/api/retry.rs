@@ -9,20 +9,26 @@
 //! This module provides a `Client` implementation which can wrap other `ApiClient` instances in
 //! order to retry requests with an exponential backoff. Only service errors (those in the `5xx`
 //! range) are retried and all others are passed through as final statuses.
+//!
+//! It also provides a `RetryPolicy` which the `Gitlab` and `AsyncGitlab` clients apply to
+//! transient failures. The policy may be overridden for individual queries using `with_retry`.
 
+use std::collections::hash_map::RandomState;
 use std::error::Error as StdError;
+use std::hash::{BuildHasher, Hasher};
 use std::iter;
 use std::thread;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use bytes::Bytes;
-use http::Response;
+use http::{Method, Response, StatusCode};
 use url::Url;
 
 use derive_builder::Builder;
 use thiserror::Error;
 
-use crate::api;
+use crate::api::{self, AsyncClient, AsyncQuery, ExtensionClient, Query};
 
 /// Parameters for retrying queries with an exponential backoff.
 #[derive(Debug, Builder)]
@@ -172,6 +178,16 @@ where
             .rest_endpoint(endpoint)
             .map_err(|e| e.map_client(Error::inner))
     }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        self.client
+            .instance_endpoint(endpoint)
+            .map_err(|e| e.map_client(Error::inner))
+    }
+
+    fn identity(&self) -> Option<u64> {
+        self.client.identity()
+    }
 }
 
 impl<C> api::Client for Client<C>
@@ -208,16 +224,212 @@ where
     }
 }
 
+/// A policy for retrying requests which failed due to transient errors.
+///
+/// Requests are retried with an exponential backoff if they fail to connect to the server or if
+/// the server responds with one of the configured statuses (by default, `502 Bad Gateway`, `503
+/// Service Unavailable`, and `504 Gateway Timeout`). Each delay is randomly shortened by up to the
+/// `jitter` fraction so that many clients failing at once do not retry in lockstep.
+///
+/// The server may have acted on a request before responding with an error status, so only
+/// requests with idempotent methods (`GET`, `HEAD`, `PUT`, `DELETE`, `OPTIONS`, and `TRACE`) are
+/// retried for statuses unless `retry_non_idempotent` is set.
+#[derive(Debug, Clone, Builder)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to make for a request (including the first).
+    ///
+    /// Defaults to `3`.
+    #[builder(default = "3")]
+    attempts: usize,
+    /// How long to wait after the first failure.
+    ///
+    /// Defaults to 500 milliseconds.
+    #[builder(default = "Duration::from_millis(500)")]
+    init: Duration,
+    /// The scale parameter for delays after each subsequent failure.
+    ///
+    /// Defaults to `2.0`.
+    #[builder(default = "2.0")]
+    scale: f64,
+    /// The longest delay between attempts.
+    ///
+    /// Defaults to 30 seconds.
+    #[builder(default = "Duration::from_secs(30)")]
+    max_delay: Duration,
+    /// The fraction of each delay which is randomized.
+    ///
+    /// Must be between `0.0` and `1.0`. Defaults to `0.5`.
+    #[builder(default = "0.5")]
+    jitter: f64,
+    /// The response statuses which are retried.
+    ///
+    /// Defaults to `502`, `503`, and `504`.
+    #[builder(default = "RetryPolicy::default_statuses()")]
+    statuses: Vec<StatusCode>,
+    /// Whether to retry requests which failed to connect to the server.
+    ///
+    /// Defaults to `true`.
+    #[builder(default = "true")]
+    connection_errors: bool,
+    /// Whether to retry requests with methods which are not idempotent (such as `POST` and
+    /// `PATCH`) when the server responds with one of the retried statuses.
+    ///
+    /// Retrying such requests may perform their action more than once (e.g., creating duplicate
+    /// issues). Defaults to `false`.
+    #[builder(default = "false")]
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Create a builder for a retry policy.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    fn default_statuses() -> Vec<StatusCode> {
+        vec![
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::GATEWAY_TIMEOUT,
+        ]
+    }
+
+    fn is_idempotent(method: &Method) -> bool {
+        [
+            Method::GET,
+            Method::HEAD,
+            Method::PUT,
+            Method::DELETE,
+            Method::OPTIONS,
+            Method::TRACE,
+        ]
+        .contains(method)
+    }
+
+    /// How long to wait before retrying a `method` request which received a response with
+    /// `status`.
+    ///
+    /// Returns `None` if the request should not be retried.
+    pub(crate) fn wait_for_status(
+        &self,
+        retries: usize,
+        method: &Method,
+        status: StatusCode,
+    ) -> Option<Duration> {
+        let retryable = self.retry_non_idempotent || Self::is_idempotent(method);
+        if retryable && self.statuses.contains(&status) {
+            self.delay(retries)
+        } else {
+            None
+        }
+    }
+
+    /// How long to wait before retrying a request which failed to connect to the server.
+    ///
+    /// Returns `None` if the request should not be retried.
+    pub(crate) fn wait_for_connection_error(&self, retries: usize) -> Option<Duration> {
+        if self.connection_errors {
+            self.delay(retries)
+        } else {
+            None
+        }
+    }
+
+    fn delay(&self, retries: usize) -> Option<Duration> {
+        self.base_delay(retries).map(|delay| {
+            let jitter = self.jitter.clamp(0., 1.);
+            delay.mul_f64(1. - jitter * random_fraction())
+        })
+    }
+
+    fn base_delay(&self, retries: usize) -> Option<Duration> {
+        if retries + 1 >= self.attempts {
+            return None;
+        }
+
+        let scale = self.scale.powi(retries as i32);
+        let delay = self.init.as_secs_f64() * scale;
+        Some(
+            if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+                Duration::from_secs_f64(delay)
+            } else {
+                self.max_delay
+            },
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
+}
+
+/// A random number in the range `[0, 1)`.
+fn random_fraction() -> f64 {
+    // Each `RandomState` is seeded randomly, so hashing nothing gives a random value.
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// A query modifier that overrides the client's retry policy.
+#[derive(Debug, Clone)]
+pub struct WithRetry<Q> {
+    query: Q,
+    policy: RetryPolicy,
+}
+
+/// Override the retry policy of the client for a query.
+///
+/// Clients which do not support retry policies ignore the override.
+pub fn with_retry<Q>(query: Q, policy: RetryPolicy) -> WithRetry<Q> {
+    WithRetry {
+        query,
+        policy,
+    }
+}
+
+impl<Q, T, C> Query<T, C> for WithRetry<Q>
+where
+    Q: for<'a> Query<T, ExtensionClient<'a, C, RetryPolicy>>,
+    C: api::Client,
+{
+    fn query(&self, client: &C) -> Result<T, api::ApiError<C::Error>> {
+        let client = ExtensionClient::new(client, self.policy.clone());
+        self.query.query(&client)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<Q, T, C> AsyncQuery<T, C> for WithRetry<Q>
+where
+    Q: for<'a> AsyncQuery<T, ExtensionClient<'a, C, RetryPolicy>> + Sync,
+    T: Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, api::ApiError<C::Error>> {
+        let client = ExtensionClient::new(client, self.policy.clone());
+        self.query.query_async(&client).await
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
     use http::{Response, StatusCode};
     use serde::Deserialize;
     use serde_json::json;
     use thiserror::Error;
+    use url::Url;
 
     use crate::api::endpoint_prelude::*;
-    use crate::api::{self, retry, ApiError, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::api::{self, retry, ApiError, AsyncQuery, Client, Query, RestClient};
+    use crate::test::client::{ExpectedUrl, SingleTestClient, TestClientError};
 
     #[derive(Debug, Error)]
     #[error("bogus")]
@@ -422,4 +634,211 @@ mod test {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn retry_policy_defaults() {
+        let policy = retry::RetryPolicy::default();
+        assert_eq!(policy.attempts, 3);
+        assert_eq!(policy.init, Duration::from_millis(500));
+        assert_eq!(policy.scale, 2.0);
+        assert_eq!(policy.max_delay, Duration::from_secs(30));
+        assert_eq!(policy.jitter, 0.5);
+        assert_eq!(
+            policy.statuses,
+            [
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+        );
+        assert!(policy.connection_errors);
+        assert!(!policy.retry_non_idempotent);
+    }
+
+    #[test]
+    fn retry_policy_statuses() {
+        let policy = retry::RetryPolicy::builder().jitter(0.).build().unwrap();
+        assert_eq!(
+            policy.wait_for_status(0, &Method::GET, StatusCode::SERVICE_UNAVAILABLE),
+            Some(Duration::from_millis(500)),
+        );
+        assert_eq!(
+            policy.wait_for_status(0, &Method::GET, StatusCode::INTERNAL_SERVER_ERROR),
+            None,
+        );
+        assert_eq!(
+            policy.wait_for_status(0, &Method::GET, StatusCode::OK),
+            None
+        );
+
+        let policy = retry::RetryPolicy::builder()
+            .statuses(vec![StatusCode::INTERNAL_SERVER_ERROR])
+            .build()
+            .unwrap();
+        assert!(policy
+            .wait_for_status(0, &Method::GET, StatusCode::INTERNAL_SERVER_ERROR)
+            .is_some());
+        assert_eq!(
+            policy.wait_for_status(0, &Method::GET, StatusCode::SERVICE_UNAVAILABLE),
+            None,
+        );
+    }
+
+    #[test]
+    fn retry_policy_methods() {
+        let policy = retry::RetryPolicy::default();
+        for method in &[Method::GET, Method::HEAD, Method::PUT, Method::DELETE] {
+            assert!(policy
+                .wait_for_status(0, method, StatusCode::GATEWAY_TIMEOUT)
+                .is_some());
+        }
+        for method in &[Method::POST, Method::PATCH] {
+            assert_eq!(
+                policy.wait_for_status(0, method, StatusCode::GATEWAY_TIMEOUT),
+                None,
+            );
+        }
+
+        let policy = retry::RetryPolicy::builder()
+            .retry_non_idempotent(true)
+            .build()
+            .unwrap();
+        assert!(policy
+            .wait_for_status(0, &Method::POST, StatusCode::GATEWAY_TIMEOUT)
+            .is_some());
+    }
+
+    #[test]
+    fn retry_policy_connection_errors() {
+        let policy = retry::RetryPolicy::default();
+        assert!(policy.wait_for_connection_error(0).is_some());
+
+        let policy = retry::RetryPolicy::builder()
+            .connection_errors(false)
+            .build()
+            .unwrap();
+        assert_eq!(policy.wait_for_connection_error(0), None);
+    }
+
+    #[test]
+    fn retry_policy_attempts() {
+        let policy = retry::RetryPolicy::builder().attempts(3).build().unwrap();
+        assert!(policy.wait_for_connection_error(0).is_some());
+        assert!(policy.wait_for_connection_error(1).is_some());
+        assert_eq!(policy.wait_for_connection_error(2), None);
+
+        let policy = retry::RetryPolicy::builder().attempts(1).build().unwrap();
+        assert_eq!(policy.wait_for_connection_error(0), None);
+    }
+
+    #[test]
+    fn retry_policy_backoff() {
+        let policy = retry::RetryPolicy::builder()
+            .attempts(10)
+            .init(Duration::from_secs(1))
+            .scale(3.)
+            .max_delay(Duration::from_secs(20))
+            .jitter(0.)
+            .build()
+            .unwrap();
+        let delays = (0..5)
+            .map(|retries| policy.wait_for_connection_error(retries).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            [
+                Duration::from_secs(1),
+                Duration::from_secs(3),
+                Duration::from_secs(9),
+                Duration::from_secs(20),
+                Duration::from_secs(20),
+            ],
+        );
+    }
+
+    #[test]
+    fn retry_policy_jitter() {
+        let policy = retry::RetryPolicy::builder()
+            .init(Duration::from_secs(1))
+            .jitter(1.)
+            .build()
+            .unwrap();
+        for _ in 0..100 {
+            let delay = policy.wait_for_connection_error(0).unwrap();
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[derive(Default)]
+    struct PolicyClient {
+        attempts: Mutex<Vec<Option<usize>>>,
+    }
+
+    impl RestClient for PolicyClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+    }
+
+    impl Client for PolicyClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let attempts = request
+                .extensions_ref()
+                .and_then(|extensions| extensions.get::<retry::RetryPolicy>())
+                .map(|policy| policy.attempts);
+            self.attempts.lock().unwrap().push(attempts);
+            Ok(Response::new(json!({"value": 0}).to_string().into()))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl api::AsyncClient for PolicyClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.rest(request, body)
+        }
+    }
+
+    #[test]
+    fn with_retry_attaches_policy() {
+        let client = PolicyClient::default();
+        let policy = retry::RetryPolicy::builder().attempts(7).build().unwrap();
+
+        let _: DummyResult = Dummy.query(&client).unwrap();
+        let res: DummyResult = api::with_retry(Dummy, policy.clone())
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.value, 0);
+        api::with_retry(api::ignore(Dummy), policy)
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(*client.attempts.lock().unwrap(), [None, Some(7), Some(7)]);
+    }
+
+    #[tokio::test]
+    async fn with_retry_attaches_policy_async() {
+        let client = PolicyClient::default();
+        let policy = retry::RetryPolicy::builder().attempts(7).build().unwrap();
+
+        let res: DummyResult = api::with_retry(Dummy, policy)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.value, 0);
+
+        assert_eq!(*client.attempts.lock().unwrap(), [Some(7)]);
+    }
 }
@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sidekiq API endpoints.
+//!
+//! These endpoints are used for querying and managing the Sidekiq cluster of the instance.
+
+mod compound_metrics;
+mod delete_queue_jobs;
+mod job_stats;
+mod process_metrics;
+mod queue_metrics;
+
+pub use self::compound_metrics::SidekiqCompoundMetrics;
+pub use self::compound_metrics::SidekiqCompoundMetricsBuilder;
+pub use self::compound_metrics::SidekiqCompoundMetricsBuilderError;
+
+pub use self::delete_queue_jobs::DeleteSidekiqQueueJobs;
+pub use self::delete_queue_jobs::DeleteSidekiqQueueJobsBuilder;
+pub use self::delete_queue_jobs::DeleteSidekiqQueueJobsBuilderError;
+
+pub use self::job_stats::SidekiqJobStats;
+pub use self::job_stats::SidekiqJobStatsBuilder;
+pub use self::job_stats::SidekiqJobStatsBuilderError;
+
+pub use self::process_metrics::SidekiqProcessMetrics;
+pub use self::process_metrics::SidekiqProcessMetricsBuilder;
+pub use self::process_metrics::SidekiqProcessMetricsBuilderError;
+
+pub use self::queue_metrics::SidekiqQueueMetrics;
+pub use self::queue_metrics::SidekiqQueueMetricsBuilder;
+pub use self::queue_metrics::SidekiqQueueMetricsBuilderError;
@@ -0,0 +1,250 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::endpoint_prelude::*;
+
+/// Delete jobs from a Sidekiq queue.
+///
+/// Only jobs matching all of the given metadata filters are deleted. At least one filter must be
+/// given. This endpoint requires administrator privileges.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct DeleteSidekiqQueueJobs<'a> {
+    /// The name of the queue.
+    #[builder(setter(into))]
+    queue: Cow<'a, str>,
+
+    /// Delete jobs scheduled by the given username.
+    #[builder(setter(into), default)]
+    user: Option<Cow<'a, str>>,
+    /// Delete jobs scheduled within the given project (by full path).
+    #[builder(setter(into), default)]
+    project: Option<Cow<'a, str>>,
+    /// Delete jobs scheduled within the given root namespace.
+    #[builder(setter(into), default)]
+    root_namespace: Option<Cow<'a, str>>,
+    /// Delete jobs scheduled within namespaces on the given plan.
+    #[builder(setter(into), default)]
+    subscription_plan: Option<Cow<'a, str>>,
+    /// Delete jobs scheduled by the given endpoint or background job.
+    #[builder(setter(into), default)]
+    caller_id: Option<Cow<'a, str>>,
+    /// Delete jobs scheduled for the given feature category.
+    #[builder(setter(into), default)]
+    feature_category: Option<Cow<'a, str>>,
+    /// Delete jobs of the given worker class.
+    #[builder(setter(into), default)]
+    worker_class: Option<Cow<'a, str>>,
+}
+
+impl<'a> DeleteSidekiqQueueJobs<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteSidekiqQueueJobsBuilder<'a> {
+        DeleteSidekiqQueueJobsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteSidekiqQueueJobs<'a> {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("admin/sidekiq/queues/{}", common::path_escaped(&self.queue),).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("user", self.user.as_ref())
+            .push_opt("project", self.project.as_ref())
+            .push_opt("root_namespace", self.root_namespace.as_ref())
+            .push_opt("subscription_plan", self.subscription_plan.as_ref())
+            .push_opt("caller_id", self.caller_id.as_ref())
+            .push_opt("feature_category", self.feature_category.as_ref())
+            .push_opt("worker_class", self.worker_class.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::sidekiq::{DeleteSidekiqQueueJobs, DeleteSidekiqQueueJobsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn queue_is_needed() {
+        let err = DeleteSidekiqQueueJobs::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteSidekiqQueueJobsBuilderError, "queue");
+    }
+
+    #[test]
+    fn queue_is_sufficient() {
+        DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_user() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("user=user")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .user("user")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_project() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("project=project")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .project("project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_root_namespace() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("root_namespace=root_namespace")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .root_namespace("root_namespace")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_subscription_plan() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("subscription_plan=subscription_plan")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .subscription_plan("subscription_plan")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_caller_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("caller_id=caller_id")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .caller_id("caller_id")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_feature_category() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("feature_category=feature_category")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .feature_category("feature_category")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_worker_class() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("admin/sidekiq/queues/default")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("worker_class=worker_class")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteSidekiqQueueJobs::builder()
+            .queue("default")
+            .worker_class("worker_class")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -44,6 +44,11 @@ pub struct Sudo<'a, E> {
 }
 
 /// Create a `sudo`-elevated version of an endpoint.
+///
+/// The endpoint is performed as the given user (by username or ID), which requires an
+/// administrator token with the `sudo` scope. This has the same effect as GitLab's `Sudo` header,
+/// but only applies to the wrapped endpoint, so a single client may act on behalf of different
+/// users for different calls. Paginated endpoints pass the parameter on every page.
 pub fn sudo<'a, E, S>(endpoint: E, sudo: S) -> Sudo<'a, E>
 where
     S: Into<Cow<'a, str>>,
@@ -66,6 +71,10 @@ where
         self.endpoint.endpoint()
     }
 
+    fn url_base(&self) -> UrlBase {
+        self.endpoint.url_base()
+    }
+
     fn parameters(&self) -> QueryParams {
         let mut params = self.endpoint.parameters();
         params.push("sudo", &self.sudo);
@@ -89,12 +98,12 @@ where
 #[cfg(test)]
 mod tests {
     use http::StatusCode;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use crate::api::endpoint_prelude::*;
-    use crate::api::{self, ApiError, Query, SudoContext};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::api::{self, ApiError, Pagination, Query, SudoContext};
+    use crate::test::client::{ExpectedUrl, PagedTestClient, SingleTestClient};
 
     struct Dummy;
 
@@ -108,7 +117,9 @@ mod tests {
         }
     }
 
-    #[derive(Debug, Deserialize)]
+    impl Pageable for Dummy {}
+
+    #[derive(Debug, Deserialize, Serialize)]
     struct DummyResult {
         value: u8,
     }
@@ -275,10 +286,14 @@ mod tests {
         if let ApiError::DataType {
             source,
             typename,
+            path,
+            snippet,
         } = err
         {
             assert_eq!(format!("{}", source), "missing field `value`");
             assert_eq!(typename, "gitlab::api::sudo::tests::DummyResult");
+            assert_eq!(path, ".");
+            assert_eq!(snippet, r#"{"not_value":0}"#);
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -321,4 +336,46 @@ mod tests {
         let res: DummyResult = endpoint.query(&client).unwrap();
         assert_eq!(res.value, 0);
     }
+
+    #[test]
+    fn test_sudo_id() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "12")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let res: DummyResult = api::sudo(Dummy, 12.to_string()).query(&client).unwrap();
+        assert_eq!(res.value, 0);
+    }
+
+    #[test]
+    fn test_sudo_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("sudo", "user")])
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client = PagedTestClient::new_raw(
+            endpoint,
+            (0..=255).map(|value| {
+                DummyResult {
+                    value,
+                }
+            }),
+        );
+
+        let res: Vec<DummyResult> = api::paged(api::sudo(Dummy, "user"), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 256);
+        assert_eq!(client.requests(), 3);
+    }
 }
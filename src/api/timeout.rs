@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::api::{ApiError, AsyncClient, AsyncQuery, Client, ExtensionClient, Query};
+
+/// A timeout for the requests of a single query.
+///
+/// This is attached to requests as an extension (e.g., using `ExtensionClient`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryTimeout(Duration);
+
+impl QueryTimeout {
+    /// Create a timeout for a request.
+    pub fn new(timeout: Duration) -> Self {
+        Self(timeout)
+    }
+
+    /// The timeout for the request.
+    pub fn duration(self) -> Duration {
+        self.0
+    }
+
+    pub(crate) fn from_extensions(extensions: &http::Extensions) -> Option<Duration> {
+        extensions.get::<Self>().map(|timeout| timeout.duration())
+    }
+}
+
+/// A query modifier that overrides the client's timeout.
+#[derive(Debug, Clone)]
+pub struct WithTimeout<Q> {
+    query: Q,
+    timeout: Duration,
+}
+
+/// Override the request timeout of the client for a query.
+///
+/// The timeout applies to each request made by the query, so a paginated query may take longer
+/// in total. Clients which do not support timeouts ignore the override.
+pub fn with_timeout<Q>(query: Q, timeout: Duration) -> WithTimeout<Q> {
+    WithTimeout {
+        query,
+        timeout,
+    }
+}
+
+impl<Q, T, C> Query<T, C> for WithTimeout<Q>
+where
+    Q: for<'a> Query<T, ExtensionClient<'a, C, QueryTimeout>>,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = ExtensionClient::new(client, QueryTimeout::new(self.timeout));
+        self.query.query(&client)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<Q, T, C> AsyncQuery<T, C> for WithTimeout<Q>
+where
+    Q: for<'a> AsyncQuery<T, ExtensionClient<'a, C, QueryTimeout>> + Sync,
+    T: Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let client = ExtensionClient::new(client, QueryTimeout::new(self.timeout));
+        self.query.query_async(&client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::Response;
+    use url::Url;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{
+        self, ApiError, AsyncClient, AsyncQuery, Client, Query, QueryTimeout, RestClient,
+    };
+    use crate::test::client::TestClientError;
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Default)]
+    struct TimeoutClient {
+        timeouts: Mutex<Vec<Option<Duration>>>,
+    }
+
+    impl RestClient for TimeoutClient {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            Ok(Url::parse(&format!(
+                "https://gitlab.host.invalid/api/v4/{}",
+                endpoint
+            ))?)
+        }
+    }
+
+    impl Client for TimeoutClient {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let timeout = request
+                .extensions_ref()
+                .and_then(QueryTimeout::from_extensions);
+            self.timeouts.lock().unwrap().push(timeout);
+            Ok(Response::new("{}".into()))
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for TimeoutClient {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.rest(request, body)
+        }
+    }
+
+    #[test]
+    fn with_timeout_attaches_timeout() {
+        let client = TimeoutClient::default();
+
+        api::ignore(Dummy).query(&client).unwrap();
+        api::with_timeout(api::ignore(Dummy), Duration::from_secs(300))
+            .query(&client)
+            .unwrap();
+
+        assert_eq!(
+            *client.timeouts.lock().unwrap(),
+            [None, Some(Duration::from_secs(300))],
+        );
+    }
+
+    #[tokio::test]
+    async fn with_timeout_attaches_timeout_async() {
+        let client = TimeoutClient::default();
+
+        api::with_timeout(api::ignore(Dummy), Duration::from_secs(300))
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *client.timeouts.lock().unwrap(),
+            [Some(Duration::from_secs(300))],
+        );
+    }
+
+    #[test]
+    fn with_timeout_and_retry() {
+        let client = TimeoutClient::default();
+        let policy = api::retry::RetryPolicy::default();
+
+        api::with_retry(
+            api::with_timeout(api::ignore(Dummy), Duration::from_secs(300)),
+            policy,
+        )
+        .query(&client)
+        .unwrap();
+
+        assert_eq!(
+            *client.timeouts.lock().unwrap(),
+            [Some(Duration::from_secs(300))],
+        );
+    }
+}
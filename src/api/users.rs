@@ -10,7 +10,34 @@
 //!
 //! These endpoints are used for querying and modifying users and their resources.
 
+mod activate;
+mod activities;
+mod approve;
+mod associations_count;
+mod ban;
+mod block;
+mod contributed_projects;
+mod create;
 mod current_user;
+mod current_user_status;
+mod deactivate;
+mod delete;
+mod edit;
+pub mod emails;
+mod events;
+mod follow;
+mod followers;
+mod following;
+pub mod keys;
+mod memberships;
+mod projects;
+mod reject;
+mod set_status;
+mod starred_projects;
+mod status;
+mod unban;
+mod unblock;
+mod unfollow;
 mod user;
 mod users;
 
@@ -22,6 +49,10 @@ pub use self::current_user::CurrentUser;
 pub use self::current_user::CurrentUserBuilder;
 pub use self::current_user::CurrentUserBuilderError;
 
+pub use self::current_user_status::CurrentUserStatus;
+pub use self::current_user_status::CurrentUserStatusBuilder;
+pub use self::current_user_status::CurrentUserStatusBuilderError;
+
 pub use self::users::ExternalProvider;
 pub use self::users::ExternalProviderBuilder;
 pub use self::users::ExternalProviderBuilderError;
@@ -29,3 +60,102 @@ pub use self::users::UserOrderBy;
 pub use self::users::Users;
 pub use self::users::UsersBuilder;
 pub use self::users::UsersBuilderError;
+
+pub use self::activate::ActivateUser;
+pub use self::activate::ActivateUserBuilder;
+pub use self::activate::ActivateUserBuilderError;
+
+pub use self::activities::UserActivities;
+pub use self::activities::UserActivitiesBuilder;
+pub use self::activities::UserActivitiesBuilderError;
+
+pub use self::approve::ApproveUser;
+pub use self::approve::ApproveUserBuilder;
+pub use self::approve::ApproveUserBuilderError;
+
+pub use self::associations_count::UserAssociationsCount;
+pub use self::associations_count::UserAssociationsCountBuilder;
+pub use self::associations_count::UserAssociationsCountBuilderError;
+
+pub use self::ban::BanUser;
+pub use self::ban::BanUserBuilder;
+pub use self::ban::BanUserBuilderError;
+
+pub use self::block::BlockUser;
+pub use self::block::BlockUserBuilder;
+pub use self::block::BlockUserBuilderError;
+
+pub use self::contributed_projects::UserContributedProjects;
+pub use self::contributed_projects::UserContributedProjectsBuilder;
+pub use self::contributed_projects::UserContributedProjectsBuilderError;
+
+pub use self::create::CreateUser;
+pub use self::create::CreateUserBuilder;
+pub use self::create::CreateUserBuilderError;
+
+pub use self::deactivate::DeactivateUser;
+pub use self::deactivate::DeactivateUserBuilder;
+pub use self::deactivate::DeactivateUserBuilderError;
+
+pub use self::delete::DeleteUser;
+pub use self::delete::DeleteUserBuilder;
+pub use self::delete::DeleteUserBuilderError;
+
+pub use self::edit::EditUser;
+pub use self::edit::EditUserBuilder;
+pub use self::edit::EditUserBuilderError;
+
+pub use self::events::UserEvents;
+pub use self::events::UserEventsBuilder;
+pub use self::events::UserEventsBuilderError;
+
+pub use self::follow::FollowUser;
+pub use self::follow::FollowUserBuilder;
+pub use self::follow::FollowUserBuilderError;
+
+pub use self::followers::UserFollowers;
+pub use self::followers::UserFollowersBuilder;
+pub use self::followers::UserFollowersBuilderError;
+
+pub use self::following::UserFollowing;
+pub use self::following::UserFollowingBuilder;
+pub use self::following::UserFollowingBuilderError;
+
+pub use self::memberships::UserMembershipType;
+pub use self::memberships::UserMemberships;
+pub use self::memberships::UserMembershipsBuilder;
+pub use self::memberships::UserMembershipsBuilderError;
+
+pub use self::projects::UserProjects;
+pub use self::projects::UserProjectsBuilder;
+pub use self::projects::UserProjectsBuilderError;
+
+pub use self::reject::RejectUser;
+pub use self::reject::RejectUserBuilder;
+pub use self::reject::RejectUserBuilderError;
+
+pub use self::set_status::SetUserStatus;
+pub use self::set_status::SetUserStatusBuilder;
+pub use self::set_status::SetUserStatusBuilderError;
+pub use self::set_status::UserStatusAvailability;
+pub use self::set_status::UserStatusClearAfter;
+
+pub use self::starred_projects::UserStarredProjects;
+pub use self::starred_projects::UserStarredProjectsBuilder;
+pub use self::starred_projects::UserStarredProjectsBuilderError;
+
+pub use self::status::UserStatus;
+pub use self::status::UserStatusBuilder;
+pub use self::status::UserStatusBuilderError;
+
+pub use self::unban::UnbanUser;
+pub use self::unban::UnbanUserBuilder;
+pub use self::unban::UnbanUserBuilderError;
+
+pub use self::unblock::UnblockUser;
+pub use self::unblock::UnblockUserBuilder;
+pub use self::unblock::UnblockUserBuilderError;
+
+pub use self::unfollow::UnfollowUser;
+pub use self::unfollow::UnfollowUserBuilder;
+pub use self::unfollow::UnfollowUserBuilderError;
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the number of projects, groups, issues, and merge requests associated with a user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserAssociationsCount {
+    /// The ID of the user.
+    user: u64,
+}
+
+impl UserAssociationsCount {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserAssociationsCountBuilder {
+        UserAssociationsCountBuilder::default()
+    }
+}
+
+impl Endpoint for UserAssociationsCount {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/associations_count", self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserAssociationsCount, UserAssociationsCountBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserAssociationsCount::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserAssociationsCountBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserAssociationsCount::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/associations_count")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserAssociationsCount::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
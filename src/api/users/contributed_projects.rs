@@ -0,0 +1,152 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{NameOrId, SortOrder};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::ProjectOrderBy;
+
+/// Query for projects a user has contributed to.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UserContributedProjects<'a> {
+    /// The ID or username of the user.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+
+    /// Return only simple fields for search results.
+    #[builder(default)]
+    simple: Option<bool>,
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<ProjectOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> UserContributedProjects<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserContributedProjectsBuilder<'a> {
+        UserContributedProjectsBuilder::default()
+    }
+}
+
+impl<'a> UserContributedProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("user", self.user.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UserContributedProjects<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/contributed_projects", self.user).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("simple", self.simple)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for UserContributedProjects<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::SortOrder;
+    use crate::api::projects::ProjectOrderBy;
+    use crate::api::users::{UserContributedProjects, UserContributedProjectsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserContributedProjects::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserContributedProjectsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserContributedProjects::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/contributed_projects")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserContributedProjects::builder()
+            .user("simple/user")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_simple() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/contributed_projects")
+            .add_query_params(&[("simple", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserContributedProjects::builder()
+            .user("simple/user")
+            .simple(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/contributed_projects")
+            .add_query_params(&[("order_by", "id")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserContributedProjects::builder()
+            .user("simple/user")
+            .order_by(ProjectOrderBy::Id)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/contributed_projects")
+            .add_query_params(&[("sort", "desc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserContributedProjects::builder()
+            .user("simple/user")
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
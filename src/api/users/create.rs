@@ -0,0 +1,790 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Create a new user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateUser<'a> {
+    /// The email address of the new user.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+    /// The username of the new user.
+    #[builder(setter(into))]
+    username: Cow<'a, str>,
+    /// The display name of the new user.
+    #[builder(setter(into))]
+    name: Cow<'a, str>,
+
+    /// Send a password reset link to the new user.
+    #[builder(default)]
+    reset_password: Option<bool>,
+    /// Set the password of the new user to a random value.
+    #[builder(default)]
+    force_random_password: Option<bool>,
+    /// Skip confirmation of the email address.
+    #[builder(default)]
+    skip_confirmation: Option<bool>,
+    /// The password of the new user.
+    #[builder(setter(into), default)]
+    password: Option<Cow<'a, str>>,
+    /// Whether the new user is an administrator.
+    #[builder(default)]
+    admin: Option<bool>,
+    /// Whether the new user is external.
+    #[builder(default)]
+    external: Option<bool>,
+    /// Whether the new user may create top-level groups.
+    #[builder(default)]
+    can_create_group: Option<bool>,
+    /// The number of projects the new user may create.
+    #[builder(default)]
+    projects_limit: Option<u64>,
+    /// The biography of the new user.
+    #[builder(setter(into), default)]
+    bio: Option<Cow<'a, str>>,
+    /// The location of the new user.
+    #[builder(setter(into), default)]
+    location: Option<Cow<'a, str>>,
+    /// The organization of the new user.
+    #[builder(setter(into), default)]
+    organization: Option<Cow<'a, str>>,
+    /// The job title of the new user.
+    #[builder(setter(into), default)]
+    job_title: Option<Cow<'a, str>>,
+    /// The LinkedIn account of the new user.
+    #[builder(setter(into), default)]
+    linkedin: Option<Cow<'a, str>>,
+    /// The Twitter account of the new user.
+    #[builder(setter(into), default)]
+    twitter: Option<Cow<'a, str>>,
+    /// The Skype ID of the new user.
+    #[builder(setter(into), default)]
+    skype: Option<Cow<'a, str>>,
+    /// The website of the new user.
+    #[builder(setter(into), default)]
+    website_url: Option<Cow<'a, str>>,
+    /// Whether the profile of the new user is private.
+    #[builder(default)]
+    private_profile: Option<bool>,
+    /// An administrator note for the new user.
+    #[builder(setter(into), default)]
+    note: Option<Cow<'a, str>>,
+    /// The ID of the interface theme for the new user.
+    #[builder(default)]
+    theme_id: Option<u64>,
+    /// The ID of the color scheme for the new user.
+    #[builder(default)]
+    color_scheme_id: Option<u64>,
+    /// The external UID of the new user.
+    #[builder(setter(into), default)]
+    extern_uid: Option<Cow<'a, str>>,
+    /// The external provider name of the new user.
+    #[builder(setter(into), default)]
+    provider: Option<Cow<'a, str>>,
+}
+
+impl<'a> CreateUser<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateUserBuilder<'a> {
+        CreateUserBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateUser<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "users".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("email", self.email.as_ref())
+            .push("username", self.username.as_ref())
+            .push("name", self.name.as_ref())
+            .push_opt("reset_password", self.reset_password)
+            .push_opt("force_random_password", self.force_random_password)
+            .push_opt("skip_confirmation", self.skip_confirmation)
+            .push_opt("password", self.password.as_ref())
+            .push_opt("admin", self.admin)
+            .push_opt("external", self.external)
+            .push_opt("can_create_group", self.can_create_group)
+            .push_opt("projects_limit", self.projects_limit)
+            .push_opt("bio", self.bio.as_ref())
+            .push_opt("location", self.location.as_ref())
+            .push_opt("organization", self.organization.as_ref())
+            .push_opt("job_title", self.job_title.as_ref())
+            .push_opt("linkedin", self.linkedin.as_ref())
+            .push_opt("twitter", self.twitter.as_ref())
+            .push_opt("skype", self.skype.as_ref())
+            .push_opt("website_url", self.website_url.as_ref())
+            .push_opt("private_profile", self.private_profile)
+            .push_opt("note", self.note.as_ref())
+            .push_opt("theme_id", self.theme_id)
+            .push_opt("color_scheme_id", self.color_scheme_id)
+            .push_opt("extern_uid", self.extern_uid.as_ref())
+            .push_opt("provider", self.provider.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{CreateUser, CreateUserBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn email_username_and_name_are_needed() {
+        let err = CreateUser::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserBuilderError, "email");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = CreateUser::builder()
+            .username("username")
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserBuilderError, "email");
+    }
+
+    #[test]
+    fn username_is_needed() {
+        let err = CreateUser::builder()
+            .email("email")
+            .name("name")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserBuilderError, "username");
+    }
+
+    #[test]
+    fn name_is_needed() {
+        let err = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserBuilderError, "name");
+    }
+
+    #[test]
+    fn email_username_and_name_are_sufficient() {
+        CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("email=email", "&username=username", "&name=name"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_reset_password() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&reset_password=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .reset_password(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_force_random_password() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&force_random_password=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .force_random_password(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_skip_confirmation() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&skip_confirmation=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .skip_confirmation(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_password() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&password=password",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .password("password")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_admin() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&admin=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .admin(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_external() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&external=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .external(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_can_create_group() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&can_create_group=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .can_create_group(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_projects_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&projects_limit=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .projects_limit(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_bio() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&bio=bio",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .bio("bio")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_location() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&location=location",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .location("location")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_organization() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&organization=organization",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .organization("organization")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_job_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&job_title=job_title",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .job_title("job_title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_linkedin() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&linkedin=linkedin",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .linkedin("linkedin")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_twitter() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&twitter=twitter",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .twitter("twitter")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_skype() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&skype=skype",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .skype("skype")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_website_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&website_url=website_url",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .website_url("website_url")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_private_profile() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&private_profile=true",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .private_profile(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_note() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&note=note",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .note("note")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_theme_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&theme_id=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .theme_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color_scheme_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&color_scheme_id=1",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .color_scheme_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_extern_uid() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&extern_uid=extern_uid",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .extern_uid("extern_uid")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_provider() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "email=email",
+                "&username=username",
+                "&name=name",
+                "&provider=provider",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUser::builder()
+            .email("email")
+            .username("username")
+            .name("name")
+            .provider("provider")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Deactivate a user.
+///
+/// Deactivated users may reactivate themselves by logging in again.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeactivateUser {
+    /// The ID of the user to deactivate.
+    user: u64,
+}
+
+impl DeactivateUser {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeactivateUserBuilder {
+        DeactivateUserBuilder::default()
+    }
+}
+
+impl Endpoint for DeactivateUser {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/deactivate", self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{DeactivateUser, DeactivateUserBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = DeactivateUser::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeactivateUserBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        DeactivateUser::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/deactivate")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeactivateUser::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
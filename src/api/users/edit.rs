@@ -0,0 +1,623 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Edit an existing user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct EditUser<'a> {
+    /// The ID of the user to edit.
+    user: u64,
+
+    /// The email address of the user.
+    #[builder(setter(into), default)]
+    email: Option<Cow<'a, str>>,
+    /// The username of the user.
+    #[builder(setter(into), default)]
+    username: Option<Cow<'a, str>>,
+    /// The display name of the user.
+    #[builder(setter(into), default)]
+    name: Option<Cow<'a, str>>,
+    /// Skip confirmation of a changed email address.
+    #[builder(default)]
+    skip_reconfirmation: Option<bool>,
+    /// The public email address of the user.
+    #[builder(setter(into), default)]
+    public_email: Option<Cow<'a, str>>,
+    /// The email address used for web-based commits.
+    #[builder(setter(into), default)]
+    commit_email: Option<Cow<'a, str>>,
+    /// The password of the user.
+    #[builder(setter(into), default)]
+    password: Option<Cow<'a, str>>,
+    /// Whether the user is an administrator.
+    #[builder(default)]
+    admin: Option<bool>,
+    /// Whether the user is external.
+    #[builder(default)]
+    external: Option<bool>,
+    /// Whether the user may create top-level groups.
+    #[builder(default)]
+    can_create_group: Option<bool>,
+    /// The number of projects the user may create.
+    #[builder(default)]
+    projects_limit: Option<u64>,
+    /// The biography of the user.
+    #[builder(setter(into), default)]
+    bio: Option<Cow<'a, str>>,
+    /// The location of the user.
+    #[builder(setter(into), default)]
+    location: Option<Cow<'a, str>>,
+    /// The organization of the user.
+    #[builder(setter(into), default)]
+    organization: Option<Cow<'a, str>>,
+    /// The job title of the user.
+    #[builder(setter(into), default)]
+    job_title: Option<Cow<'a, str>>,
+    /// The LinkedIn account of the user.
+    #[builder(setter(into), default)]
+    linkedin: Option<Cow<'a, str>>,
+    /// The Twitter account of the user.
+    #[builder(setter(into), default)]
+    twitter: Option<Cow<'a, str>>,
+    /// The Skype ID of the user.
+    #[builder(setter(into), default)]
+    skype: Option<Cow<'a, str>>,
+    /// The website of the user.
+    #[builder(setter(into), default)]
+    website_url: Option<Cow<'a, str>>,
+    /// Whether the profile of the user is private.
+    #[builder(default)]
+    private_profile: Option<bool>,
+    /// An administrator note for the user.
+    #[builder(setter(into), default)]
+    note: Option<Cow<'a, str>>,
+    /// The ID of the interface theme for the user.
+    #[builder(default)]
+    theme_id: Option<u64>,
+    /// The ID of the color scheme for the user.
+    #[builder(default)]
+    color_scheme_id: Option<u64>,
+    /// The external UID of the user.
+    #[builder(setter(into), default)]
+    extern_uid: Option<Cow<'a, str>>,
+    /// The external provider name of the user.
+    #[builder(setter(into), default)]
+    provider: Option<Cow<'a, str>>,
+}
+
+impl<'a> EditUser<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EditUserBuilder<'a> {
+        EditUserBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for EditUser<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}", self.user).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("email", self.email.as_ref())
+            .push_opt("username", self.username.as_ref())
+            .push_opt("name", self.name.as_ref())
+            .push_opt("skip_reconfirmation", self.skip_reconfirmation)
+            .push_opt("public_email", self.public_email.as_ref())
+            .push_opt("commit_email", self.commit_email.as_ref())
+            .push_opt("password", self.password.as_ref())
+            .push_opt("admin", self.admin)
+            .push_opt("external", self.external)
+            .push_opt("can_create_group", self.can_create_group)
+            .push_opt("projects_limit", self.projects_limit)
+            .push_opt("bio", self.bio.as_ref())
+            .push_opt("location", self.location.as_ref())
+            .push_opt("organization", self.organization.as_ref())
+            .push_opt("job_title", self.job_title.as_ref())
+            .push_opt("linkedin", self.linkedin.as_ref())
+            .push_opt("twitter", self.twitter.as_ref())
+            .push_opt("skype", self.skype.as_ref())
+            .push_opt("website_url", self.website_url.as_ref())
+            .push_opt("private_profile", self.private_profile)
+            .push_opt("note", self.note.as_ref())
+            .push_opt("theme_id", self.theme_id)
+            .push_opt("color_scheme_id", self.color_scheme_id)
+            .push_opt("extern_uid", self.extern_uid.as_ref())
+            .push_opt("provider", self.provider.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{EditUser, EditUserBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = EditUser::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, EditUserBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        EditUser::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email=email")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).email("email").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_username() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("username=username")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .username("username")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_name() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("name=name")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).name("name").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_skip_reconfirmation() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("skip_reconfirmation=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .skip_reconfirmation(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_public_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("public_email=public_email")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .public_email("public_email")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_commit_email() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("commit_email=commit_email")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .commit_email("commit_email")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_password() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("password=password")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .password("password")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_admin() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("admin=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).admin(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_external() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("external=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).external(true).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_can_create_group() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("can_create_group=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .can_create_group(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_projects_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("projects_limit=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .projects_limit(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_bio() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("bio=bio")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).bio("bio").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_location() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("location=location")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .location("location")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_organization() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("organization=organization")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .organization("organization")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_job_title() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("job_title=job_title")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .job_title("job_title")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_linkedin() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("linkedin=linkedin")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .linkedin("linkedin")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_twitter() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("twitter=twitter")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .twitter("twitter")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_skype() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("skype=skype")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).skype("skype").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_website_url() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("website_url=website_url")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .website_url("website_url")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_private_profile() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("private_profile=true")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .private_profile(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_note() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("note=note")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).note("note").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_theme_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("theme_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder().user(1).theme_id(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_color_scheme_id() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("color_scheme_id=1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .color_scheme_id(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_extern_uid() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("extern_uid=extern_uid")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .extern_uid("extern_uid")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_provider() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("users/1")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("provider=provider")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EditUser::builder()
+            .user(1)
+            .provider("provider")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
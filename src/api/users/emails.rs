@@ -0,0 +1,47 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::module_inception)]
+
+//! User email API endpoints.
+//!
+//! These endpoints are used for querying and managing the email addresses of users.
+
+mod create_current_user_email;
+mod create_user_email;
+mod current_user_email;
+mod current_user_emails;
+mod delete_current_user_email;
+mod delete_user_email;
+mod user_emails;
+
+pub use self::create_current_user_email::CreateCurrentUserEmail;
+pub use self::create_current_user_email::CreateCurrentUserEmailBuilder;
+pub use self::create_current_user_email::CreateCurrentUserEmailBuilderError;
+
+pub use self::create_user_email::CreateUserEmail;
+pub use self::create_user_email::CreateUserEmailBuilder;
+pub use self::create_user_email::CreateUserEmailBuilderError;
+
+pub use self::current_user_email::CurrentUserEmail;
+pub use self::current_user_email::CurrentUserEmailBuilder;
+pub use self::current_user_email::CurrentUserEmailBuilderError;
+
+pub use self::current_user_emails::CurrentUserEmails;
+pub use self::current_user_emails::CurrentUserEmailsBuilder;
+pub use self::current_user_emails::CurrentUserEmailsBuilderError;
+
+pub use self::delete_current_user_email::DeleteCurrentUserEmail;
+pub use self::delete_current_user_email::DeleteCurrentUserEmailBuilder;
+pub use self::delete_current_user_email::DeleteCurrentUserEmailBuilderError;
+
+pub use self::delete_user_email::DeleteUserEmail;
+pub use self::delete_user_email::DeleteUserEmailBuilder;
+pub use self::delete_user_email::DeleteUserEmailBuilderError;
+
+pub use self::user_emails::UserEmails;
+pub use self::user_emails::UserEmailsBuilder;
+pub use self::user_emails::UserEmailsBuilderError;
@@ -0,0 +1,83 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Add an email address for the API calling user.
+#[derive(Debug, Builder)]
+pub struct CreateCurrentUserEmail<'a> {
+    /// The email address to add.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+}
+
+impl<'a> CreateCurrentUserEmail<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateCurrentUserEmailBuilder<'a> {
+        CreateCurrentUserEmailBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateCurrentUserEmail<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/emails".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params.push("email", self.email.as_ref());
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::emails::{CreateCurrentUserEmail, CreateCurrentUserEmailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn email_is_needed() {
+        let err = CreateCurrentUserEmail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserEmailBuilderError, "email");
+    }
+
+    #[test]
+    fn email_is_sufficient() {
+        CreateCurrentUserEmail::builder()
+            .email("email")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/emails")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email=email")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserEmail::builder()
+            .email("email")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Add an email address for a user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateUserEmail<'a> {
+    /// The ID of the user to add the email address for.
+    user: u64,
+    /// The email address to add.
+    #[builder(setter(into))]
+    email: Cow<'a, str>,
+
+    /// Skip confirmation of the email address.
+    #[builder(default)]
+    skip_confirmation: Option<bool>,
+}
+
+impl<'a> CreateUserEmail<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateUserEmailBuilder<'a> {
+        CreateUserEmailBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateUserEmail<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/emails", self.user).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("email", self.email.as_ref())
+            .push_opt("skip_confirmation", self.skip_confirmation);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::emails::{CreateUserEmail, CreateUserEmailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_email_are_needed() {
+        let err = CreateUserEmail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserEmailBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = CreateUserEmail::builder()
+            .email("email")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserEmailBuilderError, "user");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = CreateUserEmail::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateUserEmailBuilderError, "email");
+    }
+
+    #[test]
+    fn user_and_email_are_sufficient() {
+        CreateUserEmail::builder()
+            .user(1)
+            .email("email")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/emails")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("email=email")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserEmail::builder()
+            .user(1)
+            .email("email")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_skip_confirmation() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/emails")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("email=email", "&skip_confirmation=true"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateUserEmail::builder()
+            .user(1)
+            .email("email")
+            .skip_confirmation(true)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an email address of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CurrentUserEmail {
+    /// The ID of the email address.
+    email: u64,
+}
+
+impl CurrentUserEmail {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserEmailBuilder {
+        CurrentUserEmailBuilder::default()
+    }
+}
+
+impl Endpoint for CurrentUserEmail {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/emails/{}", self.email).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::emails::{CurrentUserEmail, CurrentUserEmailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn email_is_needed() {
+        let err = CurrentUserEmail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CurrentUserEmailBuilderError, "email");
+    }
+
+    #[test]
+    fn email_is_sufficient() {
+        CurrentUserEmail::builder().email(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/emails/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserEmail::builder().email(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for email addresses of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CurrentUserEmails {}
+
+impl CurrentUserEmails {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserEmailsBuilder {
+        CurrentUserEmailsBuilder::default()
+    }
+}
+
+impl Endpoint for CurrentUserEmails {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/emails".into()
+    }
+}
+
+impl Pageable for CurrentUserEmails {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::emails::CurrentUserEmails;
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        CurrentUserEmails::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/emails")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserEmails::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
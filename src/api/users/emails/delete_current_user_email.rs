@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete an email address of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteCurrentUserEmail {
+    /// The ID of the email address to delete.
+    email: u64,
+}
+
+impl DeleteCurrentUserEmail {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteCurrentUserEmailBuilder {
+        DeleteCurrentUserEmailBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteCurrentUserEmail {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/emails/{}", self.email).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::emails::{DeleteCurrentUserEmail, DeleteCurrentUserEmailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn email_is_needed() {
+        let err = DeleteCurrentUserEmail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteCurrentUserEmailBuilderError, "email");
+    }
+
+    #[test]
+    fn email_is_sufficient() {
+        DeleteCurrentUserEmail::builder().email(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("user/emails/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteCurrentUserEmail::builder().email(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete an email address of a user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteUserEmail {
+    /// The ID of the user with the email address.
+    user: u64,
+    /// The ID of the email address to delete.
+    email: u64,
+}
+
+impl DeleteUserEmail {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteUserEmailBuilder {
+        DeleteUserEmailBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteUserEmail {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/emails/{}", self.user, self.email).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::emails::{DeleteUserEmail, DeleteUserEmailBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_email_are_needed() {
+        let err = DeleteUserEmail::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserEmailBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = DeleteUserEmail::builder().email(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserEmailBuilderError, "user");
+    }
+
+    #[test]
+    fn email_is_needed() {
+        let err = DeleteUserEmail::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserEmailBuilderError, "email");
+    }
+
+    #[test]
+    fn user_and_email_are_sufficient() {
+        DeleteUserEmail::builder().user(1).email(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("users/1/emails/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteUserEmail::builder().user(1).email(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
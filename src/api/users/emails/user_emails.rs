@@ -0,0 +1,67 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for email addresses of a user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserEmails {
+    /// The ID of the user to query for email addresses.
+    user: u64,
+}
+
+impl UserEmails {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserEmailsBuilder {
+        UserEmailsBuilder::default()
+    }
+}
+
+impl Endpoint for UserEmails {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/emails", self.user).into()
+    }
+}
+
+impl Pageable for UserEmails {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::emails::{UserEmails, UserEmailsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserEmails::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserEmailsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserEmails::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/emails")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserEmails::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Follow a user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct FollowUser {
+    /// The ID of the user to follow.
+    user: u64,
+}
+
+impl FollowUser {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> FollowUserBuilder {
+        FollowUserBuilder::default()
+    }
+}
+
+impl Endpoint for FollowUser {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/follow", self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{FollowUser, FollowUserBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = FollowUser::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, FollowUserBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        FollowUser::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("users/1/follow")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = FollowUser::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the users following a user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserFollowers {
+    /// The ID of the user to query for followers.
+    user: u64,
+}
+
+impl UserFollowers {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserFollowersBuilder {
+        UserFollowersBuilder::default()
+    }
+}
+
+impl Endpoint for UserFollowers {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/followers", self.user).into()
+    }
+}
+
+impl Pageable for UserFollowers {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserFollowers, UserFollowersBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserFollowers::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserFollowersBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserFollowers::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/followers")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserFollowers::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the users a user is following.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserFollowing {
+    /// The ID of the user to query for followed users.
+    user: u64,
+}
+
+impl UserFollowing {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserFollowingBuilder {
+        UserFollowingBuilder::default()
+    }
+}
+
+impl Endpoint for UserFollowing {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/following", self.user).into()
+    }
+}
+
+impl Pageable for UserFollowing {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserFollowing, UserFollowingBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserFollowing::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserFollowingBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserFollowing::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/following")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserFollowing::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
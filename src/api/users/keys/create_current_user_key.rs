@@ -0,0 +1,140 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Add an SSH key for the API calling user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct CreateCurrentUserKey<'a> {
+    /// The title of the key.
+    #[builder(setter(into))]
+    title: Cow<'a, str>,
+    /// The public half of the SSH key.
+    #[builder(setter(into))]
+    key: Cow<'a, str>,
+
+    /// When the key expires.
+    #[builder(default)]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl<'a> CreateCurrentUserKey<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateCurrentUserKeyBuilder<'a> {
+        CreateCurrentUserKeyBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for CreateCurrentUserKey<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/keys".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push("title", self.title.as_ref())
+            .push("key", self.key.as_ref())
+            .push_opt("expires_at", self.expires_at);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::Method;
+
+    use crate::api::users::keys::{CreateCurrentUserKey, CreateCurrentUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn title_and_key_are_needed() {
+        let err = CreateCurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "title");
+    }
+
+    #[test]
+    fn title_is_needed() {
+        let err = CreateCurrentUserKey::builder()
+            .key("key")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "title");
+    }
+
+    #[test]
+    fn key_is_needed() {
+        let err = CreateCurrentUserKey::builder()
+            .title("title")
+            .build()
+            .unwrap_err();
+        crate::test::assert_missing_field!(err, CreateCurrentUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn title_and_key_are_sufficient() {
+        CreateCurrentUserKey::builder()
+            .title("title")
+            .key("key")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/keys")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!("title=title", "&key=key"))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserKey::builder()
+            .title("title")
+            .key("key")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_expires_at() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("user/keys")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str(concat!(
+                "title=title",
+                "&key=key",
+                "&expires_at=2020-01-01T00%3A00%3A00Z",
+            ))
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateCurrentUserKey::builder()
+            .title("title")
+            .key("key")
+            .expires_at(Utc.ymd(2020, 1, 1).and_hms_milli(0, 0, 0, 0))
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
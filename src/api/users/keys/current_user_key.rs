@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for an SSH key of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CurrentUserKey {
+    /// The ID of the SSH key.
+    key: u64,
+}
+
+impl CurrentUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CurrentUserKeyBuilder {
+        CurrentUserKeyBuilder::default()
+    }
+}
+
+impl Endpoint for CurrentUserKey {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/keys/{}", self.key).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::keys::{CurrentUserKey, CurrentUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_needed() {
+        let err = CurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CurrentUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        CurrentUserKey::builder().key(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("user/keys/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CurrentUserKey::builder().key(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
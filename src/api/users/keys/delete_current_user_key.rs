@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete an SSH key of the API calling user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteCurrentUserKey {
+    /// The ID of the SSH key to delete.
+    key: u64,
+}
+
+impl DeleteCurrentUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteCurrentUserKeyBuilder {
+        DeleteCurrentUserKeyBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteCurrentUserKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/keys/{}", self.key).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::keys::{DeleteCurrentUserKey, DeleteCurrentUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn key_is_needed() {
+        let err = DeleteCurrentUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteCurrentUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn key_is_sufficient() {
+        DeleteCurrentUserKey::builder().key(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("user/keys/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteCurrentUserKey::builder().key(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
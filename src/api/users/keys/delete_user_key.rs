@@ -0,0 +1,82 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Delete an SSH key of a user.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteUserKey {
+    /// The ID of the user with the SSH key.
+    user: u64,
+    /// The ID of the SSH key to delete.
+    key: u64,
+}
+
+impl DeleteUserKey {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DeleteUserKeyBuilder {
+        DeleteUserKeyBuilder::default()
+    }
+}
+
+impl Endpoint for DeleteUserKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/keys/{}", self.user, self.key).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::keys::{DeleteUserKey, DeleteUserKeyBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_and_key_are_needed() {
+        let err = DeleteUserKey::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_needed() {
+        let err = DeleteUserKey::builder().key(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "user");
+    }
+
+    #[test]
+    fn key_is_needed() {
+        let err = DeleteUserKey::builder().user(1).build().unwrap_err();
+        crate::test::assert_missing_field!(err, DeleteUserKeyBuilderError, "key");
+    }
+
+    #[test]
+    fn user_and_key_are_sufficient() {
+        DeleteUserKey::builder().user(1).key(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::DELETE)
+            .endpoint("users/1/keys/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DeleteUserKey::builder().user(1).key(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for SSH keys of a user.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserKeys {
+    /// The ID of the user to query for SSH keys.
+    user: u64,
+}
+
+impl UserKeys {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserKeysBuilder {
+        UserKeysBuilder::default()
+    }
+}
+
+impl Endpoint for UserKeys {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/keys", self.user).into()
+    }
+}
+
+impl Pageable for UserKeys {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::keys::{UserKeys, UserKeysBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserKeys::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserKeysBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserKeys::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/keys")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserKeys::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
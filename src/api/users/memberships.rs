@@ -0,0 +1,134 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// The kinds of sources a user may be a member of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserMembershipType {
+    /// Memberships in projects.
+    Project,
+    /// Memberships in namespaces (groups).
+    Namespace,
+}
+
+impl UserMembershipType {
+    fn as_str(self) -> &'static str {
+        match self {
+            UserMembershipType::Project => "Project",
+            UserMembershipType::Namespace => "Namespace",
+        }
+    }
+}
+
+impl ParamValue<'static> for UserMembershipType {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Query for the projects and groups a user is a member of.
+///
+/// This endpoint requires administrator access.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct UserMemberships {
+    /// The ID of the user.
+    user: u64,
+
+    /// Filter memberships by the kind of source.
+    #[builder(default)]
+    membership_type: Option<UserMembershipType>,
+}
+
+impl UserMemberships {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserMembershipsBuilder {
+        UserMembershipsBuilder::default()
+    }
+}
+
+impl Endpoint for UserMemberships {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/memberships", self.user).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params.push_opt("type", self.membership_type);
+
+        params
+    }
+}
+
+impl Pageable for UserMemberships {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserMembershipType, UserMemberships, UserMembershipsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserMemberships::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserMembershipsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserMemberships::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/memberships")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserMemberships::builder().user(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_membership_type() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/1/memberships")
+            .add_query_params(&[("type", "Namespace")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserMemberships::builder()
+            .user(1)
+            .membership_type(UserMembershipType::Namespace)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn user_membership_type_as_str() {
+        let items = &[
+            (UserMembershipType::Project, "Project"),
+            (UserMembershipType::Namespace, "Namespace"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+}
@@ -0,0 +1,454 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::{AccessLevel, NameOrId, SortOrder, VisibilityLevel};
+use crate::api::endpoint_prelude::*;
+use crate::api::projects::ProjectOrderBy;
+
+/// Query for projects owned by a user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct UserProjects<'a> {
+    /// The ID or username of the user.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+
+    /// Search for projects using a query string.
+    ///
+    /// The search query will be escaped automatically.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Filter projects by its archived state.
+    #[builder(default)]
+    archived: Option<bool>,
+    /// Filter projects by its visibility.
+    #[builder(default)]
+    visibility: Option<VisibilityLevel>,
+    /// Return only simple fields for search results.
+    #[builder(default)]
+    simple: Option<bool>,
+    /// Filter projects by those owned by the API caller.
+    #[builder(default)]
+    owned: Option<bool>,
+    /// Filter projects by those the API caller is a member of.
+    #[builder(default)]
+    membership: Option<bool>,
+    /// Filter projects by those the API caller has starred.
+    #[builder(default)]
+    starred: Option<bool>,
+    /// Include project statistics in the results.
+    #[builder(default)]
+    statistics: Option<bool>,
+    /// Include custom attributes in the results.
+    #[builder(default)]
+    with_custom_attributes: Option<bool>,
+    /// Filter projects by whether issues are enabled.
+    #[builder(default)]
+    with_issues_enabled: Option<bool>,
+    /// Filter projects by whether merge requests are enabled.
+    #[builder(default)]
+    with_merge_requests_enabled: Option<bool>,
+    /// Filter projects by programming language.
+    #[builder(setter(into), default)]
+    with_programming_language: Option<Cow<'a, str>>,
+    /// Filter projects by those where the API caller has a minimum access level.
+    #[builder(default)]
+    min_access_level: Option<AccessLevel>,
+    /// Filter projects by those with at least this ID.
+    #[builder(default)]
+    id_after: Option<u64>,
+    /// Filter projects by those with at most this ID.
+    #[builder(default)]
+    id_before: Option<u64>,
+    /// Order results by a given key.
+    #[builder(default)]
+    order_by: Option<ProjectOrderBy>,
+    /// The sort order for return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+}
+
+impl<'a> UserProjects<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserProjectsBuilder<'a> {
+        UserProjectsBuilder::default()
+    }
+}
+
+impl<'a> UserProjectsBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("user", self.user.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UserProjects<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/projects", self.user).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("archived", self.archived)
+            .push_opt("visibility", self.visibility)
+            .push_opt("simple", self.simple)
+            .push_opt("owned", self.owned)
+            .push_opt("membership", self.membership)
+            .push_opt("starred", self.starred)
+            .push_opt("statistics", self.statistics)
+            .push_opt("with_custom_attributes", self.with_custom_attributes)
+            .push_opt("with_issues_enabled", self.with_issues_enabled)
+            .push_opt(
+                "with_merge_requests_enabled",
+                self.with_merge_requests_enabled,
+            )
+            .push_opt(
+                "with_programming_language",
+                self.with_programming_language.as_ref(),
+            )
+            .push_opt(
+                "min_access_level",
+                self.min_access_level.map(|level| level.as_u64()),
+            )
+            .push_opt("id_after", self.id_after)
+            .push_opt("id_before", self.id_before)
+            .push_opt("order_by", self.order_by)
+            .push_opt("sort", self.sort);
+
+        params
+    }
+}
+
+impl<'a> Pageable for UserProjects<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::{AccessLevel, SortOrder, VisibilityLevel};
+    use crate::api::projects::ProjectOrderBy;
+    use crate::api::users::{UserProjects, UserProjectsBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserProjects::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserProjectsBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserProjects::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder().user("simple/user").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_search() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("search", "special/query")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .search("special/query")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_archived() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("archived", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .archived(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_visibility() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("visibility", "private")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .visibility(VisibilityLevel::Private)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_simple() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("simple", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .simple(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_owned() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("owned", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .owned(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_membership() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("membership", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .membership(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_starred() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("starred", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .starred(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_statistics() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("statistics", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .statistics(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_custom_attributes() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("with_custom_attributes", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .with_custom_attributes(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_issues_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("with_issues_enabled", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .with_issues_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_merge_requests_enabled() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("with_merge_requests_enabled", "false")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .with_merge_requests_enabled(false)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_with_programming_language() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("with_programming_language", "rust")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .with_programming_language("rust")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_min_access_level() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("min_access_level", "10")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .min_access_level(AccessLevel::Guest)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_id_after() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("id_after", "100")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .id_after(100)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_id_before() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("id_before", "100")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .id_before(100)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_order_by() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("order_by", "id")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .order_by(ProjectOrderBy::Id)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_sort() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/projects")
+            .add_query_params(&[("sort", "desc")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserProjects::builder()
+            .user("simple/user")
+            .sort(SortOrder::Descending)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Availability states of a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatusAvailability {
+    /// The user has not set their availability.
+    NotSet,
+    /// The user is busy.
+    Busy,
+}
+
+impl UserStatusAvailability {
+    fn as_str(self) -> &'static str {
+        match self {
+            UserStatusAvailability::NotSet => "not_set",
+            UserStatusAvailability::Busy => "busy",
+        }
+    }
+}
+
+impl ParamValue<'static> for UserStatusAvailability {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Durations after which a user status is cleared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatusClearAfter {
+    /// Clear the status after 30 minutes.
+    ThirtyMinutes,
+    /// Clear the status after 3 hours.
+    ThreeHours,
+    /// Clear the status after 8 hours.
+    EightHours,
+    /// Clear the status after 1 day.
+    OneDay,
+    /// Clear the status after 3 days.
+    ThreeDays,
+    /// Clear the status after 7 days.
+    SevenDays,
+    /// Clear the status after 30 days.
+    ThirtyDays,
+}
+
+impl UserStatusClearAfter {
+    fn as_str(self) -> &'static str {
+        match self {
+            UserStatusClearAfter::ThirtyMinutes => "30_minutes",
+            UserStatusClearAfter::ThreeHours => "3_hours",
+            UserStatusClearAfter::EightHours => "8_hours",
+            UserStatusClearAfter::OneDay => "1_day",
+            UserStatusClearAfter::ThreeDays => "3_days",
+            UserStatusClearAfter::SevenDays => "7_days",
+            UserStatusClearAfter::ThirtyDays => "30_days",
+        }
+    }
+}
+
+impl ParamValue<'static> for UserStatusClearAfter {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Set the status of the API calling user.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct SetUserStatus<'a> {
+    /// The name of the emoji to use for the status.
+    #[builder(setter(into), default)]
+    emoji: Option<Cow<'a, str>>,
+    /// The message of the status.
+    #[builder(setter(into), default)]
+    message: Option<Cow<'a, str>>,
+    /// The availability of the user.
+    #[builder(default)]
+    availability: Option<UserStatusAvailability>,
+    /// When to clear the status.
+    #[builder(default)]
+    clear_status_after: Option<UserStatusClearAfter>,
+}
+
+impl<'a> SetUserStatus<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> SetUserStatusBuilder<'a> {
+        SetUserStatusBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SetUserStatus<'a> {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/status".into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("emoji", self.emoji.as_ref())
+            .push_opt("message", self.message.as_ref())
+            .push_opt("availability", self.availability)
+            .push_opt("clear_status_after", self.clear_status_after);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::users::{SetUserStatus, UserStatusAvailability, UserStatusClearAfter};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn defaults_are_sufficient() {
+        SetUserStatus::builder().build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserStatus::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_emoji() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("emoji=emoji")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserStatus::builder().emoji("emoji").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_message() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("message=message")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserStatus::builder().message("message").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_availability() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("availability=busy")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserStatus::builder()
+            .availability(UserStatusAvailability::Busy)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_clear_status_after() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::PUT)
+            .endpoint("user/status")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("clear_status_after=1_day")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = SetUserStatus::builder()
+            .clear_status_after(UserStatusClearAfter::OneDay)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn user_status_availability_as_str() {
+        let items = &[
+            (UserStatusAvailability::NotSet, "not_set"),
+            (UserStatusAvailability::Busy, "busy"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn user_status_clear_after_as_str() {
+        let items = &[
+            (UserStatusClearAfter::ThirtyMinutes, "30_minutes"),
+            (UserStatusClearAfter::ThreeHours, "3_hours"),
+            (UserStatusClearAfter::EightHours, "8_hours"),
+            (UserStatusClearAfter::OneDay, "1_day"),
+            (UserStatusClearAfter::ThreeDays, "3_days"),
+            (UserStatusClearAfter::SevenDays, "7_days"),
+            (UserStatusClearAfter::ThirtyDays, "30_days"),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Query for the status of a user.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct UserStatus<'a> {
+    /// The ID or username of the user.
+    #[builder(setter(into))]
+    user: NameOrId<'a>,
+}
+
+impl<'a> UserStatus<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> UserStatusBuilder<'a> {
+        UserStatusBuilder::default()
+    }
+}
+
+impl<'a> UserStatusBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("user", self.user.as_ref())
+    }
+}
+
+impl<'a> Endpoint for UserStatus<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("users/{}/status", self.user).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::users::{UserStatus, UserStatusBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn user_is_needed() {
+        let err = UserStatus::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, UserStatusBuilderError, "user");
+    }
+
+    #[test]
+    fn user_is_sufficient() {
+        UserStatus::builder().user(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("users/simple%2Fuser/status")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = UserStatus::builder().user("simple/user").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Vulnerability API endpoints.
+//!
+//! These endpoints are used for querying and managing vulnerabilities and exporting them.
+
+mod confirm;
+mod dismiss;
+pub mod exports;
+mod resolve;
+mod revert;
+mod vulnerability;
+
+pub use self::confirm::ConfirmVulnerability;
+pub use self::confirm::ConfirmVulnerabilityBuilder;
+pub use self::confirm::ConfirmVulnerabilityBuilderError;
+
+pub use self::dismiss::DismissVulnerability;
+pub use self::dismiss::DismissVulnerabilityBuilder;
+pub use self::dismiss::DismissVulnerabilityBuilderError;
+pub use self::dismiss::VulnerabilityDismissalReason;
+
+pub use self::resolve::ResolveVulnerability;
+pub use self::resolve::ResolveVulnerabilityBuilder;
+pub use self::resolve::ResolveVulnerabilityBuilderError;
+
+pub use self::revert::RevertVulnerability;
+pub use self::revert::RevertVulnerabilityBuilder;
+pub use self::revert::RevertVulnerabilityBuilderError;
+
+pub use self::vulnerability::Vulnerability;
+pub use self::vulnerability::VulnerabilityBuilder;
+pub use self::vulnerability::VulnerabilityBuilderError;
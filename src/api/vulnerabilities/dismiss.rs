@@ -0,0 +1,194 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::ParamValue;
+
+/// Reasons for dismissing a vulnerability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VulnerabilityDismissalReason {
+    /// The risk of the vulnerability is acceptable.
+    AcceptableRisk,
+    /// The vulnerability is a false positive.
+    FalsePositive,
+    /// The vulnerability is mitigated by other controls.
+    MitigatingControl,
+    /// The vulnerability is only present in test code.
+    UsedInTests,
+    /// The vulnerability does not apply.
+    NotApplicable,
+}
+
+impl VulnerabilityDismissalReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            VulnerabilityDismissalReason::AcceptableRisk => "acceptable_risk",
+            VulnerabilityDismissalReason::FalsePositive => "false_positive",
+            VulnerabilityDismissalReason::MitigatingControl => "mitigating_control",
+            VulnerabilityDismissalReason::UsedInTests => "used_in_tests",
+            VulnerabilityDismissalReason::NotApplicable => "not_applicable",
+        }
+    }
+}
+
+impl ParamValue<'static> for VulnerabilityDismissalReason {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Dismiss a vulnerability.
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct DismissVulnerability<'a> {
+    /// The ID of the vulnerability.
+    vulnerability: u64,
+
+    /// A comment explaining the dismissal.
+    #[builder(setter(into), default)]
+    comment: Option<Cow<'a, str>>,
+    /// The reason for the dismissal.
+    #[builder(default)]
+    dismissal_reason: Option<VulnerabilityDismissalReason>,
+}
+
+impl<'a> DismissVulnerability<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DismissVulnerabilityBuilder<'a> {
+        DismissVulnerabilityBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DismissVulnerability<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("vulnerabilities/{}/dismiss", self.vulnerability).into()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+
+        params
+            .push_opt("comment", self.comment.as_ref())
+            .push_opt("dismissal_reason", self.dismissal_reason);
+
+        params.into_body()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::vulnerabilities::{
+        DismissVulnerability, DismissVulnerabilityBuilderError, VulnerabilityDismissalReason,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn vulnerability_dismissal_reason_as_str() {
+        let items = &[
+            (
+                VulnerabilityDismissalReason::AcceptableRisk,
+                "acceptable_risk",
+            ),
+            (
+                VulnerabilityDismissalReason::FalsePositive,
+                "false_positive",
+            ),
+            (
+                VulnerabilityDismissalReason::MitigatingControl,
+                "mitigating_control",
+            ),
+            (VulnerabilityDismissalReason::UsedInTests, "used_in_tests"),
+            (
+                VulnerabilityDismissalReason::NotApplicable,
+                "not_applicable",
+            ),
+        ];
+
+        for (i, s) in items {
+            assert_eq!(i.as_str(), *s);
+        }
+    }
+
+    #[test]
+    fn vulnerability_is_needed() {
+        let err = DismissVulnerability::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DismissVulnerabilityBuilderError, "vulnerability");
+    }
+
+    #[test]
+    fn vulnerability_is_sufficient() {
+        DismissVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("vulnerabilities/1/dismiss")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DismissVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_comment() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("vulnerabilities/1/dismiss")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("comment=not+exploitable")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DismissVulnerability::builder()
+            .vulnerability(1)
+            .comment("not exploitable")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn endpoint_dismissal_reason() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("vulnerabilities/1/dismiss")
+            .content_type("application/x-www-form-urlencoded")
+            .body_str("dismissal_reason=false_positive")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DismissVulnerability::builder()
+            .vulnerability(1)
+            .dismissal_reason(VulnerabilityDismissalReason::FalsePositive)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
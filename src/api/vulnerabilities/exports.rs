@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Vulnerability export API endpoints.
+//!
+//! These endpoints are used for exporting the vulnerabilities of projects.
+
+mod create;
+mod download;
+mod export;
+
+pub use self::create::CreateVulnerabilityExport;
+pub use self::create::CreateVulnerabilityExportBuilder;
+pub use self::create::CreateVulnerabilityExportBuilderError;
+
+pub use self::download::DownloadVulnerabilityExport;
+pub use self::download::DownloadVulnerabilityExportBuilder;
+pub use self::download::DownloadVulnerabilityExportBuilderError;
+
+pub use self::export::VulnerabilityExport;
+pub use self::export::VulnerabilityExportBuilder;
+pub use self::export::VulnerabilityExportBuilderError;
@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::common::NameOrId;
+use crate::api::endpoint_prelude::*;
+
+/// Create an export of the vulnerabilities of a project.
+///
+/// Exports are generated asynchronously; use `VulnerabilityExport` to poll its status and
+/// `DownloadVulnerabilityExport` to fetch it once it has finished.
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct CreateVulnerabilityExport<'a> {
+    /// The project to export vulnerabilities of.
+    #[builder(setter(into))]
+    project: NameOrId<'a>,
+}
+
+impl<'a> CreateVulnerabilityExport<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CreateVulnerabilityExportBuilder<'a> {
+        CreateVulnerabilityExportBuilder::default()
+    }
+}
+
+impl<'a> CreateVulnerabilityExportBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        NameOrId::validate_path_segment("project", self.project.as_ref())
+    }
+}
+
+impl<'a> Endpoint for CreateVulnerabilityExport<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("security/projects/{}/vulnerability_exports", self.project).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::vulnerabilities::exports::{
+        CreateVulnerabilityExport, CreateVulnerabilityExportBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn project_is_needed() {
+        let err = CreateVulnerabilityExport::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, CreateVulnerabilityExportBuilderError, "project");
+    }
+
+    #[test]
+    fn project_is_sufficient() {
+        CreateVulnerabilityExport::builder()
+            .project(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("security/projects/simple%2Fproject/vulnerability_exports")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CreateVulnerabilityExport::builder()
+            .project("simple/project")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
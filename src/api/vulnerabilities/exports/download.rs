@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Download a finished vulnerability export.
+///
+/// The export is a CSV file, so this should be used with `api::raw`.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DownloadVulnerabilityExport {
+    /// The ID of the export.
+    export: u64,
+}
+
+impl DownloadVulnerabilityExport {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> DownloadVulnerabilityExportBuilder {
+        DownloadVulnerabilityExportBuilder::default()
+    }
+}
+
+impl Endpoint for DownloadVulnerabilityExport {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("security/vulnerability_exports/{}/download", self.export).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::vulnerabilities::exports::{
+        DownloadVulnerabilityExport, DownloadVulnerabilityExportBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn export_is_needed() {
+        let err = DownloadVulnerabilityExport::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, DownloadVulnerabilityExportBuilderError, "export");
+    }
+
+    #[test]
+    fn export_is_sufficient() {
+        DownloadVulnerabilityExport::builder()
+            .export(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("security/vulnerability_exports/1/download")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = DownloadVulnerabilityExport::builder()
+            .export(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,65 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for the status of a vulnerability export.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct VulnerabilityExport {
+    /// The ID of the export.
+    export: u64,
+}
+
+impl VulnerabilityExport {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> VulnerabilityExportBuilder {
+        VulnerabilityExportBuilder::default()
+    }
+}
+
+impl Endpoint for VulnerabilityExport {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("security/vulnerability_exports/{}", self.export).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::vulnerabilities::exports::{
+        VulnerabilityExport, VulnerabilityExportBuilderError,
+    };
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn export_is_needed() {
+        let err = VulnerabilityExport::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, VulnerabilityExportBuilderError, "export");
+    }
+
+    #[test]
+    fn export_is_sufficient() {
+        VulnerabilityExport::builder().export(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("security/vulnerability_exports/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = VulnerabilityExport::builder().export(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Resolve a vulnerability.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ResolveVulnerability {
+    /// The ID of the vulnerability.
+    vulnerability: u64,
+}
+
+impl ResolveVulnerability {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> ResolveVulnerabilityBuilder {
+        ResolveVulnerabilityBuilder::default()
+    }
+}
+
+impl Endpoint for ResolveVulnerability {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("vulnerabilities/{}/resolve", self.vulnerability).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::vulnerabilities::{ResolveVulnerability, ResolveVulnerabilityBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn vulnerability_is_needed() {
+        let err = ResolveVulnerability::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, ResolveVulnerabilityBuilderError, "vulnerability");
+    }
+
+    #[test]
+    fn vulnerability_is_sufficient() {
+        ResolveVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("vulnerabilities/1/resolve")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = ResolveVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
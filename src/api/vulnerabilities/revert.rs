@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Revert a vulnerability.
+///
+/// This reverts the vulnerability to the `detected` state.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct RevertVulnerability {
+    /// The ID of the vulnerability.
+    vulnerability: u64,
+}
+
+impl RevertVulnerability {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RevertVulnerabilityBuilder {
+        RevertVulnerabilityBuilder::default()
+    }
+}
+
+impl Endpoint for RevertVulnerability {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("vulnerabilities/{}/revert", self.vulnerability).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+
+    use crate::api::vulnerabilities::{RevertVulnerability, RevertVulnerabilityBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn vulnerability_is_needed() {
+        let err = RevertVulnerability::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, RevertVulnerabilityBuilderError, "vulnerability");
+    }
+
+    #[test]
+    fn vulnerability_is_sufficient() {
+        RevertVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .method(Method::POST)
+            .endpoint("vulnerabilities/1/revert")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = RevertVulnerability::builder()
+            .vulnerability(1)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+
+/// Query for a vulnerability.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Vulnerability {
+    /// The ID of the vulnerability.
+    vulnerability: u64,
+}
+
+impl Vulnerability {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> VulnerabilityBuilder {
+        VulnerabilityBuilder::default()
+    }
+}
+
+impl Endpoint for Vulnerability {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("vulnerabilities/{}", self.vulnerability).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::vulnerabilities::{Vulnerability, VulnerabilityBuilderError};
+    use crate::api::{self, Query};
+    use crate::test::client::{ExpectedUrl, SingleTestClient};
+
+    #[test]
+    fn vulnerability_is_needed() {
+        let err = Vulnerability::builder().build().unwrap_err();
+        crate::test::assert_missing_field!(err, VulnerabilityBuilderError, "vulnerability");
+    }
+
+    #[test]
+    fn vulnerability_is_sufficient() {
+        Vulnerability::builder().vulnerability(1).build().unwrap();
+    }
+
+    #[test]
+    fn endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("vulnerabilities/1")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Vulnerability::builder().vulnerability(1).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+}
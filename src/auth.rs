@@ -4,13 +4,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::collections::hash_map::DefaultHasher;
+use std::error::Error as StdError;
+use std::fmt::{self, Debug};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
 use http::{HeaderMap, HeaderValue};
 use log::error;
 use thiserror::Error;
 
+use crate::api;
+use crate::api::oauth::{Token, TokenGrant};
 use crate::api::users::CurrentUser;
-use crate::api::{self, AsyncQuery, Query};
-use crate::types::UserPublic;
+#[cfg(feature = "client_async")]
+use crate::api::AsyncQuery;
+#[cfg(feature = "client_sync")]
+use crate::api::Query;
+use crate::types::{OAuthToken, UserPublic};
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -20,10 +32,68 @@ pub enum AuthError {
         #[from]
         source: http::header::InvalidHeaderValue,
     },
+    #[error("token provider error: {}", source)]
+    TokenProvider { source: TokenProviderError },
+}
+
+impl AuthError {
+    fn token_provider(source: TokenProviderError) -> Self {
+        AuthError::TokenProvider {
+            source,
+        }
+    }
 }
 
 type AuthResult<T> = Result<T, AuthError>;
 
+/// The error type returned by token providers.
+pub type TokenProviderError = Box<dyn StdError + Send + Sync>;
+
+/// A token supplied by a `TokenProvider`.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProvidedToken {
+    /// A personal, project, or group access token.
+    Personal(String),
+    /// An OAuth2 access token.
+    OAuth2(String),
+    /// A CI job token.
+    Job(String),
+}
+
+impl Debug for ProvidedToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Never print the token itself.
+        let kind = match self {
+            ProvidedToken::Personal(_) => "Personal",
+            ProvidedToken::OAuth2(_) => "OAuth2",
+            ProvidedToken::Job(_) => "Job",
+        };
+        f.debug_tuple(kind).field(&"<redacted>").finish()
+    }
+}
+
+/// A source of tokens which is consulted for every request.
+///
+/// This allows long-running services to rotate short-lived tokens (e.g., tokens mounted into a
+/// container or obtained through an external OAuth2 flow) without rebuilding the client.
+/// Providers are expected to cache their token; fetching a new one should only happen when it is
+/// about to expire.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait TokenProvider: Send + Sync {
+    /// The token to use for a request sent by a blocking client.
+    fn token(&self) -> Result<ProvidedToken, TokenProviderError>;
+
+    /// The token to use for a request sent by an asynchronous client.
+    ///
+    /// Defaults to calling `token`. Providers which refresh tokens using blocking operations
+    /// should override this.
+    async fn token_async(&self) -> Result<ProvidedToken, TokenProviderError> {
+        self.token()
+    }
+}
+
 /// A Gitlab API token
 ///
 /// Gitlab supports two kinds of tokens
@@ -33,6 +103,12 @@ pub enum Auth {
     Token(String),
     /// An OAuth2 token, obtained through the OAuth2 flow
     OAuth2(String),
+    /// An OAuth2 token which is refreshed once it expires
+    RefreshableOAuth2(Arc<OAuth2Session>),
+    /// A CI job token, available to CI jobs as `CI_JOB_TOKEN`
+    JobToken(String),
+    /// A token obtained from a provider for every request
+    Provider(Arc<dyn TokenProvider>),
     /// Unauthenticated access
     None,
 }
@@ -40,8 +116,8 @@ pub enum Auth {
 impl Auth {
     /// Adds the appropriate header to a set of headers.
     ///
-    /// Depending on the token type, this will be either the Private-Token header
-    /// or the Authorization header.
+    /// Depending on the token type, this will be either the Private-Token header,
+    /// the Job-Token header, or the Authorization header.
     ///
     /// Returns an error if the token string cannot be parsed as a header value.
     pub fn set_header<'a>(
@@ -49,16 +125,15 @@ impl Auth {
         headers: &'a mut HeaderMap<HeaderValue>,
     ) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
         match self {
-            Auth::Token(token) => {
-                let mut token_header_value = HeaderValue::from_str(token)?;
-                token_header_value.set_sensitive(true);
-                headers.insert("PRIVATE-TOKEN", token_header_value);
+            Auth::Token(token) => Self::set_token_header(headers, "PRIVATE-TOKEN", token)?,
+            Auth::OAuth2(token) => Self::set_bearer_header(headers, token)?,
+            Auth::RefreshableOAuth2(session) => {
+                Self::set_bearer_header(headers, &session.access_token())?
             },
-            Auth::OAuth2(token) => {
-                let value = format!("Bearer {}", token);
-                let mut token_header_value = HeaderValue::from_str(&value)?;
-                token_header_value.set_sensitive(true);
-                headers.insert(http::header::AUTHORIZATION, token_header_value);
+            Auth::JobToken(token) => Self::set_token_header(headers, "JOB-TOKEN", token)?,
+            Auth::Provider(provider) => {
+                let token = provider.token().map_err(AuthError::token_provider)?;
+                Self::set_provided_header(headers, &token)?
             },
             Auth::None => {},
         }
@@ -66,6 +141,90 @@ impl Auth {
         Ok(headers)
     }
 
+    #[cfg(feature = "client_async")]
+    /// Adds the appropriate header to a set of headers without blocking.
+    ///
+    /// This only differs from `set_header` when using a token provider.
+    pub async fn set_header_async<'a>(
+        &self,
+        headers: &'a mut HeaderMap<HeaderValue>,
+    ) -> AuthResult<&'a mut HeaderMap<HeaderValue>> {
+        if let Auth::Provider(provider) = self {
+            let token = provider
+                .token_async()
+                .await
+                .map_err(AuthError::token_provider)?;
+            Self::set_provided_header(headers, &token)?;
+            Ok(headers)
+        } else {
+            self.set_header(headers)
+        }
+    }
+
+    fn set_provided_header(
+        headers: &mut HeaderMap<HeaderValue>,
+        token: &ProvidedToken,
+    ) -> AuthResult<()> {
+        match token {
+            ProvidedToken::Personal(token) => {
+                Self::set_token_header(headers, "PRIVATE-TOKEN", token)
+            },
+            ProvidedToken::OAuth2(token) => Self::set_bearer_header(headers, token),
+            ProvidedToken::Job(token) => Self::set_token_header(headers, "JOB-TOKEN", token),
+        }
+    }
+
+    fn set_token_header(
+        headers: &mut HeaderMap<HeaderValue>,
+        name: &'static str,
+        token: &str,
+    ) -> AuthResult<()> {
+        let mut token_header_value = HeaderValue::from_str(token)?;
+        token_header_value.set_sensitive(true);
+        headers.insert(name, token_header_value);
+        Ok(())
+    }
+
+    fn set_bearer_header(headers: &mut HeaderMap<HeaderValue>, token: &str) -> AuthResult<()> {
+        let value = format!("Bearer {}", token);
+        let mut token_header_value = HeaderValue::from_str(&value)?;
+        token_header_value.set_sensitive(true);
+        headers.insert(http::header::AUTHORIZATION, token_header_value);
+        Ok(())
+    }
+
+    /// An identifier for the credentials.
+    ///
+    /// Tokens are hashed. Refreshable sessions and token providers may change their tokens, so
+    /// they are identified by the instance in use instead.
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    pub(crate) fn identity(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        match self {
+            Auth::Token(token) => ("token", token).hash(&mut hasher),
+            Auth::OAuth2(token) => ("oauth2", token).hash(&mut hasher),
+            Auth::RefreshableOAuth2(session) => {
+                ("session", Arc::as_ptr(session) as usize).hash(&mut hasher)
+            },
+            Auth::JobToken(token) => ("job", token).hash(&mut hasher),
+            Auth::Provider(provider) => {
+                ("provider", Arc::as_ptr(provider) as *const () as usize).hash(&mut hasher)
+            },
+            Auth::None => "none".hash(&mut hasher),
+        }
+        hasher.finish()
+    }
+
+    /// The session for refreshing the OAuth2 token, if any.
+    pub(crate) fn oauth2_session(&self) -> Option<&OAuth2Session> {
+        if let Auth::RefreshableOAuth2(session) = self {
+            Some(session)
+        } else {
+            None
+        }
+    }
+
+    #[cfg(feature = "client_sync")]
     pub fn check_connection<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
     where
         C: api::Client,
@@ -73,6 +232,9 @@ impl Auth {
         if let Self::None = self {
             // There does not seem to be an unparameterized endpoint that can be used to reliably
             // detect whether the connection will work or not.
+        } else if let Self::JobToken(_) = self {
+            // Job tokens may only access a limited set of endpoints which does not include the
+            // current user.
         } else {
             let _: UserPublic = CurrentUser::builder().build().unwrap().query(api)?;
         }
@@ -80,6 +242,7 @@ impl Auth {
         Ok(())
     }
 
+    #[cfg(feature = "client_async")]
     pub async fn check_connection_async<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
     where
         C: api::AsyncClient + Sync,
@@ -87,6 +250,9 @@ impl Auth {
         if let Self::None = self {
             // There does not seem to be an unparameterized endpoint that can be used to reliably
             // detect whether the connection will work or not.
+        } else if let Self::JobToken(_) = self {
+            // Job tokens may only access a limited set of endpoints which does not include the
+            // current user.
         } else {
             let _: UserPublic = CurrentUser::builder()
                 .build()
@@ -98,3 +264,351 @@ impl Auth {
         Ok(())
     }
 }
+
+type RefreshCallback = Arc<dyn Fn(&OAuthToken) + Send + Sync>;
+
+/// Settings for refreshing expired OAuth2 access tokens.
+///
+/// When a request is rejected as unauthorized, the client uses the refresh token to obtain a new
+/// access token and retries the request.
+#[derive(Clone)]
+pub struct OAuth2Refresh {
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: Option<String>,
+    refresh_token: String,
+    callback: Option<RefreshCallback>,
+}
+
+impl OAuth2Refresh {
+    /// Refresh tokens of the given application using a refresh token.
+    pub fn new<I, T>(client_id: I, refresh_token: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            client_id: client_id.into(),
+            client_secret: None,
+            redirect_uri: None,
+            refresh_token: refresh_token.into(),
+            callback: None,
+        }
+    }
+
+    /// The secret of the application.
+    ///
+    /// Not required for public applications.
+    pub fn client_secret<S>(mut self, client_secret: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    /// The redirect URI used when the application was authorized.
+    pub fn redirect_uri<U>(mut self, redirect_uri: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Call a function whenever the token has been refreshed.
+    ///
+    /// Refresh tokens may only be used once, so the new tokens should usually be persisted.
+    pub fn on_refresh<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&OAuthToken) + Send + Sync + 'static,
+    {
+        self.callback = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl Debug for OAuth2Refresh {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OAuth2Refresh")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish()
+    }
+}
+
+struct OAuth2Tokens {
+    access_token: String,
+    refresh_token: String,
+    // Incremented whenever the tokens are refreshed.
+    generation: u64,
+}
+
+/// The state of a refreshable OAuth2 token.
+pub struct OAuth2Session {
+    settings: OAuth2Refresh,
+    tokens: RwLock<OAuth2Tokens>,
+}
+
+impl OAuth2Session {
+    pub(crate) fn new(access_token: String, settings: OAuth2Refresh) -> Self {
+        let tokens = OAuth2Tokens {
+            access_token,
+            refresh_token: settings.refresh_token.clone(),
+            generation: 0,
+        };
+
+        Self {
+            settings,
+            tokens: RwLock::new(tokens),
+        }
+    }
+
+    fn access_token(&self) -> String {
+        self.tokens.read().unwrap().access_token.clone()
+    }
+
+    /// The generation of the current access token.
+    pub(crate) fn generation(&self) -> u64 {
+        self.tokens.read().unwrap().generation
+    }
+
+    /// The endpoint to use to refresh the given generation of the access token.
+    ///
+    /// Returns `None` if it has already been refreshed.
+    pub(crate) fn refresh_endpoint(&self, generation: u64) -> Option<Token<'static>> {
+        let tokens = self.tokens.read().unwrap();
+        if tokens.generation != generation {
+            return None;
+        }
+
+        let mut builder = Token::builder();
+        builder
+            .grant(TokenGrant::RefreshToken(
+                tokens.refresh_token.clone().into(),
+            ))
+            .client_id(self.settings.client_id.clone());
+        if let Some(client_secret) = self.settings.client_secret.as_ref() {
+            builder.client_secret(client_secret.clone());
+        }
+        if let Some(redirect_uri) = self.settings.redirect_uri.as_ref() {
+            builder.redirect_uri(redirect_uri.clone());
+        }
+        Some(builder.build().unwrap())
+    }
+
+    /// Store a refreshed token.
+    pub(crate) fn update(&self, generation: u64, token: OAuthToken) {
+        {
+            let mut tokens = self.tokens.write().unwrap();
+            if tokens.generation != generation {
+                return;
+            }
+
+            tokens.access_token = token.access_token.clone();
+            if let Some(refresh_token) = token.refresh_token.as_ref() {
+                tokens.refresh_token = refresh_token.clone();
+            }
+            tokens.generation += 1;
+        }
+
+        if let Some(callback) = self.settings.callback.as_ref() {
+            callback(&token);
+        }
+    }
+}
+
+impl Debug for OAuth2Session {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OAuth2Session")
+            .field("settings", &self.settings)
+            .field("generation", &self.generation())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use http::HeaderMap;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::auth::{
+        Auth, AuthError, OAuth2Refresh, OAuth2Session, ProvidedToken, TokenProvider,
+        TokenProviderError,
+    };
+    use crate::types::OAuthToken;
+
+    fn token(access_token: &str, refresh_token: Option<&str>) -> OAuthToken {
+        OAuthToken {
+            access_token: access_token.into(),
+            token_type: "bearer".into(),
+            expires_in: Some(7200),
+            refresh_token: refresh_token.map(Into::into),
+            scope: None,
+            created_at: None,
+        }
+    }
+
+    fn refresh_body(session: &OAuth2Session, generation: u64) -> Option<String> {
+        session.refresh_endpoint(generation).map(|endpoint| {
+            let (_, body) = endpoint.body().unwrap().unwrap();
+            String::from_utf8(body).unwrap()
+        })
+    }
+
+    fn bearer(auth: &Auth) -> String {
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+        headers[http::header::AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn refreshable_oauth2_header() {
+        let session = OAuth2Session::new("access".into(), OAuth2Refresh::new("app", "refresh"));
+        let auth = Auth::RefreshableOAuth2(Arc::new(session));
+
+        assert_eq!(bearer(&auth), "Bearer access");
+
+        let session = auth.oauth2_session().unwrap();
+        session.update(0, token("new-access", None));
+        assert_eq!(bearer(&auth), "Bearer new-access");
+    }
+
+    #[test]
+    fn job_token_header() {
+        let auth = Auth::JobToken("job-token".into());
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+
+        assert_eq!(headers["JOB-TOKEN"], "job-token");
+        assert!(headers["JOB-TOKEN"].is_sensitive());
+        assert!(!headers.contains_key("PRIVATE-TOKEN"));
+    }
+
+    #[test]
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    fn identity() {
+        let token = Auth::Token("token".into());
+        assert_eq!(token.identity(), Auth::Token("token".into()).identity());
+        assert_ne!(token.identity(), Auth::Token("other".into()).identity());
+        assert_ne!(token.identity(), Auth::OAuth2("token".into()).identity());
+        assert_ne!(token.identity(), Auth::JobToken("token".into()).identity());
+        assert_ne!(token.identity(), Auth::None.identity());
+
+        let session = OAuth2Session::new("access".into(), OAuth2Refresh::new("app", "refresh"));
+        let session = Auth::RefreshableOAuth2(Arc::new(session));
+        assert_eq!(session.identity(), session.clone().identity());
+        let other = OAuth2Session::new("access".into(), OAuth2Refresh::new("app", "refresh"));
+        assert_ne!(
+            session.identity(),
+            Auth::RefreshableOAuth2(Arc::new(other)).identity(),
+        );
+    }
+
+    #[derive(Default)]
+    struct RotatingProvider {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl TokenProvider for RotatingProvider {
+        fn token(&self) -> Result<ProvidedToken, TokenProviderError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            match call {
+                0 => Ok(ProvidedToken::Personal(format!("token-{}", call))),
+                1 => Ok(ProvidedToken::OAuth2(format!("token-{}", call))),
+                2 => Ok(ProvidedToken::Job(format!("token-{}", call))),
+                _ => Err("provider is exhausted".into()),
+            }
+        }
+
+        async fn token_async(&self) -> Result<ProvidedToken, TokenProviderError> {
+            Ok(ProvidedToken::OAuth2("async-token".into()))
+        }
+    }
+
+    #[test]
+    fn provider_is_consulted_per_request() {
+        let auth = Auth::Provider(Arc::new(RotatingProvider::default()));
+
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+        assert_eq!(headers["PRIVATE-TOKEN"], "token-0");
+        assert!(headers["PRIVATE-TOKEN"].is_sensitive());
+
+        assert_eq!(bearer(&auth), "Bearer token-1");
+
+        let mut headers = HeaderMap::new();
+        auth.set_header(&mut headers).unwrap();
+        assert_eq!(headers["JOB-TOKEN"], "token-2");
+
+        let mut headers = HeaderMap::new();
+        let err = auth.set_header(&mut headers).unwrap_err();
+        if let AuthError::TokenProvider {
+            source,
+        } = err
+        {
+            assert_eq!(source.to_string(), "provider is exhausted");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[cfg(feature = "client_async")]
+    #[tokio::test]
+    async fn provider_async() {
+        let auth = Auth::Provider(Arc::new(RotatingProvider::default()));
+
+        let mut headers = HeaderMap::new();
+        auth.set_header_async(&mut headers).await.unwrap();
+        assert_eq!(headers[http::header::AUTHORIZATION], "Bearer async-token");
+    }
+
+    #[test]
+    fn provided_token_debug_is_redacted() {
+        let token = ProvidedToken::Personal("secret".into());
+        assert_eq!(format!("{:?}", token), "Personal(\"<redacted>\")");
+    }
+
+    #[test]
+    fn refresh_endpoint_uses_latest_refresh_token() {
+        let session = OAuth2Session::new("access".into(), OAuth2Refresh::new("app", "refresh"));
+
+        assert_eq!(
+            refresh_body(&session, 0).unwrap(),
+            "grant_type=refresh_token&refresh_token=refresh&client_id=app",
+        );
+
+        session.update(0, token("new-access", Some("new-refresh")));
+        assert_eq!(session.generation(), 1);
+        assert_eq!(refresh_body(&session, 0), None);
+        assert_eq!(
+            refresh_body(&session, 1).unwrap(),
+            "grant_type=refresh_token&refresh_token=new-refresh&client_id=app",
+        );
+    }
+
+    #[test]
+    fn stale_updates_are_ignored() {
+        let refreshed = Arc::new(Mutex::new(Vec::new()));
+        let settings = {
+            let refreshed = refreshed.clone();
+            OAuth2Refresh::new("app", "refresh")
+                .on_refresh(move |token| refreshed.lock().unwrap().push(token.access_token.clone()))
+        };
+        let session = OAuth2Session::new("access".into(), settings);
+
+        session.update(0, token("first", Some("first-refresh")));
+        session.update(0, token("second", Some("second-refresh")));
+
+        assert_eq!(session.access_token(), "first");
+        assert_eq!(*refreshed.lock().unwrap(), ["first"]);
+    }
+}
@@ -0,0 +1,181 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Platform-specific parts of the HTTP backend.
+//!
+//! On `wasm32` targets, `reqwest` sends requests using the `fetch` API of the host. There is no
+//! system clock, timers are provided by the JavaScript event loop, and the browser manages
+//! connections (including TLS, proxies, and timeouts).
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use self::wasm::Instant;
+
+#[cfg(feature = "client_async")]
+pub(crate) use self::async_backend::*;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use std::cmp::Ordering;
+    use std::ops::{Add, Sub};
+    use std::time::Duration;
+
+    /// A measurement of the clock of the JavaScript host.
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub(crate) struct Instant {
+        /// Milliseconds since the Unix epoch; never NaN.
+        millis: f64,
+    }
+
+    impl Eq for Instant {}
+
+    impl PartialOrd for Instant {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Instant {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.millis
+                .partial_cmp(&other.millis)
+                .unwrap_or(Ordering::Equal)
+        }
+    }
+
+    impl Instant {
+        pub(crate) fn now() -> Self {
+            Self {
+                millis: js_sys::Date::now(),
+            }
+        }
+
+        pub(crate) fn elapsed(&self) -> Duration {
+            Self::now() - *self
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Self;
+
+        fn add(self, rhs: Duration) -> Self {
+            Self {
+                millis: self.millis + rhs.as_secs_f64() * 1000.,
+            }
+        }
+    }
+
+    impl Sub for Instant {
+        type Output = Duration;
+
+        fn sub(self, rhs: Self) -> Duration {
+            // The JavaScript clock may be adjusted; saturate rather than panic.
+            Duration::from_secs_f64((self.millis - rhs.millis).max(0.) / 1000.)
+        }
+    }
+}
+
+#[cfg(feature = "client_async")]
+mod async_backend {
+    use std::time::Duration;
+
+    use http::Version;
+    use tokio::sync::OwnedSemaphorePermit;
+
+    use crate::api::{ApiError, BodyStream};
+    use crate::gitlab::RestError;
+
+    /// Wait for the given amount of time.
+    pub(crate) async fn sleep(duration: Duration) {
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::time::sleep(duration).await;
+        #[cfg(target_arch = "wasm32")]
+        gloo_timers::future::sleep(duration).await;
+    }
+
+    /// Set the timeout for a request.
+    ///
+    /// Timeouts are not supported by `fetch` and are ignored.
+    pub(crate) fn set_timeout(request: &mut reqwest::Request, timeout: Duration) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            *request.timeout_mut() = Some(timeout);
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = (request, timeout);
+        }
+    }
+
+    /// The HTTP version of a response.
+    pub(crate) fn version(rsp: &reqwest::Response) -> Version {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            rsp.version()
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            // `fetch` does not expose the version.
+            let _ = rsp;
+            Version::default()
+        }
+    }
+
+    /// Stream the body of a response.
+    ///
+    /// The throttle permit of the request is released once the body has been read.
+    pub(crate) fn body_stream(
+        rsp: reqwest::Response,
+        permit: Option<OwnedSemaphorePermit>,
+    ) -> BodyStream<RestError> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use futures_util::stream;
+
+            let chunks = stream::unfold(Some((rsp, permit)), |state| {
+                async move {
+                    let (mut rsp, permit) = state?;
+                    match rsp.chunk().await {
+                        Ok(Some(chunk)) => Some((Ok(chunk), Some((rsp, permit)))),
+                        Ok(None) => None,
+                        Err(err) => Some((Err(ApiError::client(err.into())), None)),
+                    }
+                }
+            });
+            Box::pin(chunks)
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            use futures_util::{stream, FutureExt, TryFutureExt};
+
+            // The body is read as a whole since `fetch` bodies cannot be read in chunks.
+            let body = rsp
+                .bytes()
+                .map_err(|err| ApiError::client(RestError::from(err)))
+                .map(move |body| {
+                    drop(permit);
+                    body
+                });
+            Box::pin(stream::once(body))
+        }
+    }
+}
+
+/// Whether an error occurred while connecting to the server.
+pub(crate) fn is_connect(err: &reqwest::Error) -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        err.is_connect()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        // `fetch` does not distinguish connection errors.
+        let _ = err;
+        false
+    }
+}
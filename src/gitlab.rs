@@ -6,26 +6,52 @@
 
 use std::any;
 use std::convert::TryInto;
+use std::env;
 use std::fmt::{self, Debug};
+use std::sync::Arc;
+#[cfg(feature = "client_sync")]
+use std::thread;
+use std::time::Duration;
 
+#[cfg(feature = "client_async")]
 use async_trait::async_trait;
 use bytes::Bytes;
 use graphql_client::{GraphQLQuery, QueryBody, Response};
-use http::{HeaderMap, Response as HttpResponse};
+use http::Response as HttpResponse;
 use itertools::Itertools;
 use log::{debug, error, info};
+#[cfg(feature = "client_sync")]
 use reqwest::blocking::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::tls::Version as TlsVersion;
+#[cfg(feature = "client_async")]
 use reqwest::Client as AsyncClient;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{Certificate, Identity as TlsIdentity, NoProxy, Proxy};
+#[cfg(not(target_arch = "wasm32"))]
+use rustls::client::ServerCertVerifier;
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+#[cfg(feature = "client_async")]
+use tokio::sync::OwnedSemaphorePermit;
 use url::Url;
 
-#[cfg(any(feature = "client_der", feature = "client_pem"))]
-use reqwest::Identity as TlsIdentity;
-
 use crate::api;
-use crate::auth::{Auth, AuthError};
+use crate::api::rate_limit::RateLimitPolicy;
+use crate::api::retry::RetryPolicy;
+#[cfg(feature = "client_async")]
+use crate::api::AsyncQuery;
+#[cfg(feature = "client_sync")]
+use crate::api::Query;
+use crate::api::QueryTimeout;
+use crate::auth::{Auth, AuthError, OAuth2Refresh, OAuth2Session, TokenProvider};
+use crate::backend::{self, Instant};
+use crate::request_hook::{RequestHook, RequestHooks};
+#[cfg(feature = "client_async")]
+use crate::throttle::{Throttle, ThrottleSettings};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::tls;
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -47,6 +73,10 @@ pub enum GitlabError {
     },
     #[error("gitlab HTTP error: {}", status)]
     Http { status: reqwest::StatusCode },
+    /// The GraphQL API reported errors.
+    ///
+    /// Each error carries its message along with the locations in the query, the path into the
+    /// response, and any extensions reported by GitLab.
     #[allow(clippy::upper_case_acronyms)]
     #[error("graphql error: [\"{}\"]", message.iter().format("\", \""))]
     GraphQL { message: Vec<graphql_client::Error> },
@@ -63,6 +93,13 @@ pub enum GitlabError {
         #[from]
         source: api::ApiError<RestError>,
     },
+    #[error("missing environment variable: {}", names.join(" or "))]
+    MissingEnvironmentVariable { names: &'static [&'static str] },
+    #[error("invalid value for environment variable {}: {:?}", name, value)]
+    InvalidEnvironmentVariable { name: &'static str, value: String },
+    /// The TLS settings of the client could not be applied.
+    #[error("invalid TLS configuration: {}", message)]
+    TlsConfiguration { message: String },
 }
 
 impl GitlabError {
@@ -82,6 +119,41 @@ impl GitlabError {
         GitlabError::NoResponse {}
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn tls_configuration<M>(message: M) -> Self
+    where
+        M: fmt::Display,
+    {
+        GitlabError::TlsConfiguration {
+            message: message.to_string(),
+        }
+    }
+
+    /// The errors reported by the GraphQL API, if any.
+    pub fn graphql_errors(&self) -> Option<&[graphql_client::Error]> {
+        if let GitlabError::GraphQL {
+            message,
+        } = self
+        {
+            Some(message)
+        } else {
+            None
+        }
+    }
+
+    fn missing_environment_variable(names: &'static [&'static str]) -> Self {
+        GitlabError::MissingEnvironmentVariable {
+            names,
+        }
+    }
+
+    fn invalid_environment_variable(name: &'static str, value: String) -> Self {
+        GitlabError::InvalidEnvironmentVariable {
+            name,
+            value,
+        }
+    }
+
     fn data_type<T>(source: serde_json::Error) -> Self {
         GitlabError::DataType {
             source,
@@ -90,8 +162,62 @@ impl GitlabError {
     }
 }
 
+impl From<RestError> for GitlabError {
+    fn from(err: RestError) -> Self {
+        match err {
+            RestError::AuthError {
+                source,
+            } => source.into(),
+            RestError::Communication {
+                source,
+            } => source.into(),
+            err => api::ApiError::client(err).into(),
+        }
+    }
+}
+
 type GitlabResult<T> = Result<T, GitlabError>;
 
+/// Build the request for a GraphQL query.
+fn graphql_request<V>(
+    url: &Url,
+    query: &QueryBody<V>,
+) -> GitlabResult<(http::request::Builder, Vec<u8>)>
+where
+    V: Serialize,
+{
+    let body = serde_json::to_vec(query).map_err(GitlabError::data_type::<QueryBody<V>>)?;
+    let request = http::Request::builder()
+        .method(http::Method::POST)
+        .uri(url.as_str())
+        .header(http::header::CONTENT_TYPE, "application/json");
+    Ok((request, body))
+}
+
+/// The pagination state of a GraphQL connection.
+///
+/// This mirrors the Relay `PageInfo` type used by GitLab's GraphQL connections. Queries used with
+/// `graphql_paged` convert their own `pageInfo` field into this type.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLPageInfo {
+    /// Whether there are more results after this page.
+    pub has_next_page: bool,
+    /// The cursor of the last result of this page.
+    pub end_cursor: Option<String>,
+}
+
+impl GraphQLPageInfo {
+    /// The cursor to request the next page with, if there is one.
+    fn next_cursor(self) -> Option<String> {
+        if self.has_next_page {
+            self.end_cursor
+        } else {
+            None
+        }
+    }
+}
+
 // Private enum that enables the parsing of the cert bytes to be
 // delayed until the client is built rather than when they're passed
 // to a builder.
@@ -104,38 +230,361 @@ enum ClientCert {
     Pem(Vec<u8>),
 }
 
+impl ClientCert {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn identity(&self) -> GitlabResult<Option<TlsIdentity>> {
+        Ok(match self {
+            ClientCert::None => None,
+            #[cfg(feature = "client_der")]
+            ClientCert::Der(der, password) => Some(TlsIdentity::from_pkcs12_der(der, password)?),
+            #[cfg(feature = "client_pem")]
+            ClientCert::Pem(pem) => Some(TlsIdentity::from_pem(pem)?),
+        })
+    }
+}
+
+// Additional root certificates are also parsed when the client is built.
+#[derive(Clone)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+enum RootCert {
+    Der(Vec<u8>),
+    Pem(Vec<u8>),
+}
+
+impl RootCert {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn certificates(&self) -> GitlabResult<Vec<Certificate>> {
+        Ok(match self {
+            RootCert::Der(der) => vec![Certificate::from_der(der)?],
+            RootCert::Pem(pem) => Certificate::from_pem_bundle(pem)?,
+        })
+    }
+}
+
 /// A representation of the Gitlab API for a single user.
 ///
 /// Separate users should use separate instances of this.
+#[cfg(feature = "client_sync")]
 #[derive(Clone)]
 pub struct Gitlab {
     /// The client to use for API calls.
     client: Client,
     /// The base URL to use for API calls.
     rest_url: Url,
+    /// The base URL to use for instance endpoints outside of the API.
+    instance_url: Url,
     /// The URL to use for GraphQL API calls.
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// Policies for retrying failed requests.
+    policies: RequestPolicies,
 }
 
+#[cfg(feature = "client_sync")]
 impl Debug for Gitlab {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("Gitlab")
             .field("rest_url", &self.rest_url)
+            .field("instance_url", &self.instance_url)
             .field("graphql_url", &self.graphql_url)
+            .field("policies", &self.policies)
             .finish()
     }
 }
 
-/// Should a certificate be validated in tls connections.
-/// The Insecure option is used for self-signed certificates.
+// Proxy settings are also validated when the client is built.
+#[derive(Clone, Default)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+struct ProxySettings {
+    url: Option<String>,
+    basic_auth: Option<(String, String)>,
+    no_proxy: Option<String>,
+    ignore_environment: bool,
+}
+
+impl ProxySettings {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn proxy(&self) -> GitlabResult<Option<Proxy>> {
+        let url = if let Some(url) = self.url.as_ref() {
+            url
+        } else {
+            return Ok(None);
+        };
+
+        let mut proxy = Proxy::all(url.as_str())?;
+        if let Some((username, password)) = self.basic_auth.as_ref() {
+            proxy = proxy.basic_auth(username, password);
+        }
+        if let Some(no_proxy) = self.no_proxy.as_ref() {
+            proxy = proxy.no_proxy(NoProxy::from_string(no_proxy));
+        }
+
+        Ok(Some(proxy))
+    }
+}
+
+/// How the certificate of the server is validated in TLS connections.
 #[derive(Debug, Clone)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
 enum CertPolicy {
+    /// Validate the certificate chain and the host name.
     Default,
+    /// Accept any certificate (e.g., self-signed certificates).
     Insecure,
+    /// Validate the certificate chain, but accept any host name.
+    AnyHostname,
+    /// Accept only certificates with one of the given SHA-256 fingerprints.
+    Pinned(Vec<[u8; 32]>),
+}
+
+/// Options for the HTTP client used to communicate with Gitlab.
+///
+/// On `wasm32` targets, the browser manages connections and only the `User-Agent` is used.
+#[derive(Clone)]
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+struct ClientOptions {
+    /// Whether to validate certificates.
+    cert_validation: CertPolicy,
+    /// The identity to present to the server.
+    identity: ClientCert,
+    /// Additional certificates to trust.
+    root_certs: Vec<RootCert>,
+    /// Whether to trust the built-in root certificates.
+    built_in_root_certs: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    /// The minimum TLS version to accept.
+    min_tls_version: Option<TlsVersion>,
+    /// The proxy to send requests through.
+    proxy: ProxySettings,
+    /// The `User-Agent` header to send.
+    user_agent: Option<String>,
+    /// How long to wait for a connection to be established.
+    connect_timeout: Option<Duration>,
+    /// How long to wait for a request to complete.
+    timeout: Option<Duration>,
+    #[cfg(feature = "client_sync")]
+    /// A preconfigured blocking client to use instead of building one.
+    blocking_client: Option<Client>,
+    #[cfg(feature = "client_async")]
+    /// A preconfigured asynchronous client to use instead of building one.
+    async_client: Option<AsyncClient>,
+}
+
+impl ClientOptions {
+    fn new(cert_validation: CertPolicy) -> Self {
+        Self {
+            cert_validation,
+            identity: ClientCert::None,
+            root_certs: Vec::new(),
+            built_in_root_certs: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            min_tls_version: None,
+            proxy: ProxySettings::default(),
+            user_agent: None,
+            connect_timeout: None,
+            timeout: None,
+            #[cfg(feature = "client_sync")]
+            blocking_client: None,
+            #[cfg(feature = "client_async")]
+            async_client: None,
+        }
+    }
+
+    #[cfg(feature = "client_sync")]
+    fn blocking_client(&self) -> GitlabResult<Client> {
+        if let Some(client) = self.blocking_client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let mut builder = match &self.cert_validation {
+            CertPolicy::Insecure => Client::builder().danger_accept_invalid_certs(true),
+            CertPolicy::Default | CertPolicy::AnyHostname | CertPolicy::Pinned(_) => {
+                Client::builder()
+            },
+        };
+
+        // The preconfigured TLS settings include the identity and root certificates.
+        if let Some(tls) = self.rustls_config()? {
+            builder = builder.use_preconfigured_tls(tls);
+        } else {
+            if let Some(id) = self.identity.identity()? {
+                builder = builder.identity(id);
+            }
+
+            for root_cert in &self.root_certs {
+                for cert in root_cert.certificates()? {
+                    builder = builder.add_root_certificate(cert);
+                }
+            }
+            if !self.built_in_root_certs {
+                builder = builder.tls_built_in_root_certs(false);
+            }
+            if let Some(version) = self.min_tls_version {
+                builder = builder.min_tls_version(version);
+            }
+        }
+        if let Some(proxy) = self.proxy.proxy()? {
+            builder = builder.proxy(proxy);
+        } else if self.proxy.ignore_environment {
+            builder = builder.no_proxy();
+        }
+        if let Some(user_agent) = self.user_agent.as_ref() {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    #[cfg(feature = "client_async")]
+    fn async_client(&self) -> GitlabResult<AsyncClient> {
+        if let Some(client) = self.async_client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let mut builder = AsyncClient::builder();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let CertPolicy::Insecure = &self.cert_validation {
+                builder = builder.danger_accept_invalid_certs(true);
+            }
+
+            // The preconfigured TLS settings include the identity and root certificates.
+            if let Some(tls) = self.rustls_config()? {
+                builder = builder.use_preconfigured_tls(tls);
+            } else {
+                if let Some(id) = self.identity.identity()? {
+                    builder = builder.identity(id);
+                }
+
+                for root_cert in &self.root_certs {
+                    for cert in root_cert.certificates()? {
+                        builder = builder.add_root_certificate(cert);
+                    }
+                }
+                if !self.built_in_root_certs {
+                    builder = builder.tls_built_in_root_certs(false);
+                }
+                if let Some(version) = self.min_tls_version {
+                    builder = builder.min_tls_version(version);
+                }
+            }
+            if let Some(proxy) = self.proxy.proxy()? {
+                builder = builder.proxy(proxy);
+            } else if self.proxy.ignore_environment {
+                builder = builder.no_proxy();
+            }
+            if let Some(connect_timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+        }
+        if let Some(user_agent) = self.user_agent.as_ref() {
+            builder = builder.user_agent(user_agent);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// The TLS configuration for certificate policies which `reqwest` does not support.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn rustls_config(&self) -> GitlabResult<Option<rustls::ClientConfig>> {
+        let verifier: Arc<dyn ServerCertVerifier> = match &self.cert_validation {
+            CertPolicy::Default | CertPolicy::Insecure => return Ok(None),
+            CertPolicy::AnyHostname => {
+                let mut certs = Vec::new();
+                for root_cert in &self.root_certs {
+                    match root_cert {
+                        RootCert::Der(der) => certs.push(rustls::Certificate(der.clone())),
+                        RootCert::Pem(pem) => {
+                            certs.extend(
+                                tls::pem_certificates(pem)
+                                    .map_err(GitlabError::tls_configuration)?,
+                            )
+                        },
+                    }
+                }
+                let roots = tls::root_store(certs, self.built_in_root_certs)
+                    .map_err(GitlabError::tls_configuration)?;
+                Arc::new(tls::AnyHostnameVerifier::new(roots))
+            },
+            CertPolicy::Pinned(fingerprints) => {
+                Arc::new(tls::PinnedVerifier::new(fingerprints.clone()))
+            },
+        };
+
+        let builder = rustls::ClientConfig::builder()
+            .with_safe_default_cipher_suites()
+            .with_safe_default_kx_groups()
+            .with_protocol_versions(&tls::protocol_versions(self.min_tls_version))
+            .map_err(GitlabError::tls_configuration)?
+            .with_custom_certificate_verifier(verifier);
+        let mut config = match &self.identity {
+            ClientCert::None => builder.with_no_client_auth(),
+            #[cfg(feature = "client_der")]
+            ClientCert::Der(..) => {
+                return Err(GitlabError::tls_configuration(
+                    "PKCS#12 client identities are not supported with certificate pinning or \
+                     host name agnostic validation",
+                ));
+            },
+            #[cfg(feature = "client_pem")]
+            ClientCert::Pem(pem) => {
+                let (certs, key) =
+                    tls::pem_identity(pem).map_err(GitlabError::tls_configuration)?;
+                builder
+                    .with_client_auth_cert(certs, key)
+                    .map_err(GitlabError::tls_configuration)?
+            },
+        };
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+        Ok(Some(config))
+    }
 }
 
+/// The base URLs of the APIs of an instance.
+struct InstanceUrls {
+    rest_url: Url,
+    instance_url: Url,
+    graphql_url: Url,
+}
+
+impl InstanceUrls {
+    /// Compute the URLs for an instance.
+    ///
+    /// The host may include a path prefix for instances served under a relative URL (e.g.,
+    /// `gitlab.example.com/gitlab`).
+    fn new(protocol: &str, host: &str) -> GitlabResult<Self> {
+        let host = host.trim_end_matches('/');
+        let instance_url = Url::parse(&format!("{}://{}/", protocol, host))?;
+
+        Ok(Self {
+            rest_url: instance_url.join("api/v4/")?,
+            graphql_url: instance_url.join("api/graphql")?,
+            instance_url,
+        })
+    }
+}
+
+/// Join an endpoint onto a base URL.
+///
+/// Leading slashes are ignored so that endpoints always stay under the base URL rather than
+/// replacing any path prefix of the instance.
+fn join_endpoint(base: &Url, endpoint: &str) -> Result<Url, url::ParseError> {
+    base.join(endpoint.trim_start_matches('/'))
+}
+
+#[cfg(feature = "client_sync")]
 impl Gitlab {
     /// Create a new Gitlab API representation.
     ///
@@ -150,8 +599,8 @@ impl Gitlab {
             "https",
             host.as_ref(),
             Auth::Token(token.into()),
-            CertPolicy::Default,
-            ClientCert::None,
+            ClientOptions::new(CertPolicy::Default),
+            RequestPolicies::default(),
         )
     }
 
@@ -167,8 +616,8 @@ impl Gitlab {
             "http",
             host.as_ref(),
             Auth::Token(token.into()),
-            CertPolicy::Insecure,
-            ClientCert::None,
+            ClientOptions::new(CertPolicy::Insecure),
+            RequestPolicies::default(),
         )
     }
 
@@ -185,8 +634,8 @@ impl Gitlab {
             "https",
             host.as_ref(),
             Auth::OAuth2(token.into()),
-            CertPolicy::Default,
-            ClientCert::None,
+            ClientOptions::new(CertPolicy::Default),
+            RequestPolicies::default(),
         )
     }
 
@@ -203,8 +652,8 @@ impl Gitlab {
             "http",
             host.as_ref(),
             Auth::OAuth2(token.into()),
-            CertPolicy::Default,
-            ClientCert::None,
+            ClientOptions::new(CertPolicy::Default),
+            RequestPolicies::default(),
         )
     }
 
@@ -213,40 +662,24 @@ impl Gitlab {
         protocol: &str,
         host: &str,
         auth: Auth,
-        cert_validation: CertPolicy,
-        identity: ClientCert,
+        options: ClientOptions,
+        policies: RequestPolicies,
     ) -> GitlabResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
-        let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
+        let InstanceUrls {
+            rest_url,
+            instance_url,
+            graphql_url,
+        } = InstanceUrls::new(protocol, host)?;
 
-        let client = match cert_validation {
-            CertPolicy::Insecure => {
-                Client::builder()
-                    .danger_accept_invalid_certs(true)
-                    .build()?
-            },
-            CertPolicy::Default => {
-                match identity {
-                    ClientCert::None => Client::new(),
-                    #[cfg(feature = "client_der")]
-                    ClientCert::Der(der, password) => {
-                        let id = TlsIdentity::from_pkcs12_der(&der, &password)?;
-                        Client::builder().identity(id).build()?
-                    },
-                    #[cfg(feature = "client_pem")]
-                    ClientCert::Pem(pem) => {
-                        let id = TlsIdentity::from_pem(&pem)?;
-                        Client::builder().identity(id).build()?
-                    },
-                }
-            },
-        };
+        let client = options.blocking_client()?;
 
         let api = Gitlab {
             client,
             rest_url,
+            instance_url,
             graphql_url,
             auth,
+            policies,
         };
 
         // Ensure the API is working.
@@ -265,6 +698,10 @@ impl Gitlab {
     }
 
     /// Send a GraphQL query.
+    ///
+    /// The request is sent with the same authentication, rate limit, retry, and hook policies as
+    /// REST requests. GraphQL requests use `POST`, so they are only retried on error statuses if
+    /// the retry policy allows retrying non-idempotent requests.
     pub fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> GitlabResult<Q::ResponseData>
     where
         Q: GraphQLQuery,
@@ -277,8 +714,8 @@ impl Gitlab {
             query.operation_name,
             query.variables,
         );
-        let req = self.client.post(self.graphql_url.clone()).json(query);
-        let rsp: Response<Q::ResponseData> = self.send(req)?;
+        let (request, body) = graphql_request(&self.graphql_url, query)?;
+        let rsp: Response<Q::ResponseData> = self.send(request, body)?;
 
         if let Some(errs) = rsp.errors {
             return Err(GitlabError::graphql(errs));
@@ -286,23 +723,59 @@ impl Gitlab {
         rsp.data.ok_or_else(GitlabError::no_response)
     }
 
-    /// Refactored code which talks to Gitlab and transforms error messages properly.
-    fn send<T>(&self, req: reqwest::blocking::RequestBuilder) -> GitlabResult<T>
+    /// Send a GraphQL query built from its variables.
+    pub fn graphql_query<Q>(&self, variables: Q::Variables) -> GitlabResult<Q::ResponseData>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+    {
+        self.graphql::<Q>(&Q::build_query(variables))
+    }
+
+    /// Send a GraphQL query for all pages of a connection.
+    ///
+    /// The `variables` function is called with the cursor of the page to request (`None` for the
+    /// first page) and the `page` function extracts the results and the pagination state of the
+    /// connection from each response. Pages are requested until the connection has no more
+    /// results.
+    pub fn graphql_paged<Q, V, P, T>(&self, mut variables: V, mut page: P) -> GitlabResult<Vec<T>>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+        V: FnMut(Option<String>) -> Q::Variables,
+        P: FnMut(Q::ResponseData) -> (Vec<T>, GraphQLPageInfo),
+    {
+        let mut results = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let data = self.graphql_query::<Q>(variables(cursor.take()))?;
+            let (items, page_info) = page(data);
+            results.extend(items);
+
+            cursor = page_info.next_cursor();
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Send a GraphQL request, applying the policies of the client.
+    fn send<T>(&self, request: http::request::Builder, body: Vec<u8>) -> GitlabResult<T>
     where
         T: DeserializeOwned,
     {
-        let auth_headers = {
-            let mut headers = HeaderMap::default();
-            self.auth.set_header(&mut headers)?;
-            headers
-        };
-        let rsp = req.headers(auth_headers).send()?;
+        let rsp = self.send_rest(request, body)?;
         let status = rsp.status();
         if status.is_server_error() {
             return Err(GitlabError::http(status));
         }
 
-        serde_json::from_reader::<_, T>(rsp).map_err(GitlabError::data_type::<T>)
+        serde_json::from_reader::<_, T>(rsp.into_body()).map_err(GitlabError::data_type::<T>)
     }
 }
 
@@ -324,39 +797,302 @@ pub enum RestError {
         #[from]
         source: http::Error,
     },
+    #[error("failed to refresh the OAuth2 token: {}", source)]
+    OAuth2Refresh {
+        source: Box<api::ApiError<RestError>>,
+    },
+}
+
+impl RestError {
+    fn oauth2_refresh(source: api::ApiError<RestError>) -> Self {
+        RestError::OAuth2Refresh {
+            source: Box::new(source),
+        }
+    }
+}
+
+/// Marks the request refreshing an OAuth2 token.
+///
+/// Such requests are sent without authentication and are not retried when unauthorized.
+#[derive(Debug, Clone, Copy)]
+struct OAuth2Refreshing;
+
+impl OAuth2Refreshing {
+    fn is_set(request: &http::request::Builder) -> bool {
+        request
+            .extensions_ref()
+            .and_then(|extensions| extensions.get::<Self>())
+            .is_some()
+    }
+}
+
+/// State for refreshing an OAuth2 token when a request is unauthorized.
+struct Refresher<'a> {
+    session: Option<&'a OAuth2Session>,
+    generation: u64,
+}
+
+impl<'a> Refresher<'a> {
+    fn new(auth: &'a Auth, refreshing: bool) -> Self {
+        let session = auth.oauth2_session().filter(|_| !refreshing);
+        let generation = session.map_or(0, OAuth2Session::generation);
+
+        Self {
+            session,
+            generation,
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// The session to refresh, if the request was rejected as unauthorized.
+    ///
+    /// Only a single refresh is attempted per request.
+    fn refresh<B>(
+        &mut self,
+        rsp: &Result<HttpResponse<B>, RestError>,
+    ) -> Option<(&'a OAuth2Session, u64)> {
+        match rsp {
+            Ok(rsp) if rsp.status() == http::StatusCode::UNAUTHORIZED => {
+                self.session
+                    .take()
+                    .map(|session| (session, self.generation))
+            },
+            _ => None,
+        }
+    }
 }
 
+#[cfg(feature = "client_sync")]
 impl api::RestClient for Gitlab {
     type Error = RestError;
 
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
         debug!(target: "gitlab", "REST api call {}", endpoint);
-        Ok(self.rest_url.join(endpoint)?)
+        Ok(join_endpoint(&self.rest_url, endpoint)?)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        debug!(target: "gitlab", "instance call {}", endpoint);
+        Ok(join_endpoint(&self.instance_url, endpoint)?)
+    }
+
+    fn identity(&self) -> Option<u64> {
+        Some(self.auth.identity())
     }
 }
 
+#[cfg(feature = "client_sync")]
 impl api::Client for Gitlab {
     fn rest(
         &self,
-        mut request: http::request::Builder,
+        request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
         let call = || -> Result<_, RestError> {
+            let (parts, rsp) = self.send_rest(request, body)?.into_parts();
+            Ok(HttpResponse::from_parts(parts, rsp.bytes()?))
+        };
+        call().map_err(api::ApiError::client)
+    }
+
+    fn rest_stream(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<api::BodyReader>, api::ApiError<Self::Error>> {
+        let rsp = self
+            .send_rest(request, body)
+            .map_err(api::ApiError::client)?;
+        Ok(rsp.map(|rsp| Box::new(rsp) as api::BodyReader))
+    }
+}
+
+#[cfg(feature = "client_sync")]
+impl Gitlab {
+    /// Send a request, applying the authentication and retry policies of the client.
+    ///
+    /// The body of the response is not read.
+    fn send_rest(
+        &self,
+        mut request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<HttpResponse<reqwest::blocking::Response>, RestError> {
+        let refreshing = OAuth2Refreshing::is_set(&request);
+        let mut refresher = Refresher::new(&self.auth, refreshing);
+        if !refreshing {
             self.auth.set_header(request.headers_mut().unwrap())?;
-            let http_request = request.body(body)?;
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request)?;
-
-            let mut http_rsp = HttpResponse::builder()
-                .status(rsp.status())
-                .version(rsp.version());
-            let headers = http_rsp.headers_mut().unwrap();
-            for (key, value) in rsp.headers() {
-                headers.insert(key, value.clone());
+        }
+        let http_request = request.body(body)?;
+        let mut retrier = self
+            .policies
+            .retrier(http_request.method(), http_request.extensions());
+        let timeout = QueryTimeout::from_extensions(http_request.extensions());
+        let mut request: reqwest::blocking::Request = http_request.try_into()?;
+        if let Some(timeout) = timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let mut retries = 0;
+        loop {
+            let next_request = if retrier.is_active() || refresher.is_active() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let start = Instant::now();
+            let rsp = self.execute(request);
+            self.policies
+                .notify(&method, &url, &rsp, start.elapsed(), retries);
+            retries += 1;
+
+            if let Some((session, generation)) = refresher.refresh(&rsp) {
+                if let Some(mut next_request) = next_request {
+                    self.refresh_oauth2(session, generation)?;
+                    self.auth.set_header(next_request.headers_mut())?;
+                    request = next_request;
+                    continue;
+                }
+                return rsp;
+            }
+
+            match (retrier.wait(&rsp), next_request) {
+                (Some(wait), Some(next_request)) => {
+                    thread::sleep(wait);
+                    request = next_request;
+                },
+                _ => return rsp,
             }
-            Ok(http_rsp.body(rsp.bytes()?)?)
+        }
+    }
+
+    /// Refresh the OAuth2 token unless it has already been refreshed.
+    fn refresh_oauth2(&self, session: &OAuth2Session, generation: u64) -> Result<(), RestError> {
+        let endpoint = if let Some(endpoint) = session.refresh_endpoint(generation) {
+            endpoint
+        } else {
+            return Ok(());
         };
-        call().map_err(api::ApiError::client)
+
+        info!(target: "gitlab", "refreshing the OAuth2 access token");
+        let client = api::ExtensionClient::new(self, OAuth2Refreshing);
+        match endpoint.query(&client) {
+            Ok(token) => {
+                session.update(generation, token);
+                Ok(())
+            },
+            // Another request may have refreshed the token in the meantime.
+            Err(_) if session.generation() != generation => Ok(()),
+            Err(err) => Err(RestError::oauth2_refresh(err)),
+        }
+    }
+
+    fn execute(
+        &self,
+        request: reqwest::blocking::Request,
+    ) -> Result<HttpResponse<reqwest::blocking::Response>, RestError> {
+        let rsp = self.client.execute(request)?;
+
+        let mut http_rsp = HttpResponse::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+        // Headers may be repeated (e.g., `Link` or `Set-Cookie`).
+        *http_rsp.headers_mut().unwrap() = rsp.headers().clone();
+        Ok(http_rsp.body(rsp)?)
+    }
+}
+
+/// Policies for the requests sent by a client.
+#[derive(Debug, Clone, Default)]
+struct RequestPolicies {
+    /// How to handle requests rejected due to the rate limit.
+    rate_limit: Option<RateLimitPolicy>,
+    /// How to handle requests which fail due to transient errors.
+    retry: Option<RetryPolicy>,
+    /// Hooks to notify about completed requests.
+    hooks: RequestHooks,
+}
+
+impl RequestPolicies {
+    /// Notify the request hooks about the result of a request.
+    fn notify<B>(
+        &self,
+        method: &reqwest::Method,
+        url: &Url,
+        rsp: &Result<HttpResponse<B>, RestError>,
+        duration: Duration,
+        retries: usize,
+    ) {
+        let rsp = rsp.as_ref().ok().map(|rsp| (rsp.status(), rsp.headers()));
+        self.hooks.notify(method, url, rsp, duration, retries);
+    }
+
+    /// Create a retry state for a request.
+    ///
+    /// A `RetryPolicy` extension on the request overrides the client's retry policy.
+    fn retrier(&self, method: &http::Method, extensions: &http::Extensions) -> Retrier<'_> {
+        let retry = extensions
+            .get::<RetryPolicy>()
+            .or(self.retry.as_ref())
+            .cloned();
+
+        Retrier {
+            method: method.clone(),
+            rate_limit: self.rate_limit.as_ref(),
+            retry,
+            rate_limit_retries: 0,
+            retries: 0,
+        }
+    }
+}
+
+/// The retry state of a single request.
+struct Retrier<'a> {
+    method: http::Method,
+    rate_limit: Option<&'a RateLimitPolicy>,
+    retry: Option<RetryPolicy>,
+    rate_limit_retries: usize,
+    retries: usize,
+}
+
+impl<'a> Retrier<'a> {
+    /// Whether the request may be retried at all.
+    fn is_active(&self) -> bool {
+        self.rate_limit.is_some() || self.retry.is_some()
+    }
+
+    /// How long to wait before retrying the request given the result of the last attempt.
+    ///
+    /// Returns `None` if the result is final.
+    fn wait<B>(&mut self, rsp: &Result<HttpResponse<B>, RestError>) -> Option<Duration> {
+        if let Ok(rsp) = rsp {
+            let rate_limit_wait = self
+                .rate_limit
+                .and_then(|policy| policy.wait_for(self.rate_limit_retries, rsp));
+            if let Some(wait) = rate_limit_wait {
+                info!(target: "gitlab", "rate limited; retrying in {:?}", wait);
+                self.rate_limit_retries += 1;
+                return Some(wait);
+            }
+        }
+
+        let policy = self.retry.as_ref()?;
+        let wait = match rsp {
+            Ok(rsp) => policy.wait_for_status(self.retries, &self.method, rsp.status()),
+            Err(RestError::Communication {
+                source,
+            }) if backend::is_connect(source) => policy.wait_for_connection_error(self.retries),
+            Err(_) => None,
+        };
+        if let Some(wait) = wait {
+            info!(target: "gitlab", "request failed; retrying in {:?}", wait);
+            self.retries += 1;
+        }
+        wait
     }
 }
 
@@ -364,12 +1100,18 @@ pub struct GitlabBuilder {
     protocol: &'static str,
     host: String,
     token: Auth,
-    cert_validation: CertPolicy,
-    identity: ClientCert,
+    oauth2_refresh: Option<OAuth2Refresh>,
+    options: ClientOptions,
+    policies: RequestPolicies,
+    #[cfg(feature = "client_async")]
+    throttle: ThrottleSettings,
 }
 
 impl GitlabBuilder {
     /// Create a new Gitlab API client builder.
+    ///
+    /// The host may include a path prefix for instances served under a relative URL (e.g.,
+    /// `gitlab.example.com/gitlab`).
     pub fn new<H, T>(host: H, token: T) -> Self
     where
         H: Into<String>,
@@ -379,24 +1121,99 @@ impl GitlabBuilder {
             protocol: "https",
             host: host.into(),
             token: Auth::Token(token.into()),
-            cert_validation: CertPolicy::Default,
-            identity: ClientCert::None,
+            oauth2_refresh: None,
+            options: ClientOptions::new(CertPolicy::Default),
+            policies: RequestPolicies::default(),
+            #[cfg(feature = "client_async")]
+            throttle: ThrottleSettings::default(),
         }
     }
 
-    /// Create a new unauthenticated Gitlab API client builder.
-    pub fn new_unauthenticated<H>(host: H) -> Self
+    /// Create a new Gitlab API client builder from environment variables.
+    ///
+    /// The instance is read from `GITLAB_URL` (e.g., `https://gitlab.example.com`) and the
+    /// personal access token from `GITLAB_TOKEN`. Inside of GitLab CI jobs, `CI_SERVER_URL` and
+    /// the job token in `CI_JOB_TOKEN` are used if these are not set. An `http` URL switches to
+    /// the insecure protocol. Setting `GITLAB_INSECURE` to a true value (`1`, `true`, `yes`, or
+    /// `on`) disables certificate validation.
+    pub fn from_env() -> GitlabResult<Self> {
+        Self::from_env_with(|name| env::var(name).ok())
+    }
+
+    fn from_env_with<F>(var: F) -> GitlabResult<Self>
     where
-        H: Into<String>,
+        F: Fn(&str) -> Option<String>,
     {
-        Self {
-            protocol: "https",
-            host: host.into(),
-            token: Auth::None,
-            cert_validation: CertPolicy::Default,
-            identity: ClientCert::None,
-        }
-    }
+        const URL_VARS: &[&str] = &["GITLAB_URL", "CI_SERVER_URL"];
+        const TOKEN_VARS: &[&str] = &["GITLAB_TOKEN", "CI_JOB_TOKEN"];
+        const INSECURE_VAR: &str = "GITLAB_INSECURE";
+
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+
+        let (url_var, url) = URL_VARS
+            .iter()
+            .find_map(|&name| var(name).map(|value| (name, value)))
+            .ok_or_else(|| GitlabError::missing_environment_variable(URL_VARS))?;
+        let (token_var, token) = TOKEN_VARS
+            .iter()
+            .find_map(|&name| var(name).map(|value| (name, value)))
+            .ok_or_else(|| GitlabError::missing_environment_variable(TOKEN_VARS))?;
+
+        let (insecure, host) = if let Some(host) = url.strip_prefix("https://") {
+            (false, host)
+        } else if let Some(host) = url.strip_prefix("http://") {
+            (true, host)
+        } else if url.contains("://") {
+            return Err(GitlabError::invalid_environment_variable(url_var, url));
+        } else {
+            (false, url.as_str())
+        };
+        let host = host.trim_end_matches('/');
+        if host.is_empty() {
+            return Err(GitlabError::invalid_environment_variable(url_var, url));
+        }
+
+        let mut builder = Self::new(host, token);
+        if insecure {
+            builder.insecure();
+        }
+        if token_var == "CI_JOB_TOKEN" {
+            builder.job_token();
+        }
+        if let Some(value) = var(INSECURE_VAR) {
+            match value.to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => {
+                    builder.cert_insecure();
+                },
+                "0" | "false" | "no" | "off" => (),
+                _ => {
+                    return Err(GitlabError::invalid_environment_variable(
+                        INSECURE_VAR,
+                        value,
+                    ))
+                },
+            }
+        }
+
+        Ok(builder)
+    }
+
+    /// Create a new unauthenticated Gitlab API client builder.
+    pub fn new_unauthenticated<H>(host: H) -> Self
+    where
+        H: Into<String>,
+    {
+        Self {
+            protocol: "https",
+            host: host.into(),
+            token: Auth::None,
+            oauth2_refresh: None,
+            options: ClientOptions::new(CertPolicy::Default),
+            policies: RequestPolicies::default(),
+            #[cfg(feature = "client_async")]
+            throttle: ThrottleSettings::default(),
+        }
+    }
 
     /// Switch to an insecure protocol (http instead of https).
     pub fn insecure(&mut self) -> &mut Self {
@@ -404,8 +1221,49 @@ impl GitlabBuilder {
         self
     }
 
+    /// Accept any certificate presented by the server.
+    ///
+    /// Replaces any other certificate validation policy.
     pub fn cert_insecure(&mut self) -> &mut Self {
-        self.cert_validation = CertPolicy::Insecure;
+        self.options.cert_validation = CertPolicy::Insecure;
+        self
+    }
+
+    /// Validate the certificate chain of the server, but accept it for any host name.
+    ///
+    /// This is useful for instances reached through an address which does not appear in their
+    /// certificate (e.g., an internal IP address). Replaces any other certificate validation
+    /// policy.
+    pub fn cert_any_hostname(&mut self) -> &mut Self {
+        self.options.cert_validation = CertPolicy::AnyHostname;
+        self
+    }
+
+    /// Only accept the certificate with the given SHA-256 fingerprint.
+    ///
+    /// The fingerprint is of the DER encoding of the certificate presented by the server (as
+    /// reported by `openssl x509 -noout -fingerprint -sha256`). Pinned certificates are accepted
+    /// regardless of their issuer, host names, and validity period. May be called multiple times
+    /// to accept any of the given certificates (e.g., while rotating certificates).
+    ///
+    /// Replaces any other certificate validation policy. Client identities must be PEM-encoded
+    /// when pinning certificates.
+    pub fn cert_pin_sha256(&mut self, fingerprint: [u8; 32]) -> &mut Self {
+        if let CertPolicy::Pinned(fingerprints) = &mut self.options.cert_validation {
+            fingerprints.push(fingerprint);
+        } else {
+            self.options.cert_validation = CertPolicy::Pinned(vec![fingerprint]);
+        }
+        self
+    }
+
+    /// The minimum TLS version to accept.
+    ///
+    /// The `rustls` backend supports TLS 1.2 and 1.3. By default, all versions supported by the
+    /// backend are accepted.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn min_tls_version(&mut self, version: reqwest::tls::Version) -> &mut Self {
+        self.options.min_tls_version = Some(version);
         self
     }
 
@@ -417,39 +1275,280 @@ impl GitlabBuilder {
         self
     }
 
+    /// Switch to using a CI job token instead of a personal access token.
+    ///
+    /// Job tokens may only access a limited set of endpoints.
+    pub fn job_token(&mut self) -> &mut Self {
+        if let Auth::Token(token) = self.token.clone() {
+            self.token = Auth::JobToken(token);
+        }
+        self
+    }
+
+    /// Obtain the token from a provider for every request.
+    ///
+    /// This replaces any token given to the builder. The provider is expected to handle the
+    /// expiration of its tokens itself.
+    pub fn token_provider<P>(&mut self, provider: P) -> &mut Self
+    where
+        P: TokenProvider + 'static,
+    {
+        self.token = Auth::Provider(Arc::new(provider));
+        self
+    }
+
+    /// Refresh the OAuth2 token once it expires.
+    ///
+    /// The token is used as an OAuth2 access token. When a request is rejected as unauthorized,
+    /// a new access token is requested using the refresh token and the request is retried. For
+    /// unauthenticated builders, an access token is requested with the first request.
+    pub fn oauth2_refresh(&mut self, refresh: OAuth2Refresh) -> &mut Self {
+        self.oauth2_refresh = Some(refresh);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    ///
+    /// This makes it easier to identify the requests of an application in the logs of the
+    /// instance. By default, no `User-Agent` header is sent.
+    pub fn user_agent<U>(&mut self, user_agent: U) -> &mut Self
+    where
+        U: Into<String>,
+    {
+        self.options.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set how long to wait for a connection to the server to be established.
+    ///
+    /// By default, there is no connection timeout.
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.options.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long to wait for a request to complete.
+    ///
+    /// This covers the entire request, from connecting until the response body has been read. By
+    /// default, the blocking client times out after 30 seconds while the asynchronous client does
+    /// not time out. The timeout may be overridden for individual queries using
+    /// `api::with_timeout`.
+    ///
+    /// There is no separate timeout for reading from the server since `reqwest` 0.11 does not
+    /// support one; a stalled response is only bounded by this timeout.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    #[cfg(feature = "client_sync")]
+    /// Use a preconfigured HTTP client for blocking clients.
+    ///
+    /// This allows sharing a connection pool, proxy, DNS overrides, or other settings with the
+    /// rest of an application. The client is used as-is, so the TLS, proxy, `User-Agent`, and
+    /// timeout settings of this builder are ignored when building a blocking client.
+    pub fn http_client(&mut self, client: Client) -> &mut Self {
+        self.options.blocking_client = Some(client);
+        self
+    }
+
+    #[cfg(feature = "client_async")]
+    /// Use a preconfigured HTTP client for asynchronous clients.
+    ///
+    /// This allows sharing a connection pool, proxy, DNS overrides, or other settings with the
+    /// rest of an application. The client is used as-is, so the TLS, proxy, `User-Agent`, and
+    /// timeout settings of this builder are ignored when building an asynchronous client.
+    pub fn async_http_client(&mut self, client: AsyncClient) -> &mut Self {
+        self.options.async_client = Some(client);
+        self
+    }
+
+    /// Trust the provided DER-encoded certificate as a root certificate.
+    ///
+    /// This is useful for instances which use certificates signed by a private certificate
+    /// authority. The certificate is parsed when the client is built.
+    pub fn add_root_certificate_der(&mut self, der: &[u8]) -> &mut Self {
+        self.options.root_certs.push(RootCert::Der(der.into()));
+        self
+    }
+
+    /// Trust the provided PEM-encoded certificates as root certificates.
+    ///
+    /// The data may contain a bundle of multiple certificates. The certificates are parsed when
+    /// the client is built.
+    pub fn add_root_certificate_pem(&mut self, pem: &[u8]) -> &mut Self {
+        self.options.root_certs.push(RootCert::Pem(pem.into()));
+        self
+    }
+
+    /// Whether to trust the built-in root certificates.
+    ///
+    /// Disabling them restricts the trusted certificates to those added with
+    /// `add_root_certificate_der` and `add_root_certificate_pem`. Defaults to `true`.
+    pub fn tls_built_in_root_certs(&mut self, built_in_root_certs: bool) -> &mut Self {
+        self.options.built_in_root_certs = built_in_root_certs;
+        self
+    }
+
+    /// Send all requests through the proxy at the given URL.
+    ///
+    /// The `http`, `https`, and (if reqwest's `socks` feature is enabled) `socks5` schemes are
+    /// supported. Credentials may be embedded in the URL or given using `proxy_basic_auth`. The
+    /// URL is parsed when the client is built.
+    ///
+    /// An explicit proxy takes precedence over the proxy environment variables.
+    pub fn proxy<U>(&mut self, url: U) -> &mut Self
+    where
+        U: Into<String>,
+    {
+        self.options.proxy.url = Some(url.into());
+        self
+    }
+
+    /// Authenticate to the proxy with the given credentials.
+    pub fn proxy_basic_auth<U, P>(&mut self, username: U, password: P) -> &mut Self
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        self.options.proxy.basic_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Hosts which should not be reached through the proxy.
+    ///
+    /// The list uses the same comma-separated format as the `NO_PROXY` environment variable
+    /// (e.g., `localhost,.example.com,192.168.0.0/16`).
+    pub fn no_proxy<L>(&mut self, list: L) -> &mut Self
+    where
+        L: Into<String>,
+    {
+        self.options.proxy.no_proxy = Some(list.into());
+        self
+    }
+
+    /// Whether to use the proxy set in the environment.
+    ///
+    /// Unless a proxy is set with `proxy`, the `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY`, and
+    /// `NO_PROXY` environment variables are honored. Defaults to `true`.
+    pub fn environment_proxy(&mut self, use_environment: bool) -> &mut Self {
+        self.options.proxy.ignore_environment = !use_environment;
+        self
+    }
+
     /// [Authenticate to Gitlab](reqwest::Identity) with the provided
     /// DER-formatted PKCS#12 archive.
+    ///
+    /// The identity is presented for mutual TLS authentication, e.g., to a proxy in front of the
+    /// instance. It is parsed when the client is built. Requires the `client_der` feature.
     #[cfg(any(doc, feature = "client_der"))]
     pub fn client_identity_from_der(&mut self, der: &[u8], password: &str) -> &mut Self {
-        self.identity = ClientCert::Der(der.into(), password.into());
+        self.options.identity = ClientCert::Der(der.into(), password.into());
         self
     }
 
     /// [Authenticate to Gitlab](reqwest::Identity) with the provided
     /// PEM-encoded private key and certificate.
+    ///
+    /// The identity is presented for mutual TLS authentication, e.g., to a proxy in front of the
+    /// instance. It is parsed when the client is built. Requires the `client_pem` feature.
     #[cfg(any(doc, feature = "client_pem"))]
     pub fn client_identity_from_pem(&mut self, pem: &[u8]) -> &mut Self {
-        self.identity = ClientCert::Pem(pem.into());
+        self.options.identity = ClientCert::Pem(pem.into());
+        self
+    }
+
+    /// Wait and retry requests which are rejected due to the rate limit.
+    ///
+    /// By default, rate limited requests are returned as errors.
+    pub fn rate_limit_policy(&mut self, policy: RateLimitPolicy) -> &mut Self {
+        self.policies.rate_limit = Some(policy);
+        self
+    }
+
+    #[cfg(feature = "client_async")]
+    /// Limit the number of requests an asynchronous client sends at once.
+    ///
+    /// Requests beyond the limit wait until earlier requests have received their response
+    /// headers. Clones of the client share the limit. This helps to avoid tripping the abuse
+    /// detection of an instance when fanning out requests over many projects. The limit is at
+    /// least one and is not applied to blocking clients.
+    pub fn max_concurrent_requests(&mut self, limit: usize) -> &mut Self {
+        self.throttle.max_concurrent = Some(limit);
+        self
+    }
+
+    #[cfg(feature = "client_async")]
+    /// Limit the rate at which an asynchronous client starts requests.
+    ///
+    /// Requests are spaced evenly so that at most `rate` requests are started per second,
+    /// including retries. Clones of the client share the limit. Non-positive rates remove the
+    /// limit. It is not applied to blocking clients.
+    pub fn max_requests_per_second(&mut self, rate: f64) -> &mut Self {
+        self.throttle.interval = if rate > 0. && rate.is_finite() {
+            Some(Duration::from_secs_f64(1. / rate))
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Retry requests which fail due to transient errors.
+    ///
+    /// By default, failed requests are not retried. The policy may be overridden for individual
+    /// queries using `api::with_retry`. Only requests with idempotent methods are retried on error
+    /// statuses unless the policy enables `retry_non_idempotent`.
+    pub fn retry_policy(&mut self, policy: RetryPolicy) -> &mut Self {
+        self.policies.retry = Some(policy);
+        self
+    }
+
+    /// Add a hook which is notified about every request sent by the client.
+    ///
+    /// This may be used to collect metrics about the requests sent to the instance.
+    pub fn request_hook<H>(&mut self, hook: H) -> &mut Self
+    where
+        H: RequestHook + 'static,
+    {
+        self.policies.hooks.add(Arc::new(hook));
         self
     }
 
+    fn auth(&self) -> Auth {
+        if let Some(refresh) = self.oauth2_refresh.as_ref() {
+            let access_token = match &self.token {
+                Auth::Token(token) | Auth::OAuth2(token) => token.clone(),
+                Auth::RefreshableOAuth2(_) | Auth::JobToken(_) | Auth::Provider(_) | Auth::None => {
+                    String::new()
+                },
+            };
+            let session = OAuth2Session::new(access_token, refresh.clone());
+            Auth::RefreshableOAuth2(Arc::new(session))
+        } else {
+            self.token.clone()
+        }
+    }
+
+    #[cfg(feature = "client_sync")]
     pub fn build(&self) -> GitlabResult<Gitlab> {
         Gitlab::new_impl(
             self.protocol,
             &self.host,
-            self.token.clone(),
-            self.cert_validation.clone(),
-            self.identity.clone(),
+            self.auth(),
+            self.options.clone(),
+            self.policies.clone(),
         )
     }
 
+    #[cfg(feature = "client_async")]
     pub async fn build_async(&self) -> GitlabResult<AsyncGitlab> {
         AsyncGitlab::new_impl(
             self.protocol,
             &self.host,
-            self.token.clone(),
-            self.cert_validation.clone(),
-            self.identity.clone(),
+            self.auth(),
+            self.options.clone(),
+            self.policies.clone(),
+            self.throttle.build(),
         )
         .await
     }
@@ -458,106 +1557,242 @@ impl GitlabBuilder {
 /// A representation of the asynchronous Gitlab API for a single user.
 ///
 /// Separate users should use separate instances of this.
+///
+/// The REST and GraphQL APIs are available with the same interface as the blocking `Gitlab`
+/// client.
+///
+/// On `wasm32-unknown-unknown`, requests are sent using `fetch`. The browser manages TLS,
+/// proxies, and timeouts, so the related `GitlabBuilder` settings have no effect there. Futures
+/// returned by the client are not `Send` on that target.
+#[cfg(feature = "client_async")]
 #[derive(Clone)]
 pub struct AsyncGitlab {
     /// The client to use for API calls.
     client: reqwest::Client,
     /// The base URL to use for API calls.
     rest_url: Url,
+    /// The base URL to use for instance endpoints outside of the API.
+    instance_url: Url,
     /// The URL to use for GraphQL API calls.
     graphql_url: Url,
     /// The authentication information to use when communicating with Gitlab.
     auth: Auth,
+    /// Policies for retrying failed requests.
+    policies: RequestPolicies,
+    /// Limits on the rate and concurrency of requests.
+    throttle: Throttle,
 }
 
+#[cfg(feature = "client_async")]
 impl Debug for AsyncGitlab {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("AsyncGitlab")
             .field("rest_url", &self.rest_url)
+            .field("instance_url", &self.instance_url)
             .field("graphql_url", &self.graphql_url)
+            .field("policies", &self.policies)
+            .field("throttle", &self.throttle)
             .finish()
     }
 }
 
-#[async_trait]
+#[cfg(feature = "client_async")]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl api::RestClient for AsyncGitlab {
     type Error = RestError;
 
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
         debug!(target: "gitlab", "REST api call {}", endpoint);
-        Ok(self.rest_url.join(endpoint)?)
+        Ok(join_endpoint(&self.rest_url, endpoint)?)
+    }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        debug!(target: "gitlab", "instance call {}", endpoint);
+        Ok(join_endpoint(&self.instance_url, endpoint)?)
+    }
+
+    fn identity(&self) -> Option<u64> {
+        Some(self.auth.identity())
     }
 }
 
-#[async_trait]
+#[cfg(feature = "client_async")]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl api::AsyncClient for AsyncGitlab {
     async fn rest_async(
         &self,
-        mut request: http::request::Builder,
+        request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
-        use futures_util::TryFutureExt;
         let call = || {
             async {
-                self.auth.set_header(request.headers_mut().unwrap())?;
-                let http_request = request.body(body)?;
-                let request = http_request.try_into()?;
-                let rsp = self.client.execute(request).await?;
-
-                let mut http_rsp = HttpResponse::builder()
-                    .status(rsp.status())
-                    .version(rsp.version());
-                let headers = http_rsp.headers_mut().unwrap();
-                for (key, value) in rsp.headers() {
-                    headers.insert(key, value.clone());
-                }
-                Ok(http_rsp.body(rsp.bytes().await?)?)
+                let (rsp, permit) = self.send_rest_async(request, body).await?;
+                let (parts, rsp) = rsp.into_parts();
+                let body = rsp.bytes().await?;
+                drop(permit);
+                Ok(HttpResponse::from_parts(parts, body))
             }
         };
-        call().map_err(api::ApiError::client).await
+        call().await.map_err(api::ApiError::client)
+    }
+
+    async fn rest_stream_async(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<
+        HttpResponse<api::BodyStream<<Self as api::RestClient>::Error>>,
+        api::ApiError<<Self as api::RestClient>::Error>,
+    > {
+        let (rsp, permit) = self
+            .send_rest_async(request, body)
+            .await
+            .map_err(api::ApiError::client)?;
+        Ok(rsp.map(|rsp| backend::body_stream(rsp, permit)))
     }
 }
 
+#[cfg(feature = "client_async")]
 impl AsyncGitlab {
+    /// Send a request, applying the authentication and retry policies of the client.
+    ///
+    /// The body of the response is not read. The returned throttle permit must be held until it
+    /// has been.
+    async fn send_rest_async(
+        &self,
+        mut request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<
+        (
+            HttpResponse<reqwest::Response>,
+            Option<OwnedSemaphorePermit>,
+        ),
+        RestError,
+    > {
+        let refreshing = OAuth2Refreshing::is_set(&request);
+        let mut refresher = Refresher::new(&self.auth, refreshing);
+        if !refreshing {
+            self.auth
+                .set_header_async(request.headers_mut().unwrap())
+                .await?;
+        }
+        let http_request = request.body(body)?;
+        let mut retrier = self
+            .policies
+            .retrier(http_request.method(), http_request.extensions());
+        let timeout = QueryTimeout::from_extensions(http_request.extensions());
+        let mut request: reqwest::Request = http_request.try_into()?;
+        if let Some(timeout) = timeout {
+            backend::set_timeout(&mut request, timeout);
+        }
+
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let mut retries = 0;
+        loop {
+            let next_request = if retrier.is_active() || refresher.is_active() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let permit = self.throttle.acquire().await;
+            let start = Instant::now();
+            let rsp = self.execute(request).await;
+            self.policies
+                .notify(&method, &url, &rsp, start.elapsed(), retries);
+            retries += 1;
+
+            if let Some((session, generation)) = refresher.refresh(&rsp) {
+                if let Some(mut next_request) = next_request {
+                    // The refresh request needs a permit of its own.
+                    drop(permit);
+                    self.refresh_oauth2(session, generation).await?;
+                    self.auth
+                        .set_header_async(next_request.headers_mut())
+                        .await?;
+                    request = next_request;
+                    continue;
+                }
+                return rsp.map(|rsp| (rsp, permit));
+            }
+
+            match (retrier.wait(&rsp), next_request) {
+                (Some(wait), Some(next_request)) => {
+                    drop(permit);
+                    backend::sleep(wait).await;
+                    request = next_request;
+                },
+                _ => return rsp.map(|rsp| (rsp, permit)),
+            }
+        }
+    }
+
+    /// Refresh the OAuth2 token unless it has already been refreshed.
+    async fn refresh_oauth2(
+        &self,
+        session: &OAuth2Session,
+        generation: u64,
+    ) -> Result<(), RestError> {
+        let endpoint = if let Some(endpoint) = session.refresh_endpoint(generation) {
+            endpoint
+        } else {
+            return Ok(());
+        };
+
+        info!(target: "gitlab", "refreshing the OAuth2 access token");
+        let client = api::ExtensionClient::new(self, OAuth2Refreshing);
+        match endpoint.query_async(&client).await {
+            Ok(token) => {
+                session.update(generation, token);
+                Ok(())
+            },
+            // Another request may have refreshed the token in the meantime.
+            Err(_) if session.generation() != generation => Ok(()),
+            Err(err) => Err(RestError::oauth2_refresh(err)),
+        }
+    }
+
+    async fn execute(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<HttpResponse<reqwest::Response>, RestError> {
+        let rsp = self.client.execute(request).await?;
+
+        let mut http_rsp = HttpResponse::builder()
+            .status(rsp.status())
+            .version(backend::version(&rsp));
+        // Headers may be repeated (e.g., `Link` or `Set-Cookie`).
+        *http_rsp.headers_mut().unwrap() = rsp.headers().clone();
+        Ok(http_rsp.body(rsp)?)
+    }
+
     /// Internal method to create a new Gitlab client.
     async fn new_impl(
         protocol: &str,
         host: &str,
         auth: Auth,
-        cert_validation: CertPolicy,
-        identity: ClientCert,
+        options: ClientOptions,
+        policies: RequestPolicies,
+        throttle: Throttle,
     ) -> GitlabResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/api/v4/", protocol, host))?;
-        let graphql_url = Url::parse(&format!("{}://{}/api/graphql", protocol, host))?;
-
-        let client = match cert_validation {
-            CertPolicy::Insecure => {
-                AsyncClient::builder()
-                    .danger_accept_invalid_certs(true)
-                    .build()?
-            },
-            CertPolicy::Default => {
-                match identity {
-                    ClientCert::None => AsyncClient::new(),
-                    #[cfg(feature = "client_der")]
-                    ClientCert::Der(der, password) => {
-                        let id = TlsIdentity::from_pkcs12_der(&der, &password)?;
-                        AsyncClient::builder().identity(id).build()?
-                    },
-                    #[cfg(feature = "client_pem")]
-                    ClientCert::Pem(pem) => {
-                        let id = TlsIdentity::from_pem(&pem)?;
-                        AsyncClient::builder().identity(id).build()?
-                    },
-                }
-            },
-        };
+        let InstanceUrls {
+            rest_url,
+            instance_url,
+            graphql_url,
+        } = InstanceUrls::new(protocol, host)?;
+
+        let client = options.async_client()?;
 
         let api = AsyncGitlab {
             client,
             rest_url,
+            instance_url,
             graphql_url,
             auth,
+            policies,
+            throttle,
         };
 
         // Ensure the API is working.
@@ -567,6 +1802,10 @@ impl AsyncGitlab {
     }
 
     /// Send a GraphQL query.
+    ///
+    /// The request is sent with the same authentication, rate limit, retry, and hook policies as
+    /// REST requests. GraphQL requests use `POST`, so they are only retried on error statuses if
+    /// the retry policy allows retrying non-idempotent requests.
     pub async fn graphql<Q>(&self, query: &QueryBody<Q::Variables>) -> GitlabResult<Q::ResponseData>
     where
         Q: GraphQLQuery,
@@ -579,8 +1818,8 @@ impl AsyncGitlab {
             query.operation_name,
             query.variables,
         );
-        let req = self.client.post(self.graphql_url.clone()).json(query);
-        let rsp: Response<Q::ResponseData> = self.send(req).await?;
+        let (request, body) = graphql_request(&self.graphql_url, query)?;
+        let rsp: Response<Q::ResponseData> = self.send(request, body).await?;
 
         if let Some(errs) = rsp.errors {
             return Err(GitlabError::graphql(errs));
@@ -588,22 +1827,756 @@ impl AsyncGitlab {
         rsp.data.ok_or_else(GitlabError::no_response)
     }
 
-    /// Refactored code which talks to Gitlab and transforms error messages properly.
-    async fn send<T>(&self, req: reqwest::RequestBuilder) -> GitlabResult<T>
+    /// Send a GraphQL query built from its variables.
+    pub async fn graphql_query<Q>(&self, variables: Q::Variables) -> GitlabResult<Q::ResponseData>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+    {
+        self.graphql::<Q>(&Q::build_query(variables)).await
+    }
+
+    /// Send a GraphQL query for all pages of a connection.
+    ///
+    /// The `variables` function is called with the cursor of the page to request (`None` for the
+    /// first page) and the `page` function extracts the results and the pagination state of the
+    /// connection from each response. Pages are requested until the connection has no more
+    /// results.
+    pub async fn graphql_paged<Q, V, P, T>(
+        &self,
+        mut variables: V,
+        mut page: P,
+    ) -> GitlabResult<Vec<T>>
+    where
+        Q: GraphQLQuery,
+        Q::Variables: Debug,
+        for<'d> Q::ResponseData: Deserialize<'d>,
+        V: FnMut(Option<String>) -> Q::Variables,
+        P: FnMut(Q::ResponseData) -> (Vec<T>, GraphQLPageInfo),
+    {
+        let mut results = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let data = self.graphql_query::<Q>(variables(cursor.take())).await?;
+            let (items, page_info) = page(data);
+            results.extend(items);
+
+            cursor = page_info.next_cursor();
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Send a GraphQL request, applying the policies of the client.
+    async fn send<T>(&self, request: http::request::Builder, body: Vec<u8>) -> GitlabResult<T>
     where
         T: DeserializeOwned,
     {
-        let auth_headers = {
-            let mut headers = HeaderMap::default();
-            self.auth.set_header(&mut headers)?;
-            headers
-        };
-        let rsp = req.headers(auth_headers).send().await?;
+        let (rsp, permit) = self.send_rest_async(request, body).await?;
         let status = rsp.status();
         if status.is_server_error() {
             return Err(GitlabError::http(status));
         }
 
-        serde_json::from_slice::<T>(&rsp.bytes().await?).map_err(GitlabError::data_type::<T>)
+        let body = rsp.into_body().bytes().await?;
+        drop(permit);
+        serde_json::from_slice::<T>(&body).map_err(GitlabError::data_type::<T>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use std::io::{BufRead, BufReader, Write};
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use std::net::TcpListener;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    #[cfg(feature = "client_async")]
+    use std::sync::mpsc::{self, Sender};
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use std::sync::Arc;
+    #[cfg(feature = "client_async")]
+    use std::sync::Mutex;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use std::thread;
+    use std::time::Duration;
+
+    #[cfg(feature = "client_async")]
+    use futures_util::future;
+    #[cfg(feature = "client_async")]
+    use futures_util::stream::StreamExt;
+    use graphql_client::{GraphQLQuery, QueryBody};
+    #[cfg(feature = "client_sync")]
+    use http::Method;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use http::{header, HeaderMap, StatusCode};
+    use serde::{Deserialize, Serialize};
+
+    #[cfg(feature = "client_sync")]
+    use crate::api::retry::RetryPolicy;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use crate::api::users::CurrentUser;
+    #[cfg(feature = "client_async")]
+    use crate::api::AsyncQuery;
+    #[cfg(feature = "client_sync")]
+    use crate::api::Query;
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    use crate::api::{self, RestClient};
+    use crate::auth::Auth;
+    use crate::gitlab::{join_endpoint, CertPolicy, GitlabBuilder, GitlabError, InstanceUrls};
+    #[cfg(feature = "client_async")]
+    use crate::AsyncGitlab;
+    use crate::GraphQLPageInfo;
+
+    #[derive(Debug, Serialize)]
+    struct DummyVariables {
+        after: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    // Only the asynchronous tests read the response.
+    #[cfg_attr(not(feature = "client_async"), allow(dead_code))]
+    struct DummyData {
+        items: Vec<u64>,
+        page_info: GraphQLPageInfo,
+    }
+
+    struct DummyQuery;
+
+    impl GraphQLQuery for DummyQuery {
+        type Variables = DummyVariables;
+        type ResponseData = DummyData;
+
+        fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+            QueryBody {
+                variables,
+                query: "query Dummy($after: String) { dummy(after: $after) { items } }",
+                operation_name: "Dummy",
+            }
+        }
+    }
+
+    #[cfg(feature = "client_async")]
+    fn assert_send<T: Send>(_: &T) {}
+
+    #[cfg(feature = "client_async")]
+    // Async services spawn their queries onto executors which require `Send` futures.
+    #[allow(dead_code)]
+    fn async_graphql_is_send(client: &AsyncGitlab) {
+        let body = DummyQuery::build_query(DummyVariables {
+            after: None,
+        });
+        assert_send(&client.graphql::<DummyQuery>(&body));
+        assert_send(&client.graphql_query::<DummyQuery>(DummyVariables {
+            after: None,
+        }));
+        assert_send(&client.graphql_paged::<DummyQuery, _, _, _>(
+            |after| {
+                DummyVariables {
+                    after,
+                }
+            },
+            |data| (data.items, data.page_info),
+        ));
+    }
+
+    #[test]
+    fn graphql_page_info_next_cursor() {
+        let page_info: GraphQLPageInfo =
+            serde_json::from_str(r#"{"hasNextPage": true, "endCursor": "abc"}"#).unwrap();
+        assert_eq!(page_info.next_cursor(), Some("abc".into()));
+
+        let page_info: GraphQLPageInfo =
+            serde_json::from_str(r#"{"hasNextPage": false, "endCursor": "abc"}"#).unwrap();
+        assert_eq!(page_info.next_cursor(), None);
+    }
+
+    fn from_env(vars: &[(&str, &str)]) -> Result<GitlabBuilder, GitlabError> {
+        let vars = vars.iter().copied().collect::<BTreeMap<_, _>>();
+        GitlabBuilder::from_env_with(|name| vars.get(name).map(|value| (*value).into()))
+    }
+
+    #[test]
+    fn builder_from_env() {
+        let builder = from_env(&[
+            ("GITLAB_URL", "https://gitlab.example.com/"),
+            ("GITLAB_TOKEN", "token"),
+            ("CI_SERVER_URL", "https://ci.example.com"),
+            ("CI_JOB_TOKEN", "job-token"),
+        ])
+        .unwrap();
+
+        assert_eq!(builder.protocol, "https");
+        assert_eq!(builder.host, "gitlab.example.com");
+        assert!(matches!(&builder.token, Auth::Token(token) if token == "token"));
+        assert!(matches!(
+            builder.options.cert_validation,
+            CertPolicy::Default
+        ));
+    }
+
+    #[test]
+    fn builder_from_env_ci() {
+        let builder = from_env(&[
+            ("GITLAB_URL", ""),
+            ("CI_SERVER_URL", "http://ci.example.com:8080/gitlab"),
+            ("CI_JOB_TOKEN", "job-token"),
+        ])
+        .unwrap();
+
+        assert_eq!(builder.protocol, "http");
+        assert_eq!(builder.host, "ci.example.com:8080/gitlab");
+        assert!(matches!(&builder.token, Auth::JobToken(token) if token == "job-token"));
+    }
+
+    #[test]
+    fn builder_from_env_bare_host() {
+        let builder = from_env(&[
+            ("GITLAB_URL", "gitlab.example.com"),
+            ("GITLAB_TOKEN", "token"),
+        ])
+        .unwrap();
+
+        assert_eq!(builder.protocol, "https");
+        assert_eq!(builder.host, "gitlab.example.com");
+    }
+
+    #[test]
+    fn builder_from_env_insecure() {
+        for value in &["1", "true", "YES", "on"] {
+            let builder = from_env(&[
+                ("GITLAB_URL", "https://gitlab.example.com"),
+                ("GITLAB_TOKEN", "token"),
+                ("GITLAB_INSECURE", value),
+            ])
+            .unwrap();
+            assert!(matches!(
+                builder.options.cert_validation,
+                CertPolicy::Insecure
+            ));
+        }
+
+        for value in &["", "0", "false", "No", "off"] {
+            let builder = from_env(&[
+                ("GITLAB_URL", "https://gitlab.example.com"),
+                ("GITLAB_TOKEN", "token"),
+                ("GITLAB_INSECURE", value),
+            ])
+            .unwrap();
+            assert!(matches!(
+                builder.options.cert_validation,
+                CertPolicy::Default
+            ));
+        }
+    }
+
+    #[test]
+    fn builder_from_env_missing() {
+        let err = from_env(&[("GITLAB_TOKEN", "token")]).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "missing environment variable: GITLAB_URL or CI_SERVER_URL",
+        );
+
+        let err = from_env(&[("GITLAB_URL", "https://gitlab.example.com")])
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "missing environment variable: GITLAB_TOKEN or CI_JOB_TOKEN",
+        );
+    }
+
+    #[test]
+    fn builder_from_env_invalid() {
+        let err = from_env(&[
+            ("GITLAB_URL", "ftp://gitlab.example.com"),
+            ("GITLAB_TOKEN", "token"),
+        ])
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid value for environment variable GITLAB_URL: \"ftp://gitlab.example.com\"",
+        );
+
+        let err = from_env(&[
+            ("GITLAB_URL", "https://gitlab.example.com"),
+            ("GITLAB_TOKEN", "token"),
+            ("GITLAB_INSECURE", "maybe"),
+        ])
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid value for environment variable GITLAB_INSECURE: \"maybe\"",
+        );
+    }
+
+    #[cfg(feature = "client_async")]
+    #[test]
+    fn builder_max_requests_per_second() {
+        let mut builder = GitlabBuilder::new("gitlab.host.invalid", "token");
+
+        builder.max_requests_per_second(4.);
+        assert_eq!(builder.throttle.interval, Some(Duration::from_millis(250)));
+
+        builder.max_requests_per_second(0.);
+        assert_eq!(builder.throttle.interval, None);
+    }
+
+    #[cfg(feature = "client_sync")]
+    #[test]
+    fn builder_http_client() {
+        let mut builder = GitlabBuilder::new("gitlab.host.invalid", "token");
+        // The proxy would fail to parse if a client were built from the options.
+        builder.proxy("not a url");
+        builder.options.blocking_client().unwrap_err();
+
+        builder.http_client(reqwest::blocking::Client::new());
+        builder.options.blocking_client().unwrap();
+    }
+
+    #[cfg(feature = "client_async")]
+    #[test]
+    fn builder_async_http_client() {
+        let mut builder = GitlabBuilder::new("gitlab.host.invalid", "token");
+        // The proxy would fail to parse if a client were built from the options.
+        builder.proxy("not a url");
+        builder.options.async_client().unwrap_err();
+
+        builder.async_http_client(reqwest::Client::new());
+        builder.options.async_client().unwrap();
+    }
+
+    #[test]
+    fn builder_cert_pin_sha256() {
+        let mut builder = GitlabBuilder::new("gitlab.host.invalid", "token");
+        builder.cert_pin_sha256([1; 32]).cert_pin_sha256([2; 32]);
+        assert!(matches!(
+            &builder.options.cert_validation,
+            CertPolicy::Pinned(fingerprints) if fingerprints == &[[1; 32], [2; 32]],
+        ));
+
+        builder.cert_insecure();
+        assert!(matches!(
+            builder.options.cert_validation,
+            CertPolicy::Insecure
+        ));
+
+        builder.cert_pin_sha256([3; 32]);
+        assert!(matches!(
+            &builder.options.cert_validation,
+            CertPolicy::Pinned(fingerprints) if fingerprints == &[[3; 32]],
+        ));
+    }
+
+    #[cfg(feature = "client_async")]
+    #[test]
+    fn builder_fine_grained_cert_validation() {
+        let mut builder = GitlabBuilder::new("gitlab.host.invalid", "token");
+        builder
+            .cert_pin_sha256([0; 32])
+            .min_tls_version(reqwest::tls::Version::TLS_1_3);
+        builder.options.async_client().unwrap();
+
+        builder.cert_any_hostname();
+        builder.options.async_client().unwrap();
+
+        builder.add_root_certificate_der(b"not a certificate");
+        let err = builder.options.async_client().unwrap_err();
+        if let GitlabError::TlsConfiguration {
+            message,
+        } = err
+        {
+            assert_eq!(message, "invalid peer certificate: BadEncoding");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    /// Read an HTTP request from a connection.
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    fn read_request<R: BufRead>(reader: &mut R) {
+        let mut content_length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end().to_ascii_lowercase();
+            if line.is_empty() {
+                break;
+            }
+            if let Some(length) = line.strip_prefix("content-length:") {
+                content_length = length.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+    }
+
+    /// Serve HTTP requests with `503 Service Unavailable`, counting the requests received.
+    #[cfg(feature = "client_sync")]
+    fn serve_unavailable() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+
+                counter.fetch_add(1, Ordering::SeqCst);
+                stream
+                    .write_all(
+                        b"HTTP/1.1 503 Service Unavailable\r\n\
+                          content-length: 0\r\n\
+                          connection: close\r\n\r\n",
+                    )
+                    .unwrap();
+            }
+        });
+
+        (host, requests)
+    }
+
+    #[cfg(feature = "client_sync")]
+    #[test]
+    fn retry_idempotent_requests_only() {
+        let (host, requests) = serve_unavailable();
+        let policy = RetryPolicy::builder()
+            .init(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = GitlabBuilder::new_unauthenticated(host)
+            .insecure()
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+        let send = |method| {
+            let url = client.rest_endpoint("projects").unwrap();
+            let request = http::Request::builder().method(method).uri(url.as_str());
+            api::Client::rest(&client, request, Vec::new())
+                .unwrap()
+                .status()
+        };
+
+        assert_eq!(send(Method::POST), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert_eq!(send(Method::GET), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+    }
+
+    #[cfg(feature = "client_sync")]
+    #[test]
+    fn retry_non_idempotent_requests() {
+        let (host, requests) = serve_unavailable();
+        let policy = RetryPolicy::builder()
+            .init(Duration::from_millis(1))
+            .retry_non_idempotent(true)
+            .build()
+            .unwrap();
+        let client = GitlabBuilder::new_unauthenticated(host)
+            .insecure()
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+
+        // GraphQL requests are sent with the policies of the client.
+        let err = client
+            .graphql_query::<DummyQuery>(DummyVariables {
+                after: None,
+            })
+            .unwrap_err();
+        if let GitlabError::Http {
+            status,
+        } = err
+        {
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    /// Serve HTTP requests with a response which repeats a header.
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    fn serve_repeated_headers() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+
+                stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\n\
+                          set-cookie: first=1\r\n\
+                          set-cookie: second=2\r\n\
+                          content-length: 0\r\n\
+                          connection: close\r\n\r\n",
+                    )
+                    .unwrap();
+            }
+        });
+
+        host
+    }
+
+    #[cfg(any(feature = "client_sync", feature = "client_async"))]
+    fn assert_repeated_headers(headers: &HeaderMap) {
+        itertools::assert_equal(
+            headers.get_all(header::SET_COOKIE),
+            &["first=1", "second=2"],
+        );
+    }
+
+    #[cfg(feature = "client_sync")]
+    #[test]
+    fn repeated_response_headers() {
+        let client = GitlabBuilder::new_unauthenticated(serve_repeated_headers())
+            .insecure()
+            .build()
+            .unwrap();
+        let url = client.rest_endpoint("projects").unwrap();
+        let request = http::Request::builder().uri(url.as_str());
+        let rsp = api::Client::rest(&client, request, Vec::new()).unwrap();
+
+        assert_repeated_headers(rsp.headers());
+    }
+
+    #[cfg(feature = "client_async")]
+    #[tokio::test]
+    async fn repeated_response_headers_async() {
+        let client = GitlabBuilder::new_unauthenticated(serve_repeated_headers())
+            .insecure()
+            .build_async()
+            .await
+            .unwrap();
+        let rsp = api::AsyncClient::rest_async(&client, request(&client), Vec::new())
+            .await
+            .unwrap();
+
+        assert_repeated_headers(rsp.headers());
+    }
+
+    #[cfg(feature = "client_sync")]
+    #[test]
+    fn head_repeated_response_headers() {
+        let client = GitlabBuilder::new_unauthenticated(serve_repeated_headers())
+            .insecure()
+            .build()
+            .unwrap();
+        let endpoint = CurrentUser::builder().build().unwrap();
+        let headers = api::head(endpoint).query(&client).unwrap();
+
+        assert_repeated_headers(&headers);
+    }
+
+    #[cfg(feature = "client_async")]
+    #[tokio::test]
+    async fn head_repeated_response_headers_async() {
+        let client = GitlabBuilder::new_unauthenticated(serve_repeated_headers())
+            .insecure()
+            .build_async()
+            .await
+            .unwrap();
+        let endpoint = CurrentUser::builder().build().unwrap();
+        let headers = api::head(endpoint).query_async(&client).await.unwrap();
+
+        assert_repeated_headers(&headers);
+    }
+
+    /// Serve each HTTP request on its own thread with an empty JSON object.
+    ///
+    /// The body of the first response is not finished until the returned sender is used.
+    #[cfg(feature = "client_async")]
+    fn serve_slow_body() -> (String, Sender<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener.local_addr().unwrap().to_string();
+        let (release, released) = mpsc::channel();
+        let released = Arc::new(Mutex::new(released));
+        let requests = AtomicUsize::new(0);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let slow = requests.fetch_add(1, Ordering::SeqCst) == 0;
+                let released = Arc::clone(&released);
+                thread::spawn(move || {
+                    read_request(&mut BufReader::new(stream.try_clone().unwrap()));
+                    stream
+                        .write_all(
+                            b"HTTP/1.1 200 OK\r\n\
+                              content-type: application/json\r\n\
+                              content-length: 2\r\n\
+                              connection: close\r\n\r\n{",
+                        )
+                        .unwrap();
+                    stream.flush().unwrap();
+                    if slow {
+                        released.lock().unwrap().recv().unwrap();
+                    }
+                    stream.write_all(b"}").unwrap();
+                });
+            }
+        });
+
+        (host, release)
+    }
+
+    #[cfg(feature = "client_async")]
+    async fn throttled_client(host: String) -> AsyncGitlab {
+        GitlabBuilder::new_unauthenticated(host)
+            .insecure()
+            .max_concurrent_requests(1)
+            .build_async()
+            .await
+            .unwrap()
+    }
+
+    #[cfg(feature = "client_async")]
+    fn request(client: &AsyncGitlab) -> http::request::Builder {
+        let url = client.rest_endpoint("projects").unwrap();
+        http::Request::builder().uri(url.as_str())
+    }
+
+    #[cfg(feature = "client_async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn throttle_covers_response_body() {
+        let (host, release) = serve_slow_body();
+        let client = throttled_client(host).await;
+        let send = |client: AsyncGitlab| {
+            tokio::spawn(async move {
+                let request = request(&client);
+                api::AsyncClient::rest_async(&client, request, Vec::new())
+                    .await
+                    .unwrap()
+            })
+        };
+
+        let mut first = send(client.clone());
+        let mut second = send(client);
+
+        // One of the bodies is still being read, so the other request may not be sent.
+        let done = tokio::time::timeout(
+            Duration::from_millis(200),
+            future::select(&mut first, &mut second),
+        )
+        .await;
+        assert!(done.is_err());
+
+        release.send(()).unwrap();
+        for rsp in [first.await.unwrap(), second.await.unwrap()].iter() {
+            assert_eq!(rsp.status(), StatusCode::OK);
+            assert_eq!(rsp.body().as_ref(), b"{}");
+        }
+    }
+
+    #[cfg(feature = "client_async")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn throttle_covers_response_stream() {
+        let (host, release) = serve_slow_body();
+        let client = throttled_client(host).await;
+
+        let rsp = api::AsyncClient::rest_stream_async(&client, request(&client), Vec::new())
+            .await
+            .unwrap();
+
+        // The body is still being read, so further requests may not be sent.
+        let blocked = tokio::time::timeout(
+            Duration::from_millis(200),
+            api::AsyncClient::rest_async(&client, request(&client), Vec::new()),
+        )
+        .await;
+        assert!(blocked.is_err());
+
+        release.send(()).unwrap();
+        let mut body = rsp.into_body();
+        let mut contents = Vec::new();
+        while let Some(chunk) = body.next().await {
+            contents.extend_from_slice(&chunk.unwrap());
+        }
+        assert_eq!(contents, b"{}");
+
+        // Reading the body to the end releases the permit.
+        let rsp = api::AsyncClient::rest_async(&client, request(&client), Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        drop(body);
+    }
+
+    #[test]
+    fn instance_urls() {
+        let items = &[
+            ("gitlab.host.invalid", "https://gitlab.host.invalid/"),
+            ("gitlab.host.invalid/", "https://gitlab.host.invalid/"),
+            (
+                "gitlab.host.invalid:8080",
+                "https://gitlab.host.invalid:8080/",
+            ),
+            (
+                "gitlab.host.invalid/gitlab",
+                "https://gitlab.host.invalid/gitlab/",
+            ),
+            (
+                "gitlab.host.invalid/gitlab/",
+                "https://gitlab.host.invalid/gitlab/",
+            ),
+        ];
+
+        for (host, instance) in items {
+            let urls = InstanceUrls::new("https", host).unwrap();
+            assert_eq!(urls.instance_url.as_str(), *instance);
+            assert_eq!(urls.rest_url.as_str(), format!("{}api/v4/", instance));
+            assert_eq!(
+                urls.graphql_url.as_str(),
+                format!("{}api/graphql", instance)
+            );
+        }
+    }
+
+    #[test]
+    fn join_endpoint_keeps_prefix() {
+        let urls = InstanceUrls::new("https", "gitlab.host.invalid/gitlab").unwrap();
+
+        let items = &[
+            (
+                &urls.rest_url,
+                "projects",
+                "https://gitlab.host.invalid/gitlab/api/v4/projects",
+            ),
+            (
+                &urls.rest_url,
+                "/projects",
+                "https://gitlab.host.invalid/gitlab/api/v4/projects",
+            ),
+            (
+                &urls.instance_url,
+                "-/health",
+                "https://gitlab.host.invalid/gitlab/-/health",
+            ),
+            (
+                &urls.instance_url,
+                "/-/health",
+                "https://gitlab.host.invalid/gitlab/-/health",
+            ),
+        ];
+
+        for (base, endpoint, url) in items {
+            assert_eq!(join_endpoint(base, endpoint).unwrap().as_str(), *url);
+        }
     }
 }
@@ -11,15 +11,85 @@
 //! Gitlab does not have consistent structures for its hooks, so they often change from
 //! version to version.
 
+use std::cell::Cell;
+
 use serde::de::{Error, Unexpected};
 use serde::{Deserialize, Deserializer};
 use serde_json::{self, Value};
 
-use crate::systemhooks::SystemHook;
-use crate::webhooks::WebHook;
+use crate::systemhooks::{GroupMemberSystemHook, SubgroupSystemHook, SystemHook};
+use crate::webhooks::{
+    BuildHook, DeploymentHook, EmojiHook, IssueHook, MergeRequestHook, NoteHook, PipelineHook,
+    PushHook, ReleaseHook, WebHook, WikiPageHook,
+};
+
+thread_local! {
+    // Set while deserializing through `Lenient`. The derived implementations of the hook
+    // structures cannot pass any state down to their fields, so the hook enumerations check this
+    // instead.
+    //
+    // `const` initializers require Rust 1.59.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static LENIENT: Cell<bool> = Cell::new(false);
+}
+
+/// Whether hooks are being deserialized through `Lenient` on this thread.
+pub(crate) fn is_lenient() -> bool {
+    LENIENT.with(Cell::get)
+}
+
+/// Restores the previous state when dropped.
+struct LenientGuard {
+    previous: bool,
+}
+
+impl Drop for LenientGuard {
+    fn drop(&mut self) {
+        LENIENT.with(|lenient| lenient.set(self.previous));
+    }
+}
+
+fn lenient<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    let _guard = LenientGuard {
+        previous: LENIENT.with(|lenient| lenient.replace(true)),
+    };
+    f()
+}
+
+/// A wrapper which deserializes hooks leniently.
+///
+/// Gitlab adds new event kinds and enumeration values over time which cause hooks to fail to
+/// deserialize. Within this wrapper, such values are instead deserialized into the `Unknown`
+/// variant of the relevant enumeration.
+///
+/// ```
+/// # use gitlab::hooks::Lenient;
+/// # use gitlab::webhooks::WebHook;
+/// let hook: Lenient<WebHook> = serde_json::from_str(r#"{"object_kind": "feature_flag"}"#).unwrap();
+/// assert!(matches!(hook.0, WebHook::Unknown(_)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lenient<T>(pub T);
+
+impl<'de, T> Deserialize<'de> for Lenient<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        lenient(|| T::deserialize(deserializer)).map(Lenient)
+    }
+}
 
 /// A deserializable structure for all Gitlab hooks.
 #[derive(Debug, Clone)]
+// Boxing `SystemHook` would change the public API.
+#[allow(clippy::large_enum_variant)]
 pub enum GitlabHook {
     /// A system hook.
     System(SystemHook),
@@ -52,3 +122,83 @@ impl<'de> Deserialize<'de> for GitlabHook {
         })
     }
 }
+
+/// A hook identified by the `X-Gitlab-Event` header sent along with it.
+///
+/// Confidential issue and note events use the same variants as their public counterparts.
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    /// A push or tag push hook.
+    Push(Box<PushHook>),
+    /// An issue hook.
+    Issue(Box<IssueHook>),
+    /// A merge request hook.
+    MergeRequest(Box<MergeRequestHook>),
+    /// A note hook.
+    Note(Box<NoteHook>),
+    /// A job hook.
+    Build(Box<BuildHook>),
+    /// A pipeline hook.
+    Pipeline(Box<PipelineHook>),
+    /// A wiki page hook.
+    WikiPage(Box<WikiPageHook>),
+    /// A deployment hook.
+    Deployment(Box<DeploymentHook>),
+    /// A release hook.
+    Release(Box<ReleaseHook>),
+    /// An emoji hook.
+    Emoji(Box<EmojiHook>),
+    /// A group membership hook.
+    Member(Box<GroupMemberSystemHook>),
+    /// A subgroup hook.
+    Subgroup(Box<SubgroupSystemHook>),
+    /// A system hook.
+    System(Box<SystemHook>),
+    /// A hook with an unrecognized `X-Gitlab-Event` header.
+    ///
+    /// Only produced by `WebhookEvent::parse_lenient`.
+    Unknown(String),
+}
+
+impl WebhookEvent {
+    /// The name of the header which identifies the hook.
+    pub const HEADER: &'static str = "X-Gitlab-Event";
+
+    /// Parse a hook given the value of its `X-Gitlab-Event` header and its body.
+    pub fn parse(header: &str, body: &[u8]) -> Result<Self, serde_json::Error> {
+        Ok(match header {
+            "Push Hook" | "Tag Push Hook" => WebhookEvent::Push(serde_json::from_slice(body)?),
+            "Issue Hook" | "Confidential Issue Hook" => {
+                WebhookEvent::Issue(serde_json::from_slice(body)?)
+            },
+            "Merge Request Hook" => WebhookEvent::MergeRequest(serde_json::from_slice(body)?),
+            "Note Hook" | "Confidential Note Hook" => {
+                WebhookEvent::Note(serde_json::from_slice(body)?)
+            },
+            "Job Hook" | "Build Hook" => WebhookEvent::Build(serde_json::from_slice(body)?),
+            "Pipeline Hook" => WebhookEvent::Pipeline(serde_json::from_slice(body)?),
+            "Wiki Page Hook" => WebhookEvent::WikiPage(serde_json::from_slice(body)?),
+            "Deployment Hook" => WebhookEvent::Deployment(serde_json::from_slice(body)?),
+            "Release Hook" => WebhookEvent::Release(serde_json::from_slice(body)?),
+            "Emoji Hook" => WebhookEvent::Emoji(serde_json::from_slice(body)?),
+            "Member Hook" => WebhookEvent::Member(serde_json::from_slice(body)?),
+            "Subgroup Hook" => WebhookEvent::Subgroup(serde_json::from_slice(body)?),
+            "System Hook" => WebhookEvent::System(serde_json::from_slice(body)?),
+            _ if is_lenient() => WebhookEvent::Unknown(header.into()),
+            _ => {
+                return Err(serde_json::Error::custom(format!(
+                    "unrecognized webhook event: {}",
+                    header,
+                )));
+            },
+        })
+    }
+
+    /// Parse a hook leniently.
+    ///
+    /// Unrecognized headers are parsed as `WebhookEvent::Unknown` and unrecognized values within
+    /// the hook are handled as described for `Lenient`.
+    pub fn parse_lenient(header: &str, body: &[u8]) -> Result<Self, serde_json::Error> {
+        lenient(|| Self::parse(header, body))
+    }
+}
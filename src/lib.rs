@@ -11,23 +11,42 @@
 
 #[macro_use]
 mod macros;
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 mod gitlab;
 
 pub mod hooks;
+#[cfg(feature = "webhook_receiver")]
+pub mod receiver;
 pub mod systemhooks;
 pub mod types;
 pub mod webhooks;
 
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 pub mod api;
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 mod auth;
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
+mod backend;
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
+mod request_hook;
+#[cfg(feature = "client_async")]
+mod throttle;
+#[cfg(all(
+    any(feature = "client_sync", feature = "client_async"),
+    not(target_arch = "wasm32"),
+))]
+mod tls;
 
-#[cfg(feature = "client_api")]
-pub use crate::auth::AuthError;
-#[cfg(feature = "client_api")]
-pub use crate::gitlab::{AsyncGitlab, Gitlab, GitlabBuilder, GitlabError};
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
+pub use crate::auth::{AuthError, OAuth2Refresh, ProvidedToken, TokenProvider, TokenProviderError};
+#[cfg(feature = "client_async")]
+pub use crate::gitlab::AsyncGitlab;
+#[cfg(feature = "client_sync")]
+pub use crate::gitlab::Gitlab;
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
+pub use crate::gitlab::{GitlabBuilder, GitlabError, GraphQLPageInfo};
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
+pub use crate::request_hook::{RequestHook, RequestInfo};
 pub use crate::types::*;
 
 #[cfg(test)]
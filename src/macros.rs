@@ -71,3 +71,44 @@ macro_rules! enum_serialize {
         }
     };
 }
+
+macro_rules! hook_enum_serialize {
+    ( $name:ident, $( $value:ident => $str:expr, )+ ) => {
+        impl $name {
+            /// String representation of the variant.
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $( $name::$value => $str, )*
+                    $name::Unknown(ref value) => value,
+                }
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+                where S: Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where D: Deserializer<'de>,
+            {
+                let val = String::deserialize(deserializer)?;
+
+                match val.as_str() {
+                    $( $str => Ok($name::$value), )*
+                    v => {
+                        if crate::hooks::is_lenient() {
+                            Ok($name::Unknown(val))
+                        } else {
+                            Err(D::Error::unknown_variant(v, &[$( $str, )*]))
+                        }
+                    },
+                }
+            }
+        }
+    };
+}
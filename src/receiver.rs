@@ -0,0 +1,349 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hook receiver
+//!
+//! A small HTTP service which accepts hooks from Gitlab, parses them, and delivers them over a
+//! channel. Requires the `webhook_receiver` feature.
+
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper::body::HttpBody;
+use hyper::service::{make_service_fn, Service};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use log::warn;
+use tokio::sync::mpsc;
+
+use crate::hooks::WebhookEvent;
+
+/// The header containing the secret token configured for the hook.
+const TOKEN_HEADER: &str = "X-Gitlab-Token";
+/// The default limit on the size of hook bodies.
+const DEFAULT_MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+
+/// A service which receives hooks from Gitlab.
+///
+/// Requests are answered once the hook has been queued on the channel. Responses use the
+/// following status codes:
+///
+///   - `200 OK`: the hook was delivered;
+///   - `400 Bad Request`: the event header is missing or the body could not be parsed;
+///   - `401 Unauthorized`: the token does not match;
+///   - `405 Method Not Allowed`: the request is not a `POST`;
+///   - `413 Payload Too Large`: the body is larger than the configured limit;
+///   - `503 Service Unavailable`: the receiving end of the channel has been dropped.
+#[derive(Debug, Clone)]
+pub struct WebhookReceiver {
+    token: Option<Arc<str>>,
+    lenient: bool,
+    max_body_size: usize,
+    sender: mpsc::Sender<WebhookEvent>,
+}
+
+impl WebhookReceiver {
+    /// Create a receiver which delivers hooks to the given channel.
+    pub fn new(sender: mpsc::Sender<WebhookEvent>) -> Self {
+        Self {
+            token: None,
+            lenient: false,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            sender,
+        }
+    }
+
+    /// Create a receiver along with a channel holding up to `buffer` undelivered hooks.
+    pub fn channel(buffer: usize) -> (Self, mpsc::Receiver<WebhookEvent>) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        (Self::new(sender), receiver)
+    }
+
+    /// Require the secret token configured for the hook in Gitlab.
+    ///
+    /// Without a token, all hooks are accepted, so anyone able to reach the service may submit
+    /// hooks. A warning is logged when serving without a token.
+    pub fn token<T>(mut self, token: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.token = Some(token.as_ref().into());
+        self
+    }
+
+    /// Parse hooks using `WebhookEvent::parse_lenient`.
+    ///
+    /// Hooks are parsed strictly by default.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// The largest hook body to accept, in bytes.
+    ///
+    /// Larger requests are rejected without reading the rest of the body. Defaults to 10 MiB.
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Serve hooks on the given address until an error occurs.
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), hyper::Error> {
+        if self.token.is_none() {
+            warn!(target: "gitlab", "serving hooks on {} without a secret token", addr);
+        }
+
+        let make_service = make_service_fn(move |_| {
+            let receiver = self.clone();
+            async move { Ok::<_, Infallible>(receiver) }
+        });
+
+        Server::try_bind(&addr)?.serve(make_service).await
+    }
+
+    fn is_authorized(&self, req: &Request<Body>) -> bool {
+        let expected = if let Some(token) = self.token.as_ref() {
+            token
+        } else {
+            return true;
+        };
+
+        if let Some(token) = req.headers().get(TOKEN_HEADER) {
+            constant_time_eq(token.as_bytes(), expected.as_bytes())
+        } else {
+            false
+        }
+    }
+
+    /// Read the body of a request, unless it exceeds the size limit.
+    async fn read_body(&self, mut body: Body) -> Result<Option<Vec<u8>>, hyper::Error> {
+        let mut data = Vec::new();
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk?;
+            if data.len() + chunk.len() > self.max_body_size {
+                return Ok(None);
+            }
+            data.extend_from_slice(&chunk);
+        }
+        Ok(Some(data))
+    }
+
+    async fn handle(self, req: Request<Body>) -> Response<Body> {
+        if req.method() != Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+        if !self.is_authorized(&req) {
+            return status(StatusCode::UNAUTHORIZED);
+        }
+
+        let event = if let Some(event) = req.headers().get(WebhookEvent::HEADER) {
+            if let Ok(event) = event.to_str() {
+                event.to_owned()
+            } else {
+                return status(StatusCode::BAD_REQUEST);
+            }
+        } else {
+            return status(StatusCode::BAD_REQUEST);
+        };
+
+        let content_length = req
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        if let Some(content_length) = content_length {
+            if content_length > self.max_body_size as u64 {
+                return status(StatusCode::PAYLOAD_TOO_LARGE);
+            }
+        }
+
+        let body = match self.read_body(req.into_body()).await {
+            Ok(Some(body)) => body,
+            Ok(None) => return status(StatusCode::PAYLOAD_TOO_LARGE),
+            Err(err) => {
+                warn!(target: "gitlab", "failed to read hook body: {:?}", err);
+                return status(StatusCode::BAD_REQUEST);
+            },
+        };
+
+        let hook = if self.lenient {
+            WebhookEvent::parse_lenient(&event, &body)
+        } else {
+            WebhookEvent::parse(&event, &body)
+        };
+        let hook = match hook {
+            Ok(hook) => hook,
+            Err(err) => {
+                warn!(target: "gitlab", "failed to parse '{}' hook: {:?}", event, err);
+                return status(StatusCode::BAD_REQUEST);
+            },
+        };
+
+        if self.sender.send(hook).await.is_err() {
+            return status(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        status(StatusCode::OK)
+    }
+}
+
+impl Service<Request<Body>> for WebhookReceiver {
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let receiver = self.clone();
+        Box::pin(async move { Ok(receiver.handle(req).await) })
+    }
+}
+
+fn status(status: StatusCode) -> Response<Body> {
+    let mut rsp = Response::new(Body::empty());
+    *rsp.status_mut() = status;
+    rsp
+}
+
+/// Compare tokens without short-circuiting on the first difference.
+fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    lhs.len() == rhs.len() && lhs.iter().zip(rhs).fold(0, |acc, (l, r)| acc | (l ^ r)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use hyper::service::Service;
+    use hyper::{header, Body, Method, Request, StatusCode};
+
+    use crate::hooks::WebhookEvent;
+    use crate::receiver::{self, WebhookReceiver};
+
+    fn request(event: &str, token: Option<&str>) -> Request<Body> {
+        let body = fs::read("src/test/examples/pipeline.json").unwrap();
+        let mut req = Request::post("/").header(WebhookEvent::HEADER, event);
+        if let Some(token) = token {
+            req = req.header("X-Gitlab-Token", token);
+        }
+        req.body(body.into()).unwrap()
+    }
+
+    async fn call(receiver: &mut WebhookReceiver, req: Request<Body>) -> StatusCode {
+        receiver.call(req).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn delivers_hooks() {
+        let (mut receiver, mut hooks) = WebhookReceiver::channel(1);
+
+        let status = call(&mut receiver, request("Pipeline Hook", None)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(matches!(
+            hooks.recv().await.unwrap(),
+            WebhookEvent::Pipeline(_),
+        ));
+    }
+
+    #[tokio::test]
+    async fn checks_token() {
+        let (receiver, mut hooks) = WebhookReceiver::channel(1);
+        let mut receiver = receiver.token("secret");
+
+        let status = call(&mut receiver, request("Pipeline Hook", None)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let status = call(&mut receiver, request("Pipeline Hook", Some("secreT"))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let status = call(&mut receiver, request("Pipeline Hook", Some("secret"))).await;
+        assert_eq!(status, StatusCode::OK);
+
+        hooks.close();
+        assert!(hooks.recv().await.is_some());
+        assert!(hooks.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_requests() {
+        let (mut receiver, hooks) = WebhookReceiver::channel(1);
+
+        let status = call(&mut receiver, request("Release Hook", None)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let status = call(&mut receiver, request("Unknown Hook", None)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let req = Request::post("/").body(Body::empty()).unwrap();
+        assert_eq!(call(&mut receiver, req).await, StatusCode::BAD_REQUEST);
+
+        let req = Request::builder()
+            .method(Method::GET)
+            .uri("/")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            call(&mut receiver, req).await,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+
+        drop(hooks);
+        let status = call(&mut receiver, request("Pipeline Hook", None)).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn limits_body_size() {
+        let size = fs::metadata("src/test/examples/pipeline.json")
+            .unwrap()
+            .len() as usize;
+        let (receiver, mut hooks) = WebhookReceiver::channel(1);
+        let mut receiver = receiver.max_body_size(size - 1);
+
+        let status = call(&mut receiver, request("Pipeline Hook", None)).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+        // The declared length is checked before reading the body.
+        let req = Request::post("/")
+            .header(WebhookEvent::HEADER, "Pipeline Hook")
+            .header(header::CONTENT_LENGTH, size)
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            call(&mut receiver, req).await,
+            StatusCode::PAYLOAD_TOO_LARGE,
+        );
+
+        let mut receiver = receiver.max_body_size(size);
+        let status = call(&mut receiver, request("Pipeline Hook", None)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(hooks.recv().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn lenient() {
+        let (receiver, mut hooks) = WebhookReceiver::channel(1);
+        let mut receiver = receiver.lenient(true);
+
+        let status = call(&mut receiver, request("Unknown Hook", None)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(matches!(
+            hooks.recv().await.unwrap(),
+            WebhookEvent::Unknown(ref header) if header == "Unknown Hook",
+        ));
+    }
+
+    #[test]
+    fn constant_time_eq() {
+        assert!(receiver::constant_time_eq(b"", b""));
+        assert!(receiver::constant_time_eq(b"token", b"token"));
+        assert!(!receiver::constant_time_eq(b"token", b"tokeN"));
+        assert!(!receiver::constant_time_eq(b"token", b"tokens"));
+    }
+}
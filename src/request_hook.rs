@@ -0,0 +1,167 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::{HeaderMap, Method, StatusCode};
+use url::Url;
+
+use crate::api::rate_limit::RateLimit;
+
+/// Information about a request sent by a client.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestInfo<'a> {
+    /// The method of the request.
+    pub method: &'a Method,
+    /// The URL of the request.
+    pub url: &'a Url,
+    /// The status of the response.
+    ///
+    /// This is `None` if no response was received (e.g., due to a connection error or timeout).
+    pub status: Option<StatusCode>,
+    /// How long it took to receive the response headers.
+    pub duration: Duration,
+    /// The state of the rate limit reported by the response.
+    pub rate_limit: RateLimit,
+    /// The number of times the request has been retried before this attempt.
+    pub retries: usize,
+}
+
+/// A hook which is notified about every request sent by a client.
+///
+/// Hooks may be used to export metrics such as latencies, status counts, or the remaining rate
+/// limit. Retried requests are reported for each attempt. Hooks are called synchronously while
+/// sending requests, so they should return quickly.
+pub trait RequestHook: Send + Sync {
+    /// Called when a request has completed.
+    fn on_request_complete(&self, info: &RequestInfo);
+}
+
+impl<F> RequestHook for F
+where
+    F: Fn(&RequestInfo) + Send + Sync,
+{
+    fn on_request_complete(&self, info: &RequestInfo) {
+        self(info)
+    }
+}
+
+/// The request hooks registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct RequestHooks {
+    hooks: Vec<Arc<dyn RequestHook>>,
+}
+
+impl RequestHooks {
+    pub(crate) fn add(&mut self, hook: Arc<dyn RequestHook>) {
+        self.hooks.push(hook);
+    }
+
+    /// Notify the hooks about a completed request.
+    pub(crate) fn notify(
+        &self,
+        method: &Method,
+        url: &Url,
+        rsp: Option<(StatusCode, &HeaderMap)>,
+        duration: Duration,
+        retries: usize,
+    ) {
+        if self.hooks.is_empty() {
+            return;
+        }
+
+        let info = RequestInfo {
+            method,
+            url,
+            status: rsp.map(|(status, _)| status),
+            duration,
+            rate_limit: rsp
+                .map(|(_, headers)| RateLimit::from_headers(headers))
+                .unwrap_or_default(),
+            retries,
+        };
+        self.hooks
+            .iter()
+            .for_each(|hook| hook.on_request_complete(&info));
+    }
+}
+
+impl Debug for RequestHooks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RequestHooks")
+            .field("hooks", &self.hooks.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use http::{HeaderMap, HeaderValue, Method, StatusCode};
+    use url::Url;
+
+    use crate::request_hook::{RequestHooks, RequestInfo};
+
+    #[test]
+    fn request_hooks_are_notified() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = RequestHooks::default();
+        for idx in 0..2 {
+            let seen = seen.clone();
+            hooks.add(Arc::new(move |info: &RequestInfo| {
+                seen.lock().unwrap().push((
+                    idx,
+                    info.method.clone(),
+                    info.url.to_string(),
+                    info.status,
+                    info.rate_limit.remaining,
+                    info.retries,
+                ));
+            }));
+        }
+
+        let url = Url::parse("https://gitlab.host.invalid/api/v4/user").unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("RateLimit-Remaining", HeaderValue::from_static("10"));
+        hooks.notify(
+            &Method::GET,
+            &url,
+            Some((StatusCode::OK, &headers)),
+            Duration::from_millis(5),
+            0,
+        );
+        hooks.notify(&Method::GET, &url, None, Duration::from_millis(5), 1);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                (
+                    0,
+                    Method::GET,
+                    url.to_string(),
+                    Some(StatusCode::OK),
+                    Some(10),
+                    0
+                ),
+                (
+                    1,
+                    Method::GET,
+                    url.to_string(),
+                    Some(StatusCode::OK),
+                    Some(10),
+                    0
+                ),
+                (0, Method::GET, url.to_string(), None, None, 1),
+                (1, Method::GET, url.to_string(), None, None, 1),
+            ],
+        );
+    }
+}
@@ -18,25 +18,32 @@ use serde::de::{Error, Unexpected};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{self, Value};
 
+use crate::hooks::is_lenient;
 use crate::types::{AccessLevel, GroupId, ObjectId, ProjectId, SshKeyId, UserId};
 use crate::webhooks::{CommitHookAttrs, ProjectHookAttrs};
 
 /// Events which occur at the project level.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProjectEvent {
     /// A project was created.
-    #[serde(rename = "project_create")]
     Create,
     /// A project was deleted.
-    #[serde(rename = "project_destroy")]
     Destroy,
     /// A project was renamed.
-    #[serde(rename = "project_rename")]
     Rename,
     /// A project moved from one namespace to another.
-    #[serde(rename = "project_transfer")]
     Transfer,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(ProjectEvent,
+    Create => "project_create",
+    Destroy => "project_destroy",
+    Rename => "project_rename",
+    Transfer => "project_transfer",
+);
 
 /// Visibility levels for projects.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -83,15 +90,24 @@ pub struct ProjectSystemHook {
 }
 
 /// Events which occur when users are added and removed from projects.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProjectMemberEvent {
     /// A user was added to a project.
-    #[serde(rename = "user_add_to_team")]
     Add,
     /// A user was removed from a project.
-    #[serde(rename = "user_remove_from_team")]
     Remove,
+    /// The access level of a user in a project was changed.
+    Update,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(ProjectMemberEvent,
+    Add => "user_add_to_team",
+    Remove => "user_remove_from_team",
+    Update => "user_update_for_team",
+);
 
 /// Access levels for groups and projects.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -152,15 +168,21 @@ pub struct ProjectMemberSystemHook {
 }
 
 /// Events which occur for user accounts.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UserEvent {
     /// The user account was created.
-    #[serde(rename = "user_create")]
     Create,
     /// The user account was deleted.
-    #[serde(rename = "user_destroy")]
     Destroy,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(UserEvent,
+    Create => "user_create",
+    Destroy => "user_destroy",
+);
 
 /// A user hook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -182,15 +204,21 @@ pub struct UserSystemHook {
 }
 
 /// Events which occur for SSH keys.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum KeyEvent {
     /// An SSH key was uploaded.
-    #[serde(rename = "key_create")]
     Create,
     /// An SSH key was deleted.
-    #[serde(rename = "key_destroy")]
     Destroy,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(KeyEvent,
+    Create => "key_create",
+    Destroy => "key_destroy",
+);
 
 /// An SSH key hook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -210,15 +238,21 @@ pub struct KeySystemHook {
 }
 
 /// Events which occur for groups.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GroupEvent {
     /// The group was created.
-    #[serde(rename = "group_create")]
     Create,
     /// The group was deleted.
-    #[serde(rename = "group_destrpy")]
     Destroy,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(GroupEvent,
+    Create => "group_create",
+    Destroy => "group_destroy",
+);
 
 /// A group hook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -242,15 +276,24 @@ pub struct GroupSystemHook {
 }
 
 /// Events which occur for group memberships.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GroupMemberEvent {
     /// A user was added to the group.
-    #[serde(rename = "user_add_to_group")]
     Add,
     /// A user was removed from the group.
-    #[serde(rename = "user_remove_from_group")]
     Remove,
+    /// The access level of a user in the group was changed.
+    Update,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(GroupMemberEvent,
+    Add => "user_add_to_group",
+    Remove => "user_remove_from_group",
+    Update => "user_update_for_group",
+);
 
 /// A group membership hook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -279,16 +322,66 @@ pub struct GroupMemberSystemHook {
     pub group_access: HumanAccessLevel,
 }
 
+/// Events which occur for subgroups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubgroupEvent {
+    /// The subgroup was created.
+    Create,
+    /// The subgroup was deleted.
+    Destroy,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(SubgroupEvent,
+    Create => "subgroup_create",
+    Destroy => "subgroup_destroy",
+);
+
+/// A subgroup hook.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubgroupSystemHook {
+    /// The event which occurred.
+    pub event_name: SubgroupEvent,
+    /// When the subgroup was created.
+    pub created_at: DateTime<Utc>,
+    /// When the subgroup was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// The name of the subgroup.
+    pub name: String,
+    /// The path of the subgroup (used for URLs).
+    pub path: String,
+    /// The path of the subgroup including its parent groups.
+    pub full_path: String,
+    /// The ID of the subgroup.
+    pub group_id: GroupId,
+    /// The ID of the parent group.
+    pub parent_group_id: GroupId,
+    /// The name of the parent group.
+    pub parent_name: String,
+    /// The path of the parent group (used for URLs).
+    pub parent_path: String,
+    /// The path of the parent group including its parent groups.
+    pub parent_full_path: String,
+}
+
 /// Events which occur when a push happens.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PushEvent {
     /// A non-tag push occurred.
-    #[serde(rename = "push")]
     Push,
     /// A tag was pushed.
-    #[serde(rename = "tag_push")]
     TagPush,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(PushEvent,
+    Push => "push",
+    TagPush => "tag_push",
+);
 
 /// A push hook.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -348,8 +441,14 @@ pub enum SystemHook {
     Group(GroupSystemHook),
     /// A group membership hook.
     GroupMember(GroupMemberSystemHook),
+    /// A subgroup hook.
+    Subgroup(SubgroupSystemHook),
     /// A push hook.
     Push(Box<PushSystemHook>),
+    /// A hook with an unrecognized event name.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
 
 impl<'de> Deserialize<'de> for SystemHook {
@@ -377,7 +476,7 @@ impl<'de> Deserialize<'de> for SystemHook {
                 serde_json::from_value(val).map(SystemHook::Project)
             },
 
-            "user_add_to_team" | "user_remove_from_team" => {
+            "user_add_to_team" | "user_remove_from_team" | "user_update_for_team" => {
                 serde_json::from_value(val).map(SystemHook::ProjectMember)
             },
 
@@ -387,14 +486,22 @@ impl<'de> Deserialize<'de> for SystemHook {
 
             "group_create" | "group_destroy" => serde_json::from_value(val).map(SystemHook::Group),
 
-            "user_add_to_group" | "user_remove_from_group" => {
+            "user_add_to_group" | "user_remove_from_group" | "user_update_for_group" => {
                 serde_json::from_value(val).map(SystemHook::GroupMember)
             },
 
+            "subgroup_create" | "subgroup_destroy" => {
+                serde_json::from_value(val).map(SystemHook::Subgroup)
+            },
+
             "push" | "tag_push" => {
                 serde_json::from_value(val).map(|hook| SystemHook::Push(Box::new(hook)))
             },
 
+            _ if is_lenient() => {
+                return Ok(SystemHook::Unknown(event_name.clone()));
+            },
+
             _ => {
                 return Err(D::Error::custom(format!(
                     "unrecognized system event name: {}",
@@ -4,13 +4,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 pub mod client;
 
+mod hooks;
+mod systemhooks;
 mod types;
 mod webhooks;
 
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 macro_rules! assert_missing_field {
     ($err:expr, $type:tt, $field:expr $(,)?) => {
         let in_err = $err;
@@ -25,5 +27,5 @@ macro_rules! assert_missing_field {
         }
     };
 }
-#[cfg(feature = "client_api")]
+#[cfg(any(feature = "client_sync", feature = "client_async"))]
 pub(crate) use assert_missing_field;
@@ -8,6 +8,7 @@ use std::borrow::Cow;
 use std::cmp;
 use std::collections::HashMap;
 use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -18,13 +19,15 @@ use serde::ser::Serialize;
 use thiserror::Error;
 use url::Url;
 
-use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::api::{ApiError, AsyncClient, Client, RestClient, UrlBase};
 
 #[derive(Debug, Builder)]
 pub struct ExpectedUrl {
     #[builder(default = "Method::GET")]
     pub method: Method,
     pub endpoint: &'static str,
+    #[builder(default = "UrlBase::ApiV4")]
+    pub url_base: UrlBase,
     #[builder(default)]
     pub query: Vec<(Cow<'static, str>, Cow<'static, str>)>,
     #[builder(setter(strip_option, into), default)]
@@ -67,7 +70,7 @@ impl ExpectedUrl {
         assert_eq!(url.password(), None);
         assert_eq!(url.host_str().unwrap(), "gitlab.host.invalid");
         assert_eq!(url.port(), None);
-        assert_eq!(url.path(), format!("/api/v4/{}", self.endpoint));
+        assert_eq!(url.path(), self.path());
         let mut count = 0;
         for (ref key, ref value) in url.query_pairs() {
             if self.paginated && Self::is_pagination_key(key) {
@@ -87,6 +90,13 @@ impl ExpectedUrl {
         assert_eq!(url.fragment(), None);
     }
 
+    fn path(&self) -> String {
+        match self.url_base {
+            UrlBase::ApiV4 => format!("/api/v4/{}", self.endpoint),
+            UrlBase::Instance => format!("/{}", self.endpoint),
+        }
+    }
+
     fn is_pagination_key(key: &str) -> bool {
         key == "pagination" || key == "__test_keyset" || key == "page" || key == "per_page"
     }
@@ -113,6 +123,7 @@ struct MockClient {
 }
 
 const CLIENT_STUB: &str = "https://gitlab.host.invalid/api/v4";
+const INSTANCE_STUB: &str = "https://gitlab.host.invalid";
 
 pub struct SingleTestClient {
     client: MockClient,
@@ -127,10 +138,7 @@ impl SingleTestClient {
     {
         let mut client = MockClient::default();
 
-        let request = (
-            expected.method.clone(),
-            format!("/api/v4/{}", expected.endpoint),
-        );
+        let request = (expected.method.clone(), expected.path());
         let response = MockResponse {
             status: expected.status,
             data: data.into(),
@@ -163,6 +171,10 @@ impl RestClient for SingleTestClient {
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
         Ok(Url::parse(&format!("{}/{}", CLIENT_STUB, endpoint))?)
     }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/{}", INSTANCE_STUB, endpoint))?)
+    }
 }
 
 impl Client for SingleTestClient {
@@ -243,6 +255,7 @@ impl Page {
 pub struct PagedTestClient<T> {
     expected: ExpectedUrl,
     data: Vec<T>,
+    requests: AtomicUsize,
 }
 
 const KEYSET_QUERY_PARAM: &str = "__test_keyset";
@@ -256,8 +269,14 @@ impl<T> PagedTestClient<T> {
         Self {
             expected,
             data: data.into_iter().collect(),
+            requests: AtomicUsize::new(0),
         }
     }
+
+    /// The number of requests which have been made through the client.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
 }
 
 impl<T> RestClient for PagedTestClient<T> {
@@ -266,6 +285,10 @@ impl<T> RestClient for PagedTestClient<T> {
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
         Ok(Url::parse(&format!("{}/{}", CLIENT_STUB, endpoint))?)
     }
+
+    fn instance_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(&format!("{}/{}", INSTANCE_STUB, endpoint))?)
+    }
 }
 
 impl<T> Client for PagedTestClient<T>
@@ -277,6 +300,8 @@ where
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+
         let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
 
         self.expected
@@ -366,6 +391,44 @@ where
             } else {
                 response
             }
+        } else if let Page::ByNumber {
+            number, ..
+        } = page
+        {
+            // Report the offset pagination headers.
+            let total_pages = self.data.chunks(per_page).len();
+            let page_url = |number: usize| {
+                let mut page_url = url.clone();
+                page_url
+                    .query_pairs_mut()
+                    .clear()
+                    .extend_pairs(url.query_pairs().filter(|(key, _)| key != "page"))
+                    .append_pair("page", &format!("{}", number));
+                page_url
+            };
+            let mut links = Vec::new();
+            let mut next_page = String::new();
+            let mut prev_page = String::new();
+            if number < total_pages {
+                links.push(format!("<{}>; rel=\"next\"", page_url(number + 1)));
+                next_page = format!("{}", number + 1);
+            }
+            if number > 1 {
+                links.push(format!("<{}>; rel=\"prev\"", page_url(number - 1)));
+                prev_page = format!("{}", number - 1);
+            }
+            let response = response
+                .header("x-total", self.data.len())
+                .header("x-total-pages", total_pages)
+                .header("x-per-page", per_page)
+                .header("x-page", number)
+                .header("x-next-page", next_page)
+                .header("x-prev-page", prev_page);
+            if links.is_empty() {
+                response
+            } else {
+                response.header(http::header::LINK, links.join(", "))
+            }
         } else {
             response
         };
@@ -0,0 +1,178 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+
+use serde_json::json;
+
+use crate::hooks::{GitlabHook, Lenient, WebhookEvent};
+use crate::systemhooks::{SubgroupEvent, SystemHook};
+use crate::webhooks::{DeploymentStatus, EmojiAction, NoteHookAttrs, NoteableType, WebHook};
+
+fn parse(header: &str, name: &str) -> Result<WebhookEvent, serde_json::Error> {
+    let body = fs::read(format!("src/test/examples/{}.json", name)).unwrap();
+    WebhookEvent::parse(header, &body)
+}
+
+#[test]
+fn test_webhook_event_parse() {
+    assert!(matches!(
+        parse("Pipeline Hook", "pipeline").unwrap(),
+        WebhookEvent::Pipeline(_),
+    ));
+    assert!(matches!(
+        parse("Deployment Hook", "deployment").unwrap(),
+        WebhookEvent::Deployment(_),
+    ));
+    assert!(matches!(
+        parse("Release Hook", "release").unwrap(),
+        WebhookEvent::Release(_),
+    ));
+    assert!(matches!(
+        parse("Emoji Hook", "emoji").unwrap(),
+        WebhookEvent::Emoji(_),
+    ));
+    assert!(matches!(
+        parse("Member Hook", "user_update_for_group").unwrap(),
+        WebhookEvent::Member(_),
+    ));
+    assert!(matches!(
+        parse("Subgroup Hook", "subgroup_create").unwrap(),
+        WebhookEvent::Subgroup(_),
+    ));
+
+    let event = parse("System Hook", "user_update_for_team").unwrap();
+    if let WebhookEvent::System(hook) = event {
+        assert!(matches!(*hook, SystemHook::ProjectMember(_)));
+    } else {
+        panic!("unexpected event: {:?}", event);
+    }
+}
+
+#[test]
+fn test_webhook_event_parse_unknown() {
+    let err = parse("Feature Flag Hook", "pipeline").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unrecognized webhook event: Feature Flag Hook"
+    );
+}
+
+#[test]
+fn test_webhook_event_parse_mismatch() {
+    parse("Release Hook", "pipeline").unwrap_err();
+}
+
+#[test]
+fn test_lenient_unknown_header() {
+    let body = fs::read("src/test/examples/pipeline.json").unwrap();
+    let event = WebhookEvent::parse_lenient("Feature Flag Hook", &body).unwrap();
+    if let WebhookEvent::Unknown(header) = event {
+        assert_eq!(header, "Feature Flag Hook");
+    } else {
+        panic!("unexpected event: {:?}", event);
+    }
+
+    // Leniency does not outlive the call.
+    WebhookEvent::parse("Feature Flag Hook", &body).unwrap_err();
+}
+
+#[test]
+fn test_lenient_unknown_object_kind() {
+    let hook = json!({
+        "object_kind": "feature_flag",
+    });
+
+    serde_json::from_value::<GitlabHook>(hook.clone()).unwrap_err();
+
+    let Lenient(hook) = serde_json::from_value::<Lenient<GitlabHook>>(hook).unwrap();
+    if let GitlabHook::Web(hook) = hook {
+        assert!(matches!(*hook, WebHook::Unknown(ref kind) if kind == "feature_flag"));
+    } else {
+        panic!("unexpected hook: {:?}", hook);
+    }
+}
+
+#[test]
+fn test_lenient_unknown_event_name() {
+    let hook = json!({
+        "event_name": "repository_update",
+    });
+
+    serde_json::from_value::<SystemHook>(hook.clone()).unwrap_err();
+
+    let Lenient(hook) = serde_json::from_value::<Lenient<SystemHook>>(hook).unwrap();
+    assert!(matches!(hook, SystemHook::Unknown(ref name) if name == "repository_update"));
+}
+
+#[test]
+fn test_lenient_unknown_enum_value() {
+    let body = fs::read_to_string("src/test/examples/emoji.json").unwrap();
+    let body = body.replace("\"event_type\": \"award\"", "\"event_type\": \"react\"");
+
+    let err = WebhookEvent::parse("Emoji Hook", body.as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `react`"));
+
+    let event = WebhookEvent::parse_lenient("Emoji Hook", body.as_bytes()).unwrap();
+    if let WebhookEvent::Emoji(hook) = event {
+        assert_eq!(hook.event_type, EmojiAction::Unknown("react".into()));
+        assert_eq!(hook.event_type.as_str(), "react");
+    } else {
+        panic!("unexpected event: {:?}", event);
+    }
+}
+
+#[test]
+fn test_lenient_unknown_noteable_type() {
+    let note = json!({
+        "id": 1,
+        "note": "A comment",
+        "noteable_type": "WikiPage",
+        "original_position": null,
+        "position": null,
+        "author_id": 2,
+        "created_at": "2024-01-01 00:00:00 UTC",
+        "updated_at": "2024-01-01 00:00:00 UTC",
+        "updated_by_id": null,
+        "resolved_at": null,
+        "resolved_by_id": null,
+        "project_id": 3,
+        "attachment": null,
+        "line_code": null,
+        "commit_id": null,
+        "discussion_id": "0123456789abcdef0123456789abcdef01234567",
+        "original_discussion_id": null,
+        "noteable_id": 4,
+        "system": false,
+        "st_diff": null,
+        "url": "https://gitlab.host.invalid/group/project/-/wikis/home#note_1",
+        "type": null,
+    });
+
+    let err = serde_json::from_value::<NoteHookAttrs>(note.clone()).unwrap_err();
+    assert!(err.to_string().contains("unknown variant `WikiPage`"));
+
+    let Lenient(note) = serde_json::from_value::<Lenient<NoteHookAttrs>>(note).unwrap();
+    assert_eq!(note.noteable_type, NoteableType::Unknown("WikiPage".into()));
+    assert_eq!(note.noteable_id(), None);
+}
+
+#[test]
+fn test_lenient_unknown_round_trip() {
+    serde_json::from_value::<DeploymentStatus>(json!("pending")).unwrap_err();
+
+    let Lenient(status) =
+        serde_json::from_value::<Lenient<DeploymentStatus>>(json!("pending")).unwrap();
+    assert_eq!(status, DeploymentStatus::Unknown("pending".into()));
+    assert_eq!(serde_json::to_value(&status).unwrap(), json!("pending"));
+
+    let event: SubgroupEvent = serde_json::from_value(json!("subgroup_create")).unwrap();
+    assert_eq!(event, SubgroupEvent::Create);
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        json!("subgroup_create")
+    );
+}
@@ -0,0 +1,78 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs::File;
+use std::io::BufReader;
+
+use serde_json::json;
+
+use crate::systemhooks::*;
+
+fn read_hook(name: &str) -> SystemHook {
+    let file = File::open(format!("src/test/examples/{}.json", name)).unwrap();
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader).unwrap()
+}
+
+#[test]
+fn test_subgroup_hook() {
+    let hook = if let SystemHook::Subgroup(hook) = read_hook("subgroup_create") {
+        hook
+    } else {
+        panic!("expected a subgroup hook");
+    };
+    assert_eq!(hook.event_name, SubgroupEvent::Create);
+    assert_eq!(hook.full_path, "group-1/subgroup-1");
+    assert_eq!(hook.group_id.value(), 10);
+    assert_eq!(hook.parent_group_id.value(), 7);
+    assert_eq!(hook.parent_full_path, "group-1");
+}
+
+#[test]
+fn test_group_member_update_hook() {
+    let hook = if let SystemHook::GroupMember(hook) = read_hook("user_update_for_group") {
+        hook
+    } else {
+        panic!("expected a group member hook");
+    };
+    assert_eq!(hook.event_name, GroupMemberEvent::Update);
+    assert_eq!(hook.group_access, HumanAccessLevel::Maintainer);
+    assert_eq!(hook.user_username, "johnsmith");
+}
+
+#[test]
+fn test_project_member_update_hook() {
+    let hook = if let SystemHook::ProjectMember(hook) = read_hook("user_update_for_team") {
+        hook
+    } else {
+        panic!("expected a project member hook");
+    };
+    assert_eq!(hook.event_name, ProjectMemberEvent::Update);
+    assert_eq!(hook.access_level, HumanAccessLevel::Maintainer);
+    assert_eq!(hook.project_visibility, ProjectVisibility::Private);
+}
+
+#[test]
+fn test_group_destroy_hook() {
+    let hook: SystemHook = serde_json::from_value(json!({
+        "created_at": "2012-07-21T07:30:54Z",
+        "updated_at": "2012-07-21T07:38:22Z",
+        "event_name": "group_destroy",
+        "name": "StoreCloud",
+        "owner_email": null,
+        "owner_name": null,
+        "path": "storecloud",
+        "group_id": 78,
+    }))
+    .unwrap();
+    assert!(matches!(
+        hook,
+        SystemHook::Group(GroupSystemHook {
+            event_name: GroupEvent::Destroy,
+            ..
+        }),
+    ));
+}
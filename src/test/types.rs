@@ -69,6 +69,8 @@ fn check_namespace_utils(namespace: &Namespace) {
     assert_eq!(namespace.avatar_url, None);
     assert_eq!(namespace.web_url, "https://gitlab.kitware.com/groups/utils");
     assert_eq!(namespace.members_count_with_descendants, None);
+    assert_eq!(namespace.parent_id, None);
+    assert_eq!(namespace.plan, None);
 }
 
 fn check_empty_time_stats(time_stats: &IssuableTimeStats) {
@@ -247,6 +249,309 @@ fn test_read_issue_reference() {
     );
 }
 
+#[test]
+fn test_read_user_agent_detail() {
+    let detail: UserAgentDetail = serde_json::from_value(json!({
+        "user_agent": "AppleWebKit/537.36",
+        "ip_address": "127.0.0.1",
+        "akismet_submitted": false,
+    }))
+    .unwrap();
+
+    assert_eq!(detail.user_agent, "AppleWebKit/537.36");
+    assert_eq!(detail.ip_address, "127.0.0.1");
+    assert!(!detail.akismet_submitted);
+}
+
+#[test]
+fn test_read_burndown_event() {
+    let event: BurndownEvent = serde_json::from_value(json!({
+        "created_at": "2020-03-05T15:14:29.871Z",
+        "weight": 3,
+        "action": "closed",
+    }))
+    .unwrap();
+
+    assert_eq!(event.created_at, datetime((2020, 3, 5), (15, 14, 29, 871)));
+    assert_eq!(event.weight, Some(3));
+    assert_eq!(event.action, BurndownEventAction::Closed);
+}
+
+#[test]
+fn test_read_namespace_plan() {
+    let namespace: Namespace = serde_json::from_value(json!({
+        "id": 2,
+        "name": "Group",
+        "path": "group",
+        "kind": "group",
+        "full_path": "parent/group",
+        "parent_id": 1,
+        "avatar_url": null,
+        "web_url": "https://gitlab.example.com/groups/parent/group",
+        "members_count_with_descendants": 2,
+        "billable_members_count": 2,
+        "plan": "premium",
+        "trial_ends_on": "2020-06-01",
+        "trial": true,
+        "seats_in_use": 3,
+        "max_seats_used": 4,
+    }))
+    .unwrap();
+
+    assert_eq!(namespace.id(), NamespaceId::Group(GroupId::new(2)));
+    assert_eq!(namespace.parent_id, Some(GroupId::new(1)));
+    assert_eq!(namespace.plan.as_ref().unwrap(), "premium");
+    assert_eq!(
+        namespace.trial_ends_on,
+        Some(NaiveDate::from_ymd(2020, 6, 1))
+    );
+    assert_eq!(namespace.trial, Some(true));
+    assert_eq!(namespace.billable_members_count, Some(2));
+    assert_eq!(namespace.seats_in_use, Some(3));
+    assert_eq!(namespace.max_seats_used, Some(4));
+}
+
+#[test]
+fn test_read_namespace_existence() {
+    let existence: NamespaceExistence = serde_json::from_value(json!({
+        "exists": true,
+        "suggests": ["group1"],
+    }))
+    .unwrap();
+
+    assert!(existence.exists);
+    assert_eq!(existence.suggests, ["group1"]);
+}
+
+#[test]
+fn test_read_personal_access_token() {
+    let token: PersonalAccessToken = serde_json::from_value(json!({
+        "id": 4,
+        "name": "Test Token",
+        "description": null,
+        "revoked": false,
+        "created_at": "2020-07-23T14:31:47.729Z",
+        "scopes": ["api", "read_user"],
+        "user_id": 24,
+        "last_used_at": null,
+        "active": true,
+        "expires_at": "2020-08-23",
+        "token": "s3cr3t",
+    }))
+    .unwrap();
+
+    assert_eq!(token.id, PersonalAccessTokenId::new(4));
+    assert_eq!(token.name, "Test Token");
+    assert!(!token.revoked);
+    assert_eq!(token.created_at, datetime((2020, 7, 23), (14, 31, 47, 729)));
+    assert_eq!(token.scopes, ["api", "read_user"]);
+    assert_eq!(token.user_id, UserId::new(24));
+    assert_eq!(token.last_used_at, None);
+    assert!(token.active);
+    assert_eq!(token.expires_at, Some(NaiveDate::from_ymd(2020, 8, 23)));
+    assert_eq!(token.token.as_ref().unwrap(), "s3cr3t");
+}
+
+#[test]
+fn test_read_project_access_token() {
+    let token: ProjectAccessToken = serde_json::from_value(json!({
+        "id": 42,
+        "name": "Deploy Token",
+        "description": "Token for deployments",
+        "revoked": false,
+        "created_at": "2021-01-21T19:35:37.921Z",
+        "scopes": ["read_repository"],
+        "user_id": 166,
+        "last_used_at": "2021-01-22T08:12:05.112Z",
+        "active": true,
+        "expires_at": null,
+        "access_level": 30,
+    }))
+    .unwrap();
+
+    assert_eq!(token.id, PersonalAccessTokenId::new(42));
+    assert_eq!(token.name, "Deploy Token");
+    assert_eq!(token.description.as_ref().unwrap(), "Token for deployments");
+    assert_eq!(token.scopes, ["read_repository"]);
+    assert_eq!(token.user_id, UserId::new(166));
+    assert_eq!(
+        token.last_used_at,
+        Some(datetime((2021, 1, 22), (8, 12, 5, 112)))
+    );
+    assert_eq!(token.expires_at, None);
+    assert_eq!(token.access_level, AccessLevel::Developer);
+    assert_eq!(token.token, None);
+}
+
+#[test]
+fn test_read_user_ssh_key() {
+    let key: SshKey = serde_json::from_value(json!({
+        "id": 1,
+        "title": "Public key",
+        "key": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIHaSIuHCNLMq1ngIVU3S4gKp1zmbNa6vXmw3N0WNjPpV",
+        "created_at": "2014-08-01T14:47:39.080Z",
+        "expires_at": "2024-08-01T00:00:00.000Z",
+    }))
+    .unwrap();
+
+    assert_eq!(key.id, SshKeyId::new(1));
+    assert_eq!(key.title, "Public key");
+    assert_eq!(key.created_at, datetime((2014, 8, 1), (14, 47, 39, 80)));
+    assert_eq!(key.expires_at, Some(datetime((2024, 8, 1), (0, 0, 0, 0))));
+    assert!(!key.can_push);
+}
+
+#[test]
+fn test_read_user_membership() {
+    let membership: UserMembership = serde_json::from_value(json!({
+        "source_id": 1,
+        "source_name": "Project one",
+        "source_type": "Project",
+        "access_level": 20,
+    }))
+    .unwrap();
+
+    assert_eq!(membership.source_id, 1);
+    assert_eq!(membership.source_name, "Project one");
+    assert_eq!(membership.source_type, MembershipSourceType::Project);
+    assert_eq!(membership.access_level, AccessLevel::Reporter);
+}
+
+#[test]
+fn test_read_user_status() {
+    let status: UserStatus = serde_json::from_value(json!({
+        "emoji": "coffee",
+        "availability": "busy",
+        "message": "I crave coffee :coffee:",
+        "message_html": "I crave coffee <gl-emoji title=\"hot beverage\" data-name=\"coffee\" data-unicode-version=\"4.0\">☕</gl-emoji>",
+        "clear_status_at": null,
+    }))
+    .unwrap();
+
+    assert_eq!(status.emoji.as_ref().unwrap(), "coffee");
+    assert_eq!(status.availability, Some(UserAvailability::Busy));
+    assert_eq!(status.message.as_ref().unwrap(), "I crave coffee :coffee:");
+    assert!(status.message_html.is_some());
+    assert_eq!(status.clear_status_at, None);
+}
+
+#[test]
+fn test_read_user_associations_count() {
+    let count: UserAssociationsCount = serde_json::from_value(json!({
+        "groups_count": 2,
+        "projects_count": 3,
+        "issues_count": 8,
+        "merge_requests_count": 5,
+    }))
+    .unwrap();
+
+    assert_eq!(count.groups_count, 2);
+    assert_eq!(count.projects_count, 3);
+    assert_eq!(count.issues_count, 8);
+    assert_eq!(count.merge_requests_count, 5);
+}
+
+#[test]
+fn test_read_user_activity() {
+    let activity: UserActivity = serde_json::from_value(json!({
+        "username": "user1",
+        "last_activity_on": "2019-01-03",
+        "last_activity_at": "2019-01-03",
+    }))
+    .unwrap();
+
+    assert_eq!(activity.username, "user1");
+    assert_eq!(activity.last_activity_on, NaiveDate::from_ymd(2019, 1, 3));
+}
+
+#[test]
+fn test_read_event() {
+    let event: Event = serde_json::from_value(json!({
+        "id": 3,
+        "title": null,
+        "project_id": 15,
+        "action_name": "closed",
+        "target_id": 830,
+        "target_iid": 82,
+        "target_type": "Issue",
+        "author_id": 1,
+        "target_title": "Public project search field",
+        "created_at": "2017-02-09T10:43:19.667Z",
+        "author": {
+            "name": "Dmitriy Zaporozhets",
+            "username": "root",
+            "id": 1,
+            "state": "active",
+            "avatar_url": null,
+            "web_url": "http://localhost:3000/root",
+        },
+        "author_username": "root",
+    }))
+    .unwrap();
+
+    assert_eq!(event.project_id, Some(ProjectId::new(15)));
+    assert_eq!(event.action_name, "closed");
+    assert_eq!(event.target_type, Some(EventTargetType::Issue));
+    assert_eq!(
+        event.target_id(),
+        Some(EventTargetId::Issue(IssueId::new(830)))
+    );
+    assert_eq!(
+        event.target_title.as_ref().unwrap(),
+        "Public project search field"
+    );
+    assert_eq!(event.created_at, datetime((2017, 2, 9), (10, 43, 19, 667)));
+}
+
+#[test]
+fn test_read_event_without_target() {
+    let event: Event = serde_json::from_value(json!({
+        "id": 4,
+        "title": null,
+        "project_id": 15,
+        "action_name": "pushed to",
+        "target_id": null,
+        "target_iid": null,
+        "target_type": null,
+        "author_id": 1,
+        "target_title": null,
+        "created_at": "2017-02-09T10:43:19.667Z",
+        "push_data": {
+            "commit_count": 1,
+            "action": "pushed",
+            "ref_type": "branch",
+            "commit_from": "50d4420237a9de7be1304607147aec22e4a14af7",
+            "commit_to": "c5feabde2d8cd023215af4d2ceeb7a64839fc428",
+            "ref": "master",
+            "commit_title": "Add simple search to projects in public area",
+        },
+        "author_username": "root",
+    }))
+    .unwrap();
+
+    assert_eq!(event.action_name, "pushed to");
+    assert_eq!(event.target_type, None);
+    assert_eq!(event.target_id(), None);
+    assert_eq!(event.target_title, None);
+}
+
+#[test]
+fn test_read_external_issue_reference() {
+    let issue_reference: IssueReference = serde_json::from_value(json!({
+        "id": 1234,
+        "title": "External issue",
+    }))
+    .unwrap();
+
+    let issue = if let IssueReference::External(issue) = issue_reference {
+        issue
+    } else {
+        panic!("expected to have an external issue reference");
+    };
+
+    assert_eq!(issue.title, "External issue");
+}
+
 #[test]
 fn test_read_member() {
     let member: Member = read_test_file("member");
@@ -835,6 +1140,163 @@ fn test_read_resource_label_events() {
     assert_eq!(label.description.as_ref().unwrap(), "Documentation issues");
 }
 
+#[test]
+fn test_read_resource_milestone_events() {
+    let event: ResourceMilestoneEvent = read_test_file("resource_milestone_event");
+
+    assert_eq!(event.id, MilestoneEventId::new(1243));
+    check_user_brad_king(&event.user);
+    assert_eq!(event.created_at, datetime((2020, 6, 15), (14, 21, 7, 348)));
+    assert_eq!(event.action, "add");
+
+    match &event.event_target() {
+        Some(ResourceLabelEventTarget::Issue(id)) if id.value() == 69328 => {
+            // this is the expected value
+        },
+        x => panic!("Unexpected resource_target: {:?}", x),
+    }
+
+    let milestone = event.milestone.unwrap();
+    assert_eq!(milestone.id, MilestoneId::new(187));
+    assert_eq!(milestone.iid, MilestoneInternalId::new(3));
+    assert_eq!(milestone.project_id, Some(ProjectId::new(855)));
+    assert_eq!(milestone.group_id, None);
+    assert_eq!(milestone.title, "v0.1301.0");
+    assert_eq!(milestone.state, MilestoneState::Active);
+    assert_eq!(milestone.due_date, Some(NaiveDate::from_ymd(2020, 7, 1)));
+}
+
+#[test]
+fn test_read_resource_state_events() {
+    let event: ResourceStateEvent = read_test_file("resource_state_event");
+
+    assert_eq!(event.id, StateEventId::new(3712));
+    check_user_brad_king(&event.user);
+    assert_eq!(event.created_at, datetime((2020, 8, 3), (18, 2, 11, 517)));
+    assert_eq!(event.state, ResourceStateEventState::Merged);
+
+    match &event.event_target() {
+        Some(ResourceLabelEventTarget::MergeRequest(id)) if id.value() == 7201 => {
+            // this is the expected value
+        },
+        x => panic!("Unexpected resource_target: {:?}", x),
+    }
+}
+
+#[test]
+fn test_read_resource_iteration_events() {
+    let event: ResourceIterationEvent = read_test_file("resource_iteration_event");
+
+    assert_eq!(event.id, IterationEventId::new(142));
+    check_user_brad_king(&event.user);
+    assert_eq!(event.created_at, datetime((2020, 9, 2), (15, 33, 19, 615)));
+    assert_eq!(event.action, "add");
+
+    match &event.event_target() {
+        Some(ResourceLabelEventTarget::Issue(id)) if id.value() == 69328 => {
+            // this is the expected value
+        },
+        x => panic!("Unexpected resource_target: {:?}", x),
+    }
+
+    let iteration = event.iteration.unwrap();
+    assert_eq!(iteration.id, IterationId::new(53));
+    assert_eq!(iteration.iid, IterationInternalId::new(4));
+    assert_eq!(iteration.group_id, GroupId::new(498));
+    assert_eq!(iteration.title.as_ref().unwrap(), "Sprint 4");
+    assert_eq!(iteration.description, None);
+    assert_eq!(iteration.state, IterationState::Current);
+    assert_eq!(iteration.start_date, Some(NaiveDate::from_ymd(2020, 8, 31)));
+    assert_eq!(iteration.due_date, Some(NaiveDate::from_ymd(2020, 9, 13)));
+}
+
+#[test]
+fn test_read_resource_weight_events() {
+    let event: ResourceWeightEvent = read_test_file("resource_weight_event");
+
+    assert_eq!(event.id, WeightEventId::new(317));
+    check_user_brad_king(&event.user);
+    assert_eq!(event.created_at, datetime((2020, 9, 2), (15, 35, 42, 27)));
+    assert_eq!(event.issue_id, IssueId::new(69328));
+    assert_eq!(event.weight, Some(3));
+}
+
+#[test]
+fn test_read_merge_request_commit() {
+    let commit: RepoCommit = read_test_file("merge_request_commit");
+
+    assert_eq!(
+        commit.id,
+        ObjectId::new("a222c5539569cda6999b8069f1e51a5202c30711"),
+    );
+    assert_eq!(commit.short_id, ObjectId::new("a222c553"));
+    assert_eq!(commit.title, "gitlab: add a method for MR award queries");
+    assert_eq!(commit.author_name, "Ben Boeckel");
+    assert_eq!(commit.author_email, "ben.boeckel@kitware.com");
+    assert_eq!(commit.committer_name, "Ben Boeckel");
+    assert_eq!(commit.committer_email, "ben.boeckel@kitware.com");
+    assert_eq!(
+        commit.authored_date,
+        datetime((2016, 11, 7), (21, 38, 51, 0))
+    );
+    assert_eq!(
+        commit.committed_date,
+        datetime((2016, 11, 7), (21, 38, 51, 0))
+    );
+    itertools::assert_equal(
+        commit.parent_ids.as_ref().unwrap(),
+        &[ObjectId::new("559f5f4a2bfe1f48e9e95afa09c029deb655cf7d")],
+    );
+}
+
+#[test]
+fn test_read_merge_request_reviewer() {
+    let reviewer: MergeRequestReviewer = read_test_file("merge_request_reviewer");
+
+    check_user_brad_king(&reviewer.user);
+    assert_eq!(reviewer.state, MergeRequestReviewState::Reviewed);
+    assert_eq!(
+        reviewer.created_at.unwrap(),
+        datetime((2021, 3, 4), (14, 51, 12, 806)),
+    );
+}
+
+#[test]
+fn test_read_merge_request_diff_version() {
+    let version: MergeRequestDiffVersion = read_test_file("merge_request_diff_version");
+
+    assert_eq!(version.id, MergeRequestDiffVersionId::new(13711));
+    assert_eq!(
+        version.head_commit_sha,
+        ObjectId::new("de4ac3cf96cb8a0893be22b03f5171d934f9d392"),
+    );
+    assert_eq!(
+        version.base_commit_sha,
+        ObjectId::new("4b1f3ba5a1b5a0a4e1c8a3f61e8cfb2b2b6e0a4c"),
+    );
+    assert_eq!(
+        version.start_commit_sha,
+        ObjectId::new("4b1f3ba5a1b5a0a4e1c8a3f61e8cfb2b2b6e0a4c"),
+    );
+    assert_eq!(version.created_at, datetime((2020, 9, 1), (17, 28, 4, 315)));
+    assert_eq!(version.merge_request_id, MergeRequestId::new(7201));
+    assert_eq!(version.state, "collected");
+    assert_eq!(version.real_size, "1");
+
+    let commits = version.commits.unwrap();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(
+        commits[0].id,
+        ObjectId::new("de4ac3cf96cb8a0893be22b03f5171d934f9d392"),
+    );
+    assert_eq!(commits[0].author_name, "Ben Boeckel");
+
+    let diffs = version.diffs.unwrap();
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].new_path, "src/types.rs");
+    assert!(!diffs[0].new_file);
+}
+
 #[test]
 fn test_read_pipelines() {
     let pipeline_basic: PipelineBasic = read_test_file("pipeline_basic");
@@ -1220,3 +1682,848 @@ fn test_read_running_job() {
         "https://gitlab.kitware.com/utils/rust-gitlab/-/jobs/4895232"
     );
 }
+
+#[test]
+fn test_read_audit_event() {
+    let event: AuditEvent = serde_json::from_value(json!({
+        "id": 1,
+        "author_id": 1,
+        "entity_id": 6,
+        "entity_type": "Project",
+        "details": {
+            "custom_message": "Project archived",
+            "author_name": "Administrator",
+            "author_email": "admin@example.com",
+            "target_id": "flightjs/flight",
+            "target_type": "Project",
+            "target_details": "flightjs/flight",
+            "ip_address": "127.0.0.1",
+            "entity_path": "flightjs/flight",
+        },
+        "created_at": "2019-08-30T07:00:41.885Z",
+    }))
+    .unwrap();
+
+    assert_eq!(event.id, AuditEventId::new(1));
+    assert_eq!(event.author_id, UserId::new(1));
+    assert_eq!(event.entity_id, 6);
+    assert_eq!(event.entity_type, AuditEventEntityType::Project);
+    assert_eq!(event.details["author_name"], "Administrator");
+    assert_eq!(event.details["custom_message"], "Project archived");
+    assert_eq!(event.details["ip_address"], "127.0.0.1");
+    assert_eq!(event.created_at, datetime((2019, 8, 30), (7, 0, 41, 885)));
+}
+
+#[test]
+fn test_read_license() {
+    let license: License = serde_json::from_value(json!({
+        "id": 2,
+        "plan": "gold",
+        "created_at": "2018-02-27T23:21:58.674Z",
+        "starts_at": "2018-01-27",
+        "expires_at": "2022-01-27",
+        "historical_max": 300,
+        "maximum_user_count": 300,
+        "expired": false,
+        "overage": 200,
+        "user_limit": 100,
+        "active_users": 300,
+        "licensee": {
+            "Name": "John Doe1",
+            "Email": "johndoe1@gitlab.com",
+            "Company": "GitLab",
+        },
+        "add_ons": {
+            "GitLab_FileLocks": 1,
+            "GitLab_Auditor_User": 1,
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(license.id, LicenseId::new(2));
+    assert_eq!(license.plan, "gold");
+    assert_eq!(
+        license.created_at,
+        datetime((2018, 2, 27), (23, 21, 58, 674))
+    );
+    assert_eq!(license.starts_at, Some(NaiveDate::from_ymd(2018, 1, 27)));
+    assert_eq!(license.expires_at, Some(NaiveDate::from_ymd(2022, 1, 27)));
+    assert_eq!(license.historical_max, 300);
+    assert_eq!(license.maximum_user_count, 300);
+    assert!(!license.expired);
+    assert_eq!(license.overage, 200);
+    assert_eq!(license.user_limit, 100);
+    assert_eq!(license.active_users, 300);
+    assert_eq!(license.licensee.name.as_ref().unwrap(), "John Doe1");
+    assert_eq!(
+        license.licensee.email.as_ref().unwrap(),
+        "johndoe1@gitlab.com"
+    );
+    assert_eq!(license.licensee.company.as_ref().unwrap(), "GitLab");
+    assert_eq!(license.add_ons["GitLab_FileLocks"], 1);
+    assert_eq!(license.add_ons["GitLab_Auditor_User"], 1);
+}
+
+#[test]
+fn test_read_appearance() {
+    let appearance: Appearance = serde_json::from_value(json!({
+        "title": "GitLab Test Instance",
+        "description": "gitlab-test.example.com",
+        "pwa_name": "GitLab PWA",
+        "logo": "/uploads/-/system/appearance/logo/1/logo.png",
+        "header_logo": "/uploads/-/system/appearance/header_logo/1/header.png",
+        "favicon": null,
+        "new_project_guidelines": "Please read the FAQs for help.",
+        "profile_image_guidelines": "Custom profile image guidelines",
+        "header_message": "",
+        "footer_message": "",
+        "message_background_color": "#e75e40",
+        "message_font_color": "#ffffff",
+        "email_header_and_footer_enabled": false,
+    }))
+    .unwrap();
+
+    assert_eq!(appearance.title, "GitLab Test Instance");
+    assert_eq!(appearance.description, "gitlab-test.example.com");
+    assert_eq!(
+        appearance.logo.as_ref().unwrap(),
+        "/uploads/-/system/appearance/logo/1/logo.png",
+    );
+    assert_eq!(
+        appearance.header_logo.as_ref().unwrap(),
+        "/uploads/-/system/appearance/header_logo/1/header.png",
+    );
+    assert_eq!(appearance.favicon, None);
+    assert_eq!(
+        appearance.new_project_guidelines,
+        "Please read the FAQs for help.",
+    );
+    assert_eq!(
+        appearance.profile_image_guidelines,
+        "Custom profile image guidelines",
+    );
+    assert_eq!(appearance.header_message, "");
+    assert_eq!(appearance.footer_message, "");
+    assert_eq!(appearance.message_background_color, "#e75e40");
+    assert_eq!(appearance.message_font_color, "#ffffff");
+    assert!(!appearance.email_header_and_footer_enabled);
+}
+
+#[test]
+fn test_read_readiness_check_result() {
+    let result: ReadinessCheckResult = serde_json::from_value(json!({
+        "status": "failed",
+        "db_check": [
+            {
+                "status": "ok",
+            },
+        ],
+        "gitaly_check": [
+            {
+                "status": "failed",
+                "message": "unavailable",
+                "labels": {
+                    "shard": "default",
+                },
+            },
+        ],
+    }))
+    .unwrap();
+
+    assert_eq!(result.status, HealthCheckStatus::Failed);
+    assert_eq!(result.checks.len(), 2);
+    let db_check = &result.checks["db_check"];
+    assert_eq!(db_check.len(), 1);
+    assert_eq!(db_check[0].status, HealthCheckStatus::Ok);
+    assert_eq!(db_check[0].message, None);
+    assert_eq!(db_check[0].labels, None);
+    let gitaly_check = &result.checks["gitaly_check"];
+    assert_eq!(gitaly_check.len(), 1);
+    assert_eq!(gitaly_check[0].status, HealthCheckStatus::Failed);
+    assert_eq!(gitaly_check[0].message.as_ref().unwrap(), "unavailable");
+    assert_eq!(gitaly_check[0].labels.as_ref().unwrap()["shard"], "default");
+}
+
+#[test]
+fn test_read_liveness_check_result() {
+    let result: LivenessCheckResult = serde_json::from_value(json!({
+        "status": "ok",
+    }))
+    .unwrap();
+
+    assert_eq!(result.status, HealthCheckStatus::Ok);
+}
+
+#[test]
+fn test_read_sidekiq_queue_metrics() {
+    let metrics: SidekiqQueueMetrics = serde_json::from_value(json!({
+        "queues": {
+            "default": {
+                "backlog": 5,
+                "latency": 2,
+            },
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(metrics.queues.len(), 1);
+    assert_eq!(metrics.queues["default"].backlog, 5);
+    assert_eq!(metrics.queues["default"].latency, 2);
+}
+
+#[test]
+fn test_read_sidekiq_process_metrics() {
+    let metrics: SidekiqProcessMetrics = serde_json::from_value(json!({
+        "processes": [
+            {
+                "hostname": "gitlab.example.com",
+                "pid": 5649,
+                "tag": "gitlab",
+                "started_at": "2016-06-14T10:45:07.159-05:00",
+                "queues": [
+                    "post_receive",
+                    "mailers",
+                ],
+                "labels": [],
+                "concurrency": 25,
+                "busy": 0,
+            },
+        ],
+    }))
+    .unwrap();
+
+    assert_eq!(metrics.processes.len(), 1);
+    let process = &metrics.processes[0];
+    assert_eq!(process.hostname, "gitlab.example.com");
+    assert_eq!(process.pid, 5649);
+    assert_eq!(process.tag, "gitlab");
+    assert_eq!(
+        process.started_at,
+        datetime((2016, 6, 14), (15, 45, 7, 159))
+    );
+    itertools::assert_equal(&process.queues, &["post_receive", "mailers"]);
+    assert!(process.labels.is_empty());
+    assert_eq!(process.concurrency, 25);
+    assert_eq!(process.busy, 0);
+}
+
+#[test]
+fn test_read_sidekiq_job_stats() {
+    let stats: SidekiqJobStats = serde_json::from_value(json!({
+        "jobs": {
+            "processed": 2,
+            "failed": 1,
+            "enqueued": 3,
+            "dead": 4,
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(stats.jobs.processed, 2);
+    assert_eq!(stats.jobs.failed, 1);
+    assert_eq!(stats.jobs.enqueued, 3);
+    assert_eq!(stats.jobs.dead, 4);
+}
+
+#[test]
+fn test_read_sidekiq_compound_metrics() {
+    let metrics: SidekiqCompoundMetrics = serde_json::from_value(json!({
+        "queues": {
+            "default": {
+                "backlog": 0,
+                "latency": 0,
+            },
+        },
+        "processes": [],
+        "jobs": {
+            "processed": 2,
+            "failed": 0,
+            "enqueued": 0,
+            "dead": 0,
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(metrics.queues["default"].backlog, 0);
+    assert!(metrics.processes.is_empty());
+    assert_eq!(metrics.jobs.processed, 2);
+}
+
+#[test]
+fn test_read_application_statistics() {
+    let statistics: ApplicationStatistics = serde_json::from_value(json!({
+        "forks": "10",
+        "issues": "76",
+        "merge_requests": "27",
+        "notes": "954",
+        "snippets": "50",
+        "ssh_keys": "10",
+        "milestones": "40",
+        "users": "50",
+        "groups": "10",
+        "projects": "20",
+        "active_users": "1,234",
+    }))
+    .unwrap();
+
+    assert_eq!(statistics.forks, 10);
+    assert_eq!(statistics.issues, 76);
+    assert_eq!(statistics.merge_requests, 27);
+    assert_eq!(statistics.notes, 954);
+    assert_eq!(statistics.snippets, 50);
+    assert_eq!(statistics.ssh_keys, 10);
+    assert_eq!(statistics.milestones, 40);
+    assert_eq!(statistics.users, 50);
+    assert_eq!(statistics.groups, 10);
+    assert_eq!(statistics.projects, 20);
+    assert_eq!(statistics.active_users, 1234);
+}
+
+#[test]
+fn test_read_feature() {
+    let feature: Feature = serde_json::from_value(json!({
+        "name": "new_library",
+        "state": "conditional",
+        "gates": [
+            {
+                "key": "boolean",
+                "value": false,
+            },
+            {
+                "key": "percentage_of_time",
+                "value": 30,
+            },
+        ],
+        "definition": {
+            "name": "new_library",
+            "introduced_by_url": "https://gitlab.com/gitlab-org/gitlab/-/merge_requests/40849",
+            "rollout_issue_url": null,
+            "milestone": "13.5",
+            "log_state_changes": null,
+            "type": "development",
+            "group": "group::acquisition",
+            "default_enabled": false,
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(feature.name, "new_library");
+    assert_eq!(feature.state, FeatureState::Conditional);
+    assert_eq!(feature.gates.len(), 2);
+    assert_eq!(feature.gates[0].key, "boolean");
+    assert_eq!(feature.gates[0].value, json!(false));
+    assert_eq!(feature.gates[1].key, "percentage_of_time");
+    assert_eq!(feature.gates[1].value, json!(30));
+    let definition = feature.definition.unwrap();
+    assert_eq!(definition.name, "new_library");
+    assert_eq!(definition.rollout_issue_url, None);
+    assert_eq!(definition.milestone.as_deref(), Some("13.5"));
+    assert_eq!(definition.feature_type, "development");
+    assert_eq!(definition.group.as_deref(), Some("group::acquisition"));
+    assert!(!definition.default_enabled);
+}
+
+#[test]
+fn test_read_feature_without_definition() {
+    let feature: Feature = serde_json::from_value(json!({
+        "name": "experimental_feature",
+        "state": "off",
+        "gates": [
+            {
+                "key": "boolean",
+                "value": false,
+            },
+        ],
+        "definition": null,
+    }))
+    .unwrap();
+
+    assert_eq!(feature.name, "experimental_feature");
+    assert_eq!(feature.state, FeatureState::Off);
+    assert!(feature.definition.is_none());
+}
+
+#[test]
+fn test_read_vulnerability() {
+    let vulnerability: Vulnerability = serde_json::from_value(json!({
+        "id": 2,
+        "title": "Predictable pseudorandom number generator",
+        "description": null,
+        "state": "confirmed",
+        "severity": "medium",
+        "confidence": "medium",
+        "report_type": "sast",
+        "project": {
+            "id": 32,
+        },
+        "author_id": 1,
+        "updated_by_id": null,
+        "last_edited_by_id": null,
+        "closed_by_id": null,
+        "confirmed_by_id": 1,
+        "resolved_by_id": null,
+        "dismissed_by_id": null,
+        "created_at": "2019-10-13T15:08:40.219Z",
+        "updated_at": "2019-10-13T15:09:40.382Z",
+        "confirmed_at": "2019-10-13T15:09:40.382Z",
+        "resolved_at": null,
+        "dismissed_at": null,
+    }))
+    .unwrap();
+
+    assert_eq!(vulnerability.id, VulnerabilityId::new(2));
+    assert_eq!(
+        vulnerability.title,
+        "Predictable pseudorandom number generator",
+    );
+    assert_eq!(vulnerability.description, None);
+    assert_eq!(vulnerability.state, VulnerabilityState::Confirmed);
+    assert_eq!(vulnerability.severity, VulnerabilitySeverity::Medium);
+    assert_eq!(vulnerability.report_type, "sast");
+    assert_eq!(vulnerability.author_id, Some(UserId::new(1)));
+    assert_eq!(vulnerability.confirmed_by_id, Some(UserId::new(1)));
+    assert_eq!(
+        vulnerability.confirmed_at,
+        Some(datetime((2019, 10, 13), (15, 9, 40, 382))),
+    );
+    assert_eq!(vulnerability.dismissed_at, None);
+}
+
+#[test]
+fn test_read_vulnerability_finding() {
+    let finding: VulnerabilityFinding = serde_json::from_value(json!({
+        "id": null,
+        "report_type": "sast",
+        "name": "Predictable pseudorandom number generator",
+        "severity": "medium",
+        "confidence": "medium",
+        "scanner": {
+            "external_id": "find_sec_bugs",
+            "name": "Find Security Bugs",
+            "vendor": "GitLab",
+        },
+        "identifiers": [
+            {
+                "external_type": "find_sec_bugs_type",
+                "external_id": "PREDICTABLE_RANDOM",
+                "name": "Find Security Bugs-PREDICTABLE_RANDOM",
+                "url": "https://find-sec-bugs.github.io/bugs.htm#PREDICTABLE_RANDOM",
+            },
+        ],
+        "project_fingerprint": "4e5b6966dd100170b4b1ad599c7058cce91b57b4",
+        "uuid": "e5388f40-18f5-566d-95c6-d64c2f46a00a",
+        "create_jira_issue_url": null,
+        "false_positive": false,
+        "create_vulnerability_feedback_issue_path": "/tests/java-maven/vulnerability_feedback",
+        "description": null,
+        "solution": null,
+        "state": "detected",
+        "blob_path": "/tests/java-maven/blob/master/src/main/java/com/gitlab/security_products/tests/App.java#L47",
+        "location": {
+            "file": "maven/src/main/java/com/gitlab/security_products/tests/App.java",
+            "start_line": 47,
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(finding.id, None);
+    assert_eq!(finding.uuid, "e5388f40-18f5-566d-95c6-d64c2f46a00a");
+    assert_eq!(finding.report_type, "sast");
+    assert_eq!(finding.severity, VulnerabilitySeverity::Medium);
+    assert_eq!(finding.state, Some(VulnerabilityState::Detected));
+    let scanner = finding.scanner.unwrap();
+    assert_eq!(scanner.external_id, "find_sec_bugs");
+    assert_eq!(scanner.vendor.as_deref(), Some("GitLab"));
+    assert_eq!(finding.identifiers.len(), 1);
+    assert_eq!(finding.identifiers[0].external_id, "PREDICTABLE_RANDOM");
+    assert_eq!(finding.location.unwrap()["start_line"], json!(47));
+}
+
+#[test]
+fn test_read_vulnerability_export() {
+    let export: VulnerabilityExport = serde_json::from_value(json!({
+        "id": 2,
+        "created_at": "2020-03-30T09:35:38.746Z",
+        "project_id": 1,
+        "group_id": null,
+        "format": "csv",
+        "status": "finished",
+        "started_at": "2020-03-30T09:36:54.469Z",
+        "finished_at": "2020-03-30T09:36:55.008Z",
+        "_links": {
+            "self": "https://gitlab.example.com/api/v4/security/vulnerability_exports/2",
+            "download": "https://gitlab.example.com/api/v4/security/vulnerability_exports/2/download",
+        },
+    }))
+    .unwrap();
+
+    assert_eq!(export.id, VulnerabilityExportId::new(2));
+    assert_eq!(export.project_id, Some(ProjectId::new(1)));
+    assert_eq!(export.group_id, None);
+    assert_eq!(export.format, "csv");
+    assert_eq!(export.status, VulnerabilityExportStatus::Finished);
+    assert_eq!(
+        export.finished_at,
+        Some(datetime((2020, 3, 30), (9, 36, 55, 8))),
+    );
+    assert_eq!(
+        export.links.download,
+        "https://gitlab.example.com/api/v4/security/vulnerability_exports/2/download",
+    );
+}
+
+#[test]
+fn test_read_value_stream() {
+    let value_stream: ValueStream = serde_json::from_value(json!({
+        "id": 1,
+        "name": "default",
+        "is_custom": false,
+    }))
+    .unwrap();
+
+    assert_eq!(value_stream.id, 1);
+    assert_eq!(value_stream.name, "default");
+    assert_eq!(value_stream.is_custom, Some(false));
+}
+
+#[test]
+fn test_read_value_stream_stage() {
+    let stage: ValueStreamStage = serde_json::from_value(json!({
+        "id": "issue",
+        "title": "Issue",
+        "hidden": false,
+        "legend": "",
+        "description": "Time before an issue gets scheduled",
+        "name": "issue",
+        "custom": false,
+        "start_event_identifier": "issue_created",
+        "end_event_identifier": "issue_stage_end",
+        "start_event_html_description": "<p>Issue created</p>",
+        "end_event_html_description": "<p>Issue first associated with a milestone</p>",
+    }))
+    .unwrap();
+
+    assert_eq!(stage.id, json!("issue"));
+    assert_eq!(stage.title, "Issue");
+    assert_eq!(stage.name, "issue");
+    assert!(!stage.hidden);
+    assert!(!stage.custom);
+    assert_eq!(
+        stage.description.as_deref(),
+        Some("Time before an issue gets scheduled"),
+    );
+    assert_eq!(
+        stage.start_event_identifier.as_deref(),
+        Some("issue_created")
+    );
+    assert_eq!(
+        stage.end_event_identifier.as_deref(),
+        Some("issue_stage_end")
+    );
+}
+
+#[test]
+fn test_read_value_stream_stage_median() {
+    let median: ValueStreamStageMedian = serde_json::from_value(json!({
+        "value": 1800.5,
+    }))
+    .unwrap();
+
+    assert_eq!(median.value, Some(1800.5));
+
+    let median: ValueStreamStageMedian = serde_json::from_value(json!({
+        "value": null,
+    }))
+    .unwrap();
+
+    assert_eq!(median.value, None);
+}
+
+#[test]
+fn test_read_bulk_import() {
+    let bulk_import: BulkImport = serde_json::from_value(json!({
+        "id": 1,
+        "status": "finished",
+        "source_type": "gitlab",
+        "created_at": "2021-06-18T09:45:55.358Z",
+        "updated_at": "2021-06-18T09:46:27.003Z",
+        "has_failures": false,
+    }))
+    .unwrap();
+
+    assert_eq!(bulk_import.id, BulkImportId::new(1));
+    assert_eq!(bulk_import.status, BulkImportStatus::Finished);
+    assert_eq!(bulk_import.source_type, "gitlab");
+    assert_eq!(
+        bulk_import.created_at,
+        datetime((2021, 6, 18), (9, 45, 55, 358)),
+    );
+    assert_eq!(bulk_import.has_failures, Some(false));
+}
+
+#[test]
+fn test_read_bulk_import_entity() {
+    let entity: BulkImportEntity = serde_json::from_value(json!({
+        "id": 2,
+        "bulk_import_id": 1,
+        "status": "started",
+        "entity_type": "group",
+        "source_full_path": "source_group",
+        "destination_full_path": "destination/full_path",
+        "destination_name": "destination_slug",
+        "destination_slug": "destination_slug",
+        "destination_namespace": "destination",
+        "parent_id": null,
+        "namespace_id": 3,
+        "project_id": null,
+        "created_at": "2021-06-18T09:47:37.390Z",
+        "updated_at": "2021-06-18T09:47:51.867Z",
+        "failures": [],
+        "migrate_projects": true,
+        "has_failures": false,
+    }))
+    .unwrap();
+
+    assert_eq!(entity.id, BulkImportEntityId::new(2));
+    assert_eq!(entity.bulk_import_id, BulkImportId::new(1));
+    assert_eq!(entity.status, BulkImportStatus::Started);
+    assert_eq!(entity.entity_type, "group");
+    assert_eq!(entity.source_full_path, "source_group");
+    assert_eq!(
+        entity.destination_full_path.as_deref(),
+        Some("destination/full_path"),
+    );
+    assert_eq!(entity.destination_namespace, "destination");
+    assert_eq!(entity.parent_id, None);
+    assert_eq!(entity.namespace_id, Some(GroupId::new(3)));
+    assert_eq!(entity.project_id, None);
+    assert!(entity.failures.is_empty());
+    assert_eq!(entity.migrate_projects, Some(true));
+}
+
+#[test]
+fn test_read_cluster() {
+    let cluster: Cluster = serde_json::from_value(json!({
+        "id": 9,
+        "name": "cluster-1",
+        "created_at": "2020-07-14T18:36:10.440Z",
+        "managed": true,
+        "enabled": true,
+        "domain": null,
+        "provider_type": "user",
+        "platform_type": "kubernetes",
+        "environment_scope": "*",
+        "cluster_type": "instance_type",
+        "user": {
+            "id": 10,
+            "name": "Brad King",
+            "username": "brad.king",
+            "state": "active",
+            "avatar_url": "https://secure.gravatar.com/avatar/0617392a2f9fd505720d0c42cefc1a10?s=80&d=identicon",
+            "web_url": "https://gitlab.kitware.com/brad.king",
+        },
+        "platform_kubernetes": {
+            "api_url": "https://example.com",
+            "namespace": null,
+            "authorization_type": "rbac",
+            "ca_cert": null,
+        },
+        "provider_gcp": null,
+        "management_project": null,
+    }))
+    .unwrap();
+
+    assert_eq!(cluster.id, ClusterId::new(9));
+    assert_eq!(cluster.name, "cluster-1");
+    assert_eq!(
+        cluster.created_at,
+        datetime((2020, 7, 14), (18, 36, 10, 440))
+    );
+    assert!(cluster.managed);
+    assert!(cluster.enabled);
+    assert_eq!(cluster.domain, None);
+    assert_eq!(cluster.provider_type, "user");
+    assert_eq!(cluster.platform_type, "kubernetes");
+    assert_eq!(cluster.environment_scope, "*");
+    assert_eq!(cluster.cluster_type, ClusterType::Instance);
+    check_user_brad_king(&cluster.user);
+    let kubernetes = cluster.platform_kubernetes.unwrap();
+    assert_eq!(kubernetes.api_url, "https://example.com");
+    assert_eq!(kubernetes.namespace, None);
+    assert_eq!(kubernetes.authorization_type.as_deref(), Some("rbac"));
+}
+
+#[test]
+fn test_read_group_push_rule() {
+    let push_rule: GroupPushRule = serde_json::from_value(json!({
+        "id": 2,
+        "created_at": "2020-08-31T15:53:00.073Z",
+        "commit_message_regex": "[a-zA-Z]",
+        "commit_message_negative_regex": "[x+]",
+        "branch_name_regex": null,
+        "deny_delete_tag": false,
+        "member_check": false,
+        "prevent_secrets": false,
+        "author_email_regex": "^[A-Za-z0-9.]+@staging.gitlab.com$",
+        "file_name_regex": null,
+        "max_file_size": 100,
+        "commit_committer_check": null,
+        "reject_unsigned_commits": null,
+    }))
+    .unwrap();
+
+    assert_eq!(push_rule.id, PushRuleId::new(2));
+    assert_eq!(
+        push_rule.created_at,
+        datetime((2020, 8, 31), (15, 53, 0, 73)),
+    );
+    assert_eq!(push_rule.commit_message_regex.as_deref(), Some("[a-zA-Z]"));
+    assert_eq!(
+        push_rule.commit_message_negative_regex.as_deref(),
+        Some("[x+]")
+    );
+    assert_eq!(push_rule.branch_name_regex, None);
+    assert!(!push_rule.deny_delete_tag);
+    assert!(!push_rule.member_check);
+    assert!(!push_rule.prevent_secrets);
+    assert_eq!(
+        push_rule.author_email_regex.as_deref(),
+        Some("^[A-Za-z0-9.]+@staging.gitlab.com$"),
+    );
+    assert_eq!(push_rule.file_name_regex, None);
+    assert_eq!(push_rule.max_file_size, 100);
+    assert_eq!(push_rule.commit_committer_check, None);
+    assert_eq!(push_rule.reject_unsigned_commits, None);
+}
+
+#[test]
+fn test_read_epic_board() {
+    let board: EpicBoard = serde_json::from_value(json!({
+        "id": 1,
+        "name": "epic board",
+        "hide_backlog_list": false,
+        "hide_closed_list": true,
+        "group": {
+            "id": 5,
+            "web_url": "https://gitlab.kitware.com/groups/utils",
+            "name": "Utils",
+        },
+        "labels": [
+            {
+                "id": 10,
+                "name": "area:docs",
+                "color": "#c21e56",
+                "description": null,
+                "description_html": "",
+                "text_color": "#FFFFFF",
+            },
+        ],
+        "lists": [
+            {
+                "id": 1,
+                "label": null,
+                "position": null,
+                "list_type": "backlog",
+            },
+            {
+                "id": 2,
+                "label": {
+                    "id": 10,
+                    "name": "area:docs",
+                    "color": "#c21e56",
+                    "description": null,
+                    "description_html": "",
+                    "text_color": "#FFFFFF",
+                },
+                "position": 0,
+                "list_type": "label",
+            },
+            {
+                "id": 3,
+                "label": null,
+                "position": null,
+                "list_type": "closed",
+            },
+        ],
+    }))
+    .unwrap();
+
+    assert_eq!(board.id, EpicBoardId::new(1));
+    assert_eq!(board.name, "epic board");
+    assert!(!board.hide_backlog_list);
+    assert!(board.hide_closed_list);
+    assert_eq!(board.group.id, GroupId::new(5));
+    assert_eq!(board.group.name, "Utils");
+    assert_eq!(
+        board.group.web_url,
+        "https://gitlab.kitware.com/groups/utils",
+    );
+    assert_eq!(board.labels.len(), 1);
+    assert_eq!(board.labels[0].id, LabelId::new(10));
+    assert_eq!(board.labels[0].name, "area:docs");
+    assert_eq!(board.lists.len(), 3);
+    assert_eq!(board.lists[0].id, EpicBoardListId::new(1));
+    assert!(board.lists[0].label.is_none());
+    assert_eq!(board.lists[0].position, None);
+    assert_eq!(board.lists[0].list_type, EpicBoardListType::Backlog);
+    let label_list = &board.lists[1];
+    assert_eq!(label_list.label.as_ref().unwrap().name, "area:docs");
+    assert_eq!(label_list.position, Some(0));
+    assert_eq!(label_list.list_type, EpicBoardListType::Label);
+    assert_eq!(board.lists[2].list_type, EpicBoardListType::Closed);
+}
+
+#[test]
+fn test_read_oauth_token() {
+    let token: OAuthToken = serde_json::from_value(json!({
+        "access_token": "de6780bc506a0446309bd9362820ba8aed28aa506c71eedbe1c5c4f9dd350e54",
+        "token_type": "bearer",
+        "expires_in": 7200,
+        "refresh_token": "8257e65c97202ed1726cf9571600918f3bffb2544b26e00a61df9897668c33a1",
+        "scope": "api read_user",
+        "created_at": 1607635748,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        token.access_token,
+        "de6780bc506a0446309bd9362820ba8aed28aa506c71eedbe1c5c4f9dd350e54",
+    );
+    assert_eq!(token.token_type, "bearer");
+    assert_eq!(token.expires_in, Some(7200));
+    assert_eq!(
+        token.refresh_token.as_deref(),
+        Some("8257e65c97202ed1726cf9571600918f3bffb2544b26e00a61df9897668c33a1"),
+    );
+    assert_eq!(token.scope.as_deref(), Some("api read_user"));
+    assert_eq!(token.created_at, Some(1607635748));
+}
+
+#[test]
+fn test_read_oauth_device_authorization() {
+    let authorization: OAuthDeviceAuthorization = serde_json::from_value(json!({
+        "device_code": "GmRhmhcxhwAzkoEqiMEg_DnyEysNkuNhszIySk9eS",
+        "user_code": "0A44L90H",
+        "verification_uri": "https://gitlab.example.com/oauth/device",
+        "verification_uri_complete": "https://gitlab.example.com/oauth/device?user_code=0A44L90H",
+        "expires_in": 300,
+        "interval": 5,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        authorization.device_code,
+        "GmRhmhcxhwAzkoEqiMEg_DnyEysNkuNhszIySk9eS",
+    );
+    assert_eq!(authorization.user_code, "0A44L90H");
+    assert_eq!(
+        authorization.verification_uri,
+        "https://gitlab.example.com/oauth/device",
+    );
+    assert_eq!(
+        authorization.verification_uri_complete.as_deref(),
+        Some("https://gitlab.example.com/oauth/device?user_code=0A44L90H"),
+    );
+    assert_eq!(authorization.expires_in, 300);
+    assert_eq!(authorization.interval, Some(5));
+}
@@ -7,6 +7,7 @@
 use chrono::{TimeZone, Utc};
 use serde_json::from_str;
 
+use crate::types::{AwardableId, AwardableType, IssueId};
 use crate::webhooks::*;
 use std::fs::File;
 use std::io::BufReader;
@@ -42,3 +43,85 @@ fn test_pipeline_hook() {
         "0000000000000000000000000000000000000000"
     );
 }
+
+#[test]
+fn test_deployment_hook() {
+    let file = File::open("src/test/examples/deployment.json").unwrap();
+    let reader = BufReader::new(file);
+    let deployment: DeploymentHook = serde_json::from_reader(reader).unwrap();
+    assert_eq!(deployment.object_kind, "deployment");
+    assert_eq!(deployment.status, DeploymentStatus::Success);
+    assert_eq!(
+        *deployment.status_changed_at.as_ref(),
+        Utc.ymd(2021, 4, 28).and_hms_milli(19, 50, 0, 0),
+    );
+    assert_eq!(deployment.deployment_id.value(), 15);
+    assert_eq!(deployment.deployable_id.value(), 796);
+    assert_eq!(
+        deployment.deployable_url,
+        "http://10.126.0.2:3000/root/test-deployment-webhooks/-/jobs/796",
+    );
+    assert_eq!(deployment.environment, "staging");
+    assert_eq!(
+        deployment.project.path_with_namespace,
+        "root/test-deployment-webhooks"
+    );
+    assert_eq!(deployment.user.username, "root");
+
+    let file = File::open("src/test/examples/deployment.json").unwrap();
+    let reader = BufReader::new(file);
+    let hook: WebHook = serde_json::from_reader(reader).unwrap();
+    assert!(matches!(hook, WebHook::Deployment(_)));
+}
+
+#[test]
+fn test_release_hook() {
+    let file = File::open("src/test/examples/release.json").unwrap();
+    let reader = BufReader::new(file);
+    let release: ReleaseHook = serde_json::from_reader(reader).unwrap();
+    assert_eq!(release.object_kind, "release");
+    assert_eq!(release.action, ReleaseAction::Create);
+    assert_eq!(release.id.value(), 1);
+    assert_eq!(release.name, "v1.1");
+    assert_eq!(release.tag, "v1.1");
+    assert_eq!(release.assets.count, 5);
+    assert_eq!(release.assets.links.len(), 1);
+    let link = &release.assets.links[0];
+    assert_eq!(link.link_type, ReleaseLinkType::Other);
+    assert_eq!(link.name, "Changelog");
+    assert_eq!(release.assets.sources.len(), 4);
+    assert_eq!(release.assets.sources[0].format, "zip");
+    assert_eq!(
+        release.commit.id.value(),
+        "ee0a3fb31ac16e11b9dbb596ad16d4af654d08f8",
+    );
+
+    let file = File::open("src/test/examples/release.json").unwrap();
+    let reader = BufReader::new(file);
+    let hook: WebHook = serde_json::from_reader(reader).unwrap();
+    assert!(matches!(hook, WebHook::Release(_)));
+}
+
+#[test]
+fn test_emoji_hook() {
+    let file = File::open("src/test/examples/emoji.json").unwrap();
+    let reader = BufReader::new(file);
+    let emoji: EmojiHook = serde_json::from_reader(reader).unwrap();
+    assert_eq!(emoji.object_kind, "emoji");
+    assert_eq!(emoji.event_type, EmojiAction::Award);
+    assert_eq!(emoji.user.username, "root");
+    assert_eq!(emoji.object_attributes.name, "thumbsup");
+    assert_eq!(emoji.object_attributes.awardable_type, AwardableType::Issue);
+    assert_eq!(
+        emoji.object_attributes.awardable_id(),
+        AwardableId::Issue(IssueId::new(73)),
+    );
+    assert_eq!(emoji.issue.unwrap().title, "Issue title!");
+    assert!(emoji.merge_request.is_none());
+    assert!(emoji.note.is_none());
+
+    let file = File::open("src/test/examples/emoji.json").unwrap();
+    let reader = BufReader::new(file);
+    let hook: WebHook = serde_json::from_reader(reader).unwrap();
+    assert!(matches!(hook, WebHook::Emoji(_)));
+}
@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::backend::{self, Instant};
+
+/// Settings for throttling the requests of a client.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ThrottleSettings {
+    /// The maximum number of requests in flight at once.
+    pub(crate) max_concurrent: Option<usize>,
+    /// The minimum time between starting requests.
+    pub(crate) interval: Option<Duration>,
+}
+
+impl ThrottleSettings {
+    /// Create the throttle for a client.
+    ///
+    /// Clones of the client share the throttle.
+    pub(crate) fn build(self) -> Throttle {
+        Throttle {
+            semaphore: self
+                .max_concurrent
+                .map(|limit| Arc::new(Semaphore::new(limit.max(1)))),
+            interval: self.interval.map(|interval| {
+                Arc::new(Interval {
+                    interval,
+                    next: Mutex::new(Instant::now()),
+                })
+            }),
+        }
+    }
+}
+
+/// Spaces requests apart by a fixed interval.
+#[derive(Debug)]
+struct Interval {
+    interval: Duration,
+    /// When the next request may be started.
+    next: Mutex<Instant>,
+}
+
+impl Interval {
+    /// Reserve a slot for a request, returning how long to wait until it starts.
+    fn reserve(&self) -> Duration {
+        let mut next = self.next.lock().unwrap();
+        let now = Instant::now();
+        let slot = if *next > now { *next } else { now };
+        *next = slot + self.interval;
+        slot - now
+    }
+}
+
+/// Limits the rate and concurrency of requests sent by an asynchronous client.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Throttle {
+    semaphore: Option<Arc<Semaphore>>,
+    interval: Option<Arc<Interval>>,
+}
+
+impl Throttle {
+    /// Wait until a request may be sent.
+    ///
+    /// The returned permit must be held until the request has completed, including reading the
+    /// body of its response.
+    pub(crate) async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = if let Some(semaphore) = self.semaphore.as_ref() {
+            // The semaphore is never closed.
+            Some(
+                Arc::clone(semaphore)
+                    .acquire_owned()
+                    .await
+                    .expect("throttle semaphore is closed"),
+            )
+        } else {
+            None
+        };
+
+        if let Some(interval) = self.interval.as_ref() {
+            let wait = interval.reserve();
+            if wait > Duration::from_secs(0) {
+                backend::sleep(wait).await;
+            }
+        }
+
+        permit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::throttle::{Throttle, ThrottleSettings};
+
+    #[tokio::test]
+    async fn unlimited() {
+        let throttle = Throttle::default();
+        assert!(throttle.acquire().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn concurrency_is_limited() {
+        let throttle = ThrottleSettings {
+            max_concurrent: Some(2),
+            interval: None,
+        }
+        .build();
+        let semaphore = throttle.semaphore.clone().unwrap();
+
+        let first = throttle.acquire().await.unwrap();
+        let second = throttle.acquire().await.unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        // Clones share the limit.
+        let clone = throttle.clone();
+        let third = tokio::time::timeout(Duration::from_millis(20), clone.acquire()).await;
+        assert!(third.is_err());
+
+        drop(first);
+        let third = clone.acquire().await.unwrap();
+        drop((second, third));
+        assert_eq!(semaphore.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn zero_concurrency_allows_one() {
+        let throttle = ThrottleSettings {
+            max_concurrent: Some(0),
+            interval: None,
+        }
+        .build();
+
+        assert!(throttle.acquire().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn requests_are_spaced() {
+        let throttle = ThrottleSettings {
+            max_concurrent: None,
+            interval: Some(Duration::from_millis(25)),
+        }
+        .build();
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            throttle.acquire().await;
+        }
+        // The first request starts immediately.
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+}
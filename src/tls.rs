@@ -0,0 +1,249 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Certificate verification which `reqwest` does not support directly.
+//!
+//! These policies are implemented by handing a fully configured `rustls` configuration to
+//! `reqwest`, so the root certificates, client identity, and protocol versions are applied here
+//! as well.
+
+use std::convert::TryFrom;
+use std::io;
+use std::time::SystemTime;
+
+use reqwest::tls::Version;
+use rustls::client::{self, ServerCertVerified, ServerCertVerifier};
+use rustls::server::ParsedCertificate;
+use rustls::version::{TLS12, TLS13};
+#[cfg(feature = "client_pem")]
+use rustls::PrivateKey;
+use rustls::{
+    Certificate, CertificateError, Error, OwnedTrustAnchor, RootCertStore, ServerName,
+    SupportedProtocolVersion,
+};
+#[cfg(feature = "client_pem")]
+use rustls_pemfile::Item;
+
+/// The SHA-256 fingerprint of a DER-encoded certificate.
+pub(crate) fn fingerprint(der: &[u8]) -> [u8; 32] {
+    let digest = ring::digest::digest(&ring::digest::SHA256, der);
+    let mut fingerprint = [0; 32];
+    fingerprint.copy_from_slice(digest.as_ref());
+    fingerprint
+}
+
+/// Verifies that the certificate chains to a trusted root without checking the host name.
+pub(crate) struct AnyHostnameVerifier {
+    roots: RootCertStore,
+}
+
+impl AnyHostnameVerifier {
+    pub(crate) fn new(roots: RootCertStore) -> Self {
+        Self {
+            roots,
+        }
+    }
+}
+
+impl ServerCertVerifier for AnyHostnameVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        _: &ServerName,
+        _: &mut dyn Iterator<Item = &[u8]>,
+        _: &[u8],
+        now: SystemTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let cert = ParsedCertificate::try_from(end_entity)?;
+        client::verify_server_cert_signed_by_trust_anchor(&cert, &self.roots, intermediates, now)?;
+        Ok(ServerCertVerified::assertion())
+    }
+}
+
+/// Accepts only certificates with one of the given SHA-256 fingerprints.
+///
+/// Certificate authorities, host names, and validity periods are not consulted; the fingerprint
+/// identifies the certificate exactly.
+pub(crate) struct PinnedVerifier {
+    fingerprints: Vec<[u8; 32]>,
+}
+
+impl PinnedVerifier {
+    pub(crate) fn new(fingerprints: Vec<[u8; 32]>) -> Self {
+        Self {
+            fingerprints,
+        }
+    }
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        _: &[Certificate],
+        _: &ServerName,
+        _: &mut dyn Iterator<Item = &[u8]>,
+        _: &[u8],
+        _: SystemTime,
+    ) -> Result<ServerCertVerified, Error> {
+        let fingerprint = fingerprint(&end_entity.0);
+        if self.fingerprints.contains(&fingerprint) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(Error::InvalidCertificate(
+                CertificateError::ApplicationVerificationFailure,
+            ))
+        }
+    }
+}
+
+/// The root certificates to trust.
+pub(crate) fn root_store<I>(certs: I, built_in: bool) -> Result<RootCertStore, Error>
+where
+    I: IntoIterator<Item = Certificate>,
+{
+    let mut roots = RootCertStore::empty();
+    if built_in {
+        let anchors = webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        });
+        roots.add_trust_anchors(anchors);
+    }
+    for cert in certs {
+        roots.add(&cert)?;
+    }
+    Ok(roots)
+}
+
+/// Parse the certificates in a PEM bundle.
+pub(crate) fn pem_certificates(pem: &[u8]) -> io::Result<Vec<Certificate>> {
+    let mut reader = pem;
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+/// Parse a client identity from a PEM-encoded private key and certificate chain.
+#[cfg(feature = "client_pem")]
+pub(crate) fn pem_identity(pem: &[u8]) -> io::Result<(Vec<Certificate>, PrivateKey)> {
+    let mut reader = pem;
+    let mut certs = Vec::new();
+    let mut key = None;
+    while let Some(item) = rustls_pemfile::read_one(&mut reader)? {
+        match item {
+            Item::X509Certificate(cert) => certs.push(Certificate(cert)),
+            Item::RSAKey(der) | Item::PKCS8Key(der) | Item::ECKey(der) => {
+                key = Some(PrivateKey(der))
+            },
+            _ => (),
+        }
+    }
+
+    let key = key.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "no private key found in the identity",
+        )
+    })?;
+    Ok((certs, key))
+}
+
+/// The protocol versions to offer given a minimum version.
+pub(crate) fn protocol_versions(min: Option<Version>) -> Vec<&'static SupportedProtocolVersion> {
+    let min = if let Some(min) = min {
+        min
+    } else {
+        return vec![&TLS13, &TLS12];
+    };
+
+    let mut versions = Vec::new();
+    if Version::TLS_1_3 >= min {
+        versions.push(&TLS13);
+    }
+    if Version::TLS_1_2 >= min {
+        versions.push(&TLS12);
+    }
+    versions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::iter;
+    use std::time::SystemTime;
+
+    use reqwest::tls::Version;
+    use rustls::client::ServerCertVerifier;
+    use rustls::version::{TLS12, TLS13};
+    use rustls::{Certificate, ServerName};
+
+    use crate::tls::{self, PinnedVerifier};
+
+    fn verify(verifier: &PinnedVerifier, der: &[u8]) -> bool {
+        let server_name = ServerName::try_from("gitlab.host.invalid").unwrap();
+        verifier
+            .verify_server_cert(
+                &Certificate(der.into()),
+                &[],
+                &server_name,
+                &mut iter::empty(),
+                &[],
+                SystemTime::now(),
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn pinned_verifier() {
+        let verifier = PinnedVerifier::new(vec![tls::fingerprint(b"first"), [0; 32]]);
+
+        assert!(verify(&verifier, b"first"));
+        assert!(!verify(&verifier, b"second"));
+
+        let verifier = PinnedVerifier::new(Vec::new());
+        assert!(!verify(&verifier, b"first"));
+    }
+
+    #[test]
+    fn fingerprint() {
+        assert_eq!(
+            tls::fingerprint(b""),
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55,
+            ],
+        );
+    }
+
+    #[test]
+    fn protocol_versions() {
+        let items = &[
+            (None, vec![&TLS13, &TLS12]),
+            (Some(Version::TLS_1_0), vec![&TLS13, &TLS12]),
+            (Some(Version::TLS_1_2), vec![&TLS13, &TLS12]),
+            (Some(Version::TLS_1_3), vec![&TLS13]),
+        ];
+
+        for (min, expected) in items {
+            let versions = tls::protocol_versions(*min);
+            let versions = versions.iter().map(|v| v.version).collect::<Vec<_>>();
+            let expected = expected.iter().map(|v| v.version).collect::<Vec<_>>();
+            assert_eq!(versions, expected);
+        }
+    }
+
+    #[cfg(feature = "client_pem")]
+    #[test]
+    fn pem_identity_requires_key() {
+        let err = tls::pem_identity(b"").unwrap_err();
+        assert_eq!(err.to_string(), "no private key found in the identity");
+    }
+}
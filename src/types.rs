@@ -12,6 +12,7 @@
 //! problems when the types and names change inside of those. If found, issues should be filed
 //! upstream.
 
+use std::collections::BTreeMap;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
@@ -254,6 +255,54 @@ impl From<UserPublic> for User {
     }
 }
 
+/// The availability of a user.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAvailability {
+    /// The user has not set their availability.
+    #[serde(rename = "not_set")]
+    NotSet,
+    /// The user is busy.
+    #[serde(rename = "busy")]
+    Busy,
+}
+
+/// The status of a user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserStatus {
+    /// The name of the emoji of the status.
+    pub emoji: Option<String>,
+    /// The availability of the user.
+    pub availability: Option<UserAvailability>,
+    /// The message of the status.
+    pub message: Option<String>,
+    /// The message of the status rendered as HTML.
+    pub message_html: Option<String>,
+    /// When the status is cleared.
+    pub clear_status_at: Option<DateTime<Utc>>,
+}
+
+/// The number of resources associated with a user.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UserAssociationsCount {
+    /// The number of groups the user is a member of.
+    pub groups_count: u64,
+    /// The number of projects the user is a member of.
+    pub projects_count: u64,
+    /// The number of issues created by the user.
+    pub issues_count: u64,
+    /// The number of merge requests created by the user.
+    pub merge_requests_count: u64,
+}
+
+/// The last activity date of a user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserActivity {
+    /// The username of the user.
+    pub username: String,
+    /// When the user was last active.
+    pub last_activity_on: NaiveDate,
+}
+
 impl_id!(EmailId, "Type-safe email ID.");
 
 /// Email address.
@@ -812,6 +861,30 @@ impl From<AccessRequester> for UserBasic {
     }
 }
 
+/// The kinds of sources a user may be a member of.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MembershipSourceType {
+    /// The user is a member of a project.
+    #[serde(rename = "Project")]
+    Project,
+    /// The user is a member of a namespace (group).
+    #[serde(rename = "Namespace")]
+    Namespace,
+}
+
+/// A membership of a user in a project or group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserMembership {
+    /// The ID of the project or group.
+    pub source_id: u64,
+    /// The name of the project or group.
+    pub source_name: String,
+    /// The kind of the source.
+    pub source_type: MembershipSourceType,
+    /// The access level of the user.
+    pub access_level: AccessLevel,
+}
+
 impl_id!(GroupId, "Type-safe group ID.");
 
 /// Group information.
@@ -1148,6 +1221,42 @@ pub struct DiffRefs {
     pub start_sha: Option<ObjectId>,
 }
 
+impl_id!(
+    MergeRequestDiffVersionId,
+    "Type-safe merge request diff version ID.",
+);
+
+/// A version of the diff of a merge request.
+///
+/// A new version is created each time the source branch of a merge request is pushed to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeRequestDiffVersion {
+    /// The ID of the version.
+    pub id: MergeRequestDiffVersionId,
+    /// The head commit of the source branch for this version.
+    pub head_commit_sha: ObjectId,
+    /// The merge base of the source and target branches for this version.
+    pub base_commit_sha: ObjectId,
+    /// The head commit of the target branch for this version.
+    pub start_commit_sha: ObjectId,
+    /// When the version was created.
+    pub created_at: DateTime<Utc>,
+    /// The ID of the merge request.
+    pub merge_request_id: MergeRequestId,
+    /// The state of the diff (e.g., `collected` or `overflow`).
+    pub state: String,
+    /// The number of changes in the version.
+    pub real_size: String,
+    /// The commits in the version.
+    ///
+    /// Only available when querying a single version.
+    pub commits: Option<Vec<MergeRequestCommit>>,
+    /// The diffs in the version.
+    ///
+    /// Only available when querying a single version.
+    pub diffs: Option<Vec<RepoDiff>>,
+}
+
 impl_id!(MilestoneId, "Type-safe milestone ID.");
 
 impl_id!(
@@ -1196,6 +1305,7 @@ pub struct Milestone {
 impl Milestone {
     /// Create a new blank milestone: it needs at least the ProjectId and title
     /// ProjectId and title are mandatory for new milestone API of Gitlab
+    #[deprecated(note = "use `api::projects::milestones::CreateProjectMilestone` instead")]
     pub fn new_for_project(project_id: ProjectId, title: String) -> Milestone {
         Milestone {
             id: MilestoneId::new(0),
@@ -1213,6 +1323,7 @@ impl Milestone {
     }
     /// Create a new blank group milestone: it needs at least the GroupId and title
     /// GroupId and title are mandatory for new milestone API of Gitlab
+    #[deprecated(note = "use `api::groups::milestones::CreateGroupMilestone` instead")]
     pub fn new_for_group(group_id: GroupId, title: String) -> Milestone {
         Milestone {
             id: MilestoneId::new(0),
@@ -1229,22 +1340,50 @@ impl Milestone {
         }
     }
     /// Complements the milestone with optional paramater: description
+    #[deprecated(note = "use the milestone creation endpoints instead")]
     pub fn with_description(mut self, description: String) -> Milestone {
         self.description = Some(description);
         self
     }
     /// Complements the milestone with optional parameter: due_date
+    #[deprecated(note = "use the milestone creation endpoints instead")]
     pub fn with_due_date(mut self, due_date: NaiveDate) -> Milestone {
         self.due_date = Some(due_date);
         self
     }
     /// Complements the milestone with optional parameter: start_date
+    #[deprecated(note = "use the milestone creation endpoints instead")]
     pub fn with_start_date(mut self, start_date: NaiveDate) -> Milestone {
         self.start_date = Some(start_date);
         self
     }
 }
 
+/// The action of a milestone burndown event.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BurndownEventAction {
+    /// The issue was created.
+    #[serde(rename = "created")]
+    Created,
+    /// The issue was closed.
+    #[serde(rename = "closed")]
+    Closed,
+    /// The issue was reopened.
+    #[serde(rename = "reopened")]
+    Reopened,
+}
+
+/// An event used to generate a milestone burndown chart.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BurndownEvent {
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The weight of the issue.
+    pub weight: Option<u64>,
+    /// The action which occurred.
+    pub action: BurndownEventAction,
+}
+
 impl_id!(LabelId, "Type-safe label ID.");
 
 /// Type-safe label color.
@@ -1542,6 +1681,17 @@ pub struct IssuableTimeStats {
     pub human_total_time_spent: Option<String>,
 }
 
+/// User agent details recorded when an issue was created.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserAgentDetail {
+    /// The user agent of the client which created the issue.
+    pub user_agent: String,
+    /// The IP address of the client which created the issue.
+    pub ip_address: String,
+    /// Whether the issue has been submitted to Akismet as spam or ham.
+    pub akismet_submitted: bool,
+}
+
 /// Type-safe external issue ID.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ExternalIssueId(u64);
@@ -1670,6 +1820,40 @@ pub struct MergeRequestBasic {
     pub web_url: String,
 }
 
+/// The review state of a reviewer on a merge request.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeRequestReviewState {
+    /// The reviewer has not reviewed the merge request yet.
+    #[serde(rename = "unreviewed")]
+    Unreviewed,
+    /// The reviewer has started a review.
+    #[serde(rename = "review_started")]
+    ReviewStarted,
+    /// The reviewer has reviewed the merge request.
+    #[serde(rename = "reviewed")]
+    Reviewed,
+    /// The reviewer has requested changes.
+    #[serde(rename = "requested_changes")]
+    RequestedChanges,
+    /// The reviewer has approved the merge request.
+    #[serde(rename = "approved")]
+    Approved,
+    /// The reviewer has revoked their approval.
+    #[serde(rename = "unapproved")]
+    Unapproved,
+}
+
+/// A reviewer of a merge request.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MergeRequestReviewer {
+    /// The reviewer.
+    pub user: UserBasic,
+    /// The state of the review.
+    pub state: MergeRequestReviewState,
+    /// When the reviewer was added to the merge request.
+    pub created_at: Option<DateTime<Utc>>,
+}
+
 /// A merge request.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MergeRequest {
@@ -2001,7 +2185,12 @@ pub struct SshKey {
     pub key: String,
     /// When the key was created.
     pub created_at: DateTime<Utc>,
+    /// When the key expires.
+    pub expires_at: Option<DateTime<Utc>>,
     /// Whether the key may push to repositories or not.
+    ///
+    /// This is only provided for deploy keys.
+    #[serde(default)]
     pub can_push: bool,
 }
 
@@ -2016,10 +2205,74 @@ pub struct SshKeyWithUser {
     pub key: String,
     /// When the key was created.
     pub created_at: DateTime<Utc>,
+    /// When the key expires.
+    pub expires_at: Option<DateTime<Utc>>,
     /// The user associated with the SSH key.
     pub user: UserPublic,
 }
 
+impl_id!(PersonalAccessTokenId, "Type-safe personal access token ID.");
+
+/// A personal access token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PersonalAccessToken {
+    /// The ID of the token.
+    pub id: PersonalAccessTokenId,
+    /// The name of the token.
+    pub name: String,
+    /// The description of the token.
+    pub description: Option<String>,
+    /// Whether the token has been revoked.
+    pub revoked: bool,
+    /// When the token was created.
+    pub created_at: DateTime<Utc>,
+    /// The scopes granted to the token.
+    pub scopes: Vec<String>,
+    /// The ID of the user owning the token.
+    pub user_id: UserId,
+    /// When the token was last used.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Whether the token is active or not.
+    pub active: bool,
+    /// When the token expires.
+    pub expires_at: Option<NaiveDate>,
+    /// The value of the token.
+    ///
+    /// This is only available when the token is created or rotated.
+    pub token: Option<String>,
+}
+
+/// An access token for a project.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProjectAccessToken {
+    /// The ID of the token.
+    pub id: PersonalAccessTokenId,
+    /// The name of the token.
+    pub name: String,
+    /// The description of the token.
+    pub description: Option<String>,
+    /// Whether the token has been revoked.
+    pub revoked: bool,
+    /// When the token was created.
+    pub created_at: DateTime<Utc>,
+    /// The scopes granted to the token.
+    pub scopes: Vec<String>,
+    /// The ID of the bot user associated with the token.
+    pub user_id: UserId,
+    /// When the token was last used.
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Whether the token is active or not.
+    pub active: bool,
+    /// When the token expires.
+    pub expires_at: Option<NaiveDate>,
+    /// The access level of the token within the project.
+    pub access_level: AccessLevel,
+    /// The value of the token.
+    ///
+    /// This is only available when the token is created or rotated.
+    pub token: Option<String>,
+}
+
 /// The entities a note may be added to.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NoteType {
@@ -2398,24 +2651,39 @@ pub struct Commit {
     pub title: Option<String>,
 }
 
+impl_id!(ReleaseId, "Type-safe release ID.");
+impl_id!(ReleaseLinkId, "Type-safe release link ID.");
+
 /// The target of an event.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventTargetType {
     /// An event targeted a commit.
-    #[serde(rename = "commit")]
+    #[serde(rename = "commit", alias = "Commit")]
     Commit,
     /// An event targeted an issue.
-    #[serde(rename = "issue")]
+    #[serde(rename = "issue", alias = "Issue")]
     Issue,
     /// An event targeted a merge request.
-    #[serde(rename = "merge_request")]
+    #[serde(rename = "merge_request", alias = "MergeRequest")]
     MergeRequest,
     /// An event targeted a snippet.
-    #[serde(rename = "snippet")]
+    #[serde(rename = "snippet", alias = "Snippet")]
     Snippet,
     /// An event targeted a project snippet.
-    #[serde(rename = "project_snippet")]
+    #[serde(rename = "project_snippet", alias = "ProjectSnippet")]
     ProjectSnippet,
+    /// An event targeted a milestone.
+    #[serde(rename = "milestone", alias = "Milestone")]
+    Milestone,
+    /// An event targeted a note.
+    #[serde(rename = "note", alias = "Note")]
+    Note,
+    /// An event targeted a note on a diff.
+    #[serde(rename = "diff_note", alias = "DiffNote")]
+    DiffNote,
+    /// An event targeted a note in a discussion.
+    #[serde(rename = "discussion_note", alias = "DiscussionNote")]
+    DiscussionNote,
 }
 
 /// The ID of an event target.
@@ -2429,6 +2697,10 @@ pub enum EventTargetId {
     MergeRequest(MergeRequestId),
     /// The ID of a snippet event target.
     Snippet(SnippetId),
+    /// The ID of a milestone event target.
+    Milestone(MilestoneId),
+    /// The ID of a note event target.
+    Note(NoteId),
 }
 
 /// An event on a project.
@@ -2437,18 +2709,22 @@ pub struct Event {
     /// The title of the event.
     pub title: Option<String>,
     /// The ID of the project.
-    pub project_id: ProjectId,
+    ///
+    /// Events which do not occur within a project (e.g., joining a group) do not have a project.
+    pub project_id: Option<ProjectId>,
     /// The action which triggered the event.
     // FIXME: This should be an enumeration.
     pub action_name: String,
     target_id: Value,
     /// The type of the event target.
-    pub target_type: EventTargetType,
+    ///
+    /// Events such as pushes do not have a target.
+    pub target_type: Option<EventTargetType>,
     /// The ID of the author of the event.
     pub author_id: UserId,
     pub data: Option<Value>,
     /// The title of the target.
-    pub target_title: String,
+    pub target_title: Option<String>,
     /// When the event was created.
     pub created_at: DateTime<Utc>,
     pub note: Option<Note>,
@@ -2461,7 +2737,7 @@ pub struct Event {
 impl Event {
     /// The ID of an event's target.
     pub fn target_id(&self) -> Option<EventTargetId> {
-        match self.target_type {
+        match self.target_type? {
             EventTargetType::Commit => {
                 self.target_id
                     .as_str()
@@ -2487,6 +2763,16 @@ impl Event {
                     .as_u64()
                     .map(|id| EventTargetId::Snippet(SnippetId(id)))
             },
+            EventTargetType::Milestone => {
+                self.target_id
+                    .as_u64()
+                    .map(|id| EventTargetId::Milestone(MilestoneId(id)))
+            },
+            EventTargetType::Note | EventTargetType::DiffNote | EventTargetType::DiscussionNote => {
+                self.target_id
+                    .as_u64()
+                    .map(|id| EventTargetId::Note(NoteId(id)))
+            },
         }
     }
 }
@@ -2530,6 +2816,22 @@ pub struct Namespace {
     pub avatar_url: Option<String>,
     /// The URL to the namespace page (user or group).
     pub web_url: String,
+    /// The ID of the parent group of the namespace.
+    pub parent_id: Option<GroupId>,
+    /// The name of the plan of the namespace.
+    ///
+    /// Only available on instances with plans.
+    pub plan: Option<String>,
+    /// When the trial of the namespace ends.
+    pub trial_ends_on: Option<NaiveDate>,
+    /// Whether the namespace is in a trial.
+    pub trial: Option<bool>,
+    /// The number of billable members of the namespace.
+    pub billable_members_count: Option<u64>,
+    /// The number of seats in use by the namespace.
+    pub seats_in_use: Option<u64>,
+    /// The maximum number of seats used by the namespace.
+    pub max_seats_used: Option<u64>,
 }
 
 impl Namespace {
@@ -2542,6 +2844,15 @@ impl Namespace {
     }
 }
 
+/// Whether a namespace path is in use.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NamespaceExistence {
+    /// Whether the namespace exists.
+    pub exists: bool,
+    /// Suggested alternative paths which are not in use.
+    pub suggests: Vec<String>,
+}
+
 impl_id!(RunnerId, "Type-safe runner ID.");
 
 /// A Gitlab CI runner.
@@ -2737,23 +3048,11 @@ pub struct ResourceLabelEvent {
 impl ResourceLabelEvent {
     /// Returns the id of the merge request or issue that this event is from
     pub fn event_target(&self) -> Option<ResourceLabelEventTarget> {
-        match self.resource_type.as_ref() {
-            "MergeRequest" => {
-                Some(ResourceLabelEventTarget::MergeRequest(MergeRequestId::new(
-                    self.resource_id,
-                )))
-            },
-            "Issue" => {
-                Some(ResourceLabelEventTarget::Issue(IssueId::new(
-                    self.resource_id,
-                )))
-            },
-            _ => None,
-        }
+        ResourceLabelEventTarget::new(&self.resource_type, self.resource_id)
     }
 }
 
-/// The type of object that on which the resource label event was created
+/// The type of object that on which the resource event was created
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ResourceLabelEventTarget {
     /// The ID of an issue event target.
@@ -2762,6 +3061,20 @@ pub enum ResourceLabelEventTarget {
     MergeRequest(MergeRequestId),
 }
 
+impl ResourceLabelEventTarget {
+    fn new(resource_type: &str, resource_id: u64) -> Option<Self> {
+        match resource_type {
+            "MergeRequest" => {
+                Some(ResourceLabelEventTarget::MergeRequest(MergeRequestId::new(
+                    resource_id,
+                )))
+            },
+            "Issue" => Some(ResourceLabelEventTarget::Issue(IssueId::new(resource_id))),
+            _ => None,
+        }
+    }
+}
+
 /// An label on a project.
 ///
 /// This is like [Label], except that it doesn't have all the same fields
@@ -2776,3 +3089,1058 @@ pub struct EventLabel {
     /// The description of the label.
     pub description: Option<String>,
 }
+
+impl_id!(MilestoneEventId, "Type-safe milestone event ID.");
+
+/// A resource milestone event
+///
+/// Note that resource milestone events were added in Gitlab 13.1.  Any milestones set or
+/// removed before then will not be returned by the API.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceMilestoneEvent {
+    /// The ID for the milestone event
+    pub id: MilestoneEventId,
+    /// The user who changed the milestone.
+    pub user: UserBasic,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The merge request id, or issue id (depending on the value of resource_type)
+    resource_id: u64,
+    /// Either "MergeRequest" or "Issue"
+    resource_type: String,
+    /// The milestone may be None if the milestone has been deleted.
+    pub milestone: Option<Milestone>,
+    /// Either "add" or "remove".
+    pub action: String,
+}
+
+impl ResourceMilestoneEvent {
+    /// Returns the id of the merge request or issue that this event is from
+    pub fn event_target(&self) -> Option<ResourceLabelEventTarget> {
+        ResourceLabelEventTarget::new(&self.resource_type, self.resource_id)
+    }
+}
+
+impl_id!(StateEventId, "Type-safe state event ID.");
+
+/// The states a resource may be moved into by a state event.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceStateEventState {
+    /// The resource was opened.
+    #[serde(rename = "opened")]
+    Opened,
+    /// The resource was closed.
+    #[serde(rename = "closed")]
+    Closed,
+    /// The resource was reopened after being closed.
+    #[serde(rename = "reopened")]
+    Reopened,
+    /// The merge request was merged.
+    #[serde(rename = "merged")]
+    Merged,
+    /// The merge request was locked.
+    #[serde(rename = "locked")]
+    Locked,
+}
+
+/// A resource state event
+///
+/// Note that resource state events were added in Gitlab 13.2.  Any state changes before then
+/// will not be returned by the API.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceStateEvent {
+    /// The ID for the state event
+    pub id: StateEventId,
+    /// The user who changed the state.
+    pub user: UserBasic,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The merge request id, or issue id (depending on the value of resource_type)
+    resource_id: u64,
+    /// Either "MergeRequest" or "Issue"
+    resource_type: String,
+    /// The new state of the resource.
+    pub state: ResourceStateEventState,
+}
+
+impl ResourceStateEvent {
+    /// Returns the id of the merge request or issue that this event is from
+    pub fn event_target(&self) -> Option<ResourceLabelEventTarget> {
+        ResourceLabelEventTarget::new(&self.resource_type, self.resource_id)
+    }
+}
+
+impl_id!(IterationId, "Type-safe iteration ID.");
+impl_id!(
+    IterationInternalId,
+    "Type-safe iteration internal ID (internal to a group).",
+);
+
+/// The states an iteration may be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterationState {
+    /// The iteration has not started yet.
+    Upcoming,
+    /// The iteration is in progress.
+    Current,
+    /// The iteration has ended.
+    Closed,
+}
+
+impl Serialize for IterationState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value: u64 = match self {
+            IterationState::Upcoming => 1,
+            IterationState::Current => 2,
+            IterationState::Closed => 3,
+        };
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IterationState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <u64 as Deserialize>::deserialize(deserializer)?;
+        match value {
+            1 => Ok(IterationState::Upcoming),
+            2 => Ok(IterationState::Current),
+            3 => Ok(IterationState::Closed),
+            _ => {
+                Err(D::Error::custom(format!(
+                    "invalid iteration state: {}",
+                    value
+                )))
+            },
+        }
+    }
+}
+
+/// An iteration (sprint) in a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Iteration {
+    /// The ID of the iteration.
+    pub id: IterationId,
+    /// The user-visible ID of the iteration.
+    pub iid: IterationInternalId,
+    /// The ID of the group the iteration belongs to.
+    pub group_id: GroupId,
+    /// The title of the iteration.
+    pub title: Option<String>,
+    /// The description of the iteration.
+    pub description: Option<String>,
+    /// The state of the iteration.
+    pub state: IterationState,
+    /// When the iteration was created.
+    pub created_at: DateTime<Utc>,
+    /// When the iteration was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// When the iteration starts.
+    pub start_date: Option<NaiveDate>,
+    /// When the iteration is due.
+    pub due_date: Option<NaiveDate>,
+    /// The URL of the iteration.
+    pub web_url: Option<String>,
+}
+
+impl_id!(IterationEventId, "Type-safe iteration event ID.");
+
+/// A resource iteration event
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceIterationEvent {
+    /// The ID for the iteration event
+    pub id: IterationEventId,
+    /// The user who added or removed the iteration.
+    pub user: UserBasic,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The merge request id, or issue id (depending on the value of resource_type)
+    resource_id: u64,
+    /// Either "MergeRequest" or "Issue"
+    resource_type: String,
+    /// The iteration that was added or removed.
+    ///
+    /// The iteration may be None if the iteration has been deleted.
+    pub iteration: Option<Iteration>,
+    /// Either "add" or "remove".
+    pub action: String,
+}
+
+impl ResourceIterationEvent {
+    /// Returns the id of the merge request or issue that this event is from
+    pub fn event_target(&self) -> Option<ResourceLabelEventTarget> {
+        ResourceLabelEventTarget::new(&self.resource_type, self.resource_id)
+    }
+}
+
+impl_id!(WeightEventId, "Type-safe weight event ID.");
+
+/// A resource weight event
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResourceWeightEvent {
+    /// The ID for the weight event
+    pub id: WeightEventId,
+    /// The user who changed the weight.
+    pub user: UserBasic,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+    /// The ID of the issue.
+    pub issue_id: IssueId,
+    /// The new weight of the issue.
+    ///
+    /// This is None if the weight was removed.
+    pub weight: Option<u64>,
+}
+
+impl_id!(AuditEventId, "Type-safe audit event ID.");
+
+/// The kinds of entities an audit event may be about.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditEventEntityType {
+    /// The event is about a user.
+    #[serde(rename = "User")]
+    User,
+    /// The event is about a group.
+    #[serde(rename = "Group")]
+    Group,
+    /// The event is about a project.
+    #[serde(rename = "Project")]
+    Project,
+}
+
+/// An audit event.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditEvent {
+    /// The ID of the audit event.
+    pub id: AuditEventId,
+    /// The ID of the user who caused the event.
+    pub author_id: UserId,
+    /// The ID of the entity the event is about.
+    pub entity_id: u64,
+    /// The type of the entity the event is about.
+    pub entity_type: AuditEventEntityType,
+    /// Details of the event.
+    ///
+    /// The available keys depend on the kind of event, but usually include `author_name`,
+    /// `target_type`, `target_details`, and `ip_address`.
+    pub details: BTreeMap<String, Value>,
+    /// When the event occurred.
+    pub created_at: DateTime<Utc>,
+}
+
+impl_id!(LicenseId, "Type-safe license ID.");
+
+/// The holder of a license.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Licensee {
+    /// The name of the licensee.
+    #[serde(rename = "Name")]
+    pub name: Option<String>,
+    /// The email address of the licensee.
+    #[serde(rename = "Email")]
+    pub email: Option<String>,
+    /// The company of the licensee.
+    #[serde(rename = "Company")]
+    pub company: Option<String>,
+}
+
+/// A license of a GitLab instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct License {
+    /// The ID of the license.
+    pub id: LicenseId,
+    /// The plan the license is for.
+    pub plan: String,
+    /// When the license was added.
+    pub created_at: DateTime<Utc>,
+    /// When the license starts.
+    pub starts_at: Option<NaiveDate>,
+    /// When the license expires.
+    pub expires_at: Option<NaiveDate>,
+    /// The highest number of billable users during the license period.
+    pub historical_max: u64,
+    /// The highest number of billable users.
+    pub maximum_user_count: u64,
+    /// Whether the license has expired.
+    pub expired: bool,
+    /// The number of users over the user limit.
+    pub overage: u64,
+    /// The number of users the license allows.
+    pub user_limit: u64,
+    /// The number of active users.
+    pub active_users: u64,
+    /// The holder of the license.
+    pub licensee: Licensee,
+    /// Add-ons included in the license.
+    #[serde(default)]
+    pub add_ons: BTreeMap<String, u64>,
+}
+
+/// The appearance of a GitLab instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Appearance {
+    /// The title on the sign in and sign up pages.
+    pub title: String,
+    /// The description on the sign in and sign up pages (Markdown).
+    pub description: String,
+    /// The URL of the logo on the sign in and sign up pages.
+    pub logo: Option<String>,
+    /// The URL of the logo in the navigation bar.
+    pub header_logo: Option<String>,
+    /// The URL of the favicon.
+    pub favicon: Option<String>,
+    /// Guidelines shown on the new project page (Markdown).
+    pub new_project_guidelines: String,
+    /// Guidelines shown on the profile page below the avatar (Markdown).
+    pub profile_image_guidelines: String,
+    /// A message shown in the header of every page.
+    pub header_message: String,
+    /// A message shown in the footer of every page.
+    pub footer_message: String,
+    /// The background color of the header and footer messages.
+    pub message_background_color: String,
+    /// The font color of the header and footer messages.
+    pub message_font_color: String,
+    /// Whether the header and footer messages are added to all outgoing emails.
+    pub email_header_and_footer_enabled: bool,
+}
+
+/// The status of a health check.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthCheckStatus {
+    /// The check passed.
+    #[serde(rename = "ok")]
+    Ok,
+    /// The check failed.
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+/// The result of an individual health check.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HealthCheckResult {
+    /// The status of the check.
+    pub status: HealthCheckStatus,
+    /// A message describing why the check failed.
+    pub message: Option<String>,
+    /// Labels identifying what was checked (e.g., the shard).
+    pub labels: Option<BTreeMap<String, String>>,
+}
+
+/// The result of a readiness check.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReadinessCheckResult {
+    /// The overall status of the instance.
+    pub status: HealthCheckStatus,
+    /// The results of the individual checks, keyed by the name of the check (e.g., `db_check`).
+    #[serde(flatten)]
+    pub checks: BTreeMap<String, Vec<HealthCheckResult>>,
+}
+
+/// The result of a liveness check.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LivenessCheckResult {
+    /// The status of the instance.
+    pub status: HealthCheckStatus,
+}
+
+/// Metrics for a Sidekiq queue.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SidekiqQueueMetric {
+    /// The number of jobs waiting in the queue.
+    pub backlog: u64,
+    /// How long the oldest job has been waiting in the queue (in seconds).
+    pub latency: u64,
+}
+
+/// Metrics for the Sidekiq queues of an instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SidekiqQueueMetrics {
+    /// The metrics of each queue, keyed by the name of the queue.
+    pub queues: BTreeMap<String, SidekiqQueueMetric>,
+}
+
+/// A Sidekiq process.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SidekiqProcess {
+    /// The hostname the process is running on.
+    pub hostname: String,
+    /// The process ID.
+    pub pid: u64,
+    /// The tag of the process.
+    pub tag: String,
+    /// When the process started.
+    pub started_at: DateTime<Utc>,
+    /// The queues the process handles.
+    pub queues: Vec<String>,
+    /// The labels of the process.
+    pub labels: Vec<String>,
+    /// The number of jobs the process may handle at once.
+    pub concurrency: u64,
+    /// The number of jobs the process is currently handling.
+    pub busy: u64,
+}
+
+/// Metrics for the Sidekiq processes of an instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SidekiqProcessMetrics {
+    /// The running processes.
+    pub processes: Vec<SidekiqProcess>,
+}
+
+/// Counts of Sidekiq jobs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SidekiqJobCounts {
+    /// The number of processed jobs.
+    pub processed: u64,
+    /// The number of failed jobs.
+    pub failed: u64,
+    /// The number of enqueued jobs.
+    pub enqueued: u64,
+    /// The number of dead jobs.
+    pub dead: u64,
+}
+
+/// Statistics for the Sidekiq jobs of an instance.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SidekiqJobStats {
+    /// The job counts.
+    pub jobs: SidekiqJobCounts,
+}
+
+/// All Sidekiq metrics of an instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SidekiqCompoundMetrics {
+    /// The metrics of each queue, keyed by the name of the queue.
+    pub queues: BTreeMap<String, SidekiqQueueMetric>,
+    /// The running processes.
+    pub processes: Vec<SidekiqProcess>,
+    /// The job counts.
+    pub jobs: SidekiqJobCounts,
+}
+
+fn deserialize_statistics_count<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // GitLab reports these counts as strings with thousands separators (e.g., "1,234").
+    match Value::deserialize(deserializer)? {
+        Value::Number(number) => {
+            number
+                .as_u64()
+                .ok_or_else(|| D::Error::custom(format!("invalid count: {}", number)))
+        },
+        Value::String(string) => {
+            string
+                .replace(',', "")
+                .parse()
+                .map_err(|_| D::Error::custom(format!("invalid count: {}", string)))
+        },
+        value => Err(D::Error::custom(format!("invalid count: {}", value))),
+    }
+}
+
+/// Statistics about a GitLab instance.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApplicationStatistics {
+    /// The number of forks.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub forks: u64,
+    /// The number of issues.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub issues: u64,
+    /// The number of merge requests.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub merge_requests: u64,
+    /// The number of notes.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub notes: u64,
+    /// The number of snippets.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub snippets: u64,
+    /// The number of SSH keys.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub ssh_keys: u64,
+    /// The number of milestones.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub milestones: u64,
+    /// The number of users.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub users: u64,
+    /// The number of groups.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub groups: u64,
+    /// The number of projects.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub projects: u64,
+    /// The number of active users.
+    #[serde(deserialize_with = "deserialize_statistics_count")]
+    pub active_users: u64,
+}
+
+/// The state of a feature flag.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureState {
+    /// The feature is enabled for everyone.
+    #[serde(rename = "on")]
+    On,
+    /// The feature is disabled for everyone.
+    #[serde(rename = "off")]
+    Off,
+    /// The feature is enabled for some actors or a percentage of time or actors.
+    #[serde(rename = "conditional")]
+    Conditional,
+}
+
+/// A gate on a feature flag.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FeatureGate {
+    /// The kind of gate (e.g., `boolean`, `actors`, or `percentage_of_time`).
+    pub key: String,
+    /// The value of the gate.
+    ///
+    /// This is a boolean for `boolean` gates, a percentage for percentage gates, and a list of
+    /// actor identifiers (e.g., `User:1`) for `actors` gates.
+    pub value: Value,
+}
+
+/// The definition of a feature flag.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeatureDefinition {
+    /// The name of the feature flag.
+    pub name: String,
+    /// The URL of the merge request which introduced the feature flag.
+    pub introduced_by_url: Option<String>,
+    /// The URL of the issue tracking the rollout of the feature flag.
+    pub rollout_issue_url: Option<String>,
+    /// The milestone the feature flag was introduced in.
+    pub milestone: Option<String>,
+    /// Whether changes to the state of the feature flag are logged.
+    pub log_state_changes: Option<bool>,
+    /// The type of the feature flag (e.g., `development` or `ops`).
+    #[serde(rename = "type")]
+    pub feature_type: String,
+    /// The group which owns the feature flag.
+    pub group: Option<String>,
+    /// Whether the feature flag is enabled by default.
+    pub default_enabled: bool,
+}
+
+/// A feature flag.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Feature {
+    /// The name of the feature flag.
+    pub name: String,
+    /// The state of the feature flag.
+    pub state: FeatureState,
+    /// The gates set on the feature flag.
+    pub gates: Vec<FeatureGate>,
+    /// The definition of the feature flag.
+    pub definition: Option<FeatureDefinition>,
+}
+
+impl_id!(VulnerabilityId, "Type-safe vulnerability ID.");
+
+/// The state of a vulnerability.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VulnerabilityState {
+    /// The vulnerability has been detected, but not triaged.
+    #[serde(rename = "detected")]
+    Detected,
+    /// The vulnerability has been confirmed.
+    #[serde(rename = "confirmed")]
+    Confirmed,
+    /// The vulnerability has been resolved.
+    #[serde(rename = "resolved")]
+    Resolved,
+    /// The vulnerability has been dismissed.
+    #[serde(rename = "dismissed")]
+    Dismissed,
+}
+
+/// The severity of a vulnerability.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VulnerabilitySeverity {
+    /// An informational finding.
+    #[serde(rename = "info")]
+    Info,
+    /// The severity is unknown.
+    #[serde(rename = "unknown")]
+    Unknown,
+    /// Low severity.
+    #[serde(rename = "low")]
+    Low,
+    /// Medium severity.
+    #[serde(rename = "medium")]
+    Medium,
+    /// High severity.
+    #[serde(rename = "high")]
+    High,
+    /// Critical severity.
+    #[serde(rename = "critical")]
+    Critical,
+}
+
+/// A vulnerability.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Vulnerability {
+    /// The ID of the vulnerability.
+    pub id: VulnerabilityId,
+    /// The title of the vulnerability.
+    pub title: String,
+    /// The description of the vulnerability.
+    pub description: Option<String>,
+    /// The state of the vulnerability.
+    pub state: VulnerabilityState,
+    /// The severity of the vulnerability.
+    pub severity: VulnerabilitySeverity,
+    /// The confidence of the vulnerability.
+    pub confidence: Option<String>,
+    /// The kind of report the vulnerability was found by (e.g., `sast`).
+    pub report_type: String,
+    /// The ID of the user who created the vulnerability.
+    pub author_id: Option<UserId>,
+    /// The ID of the user who confirmed the vulnerability.
+    pub confirmed_by_id: Option<UserId>,
+    /// The ID of the user who resolved the vulnerability.
+    pub resolved_by_id: Option<UserId>,
+    /// The ID of the user who dismissed the vulnerability.
+    pub dismissed_by_id: Option<UserId>,
+    /// When the vulnerability was created.
+    pub created_at: DateTime<Utc>,
+    /// When the vulnerability was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// When the vulnerability was confirmed.
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// When the vulnerability was resolved.
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// When the vulnerability was dismissed.
+    pub dismissed_at: Option<DateTime<Utc>>,
+}
+
+/// The scanner which reported a vulnerability finding.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VulnerabilityScanner {
+    /// The ID of the scanner.
+    pub external_id: String,
+    /// The name of the scanner.
+    pub name: String,
+    /// The vendor of the scanner.
+    pub vendor: Option<String>,
+}
+
+/// An identifier of a vulnerability finding (e.g., a CVE or CWE).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VulnerabilityIdentifier {
+    /// The type of the identifier (e.g., `cve`).
+    pub external_type: String,
+    /// The value of the identifier.
+    pub external_id: String,
+    /// The human-readable name of the identifier.
+    pub name: String,
+    /// A URL with more information about the identifier.
+    pub url: Option<String>,
+}
+
+/// A vulnerability finding reported by a security scanner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VulnerabilityFinding {
+    /// The ID of the finding.
+    pub id: Option<u64>,
+    /// The UUID of the finding.
+    pub uuid: String,
+    /// The name of the finding.
+    pub name: String,
+    /// The kind of report which reported the finding (e.g., `sast`).
+    pub report_type: String,
+    /// The severity of the finding.
+    pub severity: VulnerabilitySeverity,
+    /// The confidence of the finding.
+    pub confidence: Option<String>,
+    /// The state of the finding.
+    pub state: Option<VulnerabilityState>,
+    /// The scanner which reported the finding.
+    pub scanner: Option<VulnerabilityScanner>,
+    /// Identifiers of the finding.
+    pub identifiers: Vec<VulnerabilityIdentifier>,
+    /// The fingerprint of the finding within the project.
+    pub project_fingerprint: String,
+    /// The description of the finding.
+    pub description: Option<String>,
+    /// How to resolve the finding.
+    pub solution: Option<String>,
+    /// Where the finding was found.
+    ///
+    /// The structure depends on the report type.
+    pub location: Option<Value>,
+    /// The path to the affected file.
+    pub blob_path: Option<String>,
+}
+
+impl_id!(VulnerabilityExportId, "Type-safe vulnerability export ID.");
+
+/// The status of a vulnerability export.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VulnerabilityExportStatus {
+    /// The export has been created, but not started.
+    #[serde(rename = "created")]
+    Created,
+    /// The export is being generated.
+    #[serde(rename = "running")]
+    Running,
+    /// The export is ready for download.
+    #[serde(rename = "finished")]
+    Finished,
+    /// The export failed.
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+/// Links for a vulnerability export.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VulnerabilityExportLinks {
+    /// The API URL of the export.
+    #[serde(rename = "self")]
+    pub self_: String,
+    /// The API URL to download the export.
+    pub download: String,
+}
+
+/// An export of vulnerabilities.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VulnerabilityExport {
+    /// The ID of the export.
+    pub id: VulnerabilityExportId,
+    /// The ID of the exported project.
+    pub project_id: Option<ProjectId>,
+    /// The ID of the exported group.
+    pub group_id: Option<GroupId>,
+    /// The format of the export (e.g., `csv`).
+    pub format: String,
+    /// The status of the export.
+    pub status: VulnerabilityExportStatus,
+    /// When the export was created.
+    pub created_at: DateTime<Utc>,
+    /// When the generation of the export started.
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the generation of the export finished.
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Links for the export.
+    #[serde(rename = "_links")]
+    pub links: VulnerabilityExportLinks,
+}
+
+/// A value stream for value stream analytics.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValueStream {
+    /// The ID of the value stream.
+    pub id: u64,
+    /// The name of the value stream.
+    pub name: String,
+    /// Whether the value stream has been customized.
+    pub is_custom: Option<bool>,
+}
+
+/// A stage of a value stream.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ValueStreamStage {
+    /// The ID of the stage.
+    ///
+    /// Default stages which have not been persisted use their name as an ID.
+    pub id: Value,
+    /// The title of the stage.
+    pub title: String,
+    /// The name of the stage.
+    pub name: String,
+    /// Whether the stage is hidden.
+    pub hidden: bool,
+    /// Whether the stage is a custom stage.
+    pub custom: bool,
+    /// The description of the stage.
+    pub description: Option<String>,
+    /// The legend of the stage.
+    pub legend: Option<String>,
+    /// The identifier of the event which starts the stage.
+    pub start_event_identifier: Option<String>,
+    /// The identifier of the event which ends the stage.
+    pub end_event_identifier: Option<String>,
+}
+
+/// The median duration of a value stream stage.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct ValueStreamStageMedian {
+    /// The median duration in seconds.
+    ///
+    /// This is `None` if there is no data for the stage.
+    pub value: Option<f64>,
+}
+
+impl_id!(BulkImportId, "Type-safe bulk import ID.");
+impl_id!(BulkImportEntityId, "Type-safe bulk import entity ID.");
+
+/// The status of a bulk import or of an entity within it.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkImportStatus {
+    /// The import has been created.
+    #[serde(rename = "created")]
+    Created,
+    /// The import has started.
+    #[serde(rename = "started")]
+    Started,
+    /// The import has finished.
+    #[serde(rename = "finished")]
+    Finished,
+    /// The import timed out.
+    #[serde(rename = "timeout")]
+    Timeout,
+    /// The import failed.
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+/// A bulk import of groups and projects from another GitLab instance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BulkImport {
+    /// The ID of the bulk import.
+    pub id: BulkImportId,
+    /// The status of the bulk import.
+    pub status: BulkImportStatus,
+    /// The kind of source instance (e.g., `gitlab`).
+    pub source_type: String,
+    /// When the bulk import was created.
+    pub created_at: DateTime<Utc>,
+    /// When the bulk import was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// Whether any entity of the bulk import has failures.
+    pub has_failures: Option<bool>,
+}
+
+/// An entity (group or project) being imported as part of a bulk import.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BulkImportEntity {
+    /// The ID of the entity.
+    pub id: BulkImportEntityId,
+    /// The ID of the bulk import the entity is part of.
+    pub bulk_import_id: BulkImportId,
+    /// The status of the import of the entity.
+    pub status: BulkImportStatus,
+    /// The type of the entity (e.g., `group` or `project`).
+    pub entity_type: String,
+    /// The full path of the entity on the source instance.
+    pub source_full_path: String,
+    /// The full path of the entity on the destination instance.
+    pub destination_full_path: Option<String>,
+    /// The slug of the entity on the destination instance.
+    pub destination_slug: String,
+    /// The full path of the namespace the entity is imported into.
+    pub destination_namespace: String,
+    /// The ID of the parent entity within the bulk import.
+    pub parent_id: Option<BulkImportEntityId>,
+    /// The ID of the imported group.
+    pub namespace_id: Option<GroupId>,
+    /// The ID of the imported project.
+    pub project_id: Option<ProjectId>,
+    /// When the import of the entity was created.
+    pub created_at: DateTime<Utc>,
+    /// When the import of the entity was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// Failures which occurred while importing the entity.
+    #[serde(default)]
+    pub failures: Vec<Value>,
+    /// Whether the projects of a group are imported.
+    pub migrate_projects: Option<bool>,
+    /// Whether the import of the entity has failures.
+    pub has_failures: Option<bool>,
+}
+
+impl_id!(ClusterId, "Type-safe cluster ID.");
+
+/// The level a Kubernetes cluster is associated at.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterType {
+    /// The cluster is available to the entire instance.
+    #[serde(rename = "instance_type")]
+    Instance,
+    /// The cluster is available to a group.
+    #[serde(rename = "group_type")]
+    Group,
+    /// The cluster is available to a project.
+    #[serde(rename = "project_type")]
+    Project,
+}
+
+/// Connection information for the Kubernetes API of a cluster.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClusterPlatformKubernetes {
+    /// The URL of the Kubernetes API.
+    pub api_url: String,
+    /// The namespace used for deployments.
+    pub namespace: Option<String>,
+    /// The authorization type of the cluster (e.g., `rbac`).
+    pub authorization_type: Option<String>,
+    /// The TLS certificate of the Kubernetes API.
+    pub ca_cert: Option<String>,
+}
+
+/// A Kubernetes cluster integrated with GitLab.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Cluster {
+    /// The ID of the cluster.
+    pub id: ClusterId,
+    /// The name of the cluster.
+    pub name: String,
+    /// When the cluster was added.
+    pub created_at: DateTime<Utc>,
+    /// Whether the cluster is active.
+    pub enabled: bool,
+    /// Whether GitLab manages namespaces and service accounts for the cluster.
+    pub managed: bool,
+    /// The base domain of the cluster.
+    pub domain: Option<String>,
+    /// The provider of the cluster (e.g., `user` or `gcp`).
+    pub provider_type: String,
+    /// The platform of the cluster (e.g., `kubernetes`).
+    pub platform_type: String,
+    /// The environments the cluster applies to.
+    pub environment_scope: String,
+    /// The level the cluster is associated at.
+    pub cluster_type: ClusterType,
+    /// The user who added the cluster.
+    pub user: UserBasic,
+    /// Connection information for the Kubernetes API.
+    pub platform_kubernetes: Option<ClusterPlatformKubernetes>,
+}
+
+impl_id!(PushRuleId, "Type-safe push rule ID.");
+
+/// A push rule of a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupPushRule {
+    /// The ID of the push rule.
+    pub id: PushRuleId,
+    /// When the push rule was created.
+    pub created_at: DateTime<Utc>,
+    /// A regular expression all commit messages must match.
+    pub commit_message_regex: Option<String>,
+    /// A regular expression no commit message may match.
+    pub commit_message_negative_regex: Option<String>,
+    /// A regular expression all branch names must match.
+    pub branch_name_regex: Option<String>,
+    /// Whether deleting tags is denied.
+    pub deny_delete_tag: bool,
+    /// Whether commits must be authored by existing GitLab users.
+    pub member_check: bool,
+    /// Whether files which are likely to contain secrets are rejected.
+    pub prevent_secrets: bool,
+    /// A regular expression all commit author emails must match.
+    pub author_email_regex: Option<String>,
+    /// A regular expression no committed file name may match.
+    pub file_name_regex: Option<String>,
+    /// The maximum file size (in MiB) allowed in commits.
+    pub max_file_size: u64,
+    /// Whether commits must be committed by the pushing user.
+    pub commit_committer_check: Option<bool>,
+    /// Whether unsigned commits are rejected.
+    pub reject_unsigned_commits: Option<bool>,
+}
+
+/// Basic information about a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BasicGroupDetails {
+    /// The ID of the group.
+    pub id: GroupId,
+    /// The URL of the group's web page.
+    pub web_url: String,
+    /// The name of the group.
+    pub name: String,
+}
+
+impl_id!(EpicBoardId, "Type-safe epic board ID.");
+
+/// An epic board of a group.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EpicBoard {
+    /// The ID of the board.
+    pub id: EpicBoardId,
+    /// The name of the board.
+    pub name: String,
+    /// Whether the "Open" list is hidden.
+    pub hide_backlog_list: bool,
+    /// Whether the "Closed" list is hidden.
+    pub hide_closed_list: bool,
+    /// The group the board belongs to.
+    pub group: BasicGroupDetails,
+    /// The labels epics on the board are scoped to.
+    pub labels: Vec<EventLabel>,
+    /// The lists on the board.
+    pub lists: Vec<EpicBoardList>,
+}
+
+impl_id!(EpicBoardListId, "Type-safe epic board list ID.");
+
+/// The kind of a list on an epic board.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpicBoardListType {
+    /// The list of open epics not in any other list.
+    #[serde(rename = "backlog")]
+    Backlog,
+    /// The list of closed epics.
+    #[serde(rename = "closed")]
+    Closed,
+    /// The list of epics with a given label.
+    #[serde(rename = "label")]
+    Label,
+}
+
+/// A list on an epic board.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EpicBoardList {
+    /// The ID of the list.
+    pub id: EpicBoardListId,
+    /// The label of the list.
+    ///
+    /// Only set for label lists.
+    pub label: Option<EventLabel>,
+    /// The position of the list on the board.
+    ///
+    /// The backlog and closed lists do not have a position.
+    pub position: Option<u64>,
+    /// The kind of the list.
+    pub list_type: EpicBoardListType,
+}
+
+/// An OAuth2 access token.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OAuthToken {
+    /// The access token.
+    pub access_token: String,
+    /// The type of the token (usually `Bearer`).
+    pub token_type: String,
+    /// How long (in seconds) the access token is valid for.
+    pub expires_in: Option<u64>,
+    /// The token to use to obtain a new access token once it expires.
+    pub refresh_token: Option<String>,
+    /// The space-separated scopes granted to the token.
+    pub scope: Option<String>,
+    /// When the token was created (as a Unix timestamp).
+    pub created_at: Option<u64>,
+}
+
+/// A pending OAuth2 device authorization.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OAuthDeviceAuthorization {
+    /// The code to use to request the access token.
+    pub device_code: String,
+    /// The code the user should enter to authorize the device.
+    pub user_code: String,
+    /// Where the user should enter the code.
+    pub verification_uri: String,
+    /// The verification URI with the user code already filled in.
+    pub verification_uri_complete: Option<String>,
+    /// How long (in seconds) the codes are valid for.
+    pub expires_in: u64,
+    /// How long (in seconds) to wait between requests for the access token.
+    pub interval: Option<u64>,
+}
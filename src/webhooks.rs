@@ -17,10 +17,12 @@ use serde::de::{Error, Unexpected};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{self, Value};
 
+use crate::hooks::is_lenient;
 use crate::types::{
-    IssueId, IssueInternalId, IssueState, JobId, MergeRequestId, MergeRequestInternalId,
-    MergeRequestState, MergeStatus, MilestoneId, NoteId, NoteType, NoteableId, ObjectId,
-    PipelineId, ProjectId, RunnerId, SnippetId, StatusState, UserId,
+    AwardId, AwardableId, AwardableType, DeploymentId, IssueId, IssueInternalId, IssueState, JobId,
+    MergeRequestId, MergeRequestInternalId, MergeRequestState, MergeStatus, MilestoneId, NoteId,
+    NoteableId, ObjectId, PipelineId, ProjectId, ReleaseId, ReleaseLinkId, RunnerId, SnippetId,
+    StatusState, UserId,
 };
 
 /// A wrapper struct for dates in web hooks.
@@ -188,21 +190,27 @@ pub struct PushHook {
 }
 
 /// Actions which may occur on an issue.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IssueAction {
     /// The issue was updated.
-    #[serde(rename = "update")]
     Update,
     /// The issue was opened.
-    #[serde(rename = "open")]
     Open,
     /// The issue was closed.
-    #[serde(rename = "close")]
     Close,
     /// The issue was reopened.
-    #[serde(rename = "reopen")]
     Reopen,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(IssueAction,
+    Update => "update",
+    Open => "open",
+    Close => "close",
+    Reopen => "reopen",
+);
 
 /// Issue information exposed in hooks.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -274,30 +282,36 @@ pub struct IssueHook {
 }
 
 /// Actions which may occur on a merge request.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MergeRequestAction {
     /// The merge request was updated.
-    #[serde(rename = "update")]
     Update,
     /// The merge request was opened.
-    #[serde(rename = "open")]
     Open,
     /// The merge request was closed.
-    #[serde(rename = "close")]
     Close,
     /// The merge request was reopened.
-    #[serde(rename = "reopen")]
     Reopen,
     /// The merge request was approved.
-    #[serde(rename = "approved")]
     Approved,
     /// A merge request approval was revoked.
-    #[serde(rename = "unapproved")]
     Unapproved,
     /// The merge request was merged.
-    #[serde(rename = "merge")]
     Merge,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(MergeRequestAction,
+    Update => "update",
+    Open => "open",
+    Close => "close",
+    Reopen => "reopen",
+    Approved => "approved",
+    Unapproved => "unapproved",
+    Merge => "merge",
+);
 
 /// Merge parameters for a merge request.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -422,15 +436,21 @@ pub struct MergeRequestHook {
 }
 
 /// The type of a snippet.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SnippetType {
     /// A project-owned snippet.
-    #[serde(rename = "ProjectSnippet")]
     Project,
     /// A user-owned snippet.
-    #[serde(rename = "PersonalSnippet")]
     Personal,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(SnippetType,
+    Project => "ProjectSnippet",
+    Personal => "PersonalSnippet",
+);
 
 /// Snippet information exposed in hooks.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -457,15 +477,21 @@ pub struct SnippetHookAttrs {
 }
 
 /// Actions which may occur on a wiki page.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WikiPageAction {
     /// A wiki page was created.
-    #[serde(rename = "create")]
     Create,
     /// A wiki page was updated.
-    #[serde(rename = "update")]
     Update,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
+hook_enum_serialize!(WikiPageAction,
+    Create => "create",
+    Update => "update",
+);
 
 /// Wiki information exposed in hooks.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -522,6 +548,29 @@ pub struct PositionHookAttrs {
     pub new_path: String,
 }
 
+/// The type of entity a note is attached to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteableType {
+    /// A note on a commit.
+    Commit,
+    /// A note on an issue.
+    Issue,
+    /// A note on a merge request.
+    MergeRequest,
+    /// A note on a snippet.
+    Snippet,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(NoteableType,
+    Commit => "Commit",
+    Issue => "Issue",
+    MergeRequest => "MergeRequest",
+    Snippet => "Snippet",
+);
+
 /// Note (comment) information exposed in hooks.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct NoteHookAttrs {
@@ -530,7 +579,7 @@ pub struct NoteHookAttrs {
     /// The content of the note.
     pub note: String,
     /// The type of entity the note is attached to.
-    pub noteable_type: NoteType,
+    pub noteable_type: NoteableType,
     // pub original_position: Option<PositionHookAttrs>,
     original_position: Value,
     // pub position: Option<PositionHookAttrs>,
@@ -573,26 +622,27 @@ impl NoteHookAttrs {
     /// The ID of the object the note is for.
     pub fn noteable_id(&self) -> Option<NoteableId> {
         match self.noteable_type {
-            NoteType::Commit => {
+            NoteableType::Commit => {
                 self.noteable_id
                     .as_str()
                     .map(|id| NoteableId::Commit(ObjectId::new(id)))
             },
-            NoteType::Issue => {
+            NoteableType::Issue => {
                 self.noteable_id
                     .as_u64()
                     .map(|id| NoteableId::Issue(IssueId::new(id)))
             },
-            NoteType::MergeRequest => {
+            NoteableType::MergeRequest => {
                 self.noteable_id
                     .as_u64()
                     .map(|id| NoteableId::MergeRequest(MergeRequestId::new(id)))
             },
-            NoteType::Snippet => {
+            NoteableType::Snippet => {
                 self.noteable_id
                     .as_u64()
                     .map(|id| NoteableId::Snippet(SnippetId::new(id)))
             },
+            NoteableType::Unknown(_) => None,
         }
     }
 }
@@ -827,6 +877,256 @@ pub struct WikiPageHook {
     pub object_attributes: WikiPageHookAttrs,
 }
 
+/// The status of a deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentStatus {
+    /// The deployment has been created.
+    Created,
+    /// The deployment is running.
+    Running,
+    /// The deployment succeeded.
+    Success,
+    /// The deployment failed.
+    Failed,
+    /// The deployment was canceled.
+    Canceled,
+    /// The deployment was skipped.
+    Skipped,
+    /// The deployment is waiting for approval.
+    Blocked,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(DeploymentStatus,
+    Created => "created",
+    Running => "running",
+    Success => "success",
+    Failed => "failed",
+    Canceled => "canceled",
+    Skipped => "skipped",
+    Blocked => "blocked",
+);
+
+/// A deployment hook.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeploymentHook {
+    /// The event which occurred.
+    pub object_kind: String,
+    /// The status of the deployment.
+    pub status: DeploymentStatus,
+    /// When the status of the deployment changed.
+    pub status_changed_at: HookDate,
+    /// The ID of the deployment.
+    pub deployment_id: DeploymentId,
+    /// The ID of the job which performs the deployment.
+    pub deployable_id: JobId,
+    /// The URL of the job which performs the deployment.
+    pub deployable_url: String,
+    /// The name of the environment deployed to.
+    pub environment: String,
+    /// The tier of the environment deployed to.
+    pub environment_tier: Option<String>,
+    /// The slug of the environment deployed to.
+    pub environment_slug: Option<String>,
+    /// The external URL of the environment deployed to.
+    pub environment_external_url: Option<String>,
+    /// The project the deployment belongs to.
+    pub project: PipelineProjectAttrs,
+    /// The abbreviated object ID of the deployed commit.
+    pub short_sha: String,
+    /// The user who started the deployment.
+    pub user: UserHookAttrs,
+    /// The URL of the user who started the deployment.
+    pub user_url: String,
+    /// The URL of the deployed commit.
+    pub commit_url: String,
+    /// The title of the deployed commit.
+    pub commit_title: String,
+}
+
+/// Actions which may occur on a release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReleaseAction {
+    /// The release was created.
+    Create,
+    /// The release was updated.
+    Update,
+    /// The release was deleted.
+    Delete,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(ReleaseAction,
+    Create => "create",
+    Update => "update",
+    Delete => "delete",
+);
+
+/// The kind of an asset link of a release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReleaseLinkType {
+    /// A link to another resource.
+    Other,
+    /// A link to a runbook.
+    Runbook,
+    /// A link to an image.
+    Image,
+    /// A link to a package.
+    Package,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(ReleaseLinkType,
+    Other => "other",
+    Runbook => "runbook",
+    Image => "image",
+    Package => "package",
+);
+
+/// An asset link of a release exposed in hooks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseLinkHookAttrs {
+    /// The ID of the link.
+    pub id: ReleaseLinkId,
+    /// Whether the link points outside of the instance.
+    pub external: Option<bool>,
+    /// The kind of the link.
+    pub link_type: ReleaseLinkType,
+    /// The name of the link.
+    pub name: String,
+    /// The URL of the link.
+    pub url: String,
+}
+
+/// A source code archive of a release exposed in hooks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseSourceHookAttrs {
+    /// The format of the archive (e.g., `zip` or `tar.gz`).
+    pub format: String,
+    /// The URL of the archive.
+    pub url: String,
+}
+
+/// The assets of a release exposed in hooks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseAssetsHookAttrs {
+    /// The total number of assets.
+    pub count: u64,
+    /// The links attached to the release.
+    pub links: Vec<ReleaseLinkHookAttrs>,
+    /// The source code archives of the release.
+    pub sources: Vec<ReleaseSourceHookAttrs>,
+}
+
+/// A release hook.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseHook {
+    /// The event which occurred.
+    pub object_kind: String,
+    /// The action which occurred on the release.
+    pub action: ReleaseAction,
+    /// The ID of the release.
+    pub id: ReleaseId,
+    /// The name of the release.
+    pub name: String,
+    /// The name of the tag of the release.
+    pub tag: String,
+    /// The description of the release.
+    pub description: Option<String>,
+    /// The URL of the release.
+    pub url: String,
+    /// When the release was created.
+    pub created_at: HookDate,
+    /// When the release was (or will be) published.
+    pub released_at: HookDate,
+    /// The project the release belongs to.
+    pub project: PipelineProjectAttrs,
+    /// The assets of the release.
+    pub assets: ReleaseAssetsHookAttrs,
+    /// The commit the tag of the release points to.
+    pub commit: CommitHookAttrs,
+}
+
+/// Actions which may occur for an emoji award.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmojiAction {
+    /// The emoji was awarded.
+    Award,
+    /// The emoji award was removed.
+    Revoke,
+    /// An unrecognized value.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
+}
+hook_enum_serialize!(EmojiAction,
+    Award => "award",
+    Revoke => "revoke",
+);
+
+/// Emoji award information exposed in hooks.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EmojiHookAttrs {
+    /// The ID of the award.
+    pub id: AwardId,
+    /// The name of the awarded emoji.
+    pub name: String,
+    /// The ID of the user who awarded the emoji.
+    pub user_id: UserId,
+    /// The type of entity that is awarded.
+    pub awardable_type: AwardableType,
+    awardable_id: u64,
+    /// When the award was created.
+    pub created_at: HookDate,
+    /// When the award was last updated.
+    pub updated_at: HookDate,
+}
+
+impl EmojiHookAttrs {
+    /// The ID of the entity the award is attached to.
+    pub fn awardable_id(&self) -> AwardableId {
+        match self.awardable_type {
+            AwardableType::Issue => AwardableId::Issue(IssueId::new(self.awardable_id)),
+            AwardableType::MergeRequest => {
+                AwardableId::MergeRequest(MergeRequestId::new(self.awardable_id))
+            },
+            AwardableType::Snippet => AwardableId::Snippet(SnippetId::new(self.awardable_id)),
+            AwardableType::Note => AwardableId::Note(NoteId::new(self.awardable_id)),
+        }
+    }
+}
+
+/// An emoji hook.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EmojiHook {
+    /// The event which occurred.
+    pub object_kind: String,
+    /// Whether the emoji was awarded or revoked.
+    pub event_type: EmojiAction,
+    /// The user who awarded the emoji.
+    pub user: UserHookAttrs,
+    /// The ID of the project the awarded entity belongs to.
+    pub project_id: ProjectId,
+    /// The project the awarded entity belongs to.
+    pub project: ProjectHookAttrs,
+    /// The attributes of the award itself.
+    pub object_attributes: EmojiHookAttrs,
+    /// The issue which was awarded (or the issue of an awarded note).
+    pub issue: Option<IssueHookAttrs>,
+    /// The merge request which was awarded (or the merge request of an awarded note).
+    pub merge_request: Option<MergeRequestHookAttrs>,
+    /// The note which was awarded.
+    pub note: Option<NoteHookAttrs>,
+    /// The snippet which was awarded (or the snippet of an awarded note).
+    pub snippet: Option<SnippetHookAttrs>,
+}
+
 /// A deserializable structure for all Gitlab web hooks.
 #[derive(Debug, Clone)]
 pub enum WebHook {
@@ -844,6 +1144,16 @@ pub enum WebHook {
     Pipeline(Box<PipelineHook>),
     /// A wiki page hook.
     WikiPage(Box<WikiPageHook>),
+    /// A deployment hook.
+    Deployment(Box<DeploymentHook>),
+    /// A release hook.
+    Release(Box<ReleaseHook>),
+    /// An emoji hook.
+    Emoji(Box<EmojiHook>),
+    /// A hook with an unrecognized object kind.
+    ///
+    /// Only produced when deserializing through `hooks::Lenient`.
+    Unknown(String),
 }
 
 impl<'de> Deserialize<'de> for WebHook {
@@ -883,6 +1193,18 @@ impl<'de> Deserialize<'de> for WebHook {
 
             "pipeline" => serde_json::from_value(val).map(|hook| WebHook::Pipeline(Box::new(hook))),
 
+            "deployment" => {
+                serde_json::from_value(val).map(|hook| WebHook::Deployment(Box::new(hook)))
+            },
+
+            "release" => serde_json::from_value(val).map(|hook| WebHook::Release(Box::new(hook))),
+
+            "emoji" => serde_json::from_value(val).map(|hook| WebHook::Emoji(Box::new(hook))),
+
+            _ if is_lenient() => {
+                return Ok(WebHook::Unknown(object_kind.clone()));
+            },
+
             _ => {
                 return Err(D::Error::invalid_value(
                     Unexpected::Other("object kind"),